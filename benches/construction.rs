@@ -0,0 +1,37 @@
+use std::convert::TryFrom;
+
+use criterion::{ criterion_group, criterion_main, Criterion };
+use gamma::bench::{ path, random };
+use gamma::graph::{ Graph, DefaultGraph, CsrGraph };
+
+fn construction(c: &mut Criterion) {
+    let inputs = vec![
+        ("path", path(10_000)),
+        ("random", random(10_000, 30_000, 1))
+    ];
+    let mut group = c.benchmark_group("construction");
+
+    for (name, graph) in &inputs {
+        let edges = graph.edges().collect::<Vec<_>>();
+        let adjacency = graph.ids()
+            .map(|id| graph.neighbors(id).expect("neighbors").collect())
+            .collect::<Vec<Vec<_>>>();
+
+        group.bench_function(format!("default_edges/{}", name), |b| {
+            b.iter(|| DefaultGraph::try_from(edges.clone()).expect("graph"))
+        });
+        group.bench_function(format!("default_adjacency/{}", name), |b| {
+            b.iter(|| {
+                DefaultGraph::try_from(adjacency.clone()).expect("graph")
+            })
+        });
+        group.bench_function(format!("csr_edges/{}", name), |b| {
+            b.iter(|| CsrGraph::try_from(edges.clone()).expect("graph"))
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(benches, construction);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+use criterion::{ criterion_group, criterion_main, Criterion };
+use gamma::bench::{ path, cycle, fullerene, random };
+use gamma::matching::{ greedy, maximum_matching, Pairing };
+
+fn matching(c: &mut Criterion) {
+    let inputs = vec![
+        ("path", path(1_000)),
+        ("cycle", cycle(1_001)),
+        ("fullerene", fullerene()),
+        ("random", random(1_000, 2_000, 1))
+    ];
+    let mut group = c.benchmark_group("matching");
+
+    for (name, graph) in &inputs {
+        group.bench_function(format!("greedy/{}", name), |b| {
+            b.iter(|| greedy(graph))
+        });
+        group.bench_function(format!("maximum/{}", name), |b| {
+            b.iter(|| {
+                let mut pairing = Pairing::new();
+
+                maximum_matching(graph, &mut pairing);
+
+                pairing
+            })
+        });
+        group.bench_function(format!("greedy_then_maximum/{}", name), |b| {
+            b.iter(|| {
+                let mut pairing = greedy(graph);
+
+                maximum_matching(graph, &mut pairing);
+
+                pairing
+            })
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(benches, matching);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+use criterion::{ criterion_group, criterion_main, Criterion };
+use gamma::bench::{ cycle, fullerene, random };
+use gamma::matching::{ maximum_matching, Pairing };
+
+// Ids are the same with and without the pool feature, so a baseline saved
+// from one build can be compared against the other. See the README.
+fn pool(c: &mut Criterion) {
+    let inputs = vec![
+        ("cycle", cycle(1_001)),
+        ("fullerene", fullerene()),
+        ("random", random(1_000, 2_000, 1)),
+        ("random_dense", random(500, 5_000, 2))
+    ];
+    let mut group = c.benchmark_group("pool");
+
+    for (name, graph) in &inputs {
+        group.bench_function(format!("maximum/{}", name), |b| {
+            b.iter(|| {
+                let mut pairing = Pairing::new();
+
+                maximum_matching(graph, &mut pairing);
+
+                pairing
+            })
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(benches, pool);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+use criterion::{ criterion_group, criterion_main, Criterion };
+use gamma::bench::{ path, cycle, random };
+use gamma::traversal::{ DepthFirst, BreadthFirst };
+use gamma::selection::components;
+
+fn traversal(c: &mut Criterion) {
+    let inputs = vec![
+        ("path", path(10_000)),
+        ("cycle", cycle(10_000)),
+        ("random", random(10_000, 30_000, 1))
+    ];
+    let mut group = c.benchmark_group("traversal");
+
+    for (name, graph) in &inputs {
+        group.bench_function(format!("depth_first/{}", name), |b| {
+            b.iter(|| DepthFirst::new(graph, 0).expect("root").count())
+        });
+        group.bench_function(format!("breadth_first/{}", name), |b| {
+            b.iter(|| BreadthFirst::new(graph, 0).expect("root").count())
+        });
+        group.bench_function(format!("components/{}", name), |b| {
+            b.iter(|| components(graph).count())
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(benches, traversal);
+criterion_main!(benches);
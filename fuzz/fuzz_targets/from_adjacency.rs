@@ -0,0 +1,30 @@
+#![no_main]
+
+use std::convert::TryFrom;
+use libfuzzer_sys::fuzz_target;
+use gamma::graph::{ Graph, DefaultGraph, CsrGraph };
+
+// The first byte sets the order. Each following pair of bytes appends a
+// neighbor, which may be out of range, to one adjacency list.
+fuzz_target!(|data: &[u8]| {
+    let (&order, rest) = match data.split_first() {
+        Some(split) => split,
+        None => return
+    };
+    let order = order as usize % 32 + 1;
+    let mut adjacency = vec![ Vec::new(); order ];
+
+    for pair in rest.chunks_exact(2) {
+        adjacency[pair[0] as usize % order].push(pair[1] as usize % 40);
+    }
+
+    let default = DefaultGraph::try_from(adjacency.clone());
+    let csr = CsrGraph::try_from(adjacency);
+
+    assert_eq!(default.is_ok(), csr.is_ok());
+
+    if let (Ok(default), Ok(csr)) = (default, csr) {
+        assert_eq!(default.order(), csr.order());
+        assert_eq!(default.size(), csr.size());
+    }
+});
@@ -0,0 +1,40 @@
+#![no_main]
+
+use std::convert::TryFrom;
+use libfuzzer_sys::fuzz_target;
+use gamma::graph::{ Graph, DefaultGraph, CsrGraph, MultiGraph };
+use gamma::traversal::{ BreadthFirst, DepthFirst, Step };
+
+// Arbitrary Steps, three bytes each, must be rejected with an Error rather
+// than a panic. The same bytes, read as edges, also seed real traversals.
+fuzz_target!(|data: &[u8]| {
+    let steps = || data.chunks_exact(3).map(|step| {
+        let (sid, tid) = (step[0] as usize % 16, step[1] as usize % 16);
+
+        Step::new(sid, tid, step[2] & 1 == 1)
+    });
+
+    let _ = DefaultGraph::from_steps(steps());
+    let _ = CsrGraph::from_steps(steps());
+    let _ = MultiGraph::from_steps(steps());
+
+    let edges = data.chunks_exact(2)
+        .map(|edge| (edge[0] as usize % 16, edge[1] as usize % 16))
+        .collect::<Vec<_>>();
+    let graph = match DefaultGraph::try_from(edges) {
+        Ok(graph) => graph,
+        Err(_) => return
+    };
+
+    for root in graph.ids() {
+        let depth = DefaultGraph::try_from(
+            DepthFirst::new(&graph, root).expect("root")
+        ).expect("depth-first graph");
+        let breadth = DefaultGraph::try_from(
+            BreadthFirst::new(&graph, root).expect("root")
+        ).expect("breadth-first graph");
+
+        assert!(depth.size() <= graph.size());
+        assert!(breadth.size() <= graph.size());
+    }
+});
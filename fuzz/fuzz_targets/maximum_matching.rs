@@ -0,0 +1,40 @@
+#![no_main]
+
+use std::collections::HashSet;
+use libfuzzer_sys::fuzz_target;
+use gamma::graph::{ Graph, DefaultGraph };
+use gamma::matching::{ greedy, maximum_matching };
+
+// Pairs of bytes are edges. Self-loops and repeats are skipped so that
+// every input yields a graph to match.
+fuzz_target!(|data: &[u8]| {
+    let mut graph = DefaultGraph::new();
+
+    for edge in data.chunks_exact(2) {
+        let (sid, tid) = (edge[0] as usize % 64, edge[1] as usize % 64);
+
+        for &id in &[ sid, tid ] {
+            if !graph.has_id(id) {
+                graph.add_node(id).expect("add node");
+            }
+        }
+
+        if sid != tid && !graph.has_edge(sid, tid).expect("has edge") {
+            graph.add_edge(sid, tid).expect("add edge");
+        }
+    }
+
+    let mut pairing = greedy(&graph);
+    let greedy_order = pairing.order();
+
+    maximum_matching(&graph, &mut pairing);
+
+    let mut matched = HashSet::new();
+
+    assert!(pairing.order() >= greedy_order);
+
+    for (sid, tid) in pairing.edges() {
+        assert!(graph.has_edge(sid, tid).expect("has edge"));
+        assert!(matched.insert(sid) && matched.insert(tid));
+    }
+});
@@ -0,0 +1,44 @@
+#![no_main]
+
+use std::io::Cursor;
+use libfuzzer_sys::fuzz_target;
+use gamma::io::{
+    parse_adjacency, read_pajek, read_csv, read_matrix_market,
+    read_weighted_matrix_market, read_binary, read_weighted_binary,
+    read_csr, from_node_link_json, CsvOptions
+};
+
+// The first byte selects a reader, which is given the rest of the input.
+fuzz_target!(|data: &[u8]| {
+    let (&selector, input) = match data.split_first() {
+        Some(split) => split,
+        None => return
+    };
+    let text = String::from_utf8_lossy(input);
+
+    match selector % 7 {
+        0 => {
+            let _ = read_binary(input);
+            let _ = read_weighted_binary(input);
+        },
+        1 => {
+            let _ = parse_adjacency(&text);
+        },
+        2 => {
+            let _ = read_pajek(input);
+        },
+        3 => {
+            let _ = read_csv(input, &CsvOptions::default());
+        },
+        4 => {
+            let _ = read_matrix_market(input);
+            let _ = read_weighted_matrix_market(input);
+        },
+        5 => {
+            let _ = read_csr(Cursor::new(input));
+        },
+        _ => {
+            let _ = from_node_link_json(&text);
+        }
+    }
+});
@@ -0,0 +1,147 @@
+//! Graph generators shared by the benchmarks in `benches`. Enabled by the
+//! `bench-support` feature.
+
+use std::collections::HashSet;
+
+use crate::graph::DefaultGraph;
+use crate::rng::Rng;
+
+/// Returns a path over ids 0..order.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::bench::path;
+///
+/// let graph = path(3);
+///
+/// assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+/// ```
+pub fn path(order: usize) -> DefaultGraph {
+    let mut result = nodes(order);
+
+    for id in 1..order {
+        result.add_edge(id - 1, id).expect("add edge");
+    }
+
+    result
+}
+
+/// Returns a cycle over ids 0..order. Orders below three give a path.
+pub fn cycle(order: usize) -> DefaultGraph {
+    let mut result = path(order);
+
+    if order > 2 {
+        result.add_edge(order - 1, 0).expect("add edge");
+    }
+
+    result
+}
+
+/// Returns buckminsterfullerene (C60), a 3-regular graph of 60 nodes with
+/// a perfect matching.
+pub fn fullerene() -> DefaultGraph {
+    let mut result = nodes(60);
+
+    for &(sid, tid) in C60.iter() {
+        result.add_edge(sid, tid).expect("add edge");
+    }
+
+    result
+}
+
+/// Returns a graph over ids 0..order with size edges chosen uniformly at
+/// random, or every edge if size exceeds the number of node pairs. The
+/// same seed always gives the same graph.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::bench::random;
+///
+/// let graph = random(100, 250, 7);
+///
+/// assert_eq!(graph.order(), 100);
+/// assert_eq!(graph.size(), 250);
+/// assert_eq!(graph, random(100, 250, 7));
+/// ```
+pub fn random(order: usize, size: usize, seed: u64) -> DefaultGraph {
+    let mut result = nodes(order);
+    let mut rng = Rng::new(seed);
+    let mut edges = HashSet::new();
+    let size = size.min(order * order.saturating_sub(1) / 2);
+
+    while edges.len() < size {
+        let sid = rng.below(order);
+        let tid = rng.below(order);
+
+        if sid != tid && edges.insert((sid.min(tid), sid.max(tid))) {
+            result.add_edge(sid, tid).expect("add edge");
+        }
+    }
+
+    result
+}
+
+fn nodes(order: usize) -> DefaultGraph {
+    let mut result = DefaultGraph::with_capacity(order, order);
+
+    for id in 0..order {
+        result.add_node(id).expect("add node");
+    }
+
+    result
+}
+
+const C60: [(usize, usize); 90] = [
+    (29, 30), (30, 43), (43, 44), (44, 55), (55, 29),
+    (29, 28), (31, 30), (43, 42), (44, 45), (55, 54),
+    (28, 57), (57, 56), (56, 31), (31, 32), (32, 33),
+    (33, 42), (42, 41), (41, 40), (40, 45), (45, 46),
+    (46, 47), (47, 54), (54, 26), (26, 27), (27, 28),
+    (57, 7),  (56, 4),  (32, 3),  (33, 34), (41, 36),
+    (40, 39), (46, 51), (47, 48), (26, 25), (27, 8),
+    (7, 6),   (6, 5),   (5, 4),   (4, 3),   (3, 2),
+    (2, 35),  (35, 34), (34, 36), (36, 37), (37, 38),
+    (38, 39), (39, 51), (51, 50), (50, 49), (49, 48),
+    (48, 25), (25, 24), (24, 9),  (9, 8),   (8, 7),
+    (6, 11),  (5, 0),   (2, 1),   (35, 16), (37, 17),
+    (38, 53), (50, 52), (49, 22), (24, 23), (9, 10),
+    (11, 12), (12, 0),  (0, 1),   (1, 15),  (15, 16),
+    (16, 17), (17, 18), (18, 53), (53, 52), (52, 21),
+    (21, 22), (22, 23), (23, 58), (58, 10), (10, 11),
+    (12, 13), (15, 14), (18, 19), (21, 20), (58, 59),
+    (13, 14), (14, 19), (19, 20), (20, 59), (59, 13)
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn cycle_of_two() {
+        assert_eq!(cycle(2).size(), 1)
+    }
+
+    #[test]
+    fn cycle_of_five() {
+        let graph = cycle(5);
+        let regular = graph.ids().all(|id| graph.degree(id) == Ok(2));
+
+        assert!(regular)
+    }
+
+    #[test]
+    fn fullerene_is_cubic() {
+        let graph = fullerene();
+
+        let cubic = graph.ids().all(|id| graph.degree(id) == Ok(3));
+
+        assert_eq!(graph.size(), 90);
+        assert!(cubic)
+    }
+
+    #[test]
+    fn random_saturated() {
+        assert_eq!(random(5, 100, 1).size(), 10)
+    }
+}
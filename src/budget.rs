@@ -0,0 +1,274 @@
+//! Limits on long-running computations. A Budget is checked at intervals
+//! by algorithms that accept one, which stop early with Interrupted once it
+//! is spent. Recursive searches also report their depth, so a Budget can
+//! bound stack use as well as time.
+
+use std::error;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::time::{ Duration, Instant };
+
+/// A limit on computation by cancellation, deadline, step count, and
+/// recursion depth.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use gamma::budget::{ Budget, Interrupted };
+///
+/// let mut budget = Budget::unlimited()
+///     .with_timeout(Duration::from_secs(60))
+///     .with_steps(2);
+/// let token = budget.token();
+///
+/// assert_eq!(budget.check(), Ok(()));
+///
+/// token.cancel();
+///
+/// assert_eq!(budget.check(), Err(Interrupted::Cancelled));
+/// ```
+#[derive(Debug,Clone)]
+pub struct Budget {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+    steps: Option<usize>,
+    depth: usize,
+    max_depth: Option<usize>
+}
+
+impl Budget {
+    /// Returns a Budget that is only spent if cancelled.
+    pub fn unlimited() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            deadline: None,
+            steps: None,
+            depth: 0,
+            max_depth: None
+        }
+    }
+
+    /// Returns this Budget, spent once timeout has elapsed from now.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+
+        self
+    }
+
+    /// Returns this Budget, spent after steps checks.
+    pub fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = Some(steps);
+
+        self
+    }
+
+    /// Returns this Budget, spent once a search recurses more than
+    /// max_depth levels.
+    pub fn with_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+
+        self
+    }
+
+    /// Returns a token that cancels this Budget, for use from another
+    /// thread.
+    pub fn token(&self) -> CancellationToken {
+        CancellationToken { cancelled: self.cancelled.clone() }
+    }
+
+    /// Records one step. Returns Interrupted if the Budget is spent.
+    pub fn check(&mut self) -> Result<(), Interrupted> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(Interrupted::Cancelled);
+        }
+
+        if let Some(steps) = self.steps.as_mut() {
+            if *steps == 0 {
+                return Err(Interrupted::OutOfSteps);
+            }
+
+            *steps -= 1;
+        }
+
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                Err(Interrupted::TimedOut)
+            },
+            _ => Ok(())
+        }
+    }
+
+    /// Records one step, then runs search one level deeper. Returns
+    /// Interrupted if the Budget is spent or the depth limit is reached,
+    /// in which case search is not run.
+    ///
+    /// ```rust
+    /// use gamma::budget::{ Budget, Interrupted };
+    ///
+    /// fn descend(budget: &mut Budget) -> Result<(), Interrupted> {
+    ///     budget.descend(descend)
+    /// }
+    ///
+    /// let mut budget = Budget::unlimited().with_depth(1000);
+    ///
+    /// assert_eq!(descend(&mut budget), Err(Interrupted::LimitExceeded));
+    /// ```
+    pub fn descend<T, F>(&mut self, search: F) -> Result<T, Interrupted>
+    where F: FnOnce(&mut Self) -> Result<T, Interrupted> {
+        self.enter()?;
+
+        let result = search(self);
+
+        self.leave();
+
+        result
+    }
+
+    /// Records one step and one level of depth, for searches that keep
+    /// their own stack rather than recursing. Each successful enter must be
+    /// matched by a leave.
+    pub(crate) fn enter(&mut self) -> Result<(), Interrupted> {
+        if let Some(max_depth) = self.max_depth {
+            if self.depth >= max_depth {
+                return Err(Interrupted::LimitExceeded);
+            }
+        }
+
+        self.check()?;
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    /// Releases one level of depth recorded by enter.
+    pub(crate) fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Cancels the Budget it was taken from.
+#[derive(Debug,Clone)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>
+}
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// The reason a computation stopped before completion.
+#[derive(Debug,PartialEq,Eq,Clone,Copy)]
+pub enum Interrupted {
+    Cancelled,
+    TimedOut,
+    OutOfSteps,
+    LimitExceeded
+}
+
+impl fmt::Display for Interrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Interrupted::Cancelled => write!(f, "cancelled"),
+            Interrupted::TimedOut => write!(f, "timed out"),
+            Interrupted::OutOfSteps => write!(f, "out of steps"),
+            Interrupted::LimitExceeded => write!(f, "depth limit exceeded")
+        }
+    }
+}
+
+impl error::Error for Interrupted { }
+
+#[cfg(test)]
+mod check {
+    use super::*;
+
+    #[test]
+    fn unlimited() {
+        let mut budget = Budget::unlimited();
+
+        for _ in 0..1000 {
+            assert_eq!(budget.check(), Ok(()))
+        }
+    }
+
+    #[test]
+    fn steps() {
+        let mut budget = Budget::unlimited().with_steps(1);
+
+        assert_eq!(budget.check(), Ok(()));
+        assert_eq!(budget.check(), Err(Interrupted::OutOfSteps))
+    }
+
+    #[test]
+    fn timeout() {
+        let mut budget = Budget::unlimited().with_timeout(Duration::from_secs(0));
+
+        assert_eq!(budget.check(), Err(Interrupted::TimedOut))
+    }
+
+    #[test]
+    fn cancelled_from_thread() {
+        let mut budget = Budget::unlimited();
+        let token = budget.token();
+
+        std::thread::spawn(move || token.cancel()).join().unwrap();
+
+        assert_eq!(budget.check(), Err(Interrupted::Cancelled))
+    }
+}
+
+#[cfg(test)]
+mod descend {
+    use super::*;
+
+    fn nest(budget: &mut Budget, levels: usize) -> Result<usize, Interrupted> {
+        if levels == 0 {
+            Ok(0)
+        } else {
+            budget.descend(|budget| nest(budget, levels - 1)).map(|d| d + 1)
+        }
+    }
+
+    #[test]
+    fn within_limit() {
+        let mut budget = Budget::unlimited().with_depth(3);
+
+        assert_eq!(nest(&mut budget, 3), Ok(3));
+        assert_eq!(nest(&mut budget, 3), Ok(3))
+    }
+
+    #[test]
+    fn limit_exceeded() {
+        let mut budget = Budget::unlimited().with_depth(3);
+
+        assert_eq!(nest(&mut budget, 4), Err(Interrupted::LimitExceeded));
+        assert_eq!(nest(&mut budget, 2), Ok(2))
+    }
+
+    #[test]
+    fn counts_steps() {
+        let mut budget = Budget::unlimited().with_steps(2);
+
+        assert_eq!(nest(&mut budget, 3), Err(Interrupted::OutOfSteps))
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Interrupted::LimitExceeded.to_string(),
+            "depth limit exceeded"
+        )
+    }
+}
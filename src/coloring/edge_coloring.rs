@@ -0,0 +1,360 @@
+use std::collections::{ HashMap, VecDeque };
+use std::collections::hash_map::Entry;
+
+use crate::graph::{ Graph, Error };
+
+/// Returns a color for each edge, in the order of `graph.edges()`, such
+/// that edges sharing a node have different colors. Colors are numbered
+/// from zero. If the maximum degree is Δ, at most Δ + 1 colors are used
+/// (Misra and Gries' construction of Vizing's theorem). A bipartite graph
+/// is colored optimally with Δ colors by swapping alternating paths.
+///
+/// Returns Error if graph contains parallel edges.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::coloring::edge_coloring;
+///
+/// fn main() -> Result<(), Error> {
+///     let c4 = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 0)
+///     ])?;
+///
+///     assert_eq!(edge_coloring(&c4)?, vec![ 0, 1, 0, 1 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn edge_coloring<G: Graph>(graph: &G) -> Result<Vec<usize>, Error> {
+    let mut coloring = Coloring::new(graph);
+    let edges = graph.edges().collect::<Vec<_>>();
+
+    for &(sid, tid) in &edges {
+        if coloring.color(sid, tid).is_some() {
+            return Err(Error::DuplicateEdge(sid, tid));
+        }
+
+        if coloring.bipartite {
+            coloring.add_bipartite(sid, tid);
+        } else {
+            coloring.add_fan(sid, tid, graph);
+        }
+    }
+
+    Ok(edges.iter().map(|&(sid, tid)| {
+        coloring.color(sid, tid).expect("color")
+    }).collect())
+}
+
+struct Coloring {
+    // for each id, the neighbor reached through each color
+    colors: HashMap<usize, HashMap<usize, usize>>,
+    max_degree: usize,
+    bipartite: bool
+}
+
+impl Coloring {
+    fn new<G: Graph>(graph: &G) -> Self {
+        Self {
+            colors: graph.ids().map(|id| (id, HashMap::new())).collect(),
+            max_degree: graph.ids()
+                .map(|id| graph.degree(id).expect("degree"))
+                .max()
+                .unwrap_or(0),
+            bipartite: is_bipartite(graph)
+        }
+    }
+
+    fn color(&self, sid: usize, tid: usize) -> Option<usize> {
+        self.colors[&sid].iter()
+            .find(|&(_, &neighbor)| neighbor == tid)
+            .map(|(&color, _)| color)
+    }
+
+    fn is_free(&self, id: usize, color: usize) -> bool {
+        !self.colors[&id].contains_key(&color)
+    }
+
+    fn free_color(&self, id: usize) -> usize {
+        (0..=self.max_degree)
+            .find(|&color| self.is_free(id, color))
+            .expect("free color")
+    }
+
+    fn set(&mut self, sid: usize, tid: usize, color: usize) {
+        self.colors.get_mut(&sid).expect("colors").insert(color, tid);
+        self.colors.get_mut(&tid).expect("colors").insert(color, sid);
+    }
+
+    fn unset(&mut self, sid: usize, tid: usize, color: usize) {
+        self.colors.get_mut(&sid).expect("colors").remove(&color);
+        self.colors.get_mut(&tid).expect("colors").remove(&color);
+    }
+
+    // Swaps colors first and second along the path that leaves root
+    // through first.
+    fn invert(&mut self, root: usize, first: usize, second: usize) {
+        let mut path = Vec::new();
+        let mut id = root;
+        let mut color = first;
+
+        while let Some(&next) = self.colors[&id].get(&color) {
+            path.push((id, next, color));
+
+            id = next;
+            color = if color == first { second } else { first };
+        }
+
+        for &(sid, tid, color) in &path {
+            self.unset(sid, tid, color);
+        }
+
+        for &(sid, tid, color) in &path {
+            self.set(sid, tid, if color == first { second } else { first });
+        }
+    }
+
+    fn add_bipartite(&mut self, sid: usize, tid: usize) {
+        let first = (0..self.max_degree)
+            .find(|&color| self.is_free(sid, color))
+            .expect("free color");
+        let second = (0..self.max_degree)
+            .find(|&color| self.is_free(tid, color))
+            .expect("free color");
+
+        if !self.is_free(tid, first) {
+            self.invert(tid, first, second);
+        }
+
+        self.set(sid, tid, first);
+    }
+
+    fn add_fan<G: Graph>(&mut self, root: usize, tid: usize, graph: &G) {
+        let fan = self.fan(root, tid, graph);
+        let c = self.free_color(root);
+        let d = self.free_color(*fan.last().expect("fan"));
+
+        self.invert(root, d, c);
+
+        let mut end = 0;
+
+        for i in 0..fan.len() {
+            if i > 0 {
+                match self.color(root, fan[i]) {
+                    Some(color) if self.is_free(fan[i - 1], color) => (),
+                    _ => break
+                }
+            }
+
+            if self.is_free(fan[i], d) {
+                end = i;
+
+                break;
+            }
+        }
+
+        for i in 0..end {
+            let color = self.color(root, fan[i + 1]).expect("fan color");
+
+            self.unset(root, fan[i + 1], color);
+            self.set(root, fan[i], color);
+        }
+
+        self.set(root, fan[end], d);
+    }
+
+    // Returns a maximal fan of root starting with the uncolored edge to
+    // tid: each later neighbor is joined to root by a color free on the
+    // neighbor before it.
+    fn fan<G: Graph>(&self, root: usize, tid: usize, graph: &G) -> Vec<usize> {
+        let mut result = vec![ tid ];
+
+        loop {
+            let last = *result.last().expect("fan");
+            let next = graph.neighbors(root).expect("neighbors").find(|&id| {
+                !result.contains(&id) && match self.color(root, id) {
+                    Some(color) => self.is_free(last, color),
+                    None => false
+                }
+            });
+
+            match next {
+                Some(id) => result.push(id),
+                None => break result
+            }
+        }
+    }
+}
+
+fn is_bipartite<G: Graph>(graph: &G) -> bool {
+    let mut sides = HashMap::new();
+
+    for root in graph.ids() {
+        if sides.contains_key(&root) {
+            continue;
+        }
+
+        let mut queue = VecDeque::new();
+
+        sides.insert(root, true);
+        queue.push_back(root);
+
+        while let Some(id) = queue.pop_front() {
+            let side = sides[&id];
+
+            for neighbor in graph.neighbors(id).expect("neighbors") {
+                match sides.entry(neighbor) {
+                    Entry::Occupied(entry) => if *entry.get() == side {
+                        return false;
+                    },
+                    Entry::Vacant(entry) => {
+                        entry.insert(!side);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod edge_coloring {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::{ DefaultGraph, MultiGraph };
+
+    fn check<G: Graph>(graph: &G, colors: &[usize], count: usize) {
+        let edges = graph.edges().collect::<Vec<_>>();
+
+        assert_eq!(colors.len(), edges.len());
+        assert!(colors.iter().all(|&color| color < count));
+
+        for i in 0..edges.len() {
+            for j in (i + 1)..edges.len() {
+                let (a, b) = edges[i];
+                let (c, d) = edges[j];
+
+                if a == c || a == d || b == c || b == d {
+                    assert_ne!(colors[i], colors[j])
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(edge_coloring(&graph), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn parallel_edges() {
+        let mut graph = MultiGraph::new();
+
+        graph.add_node(0).unwrap();
+        graph.add_node(1).unwrap();
+        graph.add_edge(0, 1).unwrap();
+        graph.add_edge(0, 1).unwrap();
+
+        assert_eq!(edge_coloring(&graph), Err(Error::DuplicateEdge(0, 1)))
+    }
+
+    #[test]
+    fn c5() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+        ]).unwrap();
+
+        check(&graph, &edge_coloring(&graph).unwrap(), 3)
+    }
+
+    #[test]
+    fn k4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+        ]).unwrap();
+
+        check(&graph, &edge_coloring(&graph).unwrap(), 4)
+    }
+
+    #[test]
+    fn petersen() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+            (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+            (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+        ]).unwrap();
+
+        check(&graph, &edge_coloring(&graph).unwrap(), 4)
+    }
+
+    #[test]
+    fn k7() {
+        let mut edges = Vec::new();
+
+        for i in 0..7 {
+            for j in (i + 1)..7 {
+                edges.push((i, j))
+            }
+        }
+
+        let graph = DefaultGraph::try_from(edges).unwrap();
+
+        check(&graph, &edge_coloring(&graph).unwrap(), 7)
+    }
+
+    #[test]
+    fn k33_bipartite() {
+        let mut edges = Vec::new();
+
+        for i in 0..3 {
+            for j in 3..6 {
+                edges.push((i, j))
+            }
+        }
+
+        let graph = DefaultGraph::try_from(edges).unwrap();
+
+        check(&graph, &edge_coloring(&graph).unwrap(), 3)
+    }
+
+    #[test]
+    fn cube_bipartite() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0),
+            (4, 5), (5, 6), (6, 7), (7, 4),
+            (0, 4), (1, 5), (2, 6), (3, 7)
+        ]).unwrap();
+
+        check(&graph, &edge_coloring(&graph).unwrap(), 3)
+    }
+}
+
+#[cfg(test)]
+mod is_bipartite {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn c4_p2() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0), (4, 5)
+        ]).unwrap();
+
+        assert!(is_bipartite(&graph))
+    }
+
+    #[test]
+    fn p2_c3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (2, 3), (3, 4), (4, 2)
+        ]).unwrap();
+
+        assert!(!is_bipartite(&graph))
+    }
+}
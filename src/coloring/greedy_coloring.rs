@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+use crate::ordering::degeneracy;
+
+/// Returns a color for each node, in the order of `graph.ids()`, such that
+/// neighbors have different colors. Nodes are colored greedily in reverse
+/// smallest-last order, so a graph of degeneracy d uses at most d + 1
+/// colors. Colors are numbered from zero.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::coloring::greedy_coloring;
+///
+/// fn main() -> Result<(), Error> {
+///     // a crown: bipartite, but greedy in id order would need 3 colors
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 3), (0, 5), (2, 1), (2, 5), (4, 1), (4, 3)
+///     ])?;
+///     let colors = greedy_coloring(&graph);
+///
+///     assert_eq!(colors.iter().max(), Some(&1));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn greedy_coloring<G: Graph>(graph: &G) -> Vec<usize> {
+    let mut colors = HashMap::new();
+
+    for id in degeneracy(graph).ordering.into_iter().rev() {
+        let mut used = graph.neighbors(id).expect("neighbors")
+            .filter_map(|neighbor| colors.get(&neighbor).cloned())
+            .collect::<Vec<_>>();
+
+        used.sort_unstable();
+        used.dedup();
+
+        let color = used.iter().enumerate()
+            .find(|&(i, &color)| i != color)
+            .map_or(used.len(), |(i, _)| i);
+
+        colors.insert(id, color);
+    }
+
+    graph.ids().map(|id| colors[&id]).collect()
+}
+
+#[cfg(test)]
+mod greedy_coloring {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn check<G: Graph>(graph: &G, colors: &[usize]) {
+        let ids = graph.ids().collect::<Vec<_>>();
+
+        for (sid, tid) in graph.edges() {
+            let s = ids.iter().position(|&id| id == sid).unwrap();
+            let t = ids.iter().position(|&id| id == tid).unwrap();
+
+            assert_ne!(colors[s], colors[t])
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(greedy_coloring(&graph), [ ])
+    }
+
+    #[test]
+    fn p2_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(greedy_coloring(&graph), [ 1, 0, 0 ])
+    }
+
+    #[test]
+    fn c5() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+        ]).unwrap();
+        let colors = greedy_coloring(&graph);
+
+        check(&graph, &colors);
+        assert_eq!(colors.iter().max(), Some(&2))
+    }
+
+    #[test]
+    fn k4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+        ]).unwrap();
+        let colors = greedy_coloring(&graph);
+
+        check(&graph, &colors);
+        assert_eq!(colors.iter().max(), Some(&3))
+    }
+
+    #[test]
+    fn star() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (0, 4)
+        ]).unwrap();
+        let colors = greedy_coloring(&graph);
+
+        check(&graph, &colors);
+        assert_eq!(colors.iter().max(), Some(&1))
+    }
+}
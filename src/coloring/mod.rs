@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+mod edge_coloring;
+#[allow(clippy::module_inception)]
+mod greedy_coloring;
+
+pub use edge_coloring::edge_coloring;
+pub use greedy_coloring::greedy_coloring;
@@ -0,0 +1,197 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::graph::{ Graph, Error };
+use super::flow::Network;
+
+/// Returns up to k paths from source to target that share no nodes other
+/// than source and target, found by augmenting a unit-capacity flow over
+/// split nodes. If fewer than k paths are returned, they are as many as
+/// exist, and by Menger's theorem certify that removing that many nodes
+/// (or the edge joining source and target, if present) separates them.
+/// Returns Error if source or target are not found.
+///
+/// If source and target are the same, the single path through that node
+/// is returned when k is nonzero.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::connectivity::disjoint_paths;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 3), (0, 2), (2, 3), (1, 2)
+///     ])?;
+///     let paths = disjoint_paths(&graph, 0, 3, 5)?;
+///
+///     assert_eq!(paths.len(), 2);
+///     assert_eq!(paths.iter().all(|path| path.len() == 3), true);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn disjoint_paths<G: Graph>(
+    graph: &G, source: usize, target: usize, k: usize
+) -> Result<Vec<Vec<usize>>, Error> {
+    for &id in &[ source, target ] {
+        if !graph.has_id(id) {
+            return Err(Error::UnknownId(id));
+        }
+    }
+
+    if source == target {
+        return Ok(if k == 0 { vec![ ] } else { vec![ vec![ source ] ] });
+    }
+
+    let ids = graph.ids().collect::<Vec<_>>();
+    let indices = ids.iter().enumerate()
+        .map(|(index, &id)| (id, index))
+        .collect::<HashMap<_, _>>();
+    let (source, target) = (indices[&source], indices[&target]);
+    let mut network = Network::new(2 * ids.len());
+
+    // node i enters at 2i and leaves at 2i + 1
+    for index in 0..ids.len() {
+        if index != source && index != target {
+            network.add_arc(2 * index, 2 * index + 1, 1);
+        }
+    }
+
+    for (sid, tid) in graph.edges() {
+        let (s, t) = (indices[&sid], indices[&tid]);
+
+        network.add_arc(2 * s + 1, 2 * t, 1);
+        network.add_arc(2 * t + 1, 2 * s, 1);
+    }
+
+    let count = network.max_flow(2 * source + 1, 2 * target, k);
+    let mut result = Vec::with_capacity(count);
+    let mut used = HashSet::new();
+
+    for _ in 0..count {
+        let mut path = vec![ ids[source] ];
+        let mut index = 2 * source + 1;
+
+        loop {
+            let arc = network.arcs(index).iter()
+                .cloned()
+                .find(|&arc| {
+                    arc % 2 == 0 && network.flow(arc) > 0 && !used.contains(&arc)
+                })
+                .expect("flow");
+            let head = network.head(arc);
+
+            used.insert(arc);
+            path.push(ids[head / 2]);
+
+            if head / 2 == target {
+                break;
+            }
+
+            index = head + 1;
+        }
+
+        result.push(path);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn check(graph: &DefaultGraph, paths: &[Vec<usize>]) {
+        let mut inner = HashSet::new();
+
+        for path in paths {
+            for pair in path.windows(2) {
+                assert_eq!(graph.has_edge(pair[0], pair[1]), Ok(true))
+            }
+
+            for &id in &path[1..path.len() - 1] {
+                assert!(inner.insert(id))
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_target() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(disjoint_paths(&graph, 0, 2, 1), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn source_is_target() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(disjoint_paths(&graph, 1, 1, 3), Ok(vec![ vec![ 1 ] ]));
+        assert_eq!(disjoint_paths(&graph, 1, 1, 0), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+
+        assert_eq!(disjoint_paths(&graph, 0, 3, 2), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn adjacent() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (2, 1)
+        ]).unwrap();
+        let paths = disjoint_paths(&graph, 0, 1, 5).unwrap();
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths.contains(&vec![ 0, 1 ]));
+        assert!(paths.contains(&vec![ 0, 2, 1 ]))
+    }
+
+    #[test]
+    fn cut_vertex() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (3, 5), (4, 6), (5, 6)
+        ]).unwrap();
+
+        assert_eq!(disjoint_paths(&graph, 0, 6, 3).unwrap().len(), 1)
+    }
+
+    #[test]
+    fn k_limits() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)
+        ]).unwrap();
+
+        assert_eq!(disjoint_paths(&graph, 0, 4, 2).unwrap().len(), 2);
+        assert_eq!(disjoint_paths(&graph, 0, 4, 0).unwrap().len(), 0)
+    }
+
+    #[test]
+    fn cube() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0),
+            (4, 5), (5, 6), (6, 7), (7, 4),
+            (0, 4), (1, 5), (2, 6), (3, 7)
+        ]).unwrap();
+        let paths = disjoint_paths(&graph, 0, 6, 10).unwrap();
+
+        assert_eq!(paths.len(), 3);
+        check(&graph, &paths)
+    }
+
+    #[test]
+    fn rerouting_needed() {
+        // a greedy first path 0-1-2-5 would block the second
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 5), (0, 3), (3, 2), (1, 4), (4, 5)
+        ]).unwrap();
+        let paths = disjoint_paths(&graph, 0, 5, 2).unwrap();
+
+        assert_eq!(paths.len(), 2);
+        check(&graph, &paths)
+    }
+}
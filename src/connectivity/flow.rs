@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+/// A flow network over dense node indices, solved by augmenting along
+/// shortest residual paths (Edmonds-Karp). Arcs are stored in pairs, so
+/// the partner of arc i is arc i ^ 1.
+pub(crate) struct Network {
+    heads: Vec<usize>,
+    capacities: Vec<usize>,
+    residuals: Vec<usize>,
+    adjacency: Vec<Vec<usize>>
+}
+
+impl Network {
+    pub fn new(order: usize) -> Self {
+        Self {
+            heads: Vec::new(),
+            capacities: Vec::new(),
+            residuals: Vec::new(),
+            adjacency: vec![ Vec::new(); order ]
+        }
+    }
+
+    /// Adds an arc from tail to head.
+    pub fn add_arc(&mut self, tail: usize, head: usize, capacity: usize) {
+        self.add_pair(tail, head, capacity, 0);
+    }
+
+    /// Adds an edge carrying up to capacity in either direction.
+    pub fn add_edge(&mut self, sid: usize, tid: usize, capacity: usize) {
+        self.add_pair(sid, tid, capacity, capacity);
+    }
+
+    pub fn head(&self, arc: usize) -> usize {
+        self.heads[arc]
+    }
+
+    /// Returns the arcs leaving index, including partners.
+    pub fn arcs(&self, index: usize) -> &[usize] {
+        &self.adjacency[index]
+    }
+
+    /// Returns the flow carried by arc, or zero if flow runs the other way.
+    pub fn flow(&self, arc: usize) -> usize {
+        self.capacities[arc].saturating_sub(self.residuals[arc])
+    }
+
+    /// Removes all flow.
+    pub fn reset(&mut self) {
+        self.residuals.copy_from_slice(&self.capacities);
+    }
+
+    /// Augments flow from source to sink until no residual path remains or
+    /// limit is reached, returning the flow added.
+    pub fn max_flow(&mut self, source: usize, sink: usize, limit: usize) -> usize {
+        let mut result = 0;
+
+        while result < limit {
+            let parents = match self.residual_path(source, sink) {
+                Some(parents) => parents,
+                None => break
+            };
+            let mut amount = limit - result;
+            let mut index = sink;
+
+            while index != source {
+                let arc = parents[index].expect("parent");
+
+                amount = amount.min(self.residuals[arc]);
+                index = self.heads[arc ^ 1];
+            }
+
+            index = sink;
+
+            while index != source {
+                let arc = parents[index].expect("parent");
+
+                self.residuals[arc] -= amount;
+                self.residuals[arc ^ 1] += amount;
+                index = self.heads[arc ^ 1];
+            }
+
+            result += amount;
+        }
+
+        result
+    }
+
+    /// Returns which indices can be reached from source over arcs with
+    /// residual capacity.
+    pub fn reachable(&self, source: usize) -> Vec<bool> {
+        let mut result = vec![ false; self.adjacency.len() ];
+        let mut queue = VecDeque::new();
+
+        result[source] = true;
+        queue.push_back(source);
+
+        while let Some(index) = queue.pop_front() {
+            for &arc in &self.adjacency[index] {
+                let head = self.heads[arc];
+
+                if self.residuals[arc] > 0 && !result[head] {
+                    result[head] = true;
+                    queue.push_back(head);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn add_pair(
+        &mut self, tail: usize, head: usize, forward: usize, backward: usize
+    ) {
+        let arc = self.heads.len();
+
+        self.heads.push(head);
+        self.capacities.push(forward);
+        self.residuals.push(forward);
+        self.adjacency[tail].push(arc);
+        self.heads.push(tail);
+        self.capacities.push(backward);
+        self.residuals.push(backward);
+        self.adjacency[head].push(arc + 1);
+    }
+
+    // Returns, for each index on a shortest residual path from source to
+    // sink, the arc entering it.
+    fn residual_path(
+        &self, source: usize, sink: usize
+    ) -> Option<Vec<Option<usize>>> {
+        let mut parents = vec![ None; self.adjacency.len() ];
+        let mut queue = VecDeque::new();
+        let mut seen = vec![ false; self.adjacency.len() ];
+
+        seen[source] = true;
+        queue.push_back(source);
+
+        while let Some(index) = queue.pop_front() {
+            for &arc in &self.adjacency[index] {
+                let head = self.heads[arc];
+
+                if self.residuals[arc] == 0 || seen[head] {
+                    continue;
+                }
+
+                seen[head] = true;
+                parents[head] = Some(arc);
+
+                if head == sink {
+                    return Some(parents);
+                }
+
+                queue.push_back(head);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directed_bottleneck() {
+        let mut network = Network::new(4);
+
+        network.add_arc(0, 1, 3);
+        network.add_arc(0, 2, 2);
+        network.add_arc(1, 2, 5);
+        network.add_arc(1, 3, 2);
+        network.add_arc(2, 3, 3);
+
+        assert_eq!(network.max_flow(0, 3, usize::MAX), 5)
+    }
+
+    #[test]
+    fn limit() {
+        let mut network = Network::new(2);
+
+        network.add_arc(0, 1, 10);
+
+        assert_eq!(network.max_flow(0, 1, 4), 4)
+    }
+
+    #[test]
+    fn undirected_reset() {
+        let mut network = Network::new(3);
+
+        network.add_edge(0, 1, 2);
+        network.add_edge(1, 2, 1);
+
+        assert_eq!(network.max_flow(2, 0, usize::MAX), 1);
+        assert_eq!(network.reachable(2), vec![ false, false, true ]);
+
+        network.reset();
+
+        assert_eq!(network.max_flow(0, 1, usize::MAX), 2)
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use crate::graph::{ Graph, WeightedGraph, DefaultWeightedGraph, Error };
+use super::flow::Network;
+
+/// A Gomory-Hu tree: a weighted tree over the nodes of a graph in which
+/// the minimum cut between any two nodes equals the smallest weight on the
+/// tree path joining them. See `gomory_hu`.
+#[derive(Debug,Clone,PartialEq)]
+pub struct CutTree {
+    tree: DefaultWeightedGraph<usize>
+}
+
+impl CutTree {
+    /// Returns the tree, whose edge weights are minimum cut values.
+    pub fn tree(&self) -> &DefaultWeightedGraph<usize> {
+        &self.tree
+    }
+
+    /// Returns the total weight of a minimum cut separating sid from tid,
+    /// found along the tree path between them. Returns Error if sid or tid
+    /// are not found, or if they are the same.
+    pub fn min_cut(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        for &id in &[ sid, tid ] {
+            if !self.tree.has_id(id) {
+                return Err(Error::UnknownId(id));
+            }
+        }
+
+        if sid == tid {
+            return Err(Error::MissingEdge(sid, tid));
+        }
+
+        let mut stack = vec![ (sid, usize::MAX, usize::MAX) ];
+
+        while let Some((id, parent, smallest)) = stack.pop() {
+            if id == tid {
+                return Ok(smallest);
+            }
+
+            for neighbor in self.tree.neighbors(id)? {
+                if neighbor != parent {
+                    let weight = *self.tree.weight(id, neighbor)?
+                        .expect("weight");
+
+                    stack.push((neighbor, id, smallest.min(weight)));
+                }
+            }
+        }
+
+        unreachable!("tree is connected")
+    }
+}
+
+/// Returns the Gomory-Hu tree of graph, with the capacity of each edge
+/// (sid, tid), as reported by `edges`, given by weight. The tree is built
+/// by Gusfield's method from one maximum flow per node after the first,
+/// rather than one per pair. Separate components are joined by tree edges
+/// of weight zero.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::connectivity::gomory_hu;
+///
+/// fn main() -> Result<(), Error> {
+///     // two triangles joined by a bridge
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)
+///     ])?;
+///     let cuts = gomory_hu(&graph, |_, _| 1);
+///
+///     assert_eq!(cuts.min_cut(0, 5)?, 1);
+///     assert_eq!(cuts.min_cut(0, 1)?, 2);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn gomory_hu<G, F>(graph: &G, weight: F) -> CutTree
+where G: Graph, F: Fn(usize, usize) -> usize {
+    let ids = graph.ids().collect::<Vec<_>>();
+    let indices = ids.iter().enumerate()
+        .map(|(index, &id)| (id, index))
+        .collect::<HashMap<_, _>>();
+    let mut network = Network::new(ids.len());
+    let mut parents = vec![ 0; ids.len() ];
+    let mut tree = DefaultWeightedGraph::new();
+
+    for (sid, tid) in graph.edges() {
+        network.add_edge(indices[&sid], indices[&tid], weight(sid, tid));
+    }
+
+    for &id in &ids {
+        tree.add_node(id).expect("add node");
+    }
+
+    for source in 1..ids.len() {
+        let sink = parents[source];
+
+        network.reset();
+
+        let value = network.max_flow(source, sink, usize::MAX);
+        let side = network.reachable(source);
+
+        for index in (source + 1)..ids.len() {
+            if side[index] && parents[index] == sink {
+                parents[index] = source;
+            }
+        }
+
+        tree.add_edge(ids[source], ids[sink], value).expect("add edge");
+    }
+
+    CutTree { tree }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::rng::Rng;
+
+    #[test]
+    fn empty() {
+        let cuts = gomory_hu(&DefaultGraph::new(), |_, _| 1);
+
+        assert_eq!(cuts.tree().order(), 0)
+    }
+
+    #[test]
+    fn unknown_id() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let cuts = gomory_hu(&graph, |_, _| 1);
+
+        assert_eq!(cuts.min_cut(0, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn same_node() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let cuts = gomory_hu(&graph, |_, _| 1);
+
+        assert_eq!(cuts.min_cut(1, 1), Err(Error::MissingEdge(1, 1)))
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+        let cuts = gomory_hu(&graph, |_, _| 4);
+
+        assert_eq!(cuts.tree().size(), 3);
+        assert_eq!(cuts.min_cut(0, 1), Ok(4));
+        assert_eq!(cuts.min_cut(1, 3), Ok(0))
+    }
+
+    #[test]
+    fn weighted_path() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let weights = [ 5, 2, 7 ];
+        let cuts = gomory_hu(&graph, |sid, tid| weights[sid.min(tid)]);
+
+        assert_eq!(cuts.min_cut(0, 1), Ok(5));
+        assert_eq!(cuts.min_cut(0, 3), Ok(2));
+        assert_eq!(cuts.min_cut(2, 3), Ok(7))
+    }
+
+    #[test]
+    fn matches_max_flow() {
+        let mut rng = Rng::new(11);
+        let mut edges = Vec::new();
+
+        for sid in 0..12 {
+            for tid in (sid + 1)..12 {
+                if rng.next_f64() < 0.3 {
+                    edges.push((sid, tid, 1 + rng.below(9)));
+                }
+            }
+        }
+
+        let graph = DefaultGraph::try_from(
+            edges.iter().map(|&(sid, tid, _)| (sid, tid)).collect::<Vec<_>>()
+        ).unwrap();
+        let weights = edges.iter()
+            .map(|&(sid, tid, weight)| ((sid, tid), weight))
+            .collect::<HashMap<_, _>>();
+        let weight = |sid: usize, tid: usize| {
+            weights[&(sid.min(tid), sid.max(tid))]
+        };
+        let cuts = gomory_hu(&graph, weight);
+        let ids = graph.ids().collect::<Vec<_>>();
+
+        for (s, &sid) in ids.iter().enumerate() {
+            for (t, &tid) in ids.iter().enumerate().skip(s + 1) {
+                let mut network = Network::new(ids.len());
+
+                for (a, b) in graph.edges() {
+                    let a_index = ids.iter().position(|&id| id == a).unwrap();
+                    let b_index = ids.iter().position(|&id| id == b).unwrap();
+
+                    network.add_edge(a_index, b_index, weight(a, b));
+                }
+
+                assert_eq!(
+                    cuts.min_cut(sid, tid),
+                    Ok(network.max_flow(s, t, usize::MAX))
+                )
+            }
+        }
+    }
+}
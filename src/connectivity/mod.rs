@@ -0,0 +1,6 @@
+mod flow;
+mod disjoint_paths;
+mod gomory_hu;
+
+pub use disjoint_paths::disjoint_paths;
+pub use gomory_hu::{ gomory_hu, CutTree };
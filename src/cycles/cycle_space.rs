@@ -0,0 +1,195 @@
+use crate::graph::Graph;
+use crate::selection::component_count;
+use super::EdgeSet;
+
+/// The cycle space of a graph over GF(2). Cycles, as EdgeSets, are added
+/// one at a time and kept only if linearly independent of those already
+/// added. The retained basis is kept in reduced (echelon) form.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::cycles::{ CycleSpace, EdgeSet };
+///
+/// fn main() -> Result<(), Error> {
+///     // two squares sharing the edge (1, 4)
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 3 ],
+///         vec![ 0, 2, 4 ],
+///         vec![ 1, 5 ],
+///         vec![ 0, 4 ],
+///         vec![ 3, 1, 5 ],
+///         vec![ 4, 2 ]
+///     ])?;
+///     let mut space = CycleSpace::new(&graph);
+///     let left = EdgeSet::from_ring(&graph, &[ 0, 1, 4, 3 ])?;
+///     let right = EdgeSet::from_ring(&graph, &[ 1, 2, 5, 4 ])?;
+///     let outer = EdgeSet::from_ring(&graph, &[ 0, 1, 2, 5, 4, 3 ])?;
+///
+///     assert_eq!(space.dimension(), 2);
+///     assert_eq!(space.insert(left.clone()), true);
+///     assert_eq!(space.insert(right.clone()), true);
+///     assert_eq!(left.xor(&right), outer);
+///     assert_eq!(space.is_independent(&outer), false);
+///     assert_eq!(space.is_complete(), true);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,PartialEq,Clone)]
+pub struct CycleSpace {
+    dimension: usize,
+    basis: Vec<EdgeSet>
+}
+
+impl CycleSpace {
+    /// Returns an empty CycleSpace for graph.
+    pub fn new<G: Graph>(graph: &G) -> Self {
+        Self {
+            dimension: graph.size() + component_count(graph) - graph.order(),
+            basis: Vec::new()
+        }
+    }
+
+    /// Returns the dimension of the cycle space of the graph, also known as
+    /// its circuit rank or cyclomatic number.
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the number of independent cycles added so far.
+    pub fn rank(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Returns true if the cycles added so far span the cycle space.
+    pub fn is_complete(&self) -> bool {
+        self.rank() == self.dimension
+    }
+
+    /// Returns cycle reduced by the basis. The result is empty if and only
+    /// if cycle is a combination of the cycles added so far.
+    pub fn reduce(&self, cycle: &EdgeSet) -> EdgeSet {
+        let mut result = cycle.clone();
+
+        for vector in self.basis.iter() {
+            if result.contains(vector.first().expect("pivot")) {
+                result.xor_assign(vector);
+            }
+        }
+
+        result
+    }
+
+    /// Returns true if cycle is not a combination of the cycles added so
+    /// far.
+    pub fn is_independent(&self, cycle: &EdgeSet) -> bool {
+        !self.reduce(cycle).is_empty()
+    }
+
+    /// Adds cycle to the basis if independent, returning true, or returns
+    /// false otherwise.
+    pub fn insert(&mut self, cycle: EdgeSet) -> bool {
+        let reduced = self.reduce(&cycle);
+
+        match reduced.first() {
+            Some(pivot) => {
+                for vector in self.basis.iter_mut() {
+                    if vector.contains(pivot) {
+                        vector.xor_assign(&reduced);
+                    }
+                }
+
+                self.basis.push(reduced);
+
+                true
+            },
+            None => false
+        }
+    }
+
+    /// Returns an Iterator over the reduced basis.
+    pub fn basis(&self) -> impl Iterator<Item=&EdgeSet> + '_ {
+        self.basis.iter()
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn tree_and_triangle() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ],
+            vec![ 3, 4 ],
+            vec![ 2, 4 ],
+            vec![ 2, 3 ]
+        ]).unwrap();
+        let space = CycleSpace::new(&graph);
+
+        assert_eq!(space.dimension(), 1);
+        assert_eq!(space.rank(), 0);
+        assert!(!space.is_complete())
+    }
+
+    #[test]
+    fn c3_with_pendant() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2)
+        ]).unwrap();
+        let space = CycleSpace::new(&graph);
+
+        assert_eq!(space.dimension(), 1)
+    }
+}
+
+#[cfg(test)]
+mod insert {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn k4() -> DefaultGraph {
+        DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3 ],
+            vec![ 0, 2, 3 ],
+            vec![ 0, 1, 3 ],
+            vec![ 0, 1, 2 ]
+        ]).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        let graph = k4();
+        let mut space = CycleSpace::new(&graph);
+
+        assert!(!space.insert(EdgeSet::new(graph.size())))
+    }
+
+    #[test]
+    fn k4_faces() {
+        let graph = k4();
+        let mut space = CycleSpace::new(&graph);
+        let faces = [
+            [ 0, 1, 2 ], [ 0, 1, 3 ], [ 0, 2, 3 ], [ 1, 2, 3 ]
+        ].iter().map(|ring| {
+            EdgeSet::from_ring(&graph, ring).unwrap()
+        }).collect::<Vec<_>>();
+
+        assert_eq!(space.dimension(), 3);
+        assert!(space.insert(faces[0].clone()));
+        assert!(space.insert(faces[1].clone()));
+        assert!(space.insert(faces[2].clone()));
+        assert!(!space.insert(faces[3].clone()));
+        assert!(space.is_complete());
+
+        let square = EdgeSet::from_ring(&graph, &[ 0, 1, 2, 3 ]).unwrap();
+
+        assert!(space.reduce(&square).is_empty());
+        assert_eq!(space.basis().count(), 3)
+    }
+}
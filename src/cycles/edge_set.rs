@@ -0,0 +1,188 @@
+use std::hash::{ Hash, Hasher };
+
+use crate::graph::{ Graph, Error };
+use crate::path::path_to_edges;
+
+/// A set of edges, stored as a bitset over edge ids (see `Graph::edge`).
+/// Under symmetric difference, edge sets form a vector space over GF(2) in
+/// which cycles span the cycle space.
+#[derive(Debug,Clone)]
+pub struct EdgeSet {
+    words: Vec<u64>
+}
+
+impl EdgeSet {
+    /// Returns an empty set with room for size edges.
+    pub fn new(size: usize) -> Self {
+        Self { words: vec![ 0; size.div_ceil(64) ] }
+    }
+
+    /// Returns the set of the given edges of graph. Returns Error if any
+    /// edge is not found.
+    pub fn from_edges<G: Graph>(
+        graph: &G, edges: &[(usize, usize)]
+    ) -> Result<Self, Error> {
+        let mut result = Self::new(graph.size());
+
+        for &(sid, tid) in edges {
+            result.toggle(graph.edge_id(sid, tid)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the set of edges in the ring through nodes, including the
+    /// edge closing the last node back to the first. Returns Error if any
+    /// edge is not found.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Error, DefaultGraph };
+    /// use gamma::cycles::EdgeSet;
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let graph = DefaultGraph::try_from(vec![
+    ///         vec![ 1, 2 ],
+    ///         vec![ 0, 2 ],
+    ///         vec![ 1, 0 ]
+    ///     ])?;
+    ///     let ring = EdgeSet::from_ring(&graph, &[ 0, 1, 2 ])?;
+    ///
+    ///     assert_eq!(ring.ids().collect::<Vec<_>>(), vec![ 0, 1, 2 ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_ring<G: Graph>(
+        graph: &G, nodes: &[usize]
+    ) -> Result<Self, Error> {
+        let mut edges = path_to_edges(nodes).collect::<Vec<_>>();
+
+        if nodes.len() > 2 {
+            edges.push((nodes[nodes.len() - 1], nodes[0]));
+        }
+
+        Self::from_edges(graph, &edges)
+    }
+
+    /// Returns true if the edge with id eid is a member.
+    pub fn contains(&self, eid: usize) -> bool {
+        match self.words.get(eid / 64) {
+            Some(word) => word & (1 << (eid % 64)) != 0,
+            None => false
+        }
+    }
+
+    /// Adds the edge with id eid if absent, or removes it if present.
+    pub fn toggle(&mut self, eid: usize) {
+        if eid / 64 >= self.words.len() {
+            self.words.resize(eid / 64 + 1, 0);
+        }
+
+        self.words[eid / 64] ^= 1 << (eid % 64);
+    }
+
+    /// Returns the symmetric difference of this set and other.
+    pub fn xor(&self, other: &EdgeSet) -> EdgeSet {
+        let mut result = self.clone();
+
+        result.xor_assign(other);
+
+        result
+    }
+
+    /// Replaces this set with its symmetric difference with other.
+    pub fn xor_assign(&mut self, other: &EdgeSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word ^= other;
+        }
+    }
+
+    /// Returns the smallest member edge id, or None if empty.
+    pub fn first(&self) -> Option<usize> {
+        self.words.iter().enumerate()
+            .find(|(_, &word)| word != 0)
+            .map(|(index, word)| index * 64 + word.trailing_zeros() as usize)
+    }
+
+    /// Returns an Iterator over member edge ids, in increasing order.
+    pub fn ids(&self) -> impl Iterator<Item=usize> + '_ {
+        (0..self.words.len() * 64).filter(move |&eid| self.contains(eid))
+    }
+
+    /// Returns the number of member edges.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns true if there are no member edges.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    fn trimmed(&self) -> &[u64] {
+        let len = self.words.iter().rposition(|&word| word != 0)
+            .map_or(0, |index| index + 1);
+
+        &self.words[..len]
+    }
+}
+
+impl PartialEq for EdgeSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for EdgeSet { }
+
+impl Hash for EdgeSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trimmed().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod from_ring {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn missing_edge() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ]
+        ]).unwrap();
+
+        assert_eq!(
+            EdgeSet::from_ring(&graph, &[ 0, 1, 2 ]),
+            Err(Error::MissingEdge(2, 0))
+        )
+    }
+}
+
+#[cfg(test)]
+mod xor {
+    use super::*;
+
+    #[test]
+    fn unequal_lengths() {
+        let mut a = EdgeSet::new(0);
+        let mut b = EdgeSet::new(0);
+
+        a.toggle(1);
+        b.toggle(1);
+        b.toggle(100);
+
+        assert_eq!(a.xor(&b).ids().collect::<Vec<_>>(), [ 100 ]);
+        assert_eq!(b.xor(&a).first(), Some(100));
+        assert!(a.xor(&a).is_empty());
+        assert_eq!(a.xor(&b).xor(&b), a)
+    }
+}
@@ -0,0 +1,7 @@
+mod smallest_ring;
+mod edge_set;
+mod cycle_space;
+
+pub use smallest_ring::{ smallest_ring_through_edge, smallest_ring_through_node };
+pub use edge_set::EdgeSet;
+pub use cycle_space::CycleSpace;
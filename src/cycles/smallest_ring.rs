@@ -0,0 +1,199 @@
+use std::collections::{ HashMap, VecDeque };
+use std::collections::hash_map::Entry;
+
+use crate::graph::{ Graph, Error, filter_edges };
+
+/// Returns the nodes of a smallest ring (cycle) containing the edge (sid,
+/// tid), beginning with sid and ending with tid, or None if the edge is a
+/// bridge. Found by breadth-first search from sid to tid in graph without
+/// the edge. Returns Error if either id is not found, or if there is no such
+/// edge.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::cycles::smallest_ring_through_edge;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 2 ],
+///         vec![ 0, 2 ],
+///         vec![ 0, 1, 3 ],
+///         vec![ 2 ]
+///     ])?;
+///
+///     assert_eq!(smallest_ring_through_edge(&graph, 0, 1)?, Some(vec![ 0, 2, 1 ]));
+///     assert_eq!(smallest_ring_through_edge(&graph, 2, 3)?, None);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn smallest_ring_through_edge<G: Graph>(
+    graph: &G, sid: usize, tid: usize
+) -> Result<Option<Vec<usize>>, Error> {
+    if !graph.has_edge(sid, tid)? {
+        return Err(Error::MissingEdge(sid, tid));
+    }
+
+    let view = filter_edges(graph, |a, b| {
+        !(a == sid && b == tid || a == tid && b == sid)
+    });
+
+    Ok(shortest_path(&view, sid, tid))
+}
+
+/// Returns the nodes of a smallest ring (cycle) containing id, beginning
+/// with id, or None if id belongs to no ring. Returns Error if id is not
+/// found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::cycles::smallest_ring_through_node;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 2 ],
+///         vec![ 0, 2 ],
+///         vec![ 0, 1, 3 ],
+///         vec![ 2 ]
+///     ])?;
+///
+///     assert_eq!(smallest_ring_through_node(&graph, 2)?, Some(vec![ 2, 1, 0 ]));
+///     assert_eq!(smallest_ring_through_node(&graph, 3)?, None);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn smallest_ring_through_node<G: Graph>(
+    graph: &G, id: usize
+) -> Result<Option<Vec<usize>>, Error> {
+    let mut result: Option<Vec<usize>> = None;
+
+    for neighbor in graph.neighbors(id)? {
+        if let Some(ring) = smallest_ring_through_edge(graph, id, neighbor)? {
+            if result.as_ref().is_none_or(|best| ring.len() < best.len()) {
+                result = Some(ring);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn shortest_path<G: Graph>(
+    graph: &G, source: usize, target: usize
+) -> Option<Vec<usize>> {
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    parents.insert(source, source);
+    queue.push_back(source);
+
+    while let Some(id) = queue.pop_front() {
+        if id == target {
+            let mut result = vec![ target ];
+
+            while *result.last().expect("node") != source {
+                result.push(parents[result.last().expect("node")]);
+            }
+
+            result.reverse();
+
+            return Some(result);
+        }
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            if let Entry::Vacant(entry) = parents.entry(neighbor) {
+                entry.insert(id);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod smallest_ring_through_edge {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_tid() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(smallest_ring_through_edge(&graph, 0, 1), Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn missing_edge() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ]
+        ]).unwrap();
+
+        assert_eq!(
+            smallest_ring_through_edge(&graph, 0, 2),
+            Err(Error::MissingEdge(0, 2))
+        )
+    }
+
+    #[test]
+    fn fused_rings() {
+        // naphthalene-like: two hexagons sharing the edge (0, 5)
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 5, 9 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 4 ],
+            vec![ 3, 5 ],
+            vec![ 4, 0, 6 ],
+            vec![ 5, 7 ],
+            vec![ 6, 8 ],
+            vec![ 7, 9 ],
+            vec![ 8, 0 ]
+        ]).unwrap();
+
+        assert_eq!(
+            smallest_ring_through_edge(&graph, 1, 2),
+            Ok(Some(vec![ 1, 0, 5, 4, 3, 2 ]))
+        );
+        assert_eq!(
+            smallest_ring_through_edge(&graph, 5, 0).unwrap().unwrap().len(),
+            6
+        )
+    }
+}
+
+#[cfg(test)]
+mod smallest_ring_through_node {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_id() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(smallest_ring_through_node(&graph, 0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn bowtie_center() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3, 4, 5 ],
+            vec![ 0, 2 ],
+            vec![ 0, 1 ],
+            vec![ 0, 4 ],
+            vec![ 0, 3, 5 ],
+            vec![ 4, 0 ]
+        ]).unwrap();
+
+        assert_eq!(smallest_ring_through_node(&graph, 0), Ok(Some(vec![ 0, 2, 1 ])))
+    }
+}
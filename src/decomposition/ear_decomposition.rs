@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use crate::graph::Graph;
+use super::palm_tree::PalmTree;
+
+/// Returns an open ear decomposition of graph, or None if graph is not
+/// biconnected or has fewer than three nodes. The first ear is a cycle,
+/// given with its first node repeated at the end. Each later ear is a path
+/// whose ends lie on earlier ears and whose inner nodes are new.
+///
+/// Ears are found with Schmidt's chain decomposition over a depth-first
+/// tree rooted at the first node of `Graph::ids`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::decomposition::ear_decomposition;
+///
+/// fn main() -> Result<(), Error> {
+///     let theta = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 0), (1, 3)
+///     ])?;
+///     let bowtie = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)
+///     ])?;
+///
+///     assert_eq!(ear_decomposition(&theta), Some(vec![
+///         vec![ 0, 3, 2, 1, 0 ],
+///         vec![ 1, 3 ]
+///     ]));
+///     assert_eq!(ear_decomposition(&bowtie), None);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn ear_decomposition<G: Graph>(graph: &G) -> Option<Vec<Vec<usize>>> {
+    let root = graph.ids().next()?;
+    let tree = PalmTree::new(graph, root, None);
+
+    if graph.order() < 3 || tree.preorder.len() != graph.order() {
+        return None;
+    }
+
+    let mut marked = HashSet::new();
+    let mut result = Vec::new();
+    let mut covered = 0;
+
+    for &id in &tree.preorder {
+        for next in graph.neighbors(id).expect("neighbors") {
+            if !tree.is_back_edge_down(id, next) {
+                continue;
+            }
+
+            let mut ear = vec![ id ];
+            let mut current = next;
+
+            marked.insert(id);
+
+            loop {
+                ear.push(current);
+
+                if !marked.insert(current) {
+                    break;
+                }
+
+                current = tree.parent[&current];
+            }
+
+            // only the first ear may close on itself
+            if !result.is_empty() && ear[0] == ear[ear.len() - 1] {
+                return None;
+            }
+
+            covered += ear.len() - 1;
+            result.push(ear);
+        }
+    }
+
+    if covered == graph.size() {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod ear_decomposition {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(ear_decomposition(&DefaultGraph::new()), None)
+    }
+
+    #[test]
+    fn p2() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(ear_decomposition(&graph), None)
+    }
+
+    #[test]
+    fn c3_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 0, 1 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(ear_decomposition(&graph), None)
+    }
+
+    #[test]
+    fn c4_with_pendant() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0), (3, 4)
+        ]).unwrap();
+
+        assert_eq!(ear_decomposition(&graph), None)
+    }
+
+    #[test]
+    fn c3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+
+        assert_eq!(ear_decomposition(&graph), Some(vec![ vec![ 0, 2, 1, 0 ] ]))
+    }
+
+    #[test]
+    fn k4_covers_edges() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+        ]).unwrap();
+        let ears = ear_decomposition(&graph).unwrap();
+
+        assert_eq!(ears.len(), graph.size() - graph.order() + 1);
+        assert_eq!(ears.iter().map(|ear| ear.len() - 1).sum::<usize>(), 6)
+    }
+}
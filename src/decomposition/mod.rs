@@ -0,0 +1,11 @@
+mod palm_tree;
+#[allow(clippy::module_inception)]
+mod ear_decomposition;
+#[allow(clippy::module_inception)]
+mod st_numbering;
+#[allow(clippy::module_inception)]
+mod modular;
+
+pub use ear_decomposition::ear_decomposition;
+pub use st_numbering::st_numbering;
+pub use modular::{ modular, ModularTree };
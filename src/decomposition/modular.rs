@@ -0,0 +1,348 @@
+use std::collections::{ HashMap, HashSet, VecDeque };
+
+use crate::graph::Graph;
+
+/// A node of a modular decomposition tree. Each inner node holds the
+/// maximal strong modules of its node set, and is labeled by how they
+/// relate: Parallel if no two are joined, Series if every two are fully
+/// joined, and Prime otherwise.
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub enum ModularTree {
+    Leaf(usize),
+    Parallel(Vec<ModularTree>),
+    Series(Vec<ModularTree>),
+    Prime(Vec<ModularTree>)
+}
+
+impl ModularTree {
+    /// Returns the ids below this node, in the order they appear in the
+    /// tree.
+    pub fn leaves(&self) -> Vec<usize> {
+        match self {
+            ModularTree::Leaf(id) => vec![ *id ],
+            ModularTree::Parallel(children) |
+            ModularTree::Series(children) |
+            ModularTree::Prime(children) => {
+                children.iter().flat_map(|child| child.leaves()).collect()
+            }
+        }
+    }
+
+    /// Returns true if no node of this tree is Prime. A graph is a cograph
+    /// exactly when its modular decomposition tree has this property.
+    pub fn is_cotree(&self) -> bool {
+        match self {
+            ModularTree::Leaf(_) => true,
+            ModularTree::Parallel(children) |
+            ModularTree::Series(children) => {
+                children.iter().all(|child| child.is_cotree())
+            },
+            ModularTree::Prime(_) => false
+        }
+    }
+}
+
+/// Returns the modular decomposition tree of graph, or None if graph is
+/// empty. Children are ordered by the first of their ids to appear in
+/// `Graph::ids`.
+///
+/// Prime nodes are split by growing the smallest module around each pair
+/// of nodes, which takes time quartic in order in the worst case. This
+/// suits the small and medium graphs for which cograph recognition and
+/// module-based preprocessing are typically used.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::decomposition::{ modular, ModularTree::* };
+///
+/// fn main() -> Result<(), Error> {
+///     let c4 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3), (3, 0) ])?;
+///     let p4 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3) ])?;
+///
+///     assert_eq!(modular(&c4), Some(Series(vec![
+///         Parallel(vec![ Leaf(0), Leaf(2) ]),
+///         Parallel(vec![ Leaf(1), Leaf(3) ])
+///     ])));
+///     assert_eq!(modular(&p4).unwrap().is_cotree(), false);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn modular<G: Graph>(graph: &G) -> Option<ModularTree> {
+    let adjacency = graph.ids().map(|id| {
+        (id, graph.neighbors(id).expect("neighbors").collect::<HashSet<_>>())
+    }).collect::<HashMap<_,_>>();
+    let ids = graph.ids().collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        None
+    } else {
+        Some(decompose(&adjacency, ids))
+    }
+}
+
+type Adjacency = HashMap<usize, HashSet<usize>>;
+
+fn decompose(adjacency: &Adjacency, ids: Vec<usize>) -> ModularTree {
+    if ids.len() == 1 {
+        return ModularTree::Leaf(ids[0]);
+    }
+
+    let parts = components(adjacency, &ids, false);
+
+    if parts.len() > 1 {
+        return ModularTree::Parallel(children(adjacency, parts));
+    }
+
+    let parts = components(adjacency, &ids, true);
+
+    if parts.len() > 1 {
+        return ModularTree::Series(children(adjacency, parts));
+    }
+
+    ModularTree::Prime(children(adjacency, maximal_modules(adjacency, &ids)))
+}
+
+fn children(adjacency: &Adjacency, parts: Vec<Vec<usize>>) -> Vec<ModularTree> {
+    parts.into_iter().map(|part| decompose(adjacency, part)).collect()
+}
+
+/// Returns the connected components of the subgraph induced by ids, or of
+/// its complement. Each component keeps the order of ids.
+fn components(
+    adjacency: &Adjacency, ids: &[usize], complement: bool
+) -> Vec<Vec<usize>> {
+    let mut labels = HashMap::new();
+    let mut count = 0;
+
+    for &root in ids {
+        if labels.contains_key(&root) {
+            continue;
+        }
+
+        let mut queue = VecDeque::new();
+
+        labels.insert(root, count);
+        queue.push_back(root);
+
+        while let Some(id) = queue.pop_front() {
+            for &other in ids {
+                if other == id || labels.contains_key(&other) {
+                    continue;
+                }
+
+                if adjacency[&id].contains(&other) != complement {
+                    labels.insert(other, count);
+                    queue.push_back(other);
+                }
+            }
+        }
+
+        count += 1;
+    }
+
+    let mut result = vec![ Vec::new(); count ];
+
+    for &id in ids {
+        result[labels[&id]].push(id);
+    }
+
+    result
+}
+
+/// Returns the maximal strong modules of ids, for a node set whose graph
+/// and complement are both connected. In that case the maximal proper
+/// modules partition ids, and two nodes share one exactly when the
+/// smallest module containing both is proper.
+fn maximal_modules(adjacency: &Adjacency, ids: &[usize]) -> Vec<Vec<usize>> {
+    let mut assigned = HashSet::new();
+    let mut result = Vec::new();
+
+    for (index, &id) in ids.iter().enumerate() {
+        if assigned.contains(&id) {
+            continue;
+        }
+
+        let mut module = vec![ id ];
+
+        assigned.insert(id);
+
+        for &other in &ids[index + 1..] {
+            if !assigned.contains(&other) &&
+                closure(adjacency, ids, id, other) < ids.len() {
+                assigned.insert(other);
+                module.push(other);
+            }
+        }
+
+        result.push(module);
+    }
+
+    result
+}
+
+/// Returns the size of the smallest module of ids containing first and
+/// second, found by repeatedly adding nodes that distinguish its members.
+fn closure(
+    adjacency: &Adjacency, ids: &[usize], first: usize, second: usize
+) -> usize {
+    let mut members = HashSet::new();
+    let mut adjacent = ids.iter().map(|&id| (id, 0)).collect::<HashMap<_,_>>();
+    let mut pending = vec![ first, second ];
+
+    while let Some(member) = pending.pop() {
+        if !members.insert(member) {
+            continue;
+        }
+
+        for neighbor in &adjacency[&member] {
+            if let Some(count) = adjacent.get_mut(neighbor) {
+                *count += 1;
+            }
+        }
+
+        if pending.is_empty() {
+            for &id in ids {
+                let count = adjacent[&id];
+
+                if !members.contains(&id) && count > 0 && count < members.len() {
+                    pending.push(id);
+                }
+            }
+        }
+    }
+
+    members.len()
+}
+
+#[cfg(test)]
+mod modular {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use ModularTree::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(modular(&DefaultGraph::new()), None)
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(modular(&graph), Some(Leaf(0)))
+    }
+
+    #[test]
+    fn p1_p1_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ], vec![ ], vec![ ]
+        ]).unwrap();
+
+        assert_eq!(modular(&graph), Some(Parallel(vec![
+            Leaf(0), Leaf(1), Leaf(2)
+        ])))
+    }
+
+    #[test]
+    fn k3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+
+        assert_eq!(modular(&graph), Some(Series(vec![
+            Leaf(0), Leaf(1), Leaf(2)
+        ])))
+    }
+
+    #[test]
+    fn p4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+
+        assert_eq!(modular(&graph), Some(Prime(vec![
+            Leaf(0), Leaf(1), Leaf(2), Leaf(3)
+        ])))
+    }
+
+    #[test]
+    fn p4_with_twin() {
+        // 4 is a false twin of 0, so { 0, 4 } is a module
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (4, 1)
+        ]).unwrap();
+
+        assert_eq!(modular(&graph), Some(Prime(vec![
+            Parallel(vec![ Leaf(0), Leaf(4) ]), Leaf(1), Leaf(2), Leaf(3)
+        ])))
+    }
+
+    #[test]
+    fn p4_with_substituted_triangle() {
+        // the end 3 is replaced by a triangle { 3, 4, 5 }
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (2, 4), (2, 5), (3, 4), (4, 5), (5, 3)
+        ]).unwrap();
+        let tree = modular(&graph).unwrap();
+
+        assert_eq!(tree, Prime(vec![
+            Leaf(0), Leaf(1), Leaf(2), Series(vec![ Leaf(3), Leaf(4), Leaf(5) ])
+        ]))
+    }
+
+    #[test]
+    fn disconnected_with_prime_part() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (4, 5)
+        ]).unwrap();
+        let tree = modular(&graph).unwrap();
+
+        assert_eq!(tree, Parallel(vec![
+            Prime(vec![ Leaf(0), Leaf(1), Leaf(2), Leaf(3) ]),
+            Series(vec![ Leaf(4), Leaf(5) ])
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod leaves {
+    use super::*;
+    use ModularTree::*;
+
+    #[test]
+    fn nested() {
+        let tree = Prime(vec![
+            Leaf(0), Leaf(1), Leaf(2), Series(vec![ Leaf(3), Leaf(4), Leaf(5) ])
+        ]);
+
+        assert_eq!(tree.leaves(), [ 0, 1, 2, 3, 4, 5 ])
+    }
+}
+
+#[cfg(test)]
+mod is_cotree {
+    use super::*;
+    use ModularTree::*;
+
+    #[test]
+    fn series_of_parallel() {
+        let tree = Series(vec![
+            Parallel(vec![ Leaf(0), Leaf(1) ]), Leaf(2)
+        ]);
+
+        assert!(tree.is_cotree())
+    }
+
+    #[test]
+    fn prime_part() {
+        let tree = Parallel(vec![
+            Prime(vec![ Leaf(0), Leaf(1), Leaf(2), Leaf(3) ]),
+            Series(vec![ Leaf(4), Leaf(5) ])
+        ]);
+
+        assert!(!tree.is_cotree())
+    }
+}
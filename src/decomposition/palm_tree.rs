@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+
+/// A depth-first spanning tree of the component containing its root, with
+/// nodes numbered in preorder.
+pub(crate) struct PalmTree {
+    pub preorder: Vec<usize>,
+    pub index: HashMap<usize, usize>,
+    pub parent: HashMap<usize, usize>
+}
+
+impl PalmTree {
+    /// Returns the palm tree of graph rooted at root. If first is given and
+    /// adjacent to root, it is visited first.
+    pub fn new<G: Graph>(graph: &G, root: usize, first: Option<usize>) -> Self {
+        let mut result = Self {
+            preorder: vec![ root ],
+            index: HashMap::new(),
+            parent: HashMap::new()
+        };
+        let mut stack = vec![ (root, neighbors(graph, root, first)) ];
+
+        result.index.insert(root, 0);
+
+        while let Some((id, pending)) = stack.last_mut() {
+            match pending.pop() {
+                Some(next) => {
+                    if result.index.contains_key(&next) {
+                        continue;
+                    }
+
+                    result.index.insert(next, result.preorder.len());
+                    result.parent.insert(next, *id);
+                    result.preorder.push(next);
+                    stack.push((next, neighbors(graph, next, None)));
+                },
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns true if (sid, tid) is a back edge from sid down to its
+    /// descendant tid.
+    pub fn is_back_edge_down(&self, sid: usize, tid: usize) -> bool {
+        self.index[&tid] > self.index[&sid] && self.parent[&tid] != sid
+    }
+}
+
+/// Returns the neighbors of id reversed for popping, with first on top.
+fn neighbors<G: Graph>(graph: &G, id: usize, first: Option<usize>) -> Vec<usize> {
+    let mut result = graph.neighbors(id).expect("neighbors").collect::<Vec<_>>();
+
+    result.reverse();
+
+    if let Some(first) = first {
+        if let Some(position) = result.iter().position(|&other| other == first) {
+            result.remove(position);
+            result.push(first);
+        }
+    }
+
+    result
+}
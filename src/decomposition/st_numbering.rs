@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use crate::graph::{ Graph, Error };
+use super::palm_tree::PalmTree;
+
+/// Returns the nodes of graph in an st-order: s first, t last, and every
+/// other node adjacent to both an earlier and a later node. A node's
+/// st-number is its position in the result. Returns None if no such order
+/// exists, which is the case unless graph is biconnected. Returns Error if
+/// s or t is not found, or if s and t are not adjacent.
+///
+/// Uses Tarjan's list-insertion method over a depth-first tree from s
+/// whose first edge leads to t.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::decomposition::st_numbering;
+///
+/// fn main() -> Result<(), Error> {
+///     let c4 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3), (3, 0) ])?;
+///
+///     assert_eq!(st_numbering(&c4, 0, 1)?, Some(vec![ 0, 3, 2, 1 ]));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn st_numbering<G: Graph>(
+    graph: &G, s: usize, t: usize
+) -> Result<Option<Vec<usize>>, Error> {
+    if !graph.has_edge(s, t)? {
+        return Err(Error::MissingEdge(s, t));
+    }
+
+    let tree = PalmTree::new(graph, s, Some(t));
+
+    if tree.preorder.len() != graph.order() {
+        return Ok(None);
+    }
+
+    let low = lowpoints(graph, &tree);
+
+    if !is_biconnected(&tree, &low) {
+        return Ok(None);
+    }
+
+    let mut next = HashMap::new();
+    let mut previous = HashMap::new();
+    let mut minus = HashMap::new();
+
+    next.insert(s, t);
+    previous.insert(t, s);
+    minus.insert(s, true);
+
+    for &id in &tree.preorder[2..] {
+        let parent = tree.parent[&id];
+
+        if minus.get(&low[&id]).cloned().unwrap_or(false) {
+            let before = match previous.get(&parent) {
+                Some(&before) => before,
+                None => return Ok(None)
+            };
+
+            next.insert(before, id);
+            previous.insert(id, before);
+            next.insert(id, parent);
+            previous.insert(parent, id);
+            minus.insert(parent, false);
+        } else {
+            if let Some(&after) = next.get(&parent) {
+                previous.insert(after, id);
+                next.insert(id, after);
+            }
+
+            next.insert(parent, id);
+            previous.insert(id, parent);
+            minus.insert(parent, true);
+        }
+    }
+
+    let mut result = vec![ s ];
+
+    while let Some(&id) = next.get(&result[result.len() - 1]) {
+        result.push(id);
+    }
+
+    Ok(if is_st_order(graph, &result, s, t) { Some(result) } else { None })
+}
+
+/// Returns, for each node, the node of least preorder index reachable
+/// from it by descending tree edges then following one back edge.
+fn lowpoints<G: Graph>(graph: &G, tree: &PalmTree) -> HashMap<usize, usize> {
+    let mut result = HashMap::new();
+
+    for &id in tree.preorder.iter().rev() {
+        let mut low = id;
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            let candidate = if tree.parent.get(&neighbor) == Some(&id) {
+                result[&neighbor]
+            } else {
+                neighbor
+            };
+
+            if tree.index[&candidate] < tree.index[&low] {
+                low = candidate;
+            }
+        }
+
+        result.insert(id, low);
+    }
+
+    result
+}
+
+/// Returns true if a spanning tree has a single child at its root and no
+/// other node separates the subtree of a child from the rest.
+fn is_biconnected(tree: &PalmTree, low: &HashMap<usize, usize>) -> bool {
+    let root = tree.preorder[0];
+
+    tree.preorder[1..].iter().all(|id| {
+        let parent = tree.parent[id];
+
+        if parent == root {
+            tree.index[id] == 1
+        } else {
+            tree.index[&low[id]] < tree.index[&parent]
+        }
+    })
+}
+
+fn is_st_order<G: Graph>(
+    graph: &G, order: &[usize], s: usize, t: usize
+) -> bool {
+    if order.len() != graph.order() ||
+        order.first() != Some(&s) ||
+        order.last() != Some(&t) {
+        return false;
+    }
+
+    let positions = order.iter().enumerate()
+        .map(|(position, &id)| (id, position))
+        .collect::<HashMap<_,_>>();
+
+    if positions.len() != order.len() {
+        return false;
+    }
+
+    order[1..order.len() - 1].iter().all(|&id| {
+        let position = positions[&id];
+        let neighbors = graph.neighbors(id).expect("neighbors")
+            .filter_map(|neighbor| positions.get(&neighbor).cloned())
+            .collect::<Vec<_>>();
+
+        neighbors.iter().any(|&other| other < position) &&
+            neighbors.iter().any(|&other| other > position)
+    })
+}
+
+#[cfg(test)]
+mod st_numbering {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_t() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 0, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn not_adjacent() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 0, 2), Err(Error::MissingEdge(0, 2)))
+    }
+
+    #[test]
+    fn p2() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 1, 0), Ok(Some(vec![ 1, 0 ])))
+    }
+
+    #[test]
+    fn bowtie() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)
+        ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 0, 1), Ok(None))
+    }
+
+    #[test]
+    fn c3_with_pendant_at_s() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (0, 3)
+        ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 0, 1), Ok(None))
+    }
+
+    #[test]
+    fn p3_from_center() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 1, 0), Ok(None))
+    }
+
+    #[test]
+    fn p3_from_end() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 0, 1), Ok(None))
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)
+        ]).unwrap();
+
+        assert_eq!(st_numbering(&graph, 0, 1), Ok(None))
+    }
+
+    #[test]
+    fn every_edge_of_cube() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0),
+            (4, 5), (5, 6), (6, 7), (7, 4),
+            (0, 4), (1, 5), (2, 6), (3, 7)
+        ]).unwrap();
+
+        for (sid, tid) in graph.edges() {
+            for &(s, t) in &[ (sid, tid), (tid, sid) ] {
+                let order = st_numbering(&graph, s, t).unwrap().unwrap();
+
+                assert_eq!(order[0], s);
+                assert_eq!(order[order.len() - 1], t);
+                assert!(is_st_order(&graph, &order, s, t))
+            }
+        }
+    }
+
+    #[test]
+    fn every_edge_of_k5() {
+        let graph = (0..5)
+            .flat_map(|sid| (sid + 1..5).map(move |tid| (sid, tid)))
+            .collect::<DefaultGraph>();
+
+        for (s, t) in graph.edges() {
+            assert!(st_numbering(&graph, s, t).unwrap().is_some())
+        }
+    }
+}
+
+#[cfg(test)]
+mod is_st_order {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn wrong_t() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        assert!(!is_st_order(&graph, &[ 0, 1, 2 ], 0, 1))
+    }
+
+    #[test]
+    fn p3() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        assert!(is_st_order(&graph, &[ 0, 1, 2 ], 0, 2))
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::error;
+
+use crate::{ graph, matching, io };
+
+/// An error from any module of the crate. Each module error converts with
+/// From, so `?` works in functions that call into several modules. Display
+/// names only the module; the module error is available as `source`, so
+/// error reporters that walk the chain print each message once.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::Error;
+/// use gamma::graph::DefaultGraph;
+/// use gamma::matching::{ konig_vertex_cover, maximum_matching, Pairing };
+///
+/// fn cover(edges: Vec<(usize, usize)>) -> Result<Vec<usize>, Error> {
+///     let graph = DefaultGraph::try_from(edges)?;
+///     let mut pairing = Pairing::new();
+///
+///     maximum_matching(&graph, &mut pairing);
+///
+///     Ok(konig_vertex_cover(&graph, &pairing)?)
+/// }
+///
+/// fn main() {
+///     assert_eq!(cover(vec![ (0, 1), (1, 2) ]).unwrap(), vec![ 1 ]);
+///     assert_eq!(cover(vec![ (0, 0) ]).is_err(), true);
+///     assert_eq!(cover(vec![ (0, 1), (1, 2), (2, 0) ]).is_err(), true);
+/// }
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    Graph(graph::Error),
+    Matching(matching::Error),
+    Io(io::Error)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Graph(_) => write!(f, "graph error"),
+            Error::Matching(_) => write!(f, "matching error"),
+            Error::Io(_) => write!(f, "io error")
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Graph(error) => Some(error),
+            Error::Matching(error) => Some(error),
+            Error::Io(error) => Some(error)
+        }
+    }
+}
+
+impl From<graph::Error> for Error {
+    fn from(error: graph::Error) -> Self {
+        Error::Graph(error)
+    }
+}
+
+impl From<matching::Error> for Error {
+    fn from(error: matching::Error) -> Self {
+        Error::Matching(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn graph() {
+        assert_eq!(
+            Error::from(graph::Error::UnknownId(3)).to_string(),
+            "graph error"
+        )
+    }
+
+    #[test]
+    fn matching() {
+        assert_eq!(
+            Error::from(matching::Error::NotMaximum).to_string(),
+            "matching error"
+        )
+    }
+
+    #[test]
+    fn io() {
+        assert_eq!(
+            Error::from(io::Error::InvalidLine(2)).to_string(),
+            "io error"
+        )
+    }
+}
+
+#[cfg(test)]
+mod source {
+    use std::error::Error as _;
+    use super::*;
+
+    #[test]
+    fn matching() {
+        let error = Error::from(matching::Error::MissingRoot);
+
+        assert_eq!(
+            error.source().map(|source| source.to_string()),
+            Some("blossom root not found".to_string())
+        )
+    }
+
+    #[test]
+    fn chain() {
+        let error = Error::from(
+            matching::Error::Graph(graph::Error::UnknownId(3))
+        );
+        let mut messages = vec![ error.to_string() ];
+        let mut source = error.source();
+
+        while let Some(error) = source {
+            messages.push(error.to_string());
+            source = error.source();
+        }
+
+        assert_eq!(messages, [
+            "matching error", "graph error", "unknown id: 3"
+        ])
+    }
+}
@@ -0,0 +1,122 @@
+/// A fixed-length bitset summarizing the features of a graph, such as
+/// those produced by `paths`. If one graph is a subgraph of another, its
+/// fingerprint is a subset of the other's, so `is_subset` can rule out
+/// most candidates before an expensive subgraph isomorphism search.
+///
+/// ```rust
+/// use gamma::fingerprint::Fingerprint;
+///
+/// let mut small = Fingerprint::new(64);
+/// let mut large = Fingerprint::new(64);
+///
+/// small.insert(3);
+/// large.insert(3);
+/// large.insert(40);
+///
+/// assert_eq!(small.is_subset(&large), true);
+/// assert_eq!(large.is_subset(&small), false);
+/// assert_eq!(small.tanimoto(&large), 0.5);
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+pub struct Fingerprint {
+    words: Vec<u64>,
+    length: usize
+}
+
+impl Fingerprint {
+    /// Returns a Fingerprint of length bits, all clear.
+    pub fn new(length: usize) -> Self {
+        Self { words: vec![ 0; length.div_ceil(64) ], length }
+    }
+
+    /// Returns the number of bits, set or clear.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Sets bit. Panics if bit is not less than length.
+    pub fn insert(&mut self, bit: usize) {
+        assert!(bit < self.length, "bit out of range");
+
+        self.words[bit / 64] |= 1 << (bit % 64);
+    }
+
+    /// Returns true if bit is set, or false otherwise.
+    pub fn contains(&self, bit: usize) -> bool {
+        match self.words.get(bit / 64) {
+            Some(word) => word & (1 << (bit % 64)) != 0,
+            None => false
+        }
+    }
+
+    /// Returns an Iterator over set bits, in increasing order.
+    pub fn bits(&self) -> impl Iterator<Item=usize> + '_ {
+        (0..self.length).filter(move |&bit| self.contains(bit))
+    }
+
+    /// Returns the number of set bits.
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns true if every bit set here is also set in other.
+    pub fn is_subset(&self, other: &Fingerprint) -> bool {
+        self.words.iter().enumerate().all(|(index, word)| {
+            word & !other.words.get(index).cloned().unwrap_or(0) == 0
+        })
+    }
+
+    /// Returns the Tanimoto (Jaccard) similarity with other: the number of
+    /// bits set in both divided by the number set in either. Two
+    /// fingerprints with no bits set have similarity one.
+    pub fn tanimoto(&self, other: &Fingerprint) -> f64 {
+        let (mut both, mut either) = (0, 0);
+
+        for index in 0..self.words.len().max(other.words.len()) {
+            let a = self.words.get(index).cloned().unwrap_or(0);
+            let b = other.words.get(index).cloned().unwrap_or(0);
+
+            both += (a & b).count_ones();
+            either += (a | b).count_ones();
+        }
+
+        if either == 0 {
+            1.0
+        } else {
+            both as f64 / either as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "bit out of range")]
+    fn insert_out_of_range() {
+        Fingerprint::new(10).insert(10)
+    }
+
+    #[test]
+    fn bits() {
+        let mut fingerprint = Fingerprint::new(130);
+
+        fingerprint.insert(129);
+        fingerprint.insert(0);
+        fingerprint.insert(64);
+
+        assert_eq!(fingerprint.bits().collect::<Vec<_>>(), vec![ 0, 64, 129 ]);
+        assert_eq!(fingerprint.count(), 3)
+    }
+
+    #[test]
+    fn empty_subset() {
+        assert!(Fingerprint::new(8).is_subset(&Fingerprint::new(8)))
+    }
+
+    #[test]
+    fn empty_tanimoto() {
+        assert_eq!(Fingerprint::new(8).tanimoto(&Fingerprint::new(8)), 1.0)
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod fingerprint;
+mod paths;
+
+pub use fingerprint::Fingerprint;
+pub use paths::paths;
@@ -0,0 +1,182 @@
+use std::hash::{ Hash, Hasher };
+
+use crate::graph::Graph;
+use crate::hash::FxHasher;
+use super::Fingerprint;
+
+/// Returns a path-based Fingerprint of graph with length bits, in the
+/// style of Daylight fingerprints. Every path with no repeated nodes and
+/// at most max_length edges, including single nodes, is described by the
+/// labels of its nodes, read in whichever direction gives the smaller
+/// sequence. Each description is hashed to set one bit. Hashing uses
+/// `FxHasher`, so fingerprints are reproducible across runs and can be
+/// stored. Panics if length is zero.
+///
+/// The number of paths grows quickly with max_length in dense graphs.
+/// Values up to about seven are practical for molecules.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::fingerprint::paths;
+///
+/// fn main() -> Result<(), Error> {
+///     let elements = [ "C", "C", "O", "C" ];
+///     let ether = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3) ])?;
+///     let ethanol = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ])?;
+///     let label = |id: usize| elements[id];
+///     let query = paths(&ethanol, 4, 1024, label);
+///     let target = paths(&ether, 4, 1024, label);
+///
+///     assert_eq!(query.is_subset(&target), true);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn paths<G, F, L>(
+    graph: &G, max_length: usize, length: usize, label: F
+) -> Fingerprint
+where
+    G: Graph,
+    F: Fn(usize) -> L,
+    L: Hash
+{
+    assert!(length > 0, "zero length");
+
+    let mut result = Fingerprint::new(length);
+    let mut set = |labels: &[u64]| {
+        let forward = labels.iter();
+        let backward = labels.iter().rev();
+        let mut hasher = FxHasher::default();
+
+        if backward.clone().lt(forward.clone()) {
+            backward.for_each(|label| hasher.write_u64(*label));
+        } else {
+            forward.for_each(|label| hasher.write_u64(*label));
+        }
+
+        hasher.write_usize(labels.len());
+        result.insert((hasher.finish() % length as u64) as usize);
+    };
+
+    for root in graph.ids() {
+        let mut path = vec![ root ];
+        let mut labels = vec![ hash(&label(root)) ];
+        let mut stack = Vec::new();
+
+        set(&labels);
+
+        if max_length > 0 {
+            stack.push(graph.neighbors(root).expect("neighbors"));
+        }
+
+        while let Some(neighbors) = stack.last_mut() {
+            match neighbors.next() {
+                Some(id) => {
+                    if path.contains(&id) {
+                        continue;
+                    }
+
+                    path.push(id);
+                    labels.push(hash(&label(id)));
+                    set(&labels);
+
+                    if path.len() <= max_length {
+                        stack.push(graph.neighbors(id).expect("neighbors"));
+                    } else {
+                        path.pop();
+                        labels.pop();
+                    }
+                },
+                None => {
+                    stack.pop();
+                    path.pop();
+                    labels.pop();
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn hash<L: Hash>(label: &L) -> u64 {
+    let mut hasher = FxHasher::default();
+
+    label.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    #[should_panic(expected = "zero length")]
+    fn zero_length() {
+        paths(&DefaultGraph::new(), 3, 0, |_| 0);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(paths(&DefaultGraph::new(), 3, 64, |_| 0).count(), 0)
+    }
+
+    #[test]
+    fn single_nodes_only() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let fingerprint = paths(&graph, 0, 1024, |id| id % 2);
+
+        assert_eq!(fingerprint.count(), 2)
+    }
+
+    #[test]
+    fn direction_independent() {
+        let forward = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let labels = [ "C", "N", "O" ];
+        let reversed = [ "O", "N", "C" ];
+
+        assert_eq!(
+            paths(&forward, 2, 1024, |id| labels[id]),
+            paths(&forward, 2, 1024, |id| reversed[id])
+        )
+    }
+
+    #[test]
+    fn ids_ignored() {
+        let a = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let b = DefaultGraph::try_from(vec![ (7, 3), (3, 5) ]).unwrap();
+
+        assert_eq!(paths(&a, 3, 256, |_| 6), paths(&b, 3, 256, |_| 6))
+    }
+
+    #[test]
+    fn max_length_bounds_paths() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4)
+        ]).unwrap();
+
+        // with a single label, each path length gives one bit
+        assert_eq!(paths(&graph, 2, 4096, |_| 0).count(), 3);
+        assert_eq!(paths(&graph, 10, 4096, |_| 0).count(), 5)
+    }
+
+    #[test]
+    fn subgraph_is_subset() {
+        let ring = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 6)
+        ]).unwrap();
+        let chain = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (0, 6)
+        ]).unwrap();
+        let label = |id: usize| if id == 6 { "O" } else { "C" };
+        let query = paths(&chain, 5, 512, label);
+        let target = paths(&ring, 5, 512, label);
+
+        assert!(query.is_subset(&target));
+        assert!(!target.is_subset(&query))
+    }
+}
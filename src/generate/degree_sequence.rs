@@ -0,0 +1,207 @@
+use std::cmp::Reverse;
+use std::collections::{ BinaryHeap, HashSet };
+
+use crate::graph::{ Graph, DefaultGraph, Error };
+use crate::rng::Rng;
+use super::rewire;
+
+// Configuration model attempts before falling back to a rewired Havel-Hakimi
+// realization. Skewed sequences rarely give a simple pairing.
+const TRIES: usize = 100;
+
+/// Returns the simple graph over ids 0..sequence.len() given by the
+/// Havel-Hakimi algorithm, in which node i has degree sequence[i]. The
+/// node with most remaining degree is repeatedly joined to the nodes with
+/// the next most. Returns DegreeMismatch if sequence isn't graphical,
+/// naming the node whose degree can't be met.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error };
+/// use gamma::generate::havel_hakimi;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = havel_hakimi(&[ 2, 2, 1, 1 ])?;
+///
+///     assert_eq!(graph.degree(0), Ok(2));
+///     assert_eq!(havel_hakimi(&[ 3, 1, 1 ]), Err(Error::DegreeMismatch(0)));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn havel_hakimi(sequence: &[usize]) -> Result<DefaultGraph, Error> {
+    let mut result = nodes(sequence);
+    let mut heap = sequence.iter().enumerate()
+        .filter(|&(_, &degree)| degree > 0)
+        .map(|(id, &degree)| (degree, Reverse(id)))
+        .collect::<BinaryHeap<_>>();
+
+    while let Some((degree, Reverse(sid))) = heap.pop() {
+        if heap.len() < degree {
+            return Err(Error::DegreeMismatch(sid));
+        }
+
+        let targets = (0..degree)
+            .map(|_| heap.pop().expect("target"))
+            .collect::<Vec<_>>();
+
+        for (remaining, Reverse(tid)) in targets {
+            result.add_edge(sid, tid)?;
+
+            if remaining > 1 {
+                heap.push((remaining - 1, Reverse(tid)));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns a random simple graph over ids 0..sequence.len() in which node
+/// i has degree sequence[i]. Nodes' edge ends are paired at random, as in
+/// the configuration model, until a pairing yields no self-loop or
+/// duplicate edge. If 100 pairings fail, the Havel-Hakimi realization is
+/// randomized with double-edge swaps instead. The same seed gives the same
+/// graph. Returns DegreeMismatch as `havel_hakimi` does.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error };
+/// use gamma::generate::from_degree_sequence;
+///
+/// fn main() -> Result<(), Error> {
+///     let sequence = [ 3, 3, 2, 2, 2, 1, 1 ];
+///     let graph = from_degree_sequence(&sequence, 7)?;
+///
+///     for (id, &degree) in sequence.iter().enumerate() {
+///         assert_eq!(graph.degree(id), Ok(degree));
+///     }
+///
+///     assert_eq!(graph, from_degree_sequence(&sequence, 7)?);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn from_degree_sequence(
+    sequence: &[usize], seed: u64
+) -> Result<DefaultGraph, Error> {
+    let realization = havel_hakimi(sequence)?;
+    let mut rng = Rng::new(seed);
+    let mut stubs = sequence.iter().enumerate()
+        .flat_map(|(id, &degree)| std::iter::repeat_n(id, degree))
+        .collect::<Vec<_>>();
+
+    'tries: for _ in 0..TRIES {
+        let len = stubs.len();
+        let mut edges = HashSet::new();
+
+        rng.partial_shuffle(&mut stubs, len);
+
+        for pair in stubs.chunks_exact(2) {
+            let (sid, tid) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+
+            if sid == tid || !edges.insert((sid, tid)) {
+                continue 'tries;
+            }
+        }
+
+        let mut result = nodes(sequence);
+
+        for pair in stubs.chunks_exact(2) {
+            result.add_edge(pair[0], pair[1])?;
+        }
+
+        return Ok(result);
+    }
+
+    rewire(&realization, realization.size().saturating_mul(10), seed)
+}
+
+fn nodes(sequence: &[usize]) -> DefaultGraph {
+    let size = sequence.iter().sum::<usize>() / 2;
+    let mut result = DefaultGraph::with_capacity(sequence.len(), size);
+
+    for id in 0..sequence.len() {
+        result.add_node(id).expect("add node");
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn degrees(graph: &DefaultGraph) -> Vec<usize> {
+        graph.ids().map(|id| graph.degree(id).unwrap()).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(havel_hakimi(&[ ]), Ok(DefaultGraph::new()));
+        assert_eq!(from_degree_sequence(&[ ], 1), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn zeros() {
+        let graph = from_degree_sequence(&[ 0, 0, 0 ], 1).unwrap();
+
+        assert_eq!(graph.order(), 3);
+        assert_eq!(graph.size(), 0)
+    }
+
+    #[test]
+    fn odd_sum() {
+        assert_eq!(
+            from_degree_sequence(&[ 1, 1, 1 ], 1),
+            Err(Error::DegreeMismatch(2))
+        )
+    }
+
+    #[test]
+    fn degree_exceeds_order() {
+        assert_eq!(
+            from_degree_sequence(&[ 4, 2, 1, 1 ], 1),
+            Err(Error::DegreeMismatch(0))
+        )
+    }
+
+    #[test]
+    fn even_sum_not_graphical() {
+        assert_eq!(havel_hakimi(&[ 3, 3, 1, 1 ]), Err(Error::DegreeMismatch(1)))
+    }
+
+    #[test]
+    fn havel_hakimi_complete() {
+        let graph = havel_hakimi(&[ 3, 3, 3, 3 ]).unwrap();
+
+        assert_eq!(graph.size(), 6)
+    }
+
+    #[test]
+    fn havel_hakimi_degrees() {
+        let sequence = vec![ 4, 3, 3, 2, 2, 2, 1, 1 ];
+
+        assert_eq!(degrees(&havel_hakimi(&sequence).unwrap()), sequence)
+    }
+
+    #[test]
+    fn random_degrees() {
+        let sequence = (0..60).map(|id| 1 + id % 5).collect::<Vec<_>>();
+
+        for seed in 0..10 {
+            let graph = from_degree_sequence(&sequence, seed).unwrap();
+
+            assert_eq!(degrees(&graph), sequence)
+        }
+    }
+
+    #[test]
+    fn skewed() {
+        let mut sequence = vec![ 9, 9, 9 ];
+
+        sequence.extend(vec![ 3; 9 ]);
+
+        let graph = from_degree_sequence(&sequence, 3).unwrap();
+
+        assert_eq!(degrees(&graph), sequence)
+    }
+}
@@ -0,0 +1,13 @@
+mod rewire;
+mod degree_sequence;
+mod watts_strogatz;
+mod random_geometric;
+mod random_bipartite;
+mod random_regular;
+
+pub use rewire::rewire;
+pub use degree_sequence::{ havel_hakimi, from_degree_sequence };
+pub use watts_strogatz::watts_strogatz;
+pub use random_geometric::random_geometric;
+pub use random_bipartite::random_bipartite;
+pub use random_regular::random_regular;
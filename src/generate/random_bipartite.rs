@@ -0,0 +1,66 @@
+use crate::graph::DefaultGraph;
+use crate::rng::Rng;
+
+/// Returns a random bipartite graph with left nodes 0..left and right nodes
+/// left..left + right, in which each left-right pair is joined with
+/// probability p. The same seed gives the same graph.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::generate::random_bipartite;
+///
+/// let graph = random_bipartite(3, 4, 0.5, 7);
+///
+/// assert_eq!(graph.order(), 7);
+/// assert_eq!(graph.edges().all(|(sid, tid)| (sid < 3) != (tid < 3)), true);
+/// assert_eq!(graph, random_bipartite(3, 4, 0.5, 7));
+/// ```
+pub fn random_bipartite(
+    left: usize, right: usize, p: f64, seed: u64
+) -> DefaultGraph {
+    let mut rng = Rng::new(seed);
+    let mut result = DefaultGraph::new();
+
+    for id in 0..(left + right) {
+        result.add_node(id).expect("add node");
+    }
+
+    for sid in 0..left {
+        for tid in left..(left + right) {
+            if rng.next_f64() < p {
+                result.add_edge(sid, tid).expect("add edge");
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(random_bipartite(0, 0, 0.5, 1), DefaultGraph::new())
+    }
+
+    #[test]
+    fn one_side() {
+        let graph = random_bipartite(4, 0, 1.0, 1);
+
+        assert_eq!(graph.order(), 4);
+        assert_eq!(graph.size(), 0)
+    }
+
+    #[test]
+    fn complete() {
+        assert_eq!(random_bipartite(3, 5, 1.0, 1).size(), 15)
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(random_bipartite(3, 5, 0.0, 1).size(), 0)
+    }
+}
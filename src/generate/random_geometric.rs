@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::graph::DefaultGraph;
+use crate::rng::Rng;
+
+/// Returns a random geometric graph over ids 0..order, with the position
+/// of each node. Nodes are placed uniformly at random in the unit square,
+/// and joined when no more than radius apart. Points are bucketed into
+/// cells of width radius, so only nearby pairs are compared. The same seed
+/// gives the same graph.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::generate::random_geometric;
+///
+/// let (graph, positions) = random_geometric(50, 0.2, 7);
+///
+/// for (sid, tid) in graph.edges() {
+///     let (x1, y1) = positions[&sid];
+///     let (x2, y2) = positions[&tid];
+///
+///     assert_eq!((x1 - x2).hypot(y1 - y2) <= 0.2, true);
+/// }
+/// ```
+pub fn random_geometric(
+    order: usize, radius: f64, seed: u64
+) -> (DefaultGraph, HashMap<usize, (f64, f64)>) {
+    let mut rng = Rng::new(seed);
+    let points = (0..order)
+        .map(|_| (rng.next_f64(), rng.next_f64()))
+        .collect::<Vec<_>>();
+    let mut result = DefaultGraph::with_capacity(order, order);
+
+    for id in 0..order {
+        result.add_node(id).expect("add node");
+    }
+
+    if radius > 0.0 {
+        let cell = |(x, y): (f64, f64)| {
+            ((x / radius) as i64, (y / radius) as i64)
+        };
+        let mut cells = HashMap::<_, Vec<usize>>::new();
+
+        for (id, &point) in points.iter().enumerate() {
+            cells.entry(cell(point)).or_default().push(id);
+        }
+
+        for (sid, &point) in points.iter().enumerate() {
+            let (cx, cy) = cell(point);
+
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    let others = match cells.get(&(cx + dx, cy + dy)) {
+                        Some(others) => others,
+                        None => continue
+                    };
+
+                    for &tid in others.iter().filter(|&&tid| tid > sid) {
+                        let (x, y) = points[tid];
+
+                        if (point.0 - x).hypot(point.1 - y) <= radius {
+                            result.add_edge(sid, tid).expect("add edge");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (result, points.into_iter().enumerate().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    fn brute(
+        positions: &HashMap<usize, (f64, f64)>, radius: f64
+    ) -> usize {
+        let mut result = 0;
+
+        for (sid, &(x1, y1)) in positions {
+            for (tid, &(x2, y2)) in positions {
+                if sid < tid && (x1 - x2).hypot(y1 - y2) <= radius {
+                    result += 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn empty() {
+        let (graph, positions) = random_geometric(0, 0.5, 1);
+
+        assert!(graph.is_empty());
+        assert!(positions.is_empty())
+    }
+
+    #[test]
+    fn zero_radius() {
+        let (graph, positions) = random_geometric(20, 0.0, 1);
+
+        assert_eq!(graph.order(), 20);
+        assert_eq!(graph.size(), 0);
+        assert_eq!(positions.len(), 20)
+    }
+
+    #[test]
+    fn large_radius_complete() {
+        let (graph, _) = random_geometric(10, 1.5, 1);
+
+        assert_eq!(graph.size(), 45)
+    }
+
+    #[test]
+    fn matches_all_pairs() {
+        for &radius in &[ 0.05, 0.1, 0.3 ] {
+            let (graph, positions) = random_geometric(300, radius, 9);
+
+            assert_eq!(graph.size(), brute(&positions, radius))
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::graph::{ DefaultGraph, Error };
+use super::from_degree_sequence;
+
+/// Returns a random graph over ids 0..order in which every node has the
+/// given degree, as built by `from_degree_sequence`. The same seed gives
+/// the same graph. Returns DegreeMismatch if no such graph exists, which
+/// is when degree is at least order, or both are odd.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error };
+/// use gamma::generate::random_regular;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = random_regular(10, 3, 7)?;
+///
+///     assert_eq!(graph.ids().all(|id| graph.degree(id) == Ok(3)), true);
+///     assert_eq!(random_regular(5, 3, 7).is_err(), true);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn random_regular(
+    order: usize, degree: usize, seed: u64
+) -> Result<DefaultGraph, Error> {
+    from_degree_sequence(&vec![ degree; order ], seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(random_regular(0, 3, 1), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn degree_too_large() {
+        assert!(random_regular(4, 4, 1).is_err())
+    }
+
+    #[test]
+    fn odd_order_odd_degree() {
+        assert!(random_regular(7, 3, 1).is_err())
+    }
+
+    #[test]
+    fn complete() {
+        assert_eq!(random_regular(6, 5, 1).unwrap().size(), 15)
+    }
+
+    #[test]
+    fn cubic() {
+        for seed in 0..10 {
+            let graph = random_regular(50, 3, seed).unwrap();
+            let cubic = graph.ids().all(|id| graph.degree(id) == Ok(3));
+
+            assert!(cubic)
+        }
+    }
+}
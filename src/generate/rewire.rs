@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Graph, DefaultGraph, Error };
+use crate::rng::Rng;
+
+// Attempts allowed per requested swap before giving up, so that graphs
+// with few or no valid swaps, such as stars, still terminate.
+const TRIES: usize = 100;
+
+/// Returns a copy of graph randomized by swaps double-edge swaps, each
+/// replacing edges (a, b) and (c, d) with (a, d) and (c, b). Swaps that
+/// would create a self-loop or duplicate edge are rejected, so every node
+/// keeps its degree and the result stays simple. Such graphs serve as null
+/// models when judging whether counts of triangles or motifs are unusual.
+///
+/// Edges are drawn at random from seed, so the same seed gives the same
+/// result. Fewer swaps are made if 100 attempts per swap don't find them,
+/// as when graph has fewer than two edges.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::generate::rewire;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)
+///     ])?;
+///     let rewired = rewire(&graph, 10, 7)?;
+///
+///     for id in graph.ids() {
+///         assert_eq!(rewired.degree(id), graph.degree(id));
+///     }
+///
+///     assert_eq!(rewired, rewire(&graph, 10, 7)?);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn rewire<G: Graph>(
+    graph: &G, swaps: usize, seed: u64
+) -> Result<DefaultGraph, Error> {
+    let mut edges = graph.edges().collect::<Vec<_>>();
+    let mut present = edges.iter()
+        .map(|&(sid, tid)| key(sid, tid))
+        .collect::<HashSet<_>>();
+    let mut rng = Rng::new(seed);
+    let mut done = 0;
+
+    if edges.len() > 1 {
+        for _ in 0..swaps.saturating_mul(TRIES) {
+            if done == swaps {
+                break;
+            }
+
+            let i = rng.below(edges.len());
+            let j = rng.below(edges.len());
+            let (a, b) = edges[i];
+            let (c, d) = if rng.below(2) == 0 {
+                edges[j]
+            } else {
+                (edges[j].1, edges[j].0)
+            };
+
+            if a == c || a == d || b == c || b == d {
+                continue;
+            }
+
+            if present.contains(&key(a, d)) || present.contains(&key(c, b)) {
+                continue;
+            }
+
+            present.remove(&key(a, b));
+            present.remove(&key(c, d));
+            present.insert(key(a, d));
+            present.insert(key(c, b));
+            edges[i] = (a, d);
+            edges[j] = (c, b);
+            done += 1;
+        }
+    }
+
+    let mut result = DefaultGraph::with_capacity(graph.order(), edges.len());
+
+    for id in graph.ids() {
+        result.add_node(id)?;
+    }
+
+    for (sid, tid) in edges {
+        result.add_edge(sid, tid)?;
+    }
+
+    Ok(result)
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(rewire(&graph, 10, 1), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn single_edge() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(rewire(&graph, 10, 1), Ok(graph))
+    }
+
+    #[test]
+    fn star_has_no_swaps() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (0, 4)
+        ]).unwrap();
+
+        assert_eq!(rewire(&graph, 10, 1), Ok(graph))
+    }
+
+    #[test]
+    fn isolated_node_kept() {
+        let mut graph = DefaultGraph::try_from(vec![
+            (0, 1), (2, 3)
+        ]).unwrap();
+
+        graph.add_node(4).unwrap();
+
+        let rewired = rewire(&graph, 1, 3).unwrap();
+
+        assert_eq!(rewired.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3, 4 ]);
+        assert_eq!(rewired.size(), 2);
+        assert_eq!(rewired.has_edge(0, 1), Ok(false))
+    }
+
+    #[test]
+    fn degrees_preserved() {
+        let mut edges = Vec::new();
+
+        for sid in 0..20 {
+            edges.push((sid, (sid + 1) % 20));
+            edges.push((sid, (sid + 7) % 20));
+        }
+
+        let graph = DefaultGraph::try_from(edges).unwrap();
+        let rewired = rewire(&graph, 100, 42).unwrap();
+
+        for id in graph.ids() {
+            assert_eq!(rewired.degree(id), graph.degree(id))
+        }
+
+        assert_eq!(rewired.size(), graph.size());
+        assert_ne!(rewired, graph)
+    }
+}
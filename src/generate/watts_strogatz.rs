@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use crate::graph::DefaultGraph;
+use crate::rng::Rng;
+
+/// Returns a small-world graph over ids 0..order from the model of Watts
+/// and Strogatz. Nodes start on a ring, each joined to its k / 2 nearest
+/// neighbors on either side. Each ring edge then has probability p of
+/// having its far end moved to a node chosen at random, avoiding
+/// self-loops and duplicate edges. Odd k is rounded down, and k is capped
+/// at order - 1. The same seed gives the same graph.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::generate::watts_strogatz;
+///
+/// let ring = watts_strogatz(10, 4, 0.0, 7);
+/// let rewired = watts_strogatz(10, 4, 0.5, 7);
+///
+/// assert_eq!(ring.has_edge(0, 2), Ok(true));
+/// assert_eq!(rewired.size(), ring.size());
+/// assert_eq!(rewired, watts_strogatz(10, 4, 0.5, 7));
+/// ```
+pub fn watts_strogatz(
+    order: usize, k: usize, p: f64, seed: u64
+) -> DefaultGraph {
+    let half = k.min(order.saturating_sub(1)) / 2;
+    let mut rng = Rng::new(seed);
+    let mut edges = Vec::with_capacity(order * half);
+    let mut present = HashSet::new();
+    let mut degrees = vec![ 2 * half; order ];
+
+    for step in 1..=half {
+        for sid in 0..order {
+            let tid = (sid + step) % order;
+
+            present.insert(key(sid, tid));
+            edges.push((sid, tid));
+        }
+    }
+
+    for edge in edges.iter_mut() {
+        let (sid, tid) = *edge;
+
+        if rng.next_f64() >= p || degrees[sid] + 1 >= order {
+            continue;
+        }
+
+        let target = loop {
+            let target = rng.below(order);
+
+            if target != sid && !present.contains(&key(sid, target)) {
+                break target;
+            }
+        };
+
+        present.remove(&key(sid, tid));
+        present.insert(key(sid, target));
+        degrees[tid] -= 1;
+        degrees[target] += 1;
+        *edge = (sid, target);
+    }
+
+    let mut result = DefaultGraph::with_capacity(order, edges.len());
+
+    for id in 0..order {
+        result.add_node(id).expect("add node");
+    }
+
+    for (sid, tid) in edges {
+        result.add_edge(sid, tid).expect("add edge");
+    }
+
+    result
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(watts_strogatz(0, 4, 0.5, 1), DefaultGraph::new())
+    }
+
+    #[test]
+    fn ring() {
+        let graph = watts_strogatz(8, 2, 0.0, 1);
+
+        assert_eq!(graph.size(), 8);
+        assert_eq!(graph.has_edge(7, 0), Ok(true))
+    }
+
+    #[test]
+    fn odd_k() {
+        assert_eq!(watts_strogatz(8, 3, 0.0, 1).size(), 8)
+    }
+
+    #[test]
+    fn k_exceeds_order() {
+        let graph = watts_strogatz(5, 10, 0.0, 1);
+
+        assert_eq!(graph.size(), 10)
+    }
+
+    #[test]
+    fn complete() {
+        let graph = watts_strogatz(7, 6, 1.0, 1);
+
+        assert_eq!(graph.size(), 21)
+    }
+
+    #[test]
+    fn fully_rewired() {
+        let graph = watts_strogatz(100, 6, 1.0, 3);
+        let lattice = graph.edges()
+            .filter(|&(sid, tid)| {
+                let gap = (sid + 100 - tid) % 100;
+
+                gap <= 3 || gap >= 97
+            })
+            .count();
+
+        assert_eq!(graph.size(), 300);
+        assert!(lattice < 50)
+    }
+}
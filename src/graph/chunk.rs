@@ -0,0 +1,359 @@
+use std::hash::{ Hash, Hasher };
+use std::iter::Copied;
+use std::ops::Range;
+use std::slice::Iter;
+
+use super::Graph;
+use super::default_graph::{ Id, from_id };
+use super::csr_graph::{ CsrGraph, EdgeRange };
+
+/// A contiguous run of node ids borrowed from a concrete graph. Ids are
+/// yielded as usize regardless of how they're stored, so the same code
+/// works with and without the `u32-ids` feature. Chunks with the same ids
+/// are equal whichever graph they came from.
+#[derive(Debug,Clone,Copy)]
+pub struct IdChunk<'a> {
+    ids: Ids<'a>
+}
+
+#[derive(Debug,Clone,Copy)]
+enum Ids<'a> {
+    Stored(&'a [Id]),
+    Wide(&'a [usize]),
+    Dense(usize, usize)
+}
+
+impl<'a> IdChunk<'a> {
+    pub(super) fn new(ids: &'a [Id]) -> Self {
+        Self { ids: Ids::Stored(ids) }
+    }
+
+    pub(super) fn wide(ids: &'a [usize]) -> Self {
+        Self { ids: Ids::Wide(ids) }
+    }
+
+    /// Returns the chunk of ids from start up to, but not including, end.
+    pub(super) fn dense(start: usize, end: usize) -> Self {
+        Self { ids: Ids::Dense(start, end) }
+    }
+
+    pub fn len(&self) -> usize {
+        match self.ids {
+            Ids::Stored(ids) => ids.len(),
+            Ids::Wide(ids) => ids.len(),
+            Ids::Dense(start, end) => end - start
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<usize> {
+        match self.ids {
+            Ids::Stored(ids) => ids.get(index).copied().map(from_id),
+            Ids::Wide(ids) => ids.get(index).copied(),
+            Ids::Dense(start, end) => {
+                if index < end - start { Some(start + index) } else { None }
+            }
+        }
+    }
+
+    pub fn iter(&self) -> IdChunkIter<'a> {
+        let ids = match self.ids {
+            Ids::Stored(ids) => IdsIter::Stored(ids.iter().copied()),
+            Ids::Wide(ids) => IdsIter::Wide(ids.iter().copied()),
+            Ids::Dense(start, end) => IdsIter::Dense(start..end)
+        };
+
+        IdChunkIter { ids }
+    }
+
+    pub fn to_vec(&self) -> Vec<usize> {
+        self.iter().collect()
+    }
+}
+
+impl<'a> PartialEq for IdChunk<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a> Eq for IdChunk<'a> { }
+
+impl<'a> Hash for IdChunk<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for id in self.iter() {
+            id.hash(state);
+        }
+    }
+}
+
+impl<'a> IntoIterator for IdChunk<'a> {
+    type Item = usize;
+    type IntoIter = IdChunkIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the ids of an IdChunk.
+#[derive(Debug,Clone)]
+pub struct IdChunkIter<'a> {
+    ids: IdsIter<'a>
+}
+
+#[derive(Debug,Clone)]
+enum IdsIter<'a> {
+    Stored(Copied<Iter<'a, Id>>),
+    Wide(Copied<Iter<'a, usize>>),
+    Dense(Range<usize>)
+}
+
+impl<'a> Iterator for IdChunkIter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match &mut self.ids {
+            IdsIter::Stored(ids) => ids.next().map(from_id),
+            IdsIter::Wide(ids) => ids.next(),
+            IdsIter::Dense(ids) => ids.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.ids {
+            IdsIter::Stored(ids) => ids.size_hint(),
+            IdsIter::Wide(ids) => ids.size_hint(),
+            IdsIter::Dense(ids) => ids.size_hint()
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for IdChunkIter<'a> {
+    fn next_back(&mut self) -> Option<usize> {
+        match &mut self.ids {
+            IdsIter::Stored(ids) => ids.next_back().map(from_id),
+            IdsIter::Wide(ids) => ids.next_back(),
+            IdsIter::Dense(ids) => ids.next_back()
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for IdChunkIter<'a> { }
+
+/// A contiguous run of edges borrowed from a concrete graph. Like IdChunk,
+/// edges are yielded as usize pairs under either id width, and chunks with
+/// the same edges are equal whichever graph they came from.
+#[derive(Debug,Clone,Copy)]
+pub struct EdgeChunk<'a> {
+    edges: Edges<'a>
+}
+
+#[derive(Debug,Clone,Copy)]
+enum Edges<'a> {
+    Stored(&'a [(Id, Id)]),
+    Wide(&'a [(usize, usize)]),
+    Csr(&'a CsrGraph, usize, usize)
+}
+
+impl<'a> EdgeChunk<'a> {
+    pub(super) fn new(edges: &'a [(Id, Id)]) -> Self {
+        Self { edges: Edges::Stored(edges) }
+    }
+
+    pub(super) fn wide(edges: &'a [(usize, usize)]) -> Self {
+        Self { edges: Edges::Wide(edges) }
+    }
+
+    /// Returns the chunk of graph's edges with ids from start up to, but not
+    /// including, end.
+    pub(super) fn csr(graph: &'a CsrGraph, start: usize, end: usize) -> Self {
+        Self { edges: Edges::Csr(graph, start, end) }
+    }
+
+    pub fn len(&self) -> usize {
+        match self.edges {
+            Edges::Stored(edges) => edges.len(),
+            Edges::Wide(edges) => edges.len(),
+            Edges::Csr(_, start, end) => end - start
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<(usize, usize)> {
+        match self.edges {
+            Edges::Stored(edges) => edges.get(index).copied().map(from_edge),
+            Edges::Wide(edges) => edges.get(index).copied(),
+            Edges::Csr(graph, start, end) => {
+                if index < end - start {
+                    Some(graph.edge(start + index).expect("edge"))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub fn iter(&self) -> EdgeChunkIter<'a> {
+        let edges = match self.edges {
+            Edges::Stored(edges) => EdgesIter::Stored(edges.iter().copied()),
+            Edges::Wide(edges) => EdgesIter::Wide(edges.iter().copied()),
+            Edges::Csr(graph, start, end) => {
+                EdgesIter::Csr(EdgeRange::new(graph, start, end))
+            }
+        };
+
+        EdgeChunkIter { edges }
+    }
+
+    pub fn to_vec(&self) -> Vec<(usize, usize)> {
+        self.iter().collect()
+    }
+}
+
+impl<'a> PartialEq for EdgeChunk<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a> Eq for EdgeChunk<'a> { }
+
+impl<'a> Hash for EdgeChunk<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for edge in self.iter() {
+            edge.hash(state);
+        }
+    }
+}
+
+impl<'a> IntoIterator for EdgeChunk<'a> {
+    type Item = (usize, usize);
+    type IntoIter = EdgeChunkIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the edges of an EdgeChunk.
+#[derive(Debug,Clone)]
+pub struct EdgeChunkIter<'a> {
+    edges: EdgesIter<'a>
+}
+
+#[derive(Debug,Clone)]
+enum EdgesIter<'a> {
+    Stored(Copied<Iter<'a, (Id, Id)>>),
+    Wide(Copied<Iter<'a, (usize, usize)>>),
+    Csr(EdgeRange<'a>)
+}
+
+impl<'a> Iterator for EdgeChunkIter<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        match &mut self.edges {
+            EdgesIter::Stored(edges) => edges.next().map(from_edge),
+            EdgesIter::Wide(edges) => edges.next(),
+            EdgesIter::Csr(edges) => edges.next()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.edges {
+            EdgesIter::Stored(edges) => edges.size_hint(),
+            EdgesIter::Wide(edges) => edges.size_hint(),
+            EdgesIter::Csr(edges) => edges.size_hint()
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for EdgeChunkIter<'a> {
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        match &mut self.edges {
+            EdgesIter::Stored(edges) => edges.next_back().map(from_edge),
+            EdgesIter::Wide(edges) => edges.next_back(),
+            EdgesIter::Csr(edges) => edges.next_back()
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for EdgeChunkIter<'a> { }
+
+fn from_edge((sid, tid): (Id, Id)) -> (usize, usize) {
+    (from_id(sid), from_id(tid))
+}
+
+#[cfg(test)]
+mod id_chunk {
+    use super::*;
+
+    #[test]
+    fn get() {
+        let ids: Vec<Id> = vec![ 3, 1 ];
+        let chunk = IdChunk::new(&ids);
+
+        assert_eq!(chunk.get(1), Some(1));
+        assert_eq!(chunk.get(2), None)
+    }
+
+    #[test]
+    fn iter_rev() {
+        let ids: Vec<Id> = vec![ 3, 1, 2 ];
+        let chunk = IdChunk::new(&ids);
+
+        assert_eq!(chunk.iter().rev().collect::<Vec<_>>(), [ 2, 1, 3 ]);
+        assert_eq!(chunk.iter().len(), 3)
+    }
+
+    #[test]
+    fn dense() {
+        let chunk = IdChunk::dense(2, 5);
+
+        assert_eq!(chunk.to_vec(), [ 2, 3, 4 ]);
+        assert_eq!(chunk.get(2), Some(4));
+        assert_eq!(chunk.get(3), None)
+    }
+
+    #[test]
+    fn eq_across_storage() {
+        let ids: Vec<Id> = vec![ 0, 1 ];
+        let wide = vec![ 0, 1 ];
+
+        assert_eq!(IdChunk::new(&ids), IdChunk::dense(0, 2));
+        assert_eq!(IdChunk::wide(&wide), IdChunk::dense(0, 2))
+    }
+}
+
+#[cfg(test)]
+mod edge_chunk {
+    use super::*;
+
+    #[test]
+    fn get() {
+        let edges: Vec<(Id, Id)> = vec![ (0, 1), (1, 2) ];
+        let chunk = EdgeChunk::new(&edges);
+
+        assert_eq!(chunk.get(1), Some((1, 2)));
+        assert_eq!(chunk.get(2), None)
+    }
+
+    #[test]
+    fn eq_across_storage() {
+        let edges: Vec<(Id, Id)> = vec![ (0, 1) ];
+        let wide = vec![ (0, 1) ];
+
+        assert_eq!(EdgeChunk::new(&edges), EdgeChunk::wide(&wide))
+    }
+}
@@ -0,0 +1,1051 @@
+use std::convert::TryFrom;
+
+use super::{ Graph, Error, DefaultGraph, IdChunk, EdgeChunk };
+use crate::traversal::{ DepthFirst, BreadthFirst, Step };
+
+/// An undirected Graph in compressed sparse row (CSR) form. Ids are dense,
+/// running from zero to one less than order. The neighbors of each node are
+/// stored contiguously in a single Vec, avoiding the per-node allocations of
+/// DefaultGraph. Nodes can be appended cheaply, but adding an edge shifts
+/// storage, so CsrGraph suits graphs built once and then queried.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, CsrGraph };
+///
+/// fn main() -> Result<(), Error> {
+///     let p3 = CsrGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///
+///     assert_eq!(p3.neighbors(1)?.collect::<Vec<_>>(), vec![ 0, 2 ]);
+///     assert_eq!(p3.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+pub struct CsrGraph {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    // edge_offsets[id] counts edges whose lesser id is below id
+    edge_offsets: Vec<usize>
+}
+
+impl CsrGraph {
+    /// Returns an empty graph, to be grown with add_node and add_edge.
+    pub fn new() -> Self {
+        Self {
+            offsets: vec![ 0 ],
+            targets: Vec::new(),
+            edge_offsets: vec![ 0 ]
+        }
+    }
+
+    /// Returns a CsrGraph containing the nodes and edges visited by a
+    /// traversal. As with `TryFrom<Vec<(usize, usize)>>`, ids run from zero
+    /// through the largest id visited, so unvisited ids below it become
+    /// isolated nodes.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Graph, Error, CsrGraph };
+    /// use gamma::traversal::Step;
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let graph = CsrGraph::from_steps(vec![
+    ///         Step::new(0, 1, false),
+    ///         Step::new(1, 2, false),
+    ///         Step::new(2, 0, true)
+    ///     ])?;
+    ///
+    ///     assert_eq!(graph.ids().collect::<Vec<_>>(), vec![ 0, 1, 2 ]);
+    ///     assert_eq!(graph.size(), 3);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_steps<I: IntoIterator<Item=Step>>(
+        steps: I
+    ) -> Result<Self, Error> {
+        let graph = DefaultGraph::from_steps(steps)?;
+
+        Self::try_from(graph.edges().collect::<Vec<_>>())
+    }
+
+    /// Adds a node with no neighbors and returns its id, which is the
+    /// previous order.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Graph, Error, CsrGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut graph = CsrGraph::new();
+    ///     let sid = graph.add_node();
+    ///     let tid = graph.add_node();
+    ///
+    ///     graph.add_edge(sid, tid)?;
+    ///
+    ///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1) ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_node(&mut self) -> usize {
+        self.offsets.push(self.targets.len());
+        self.edge_offsets.push(self.size());
+
+        self.order() - 1
+    }
+
+    /// Adds the edge (sid, tid), appending each id to the other's
+    /// neighbors. Because neighbors are stored contiguously, this shifts
+    /// the neighbors of every later node and takes time linear in order
+    /// plus size. Prefer CsrBuilder when all edges are known up front.
+    /// Returns Error if either id is not found, if the edge is a self-loop,
+    /// or if it is already present.
+    pub fn add_edge(&mut self, sid: usize, tid: usize) -> Result<(), Error> {
+        if !self.has_edge(sid, tid)? {
+            if sid == tid {
+                return Err(Error::SelfLoop(sid));
+            }
+
+            self.insert(sid, tid);
+            self.insert(tid, sid);
+
+            for offset in self.edge_offsets[sid.min(tid) + 1..].iter_mut() {
+                *offset += 1;
+            }
+
+            Ok(())
+        } else {
+            Err(Error::DuplicateEdge(sid, tid))
+        }
+    }
+
+    /// Returns the neighbors of id greater than id, whose edges are
+    /// numbered from `edge_offsets[id]` in order.
+    fn upper_neighbors(&self, id: usize) -> impl Iterator<Item=&usize> {
+        self.targets[self.offsets[id]..self.offsets[id + 1]].iter()
+            .filter(move |&&tid| tid > id)
+    }
+
+    fn insert(&mut self, sid: usize, tid: usize) {
+        self.targets.insert(self.offsets[sid + 1], tid);
+
+        for offset in self.offsets[sid + 1..].iter_mut() {
+            *offset += 1;
+        }
+    }
+
+    /// Returns the neighbors of id as a slice, or Error if id not found.
+    pub fn neighbor_slice(&self, id: usize) -> Result<&[usize], Error> {
+        if id < self.order() {
+            Ok(&self.targets[self.offsets[id]..self.offsets[id + 1]])
+        } else {
+            Err(Error::UnknownId(id))
+        }
+    }
+
+    /// Returns ids as chunks of length n, in iteration order. The last
+    /// chunk may be shorter. Because ids are dense, each chunk is a range
+    /// and no storage is borrowed. Panics if n is zero.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Error, CsrGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let p5 = CsrGraph::try_from(vec![
+    ///         (0, 1), (1, 2), (2, 3), (3, 4)
+    ///     ])?;
+    ///     let chunks = p5.ids_chunked(2).map(|chunk| {
+    ///         chunk.to_vec()
+    ///     }).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(chunks, vec![ vec![ 0, 1 ], vec![ 2, 3 ], vec![ 4 ] ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn ids_chunked(&self, n: usize) -> impl Iterator<Item=IdChunk<'_>> {
+        let order = self.order();
+
+        chunk_starts(order, n).map(move |start| {
+            IdChunk::dense(start, order.min(start + n))
+        })
+    }
+
+    /// Returns edges as chunks of length n, in iteration order. The last
+    /// chunk may be shorter. Each chunk covers a contiguous range of edge
+    /// ids and reads the neighbors of its nodes in place, so chunks can be
+    /// handed to separate threads without copying. Panics if n is zero.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Error, CsrGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let p4 = CsrGraph::try_from(vec![ (0, 1), (1, 2), (2, 3) ])?;
+    ///     let chunks = p4.edges_chunked(2).map(|chunk| {
+    ///         chunk.to_vec()
+    ///     }).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(chunks, vec![
+    ///         vec![ (0, 1), (1, 2) ], vec![ (2, 3) ]
+    ///     ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn edges_chunked(
+        &self, n: usize
+    ) -> impl Iterator<Item=EdgeChunk<'_>> {
+        let size = self.size();
+
+        chunk_starts(size, n).map(move |start| {
+            EdgeChunk::csr(self, start, size.min(start + n))
+        })
+    }
+}
+
+fn chunk_starts(len: usize, n: usize) -> impl Iterator<Item=usize> {
+    assert!(n != 0, "chunk size must be non-zero");
+
+    (0..len).step_by(n)
+}
+
+/// Iterates the edges of a CsrGraph with ids from start up to, but not
+/// including, end. Edges are read forward from the lesser id of the first,
+/// in the order of `edges`.
+#[derive(Debug,Clone)]
+pub(super) struct EdgeRange<'a> {
+    graph: &'a CsrGraph,
+    sid: usize,
+    position: usize,
+    start: usize,
+    end: usize
+}
+
+impl<'a> EdgeRange<'a> {
+    pub(super) fn new(graph: &'a CsrGraph, start: usize, end: usize) -> Self {
+        let sid = if start < end {
+            graph.edge_offsets.partition_point(|&offset| offset <= start) - 1
+        } else {
+            0
+        };
+        let mut result = Self {
+            graph,
+            sid,
+            position: graph.offsets[sid],
+            start,
+            end
+        };
+
+        for _ in graph.edge_offsets[sid]..start {
+            result.advance();
+        }
+
+        result
+    }
+
+    // Returns the next edge at or after the cursor, moving past it.
+    fn advance(&mut self) -> (usize, usize) {
+        loop {
+            while self.position == self.graph.offsets[self.sid + 1] {
+                self.sid += 1;
+            }
+
+            let tid = self.graph.targets[self.position];
+
+            self.position += 1;
+
+            if tid > self.sid {
+                return (self.sid, tid);
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for EdgeRange<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.start += 1;
+
+        Some(self.advance())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+
+        (len, Some(len))
+    }
+}
+
+/// Takes time logarithmic in order plus linear in the degree of each
+/// edge's lesser id, as `edge` does.
+impl<'a> DoubleEndedIterator for EdgeRange<'a> {
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        Some(self.graph.edge(self.end).expect("edge"))
+    }
+}
+
+impl Default for CsrGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Graph for CsrGraph {
+    fn is_empty(&self) -> bool {
+        self.order() == 0
+    }
+
+    fn order(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn size(&self) -> usize {
+        self.edge_offsets[self.edge_offsets.len() - 1]
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        Box::new(0..self.order())
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        Ok(Box::new(self.neighbor_slice(id)?.iter().cloned()))
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        id < self.order()
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        Ok(self.neighbor_slice(id)?.len())
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        Box::new(self.ids().flat_map(move |sid| {
+            self.neighbor_slice(sid).expect("neighbors").iter()
+                .filter(move |&&tid| sid < tid)
+                .map(move |&tid| (sid, tid))
+        }))
+    }
+
+    /// Takes time logarithmic in order plus linear in the degree of the
+    /// edge's lesser id.
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        if eid >= self.size() {
+            return Err(Error::UnknownEdge(eid));
+        }
+
+        let sid = self.edge_offsets.partition_point(|&offset| offset <= eid) - 1;
+        let tid = self.upper_neighbors(sid)
+            .nth(eid - self.edge_offsets[sid])
+            .expect("upper neighbor");
+
+        Ok((sid, *tid))
+    }
+
+    /// Takes time linear in the degree of the edge's lesser id.
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        if !self.has_edge(sid, tid)? {
+            return Err(Error::MissingEdge(sid, tid));
+        }
+
+        let (lesser, greater) = (sid.min(tid), sid.max(tid));
+        let position = self.upper_neighbors(lesser)
+            .position(|&id| id == greater)
+            .expect("upper neighbor");
+
+        Ok(self.edge_offsets[lesser] + position)
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        let neighbors = self.neighbor_slice(sid)?;
+
+        if self.has_id(tid) {
+            Ok(neighbors.contains(&tid))
+        } else {
+            Err(Error::UnknownId(tid))
+        }
+    }
+}
+
+impl TryFrom<Vec<Vec<usize>>> for CsrGraph {
+    type Error = Error;
+
+    fn try_from(adjacency: Vec<Vec<usize>>) -> Result<Self, Self::Error> {
+        let mut builder = CsrBuilder::new(
+            adjacency.iter().map(|neighbors| neighbors.len()).collect()
+        );
+
+        for (sid, neighbors) in adjacency.iter().enumerate() {
+            for (index, &tid) in neighbors.iter().enumerate() {
+                if tid >= adjacency.len() {
+                    return Err(Error::UnknownId(tid));
+                } else if tid == sid {
+                    return Err(Error::SelfLoop(sid));
+                } else if neighbors[index+1..].contains(&tid) {
+                    return Err(Error::DuplicateEdge(sid, tid));
+                } else if !adjacency[tid].contains(&sid) {
+                    return Err(Error::MissingEdge(tid, sid));
+                }
+            }
+        }
+
+        builder.targets = adjacency.into_iter().flatten().collect();
+        builder.cursors = builder.offsets[1..].to_vec();
+
+        builder.build()
+    }
+}
+
+impl<'a, G: Graph> TryFrom<DepthFirst<'a, G>> for CsrGraph {
+    type Error = Error;
+
+    fn try_from(traversal: DepthFirst<'a, G>) -> Result<Self, Self::Error> {
+        Self::from_steps(traversal)
+    }
+}
+
+impl<'a, G: Graph> TryFrom<BreadthFirst<'a, G>> for CsrGraph {
+    type Error = Error;
+
+    fn try_from(traversal: BreadthFirst<'a, G>) -> Result<Self, Self::Error> {
+        Self::from_steps(traversal)
+    }
+}
+
+/// Returns a CsrGraph with ids running from zero through the largest id
+/// in edges. Ids below it that appear in no edge become isolated nodes.
+/// Ids should therefore be dense: storage grows with the largest id, not
+/// with the number of edges, so a single edge such as (0, 1 << 40) asks
+/// for terabytes. Returns IdOverflow if storage for the largest id can't
+/// be allocated. For sparse ids, build a DefaultGraph instead.
+impl TryFrom<Vec<(usize, usize)>> for CsrGraph {
+    type Error = Error;
+
+    fn try_from(edges: Vec<(usize, usize)>) -> Result<Self, Self::Error> {
+        let mut order = 0;
+
+        for &(sid, tid) in &edges {
+            let max = sid.max(tid);
+
+            order = order.max(max.checked_add(1).ok_or(Error::IdOverflow(max))?);
+        }
+
+        let mut degrees = Vec::new();
+
+        degrees.try_reserve_exact(order)
+            .map_err(|_| Error::IdOverflow(order - 1))?;
+        degrees.resize(order, 0);
+
+        for &(sid, tid) in &edges {
+            degrees[sid] += 1;
+            degrees[tid] += 1;
+        }
+
+        let mut builder = CsrBuilder::new(degrees);
+
+        for (sid, tid) in edges {
+            builder.add_edge(sid, tid)?;
+        }
+
+        builder.build()
+    }
+}
+
+/// Builds a CsrGraph from known degrees, for cases in which edges can be
+/// produced twice: once to count degrees and once to fill them in. Unlike
+/// collecting edges first, no storage beyond the final graph is needed.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error, CsrBuilder };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut builder = CsrBuilder::new(vec![ 1, 2, 1 ]);
+///
+///     builder.add_edge(0, 1)?;
+///     builder.add_edge(2, 1)?;
+///
+///     let graph = builder.build()?;
+///
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CsrBuilder {
+    offsets: Vec<usize>,
+    cursors: Vec<usize>,
+    targets: Vec<usize>
+}
+
+impl CsrBuilder {
+    /// Returns a builder for a graph in which the node with id i has degree
+    /// `degrees[i]`.
+    pub fn new(degrees: Vec<usize>) -> Self {
+        let mut offsets = Vec::with_capacity(degrees.len() + 1);
+        let mut total = 0;
+
+        offsets.push(total);
+
+        for degree in degrees {
+            total += degree;
+            offsets.push(total);
+        }
+
+        Self {
+            cursors: offsets[..offsets.len() - 1].to_vec(),
+            targets: vec![ 0; total ],
+            offsets
+        }
+    }
+
+    /// Adds the edge (sid, tid). Returns Error if either id is not found,
+    /// if the edge is a self-loop or is already present, or if adding it
+    /// would exceed the degree given for sid or tid.
+    pub fn add_edge(&mut self, sid: usize, tid: usize) -> Result<(), Error> {
+        let order = self.cursors.len();
+
+        if sid >= order {
+            return Err(Error::UnknownId(sid));
+        } else if tid >= order {
+            return Err(Error::UnknownId(tid));
+        } else if sid == tid {
+            return Err(Error::SelfLoop(sid));
+        } else if self.filled(sid).contains(&tid) {
+            return Err(Error::DuplicateEdge(sid, tid));
+        } else if self.is_full(sid) {
+            return Err(Error::DegreeMismatch(sid));
+        } else if self.is_full(tid) {
+            return Err(Error::DegreeMismatch(tid));
+        }
+
+        self.push(sid, tid);
+        self.push(tid, sid);
+
+        Ok(())
+    }
+
+    /// Returns the CsrGraph, or Error if any node has fewer neighbors than
+    /// the degree it was given.
+    pub fn build(self) -> Result<CsrGraph, Error> {
+        for (id, &cursor) in self.cursors.iter().enumerate() {
+            if cursor != self.offsets[id + 1] {
+                return Err(Error::DegreeMismatch(id));
+            }
+        }
+
+        let mut edge_offsets = Vec::with_capacity(self.offsets.len());
+
+        edge_offsets.push(0);
+
+        for sid in 0..self.cursors.len() {
+            let neighbors = &self.targets[self.offsets[sid]..self.offsets[sid + 1]];
+            let upper = neighbors.iter().filter(|&&tid| sid < tid).count();
+
+            edge_offsets.push(edge_offsets[sid] + upper);
+        }
+
+        Ok(CsrGraph {
+            offsets: self.offsets,
+            targets: self.targets,
+            edge_offsets
+        })
+    }
+
+    fn filled(&self, id: usize) -> &[usize] {
+        &self.targets[self.offsets[id]..self.cursors[id]]
+    }
+
+    fn is_full(&self, id: usize) -> bool {
+        self.cursors[id] == self.offsets[id + 1]
+    }
+
+    fn push(&mut self, sid: usize, tid: usize) {
+        self.targets[self.cursors[sid]] = tid;
+        self.cursors[sid] += 1;
+    }
+}
+
+#[cfg(test)]
+mod try_from_adjacency {
+    use super::*;
+
+    #[test]
+    fn missing_node() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ]
+        ]);
+
+        assert_eq!(graph, Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn self_loop() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 1 ]
+        ]);
+
+        assert_eq!(graph, Err(Error::SelfLoop(1)))
+    }
+
+    #[test]
+    fn duplicate_edge() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1, 1 ],
+            vec![ 0 ]
+        ]);
+
+        assert_eq!(graph, Err(Error::DuplicateEdge(0, 1)))
+    }
+
+    #[test]
+    fn missing_edge() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ ]
+        ]);
+
+        assert_eq!(graph, Err(Error::MissingEdge(1, 0)))
+    }
+
+    #[test]
+    fn p0() {
+        let graph = CsrGraph::try_from(Vec::<Vec<usize>>::new()).unwrap();
+
+        assert!(graph.is_empty());
+        assert_eq!(graph.order(), 0);
+        assert_eq!(graph.size(), 0)
+    }
+
+    #[test]
+    fn p3_p1() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(graph.order(), 4);
+        assert_eq!(graph.size(), 2);
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3 ]);
+        assert_eq!(graph.degree(3), Ok(0));
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+}
+
+#[cfg(test)]
+mod add_node {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let mut graph = CsrGraph::new();
+
+        assert_eq!(graph.add_node(), 0);
+        assert_eq!(graph.add_node(), 1);
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1 ]);
+        assert_eq!(graph.size(), 0)
+    }
+}
+
+#[cfg(test)]
+mod add_edge {
+    use super::*;
+
+    #[test]
+    fn unknown_id() {
+        let mut graph = CsrGraph::new();
+
+        graph.add_node();
+
+        assert_eq!(graph.add_edge(0, 1), Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut graph = CsrGraph::new();
+
+        graph.add_node();
+
+        assert_eq!(graph.add_edge(0, 0), Err(Error::SelfLoop(0)))
+    }
+
+    #[test]
+    fn duplicate() {
+        let mut graph = CsrGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(graph.add_edge(1, 0), Err(Error::DuplicateEdge(1, 0)))
+    }
+
+    #[test]
+    fn c3() {
+        let mut graph = CsrGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        assert_eq!(graph.add_edge(2, 0), Ok(()));
+        assert_eq!(graph, CsrGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 1, 0 ]
+        ]).unwrap());
+        assert_eq!(graph.size(), 3)
+    }
+}
+
+#[cfg(test)]
+mod try_from_edges {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let graph = CsrGraph::try_from(Vec::<(usize, usize)>::new()).unwrap();
+
+        assert_eq!(graph.order(), 0)
+    }
+
+    #[test]
+    fn self_loop() {
+        let graph = CsrGraph::try_from(vec![ (0, 1), (1, 1) ]);
+
+        assert_eq!(graph, Err(Error::SelfLoop(1)))
+    }
+
+    #[test]
+    fn duplicate_edge() {
+        let graph = CsrGraph::try_from(vec![ (0, 1), (1, 0) ]);
+
+        assert_eq!(graph, Err(Error::DuplicateEdge(1, 0)))
+    }
+
+    #[test]
+    fn id_overflow() {
+        let graph = CsrGraph::try_from(vec![ (usize::MAX, 0) ]);
+
+        assert_eq!(graph, Err(Error::IdOverflow(usize::MAX)))
+    }
+
+    #[test]
+    fn sparse_id() {
+        let id = usize::MAX >> 2;
+        let graph = CsrGraph::try_from(vec![ (0, id) ]);
+
+        assert_eq!(graph, Err(Error::IdOverflow(id)))
+    }
+
+    #[test]
+    fn p3_gap() {
+        let graph = CsrGraph::try_from(vec![ (0, 1), (3, 1) ]).unwrap();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3 ]);
+        assert_eq!(graph.degree(2), Ok(0));
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 3) ])
+    }
+}
+
+#[cfg(test)]
+mod ids_chunked {
+    use super::*;
+
+    #[test]
+    fn p0() {
+        let graph = CsrGraph::new();
+
+        assert_eq!(graph.ids_chunked(2).count(), 0)
+    }
+
+    #[test]
+    #[should_panic(expected="chunk size must be non-zero")]
+    fn zero_length() {
+        let graph = CsrGraph::new();
+
+        graph.ids_chunked(0).count();
+    }
+}
+
+#[cfg(test)]
+mod edges_chunked {
+    use super::*;
+
+    fn graph() -> CsrGraph {
+        CsrGraph::try_from(vec![
+            (0, 3), (0, 1), (3, 5), (1, 3), (0, 5), (4, 5)
+        ]).unwrap()
+    }
+
+    #[test]
+    fn every_size() {
+        let graph = graph();
+        let edges = graph.edges().collect::<Vec<_>>();
+
+        for n in 1..=graph.size() + 1 {
+            let chunks = graph.edges_chunked(n).collect::<Vec<_>>();
+
+            assert_eq!(chunks.iter().flat_map(|chunk| {
+                chunk.to_vec()
+            }).collect::<Vec<_>>(), edges);
+            assert_eq!(chunks.iter().map(|chunk| {
+                chunk.iter().len()
+            }).collect::<Vec<_>>(), chunks.iter().map(|chunk| {
+                chunk.len()
+            }).collect::<Vec<_>>())
+        }
+    }
+
+    #[test]
+    fn rev_and_get() {
+        let graph = graph();
+        let chunk = graph.edges_chunked(4).nth(1).unwrap();
+
+        assert_eq!(chunk.iter().rev().collect::<Vec<_>>(), [ (4, 5), (3, 5) ]);
+        assert_eq!(chunk.get(0), Some((3, 5)));
+        assert_eq!(chunk.get(2), None)
+    }
+
+    #[test]
+    fn mixed_ends() {
+        let graph = graph();
+        let mut iter = graph.edges_chunked(6).next().unwrap().iter();
+
+        assert_eq!(iter.next(), Some((0, 3)));
+        assert_eq!(iter.next_back(), Some((4, 5)));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.collect::<Vec<_>>(), [
+            (0, 1), (0, 5), (1, 3), (3, 5)
+        ])
+    }
+}
+
+#[cfg(test)]
+mod from_steps {
+    use super::*;
+
+    #[test]
+    fn unknown_cut() {
+        assert_eq!(
+            CsrGraph::from_steps(vec![ Step::new(0, 1, true) ]),
+            Err(Error::UnknownId(1))
+        )
+    }
+
+    #[test]
+    fn depth_first() {
+        let g1 = CsrGraph::try_from(vec![ (0, 1), (1, 2), (2, 0) ]).unwrap();
+        let traversal = DepthFirst::new(&g1, 0).unwrap();
+        let g2 = CsrGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2, g1)
+    }
+
+    #[test]
+    fn breadth_first_gap() {
+        let g1 = DefaultGraph::try_from(vec![
+            (0, 1), (2, 3), (3, 4)
+        ]).unwrap();
+        let traversal = BreadthFirst::new(&g1, 3).unwrap();
+        let g2 = CsrGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3, 4 ]);
+        assert_eq!(g2.degree(0), Ok(0));
+        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (2, 3), (3, 4) ])
+    }
+}
+
+#[cfg(test)]
+mod neighbors {
+    use super::*;
+
+    #[test]
+    fn given_outside() {
+        let graph = CsrGraph::try_from(Vec::<Vec<usize>>::new()).unwrap();
+
+        assert_eq!(graph.neighbors(0).err(), Some(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn given_max() {
+        let graph = CsrGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(
+            graph.neighbors(usize::MAX).err(),
+            Some(Error::UnknownId(usize::MAX))
+        );
+        assert_eq!(graph.degree(usize::MAX), Err(Error::UnknownId(usize::MAX)))
+    }
+
+    #[test]
+    fn given_inside_p3() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ]
+        ]).unwrap();
+
+        assert_eq!(graph.neighbors(1).unwrap().collect::<Vec<_>>(), [ 0, 2 ])
+    }
+}
+
+#[cfg(test)]
+mod has_edge {
+    use super::*;
+
+    #[test]
+    fn sid_unk() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(graph.has_edge(0, 1), Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn sid_max() {
+        let graph = CsrGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(
+            graph.has_edge(usize::MAX, 0),
+            Err(Error::UnknownId(usize::MAX))
+        )
+    }
+
+    #[test]
+    fn tid_sid() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap();
+
+        assert_eq!(graph.has_edge(1, 0), Ok(true))
+    }
+}
+
+#[cfg(test)]
+mod edge_id {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 1, 0 ]
+        ]).unwrap();
+
+        for eid in 0..graph.size() {
+            let (sid, tid) = graph.edge(eid).unwrap();
+
+            assert_eq!(graph.edge_id(tid, sid), Ok(eid))
+        }
+
+        assert_eq!(graph.edge(3), Err(Error::UnknownEdge(3)))
+    }
+
+    #[test]
+    fn follows_edges() {
+        let mut graph = CsrGraph::try_from(vec![
+            (0, 4), (4, 2), (2, 0), (4, 5)
+        ]).unwrap();
+
+        graph.add_edge(3, 1).unwrap();
+
+        for (eid, (sid, tid)) in graph.edges().enumerate() {
+            assert_eq!(graph.edge(eid), Ok((sid, tid)));
+            assert_eq!(graph.edge_id(sid, tid), Ok(eid))
+        }
+
+        assert_eq!(graph.edge_id(1, 2), Err(Error::MissingEdge(1, 2)))
+    }
+}
+
+#[cfg(test)]
+mod builder {
+    use super::*;
+
+    #[test]
+    fn unknown_id() {
+        let mut builder = CsrBuilder::new(vec![ 1 ]);
+
+        assert_eq!(builder.add_edge(0, 1), Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut builder = CsrBuilder::new(vec![ 2 ]);
+
+        assert_eq!(builder.add_edge(0, 0), Err(Error::SelfLoop(0)))
+    }
+
+    #[test]
+    fn duplicate_edge() {
+        let mut builder = CsrBuilder::new(vec![ 2, 2 ]);
+
+        assert_eq!(builder.add_edge(0, 1), Ok(()));
+        assert_eq!(builder.add_edge(1, 0), Err(Error::DuplicateEdge(1, 0)))
+    }
+
+    #[test]
+    fn degree_exceeded() {
+        let mut builder = CsrBuilder::new(vec![ 1, 1, 1 ]);
+
+        assert_eq!(builder.add_edge(0, 1), Ok(()));
+        assert_eq!(builder.add_edge(1, 2), Err(Error::DegreeMismatch(1)))
+    }
+
+    #[test]
+    fn degree_exceeded_leaves_state() {
+        let mut builder = CsrBuilder::new(vec![ 1, 1, 1, 1 ]);
+
+        assert_eq!(builder.add_edge(0, 1), Ok(()));
+        assert_eq!(builder.add_edge(2, 1), Err(Error::DegreeMismatch(1)));
+        assert_eq!(builder.add_edge(2, 3), Ok(()));
+        assert_eq!(builder.build(), CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ],
+            vec![ 3 ],
+            vec![ 2 ]
+        ]))
+    }
+
+    #[test]
+    fn degree_unfilled() {
+        let mut builder = CsrBuilder::new(vec![ 1, 2, 1 ]);
+
+        assert_eq!(builder.add_edge(0, 1), Ok(()));
+        assert_eq!(builder.build(), Err(Error::DegreeMismatch(1)))
+    }
+
+    #[test]
+    fn c3() {
+        let mut builder = CsrBuilder::new(vec![ 2, 2, 2 ]);
+
+        assert_eq!(builder.add_edge(0, 1), Ok(()));
+        assert_eq!(builder.add_edge(1, 2), Ok(()));
+        assert_eq!(builder.add_edge(2, 0), Ok(()));
+
+        assert_eq!(builder.build(), CsrGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 1, 0 ]
+        ]))
+    }
+}
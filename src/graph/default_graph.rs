@@ -2,9 +2,13 @@ use std::convert::TryFrom;
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 use std::cmp::PartialEq;
+use std::iter::FromIterator;
+use std::hash::{ BuildHasher, Hash, Hasher };
+use std::fmt;
 
-use super::{ Graph, Error };
-use crate::traversal::DepthFirst;
+use super::{ Graph, Error, Event, IdChunk, EdgeChunk };
+use crate::traversal::{ DepthFirst, BreadthFirst, Step };
+use crate::hash::DefaultBuildHasher;
 
 /// An undirected Graph backed by an adjacency matrix. Nodes and neighbors are
 /// iterated in the order in which they're added.
@@ -27,68 +31,422 @@ use crate::traversal::DepthFirst;
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug)]
-pub struct DefaultGraph {
-    indices: HashMap<usize, usize>,
-    adjacency: Vec<Vec<usize>>,
-    ids: Vec<usize>,
-    edges: Vec<(usize, usize)>
+///
+/// With the `u32-ids` feature, ids and indices are stored as u32, roughly
+/// halving memory use. Ids that don't fit are rejected with
+/// `Error::IdOverflow`. With the `smallvec` feature, neighbor lists of up to
+/// four nodes are stored inline rather than on the heap.
+///
+/// Ids are indexed with a HashMap using the BuildHasher S. See the `hash`
+/// module for the default.
+#[derive(Debug,Clone)]
+pub struct DefaultGraph<S = DefaultBuildHasher> {
+    indices: HashMap<Id, Id, S>,
+    adjacency: Vec<Neighbors>,
+    incidence: Vec<Neighbors>,
+    ids: Vec<Id>,
+    edges: Vec<(Id, Id)>,
+    checkpoints: Vec<(usize, usize)>,
+    events: Option<Vec<Event>>
 }
 
+#[cfg(not(feature = "u32-ids"))]
+pub(super) type Id = usize;
+
+#[cfg(feature = "u32-ids")]
+pub(super) type Id = u32;
+
+#[cfg(not(feature = "smallvec"))]
+type Neighbors = Vec<Id>;
+
+#[cfg(feature = "smallvec")]
+type Neighbors = smallvec::SmallVec<[Id; 4]>;
+
 impl DefaultGraph {
     pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+
+    /// Returns an empty graph with room for at least order nodes and size
+    /// edges before reallocating.
+    pub fn with_capacity(order: usize, size: usize) -> Self {
+        Self::with_capacity_and_hasher(
+            order, size, DefaultBuildHasher::default()
+        )
+    }
+
+    /// Returns a copy of any Graph, preserving the iteration order of its
+    /// nodes and edges. Returns Error if graph reports an edge over an
+    /// unknown node or a duplicate node or edge.
+    pub fn from_graph<G: Graph>(graph: &G) -> Result<Self, Error> {
+        let mut result = Self::with_capacity(graph.order(), graph.size());
+
+        for id in graph.ids() {
+            result.add_node(id)?;
+        }
+
+        for (sid, tid) in graph.edges() {
+            result.add_edge(sid, tid)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the graph traced by steps, such as those produced by a
+    /// traversal. The source of the first step is added as the root, and the
+    /// target of each step without a cut is added as a new node. Returns
+    /// Error if a step refers to an unknown node or repeats an edge.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Graph, Error, DefaultGraph };
+    /// use gamma::traversal::Step;
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let graph = DefaultGraph::from_steps(vec![
+    ///         Step::new(0, 1, false),
+    ///         Step::new(1, 2, false),
+    ///         Step::new(2, 0, true)
+    ///     ])?;
+    ///
+    ///     assert_eq!(graph.ids().collect::<Vec<_>>(), vec![ 0, 1, 2 ]);
+    ///     assert_eq!(graph.size(), 3);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_steps<I: IntoIterator<Item=Step>>(
+        steps: I
+    ) -> Result<Self, Error> {
+        let mut result = Self::new();
+
+        for step in steps {
+            if result.is_empty() {
+                result.add_node(step.sid)?;
+            }
+
+            if !step.cut {
+                result.add_node(step.tid)?;
+            }
+
+            result.add_edge(step.sid, step.tid)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the union of node-disjoint graphs, such as those produced by
+    /// components. Nodes and edges are added in component order. Returns
+    /// Error if two components share a node.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Graph, Error, DefaultGraph };
+    /// use gamma::selection::components;
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let graph = DefaultGraph::try_from(vec![
+    ///         (0, 1), (2, 3)
+    ///     ])?;
+    ///     let union = DefaultGraph::from_components(components(&graph))?;
+    ///
+    ///     assert_eq!(union, graph);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_components<G: Graph, I: IntoIterator<Item=G>>(
+        components: I
+    ) -> Result<Self, Error> {
+        let mut result = Self::new();
+
+        for component in components {
+            for id in component.ids() {
+                result.add_node(id)?;
+            }
+
+            for (sid, tid) in component.edges() {
+                result.add_edge(sid, tid)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<S: BuildHasher> DefaultGraph<S> {
+    /// Returns an empty graph that will use hasher to index ids.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(0, 0, hasher)
+    }
+
+    /// Returns an empty graph with room for at least order nodes and size
+    /// edges, that will use hasher to index ids.
+    pub fn with_capacity_and_hasher(
+        order: usize, size: usize, hasher: S
+    ) -> Self {
         Self {
-            indices: HashMap::new(),
-            adjacency: Vec::new(),
-            ids: Vec::new(),
-            edges: Vec::new()
+            indices: HashMap::with_capacity_and_hasher(order, hasher),
+            adjacency: Vec::with_capacity(order),
+            incidence: Vec::with_capacity(order),
+            ids: Vec::with_capacity(order),
+            edges: Vec::with_capacity(size),
+            checkpoints: Vec::new(),
+            events: None
+        }
+    }
+
+    /// Shrinks the capacity of internal storage, including each neighbor
+    /// list, as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.indices.shrink_to_fit();
+        self.ids.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        self.adjacency.shrink_to_fit();
+        self.incidence.shrink_to_fit();
+
+        for neighbors in self.adjacency.iter_mut() {
+            neighbors.shrink_to_fit();
+        }
+
+        for edge_ids in self.incidence.iter_mut() {
+            edge_ids.shrink_to_fit();
         }
     }
 
+    /// Returns ids as chunks of length n, in iteration order. The last
+    /// chunk may be shorter. Chunks borrow internal storage, so parallel
+    /// workers can each claim a contiguous range without collecting ids.
+    /// Panics if n is zero.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Error, DefaultGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let p5 = DefaultGraph::try_from(vec![
+    ///         (0, 1), (1, 2), (2, 3), (3, 4)
+    ///     ])?;
+    ///     let chunks = p5.ids_chunked(2).map(|chunk| {
+    ///         chunk.to_vec()
+    ///     }).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(chunks, vec![ vec![ 0, 1 ], vec![ 2, 3 ], vec![ 4 ] ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn ids_chunked(&self, n: usize) -> impl Iterator<Item=IdChunk<'_>> {
+        self.ids.chunks(n).map(IdChunk::new)
+    }
+
+    /// Returns edges as chunks of length n, in iteration order. The last
+    /// chunk may be shorter. Panics if n is zero.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Error, DefaultGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let p4 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3) ])?;
+    ///     let chunks = p4.edges_chunked(2).map(|chunk| {
+    ///         chunk.to_vec()
+    ///     }).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(chunks, vec![
+    ///         vec![ (0, 1), (1, 2) ], vec![ (2, 3) ]
+    ///     ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn edges_chunked(
+        &self, n: usize
+    ) -> impl Iterator<Item=EdgeChunk<'_>> {
+        self.edges.chunks(n).map(EdgeChunk::new)
+    }
+
     pub fn add_node(&mut self, id: usize) -> Result<(), Error> {
-        match self.indices.entry(id) {
+        let key = to_id(id)?;
+        let index = to_id(self.ids.len())?;
+
+        match self.indices.entry(key) {
             Entry::Occupied(_) => return Err(Error::DuplicateId(id)),
             Entry::Vacant(entry) => {
-                entry.insert(self.ids.len());
+                entry.insert(index);
             }
         }
 
-        self.ids.push(id);
-        self.adjacency.push(vec![ ]);
+        self.ids.push(key);
+        self.adjacency.push(Neighbors::new());
+        self.incidence.push(Neighbors::new());
+        self.emit(Event::NodeAdded(id));
 
         Ok(())
     }
 
     pub fn add_edge(&mut self, sid: usize, tid: usize) -> Result<(), Error> {
-        let &source_index = match self.indices.get(&sid) {
-            Some(index) => index,
-            None => return Err(Error::UnknownId(sid))
-        };
-        let &target_index = match self.indices.get(&tid) {
-            Some(index) => index,
-            None => return Err(Error::UnknownId(tid))
-        };
+        let source_index = self.index_for(sid)?;
+        let target_index = self.index_for(tid)?;
+        let source = self.ids[source_index];
+        let target = self.ids[target_index];
         
-        if self.adjacency[source_index].contains(&tid) {
+        if sid == tid {
+            return Err(Error::SelfLoop(sid));
+        } else if self.adjacency[source_index].contains(&target) {
             return Err(Error::DuplicateEdge(sid, tid));
         }
+
+        let eid = to_id(self.edges.len())?;
         
-        self.adjacency[source_index].push(tid);
-        self.adjacency[target_index].push(sid);
-        self.edges.push((sid, tid));
+        self.adjacency[source_index].push(target);
+        self.adjacency[target_index].push(source);
+        self.incidence[source_index].push(eid);
+        self.incidence[target_index].push(eid);
+        self.edges.push((source, target));
+        self.emit(Event::EdgeAdded(sid, tid));
 
         Ok(())
     }
 
+    /// Starts recording mutations as Events, so that dependent indexes can
+    /// be brought up to date incrementally with `drain_events`. Recording
+    /// continues until `unwatch` is called.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Error, DefaultGraph, Event };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut graph = DefaultGraph::new();
+    ///
+    ///     graph.watch();
+    ///     graph.add_node(0)?;
+    ///     graph.add_node(1)?;
+    ///     graph.add_edge(1, 0)?;
+    ///
+    ///     assert_eq!(graph.drain_events().collect::<Vec<_>>(), vec![
+    ///         Event::NodeAdded(0),
+    ///         Event::NodeAdded(1),
+    ///         Event::EdgeAdded(1, 0)
+    ///     ]);
+    ///     assert_eq!(graph.drain_events().count(), 0);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn watch(&mut self) {
+        if self.events.is_none() {
+            self.events = Some(Vec::new());
+        }
+    }
+
+    /// Stops recording mutations, discarding any Events not yet drained.
+    pub fn unwatch(&mut self) {
+        self.events = None;
+    }
+
+    /// Returns an iterator over the Events recorded since the last drain,
+    /// oldest first. Returns an empty iterator if not watching.
+    pub fn drain_events(&mut self) -> impl Iterator<Item=Event> + '_ {
+        self.events.iter_mut().flat_map(|events| events.drain(..))
+    }
+
+    fn emit(&mut self, event: Event) {
+        if let Some(events) = self.events.as_mut() {
+            events.push(event);
+        }
+    }
+
+    /// Opens a transaction. Nodes and edges added from here on can be
+    /// discarded with `rollback`, or kept with `commit`. Transactions nest:
+    /// each call must be matched by its own commit or rollback.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Graph, Error, DefaultGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut graph = DefaultGraph::try_from(vec![ (0, 1) ])?;
+    ///
+    ///     graph.begin();
+    ///     graph.add_node(2)?;
+    ///     graph.add_edge(1, 2)?;
+    ///
+    ///     assert_eq!(graph.rollback(), true);
+    ///     assert_eq!(graph, DefaultGraph::try_from(vec![ (0, 1) ])?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn begin(&mut self) {
+        self.checkpoints.push((self.ids.len(), self.edges.len()));
+    }
+
+    /// Closes the innermost transaction, keeping its changes. Returns false
+    /// if no transaction is open.
+    pub fn commit(&mut self) -> bool {
+        self.checkpoints.pop().is_some()
+    }
+
+    /// Closes the innermost transaction, discarding the nodes and edges
+    /// added since it began. Time is proportional to the number of changes
+    /// discarded. Returns false if no transaction is open.
+    pub fn rollback(&mut self) -> bool {
+        let (order, size) = match self.checkpoints.pop() {
+            Some(checkpoint) => checkpoint,
+            None => return false
+        };
+
+        // edges are undone newest first, so each is last in its lists
+        while self.edges.len() > size {
+            let (source, target) = self.edges.pop().expect("edge");
+            let source_index = self.indices[&source];
+            let target_index = self.indices[&target];
+
+            self.adjacency[from_id(source_index)].pop();
+            self.adjacency[from_id(target_index)].pop();
+            self.incidence[from_id(source_index)].pop();
+            self.incidence[from_id(target_index)].pop();
+            self.emit(Event::EdgeRemoved(from_id(source), from_id(target)));
+        }
+
+        while self.ids.len() > order {
+            let id = self.ids.pop().expect("id");
+
+            self.indices.remove(&id);
+            self.emit(Event::NodeRemoved(from_id(id)));
+        }
+
+        self.adjacency.truncate(order);
+        self.incidence.truncate(order);
+
+        true
+    }
+
     fn index_for(&self, id: usize) -> Result<usize, Error> {
-        match self.indices.get(&id) {
-            Some(index) => Ok(*index),
+        match to_id(id).ok().and_then(|key| self.indices.get(&key)) {
+            Some(&index) => Ok(from_id(index)),
             None => Err(Error::UnknownId(id))
         }
     }
 }
 
-impl Graph for DefaultGraph {
+#[allow(clippy::useless_conversion)]
+fn to_id(id: usize) -> Result<Id, Error> {
+    Id::try_from(id).map_err(|_| Error::IdOverflow(id))
+}
+
+#[allow(clippy::unnecessary_cast)]
+pub(super) fn from_id(id: Id) -> usize {
+    id as usize
+}
+
+impl<S: BuildHasher + Default> Default for DefaultGraph<S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<S: BuildHasher> Graph for DefaultGraph<S> {
     fn is_empty(&self) -> bool {
         self.ids.is_empty()
     }
@@ -102,7 +460,7 @@ impl Graph for DefaultGraph {
     }
 
     fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
-        Box::new(self.ids.iter().cloned())
+        Box::new(self.ids.iter().map(|&id| from_id(id)))
     }
 
     fn neighbors(
@@ -110,11 +468,11 @@ impl Graph for DefaultGraph {
     ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
         let index = self.index_for(id)?;
 
-        Ok(Box::new(self.adjacency[index].iter().cloned()))
+        Ok(Box::new(self.adjacency[index].iter().map(|&id| from_id(id))))
     }
     
     fn has_id(&self, id: usize) -> bool {
-        self.indices.contains_key(&id)
+        self.index_for(id).is_ok()
     }
 
     fn degree(&self, id: usize) -> Result<usize, Error> {
@@ -124,18 +482,38 @@ impl Graph for DefaultGraph {
     }
 
     fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
-        Box::new(self.edges.iter().cloned())
+        Box::new(
+            self.edges.iter().map(|&(sid, tid)| (from_id(sid), from_id(tid)))
+        )
     }
 
-    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
-        let index = self.index_for(sid)?;
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        match self.edges.get(eid) {
+            Some(&(sid, tid)) => Ok((from_id(sid), from_id(tid))),
+            None => Err(Error::UnknownEdge(eid))
+        }
+    }
 
-        if self.indices.contains_key(&tid) {
-            Ok(self.adjacency[index].contains(&tid))
-        } else {
-            return Err(Error::UnknownId(tid));
+    /// Takes time linear in the degree of sid.
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        let source_index = self.index_for(sid)?;
+        let target_index = self.index_for(tid)?;
+        let target = self.ids[target_index];
+
+        match self.adjacency[source_index].iter().position(|&id| id == target) {
+            Some(position) => {
+                Ok(from_id(self.incidence[source_index][position]))
+            },
+            None => Err(Error::MissingEdge(sid, tid))
         }
     }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        let source_index = self.index_for(sid)?;
+        let target_index = self.index_for(tid)?;
+
+        Ok(self.adjacency[source_index].contains(&self.ids[target_index]))
+    }
 }
 
 impl TryFrom<Vec<Vec<usize>>> for DefaultGraph {
@@ -148,6 +526,8 @@ impl TryFrom<Vec<Vec<usize>>> for DefaultGraph {
             for (index, &tid) in neighbors.iter().enumerate() {
                 if tid >= adjacency.len() {
                     return Err(Error::UnknownId(tid));
+                } else if tid == sid {
+                    return Err(Error::SelfLoop(sid));
                 } else if neighbors[index+1..].contains(&tid) {
                     return Err(Error::DuplicateEdge(sid, tid));
                 } else if !adjacency[tid].contains(&sid) {
@@ -155,15 +535,28 @@ impl TryFrom<Vec<Vec<usize>>> for DefaultGraph {
                 }
 
                 if sid < tid {
-                    result.edges.push((sid, tid));
+                    result.edges.push((to_id(sid)?, to_id(tid)?));
                 }
             }
 
-            result.ids.push(sid);
-            result.indices.insert(sid, sid);
+            result.ids.push(to_id(sid)?);
+            result.indices.insert(to_id(sid)?, to_id(sid)?);
         }
 
-        result.adjacency = adjacency;
+        let eids = result.edges.iter().enumerate()
+            .map(|(eid, &edge)| to_id(eid).map(|eid| (edge, eid)))
+            .collect::<Result<HashMap<_,_>, _>>()?;
+
+        result.incidence = adjacency.iter().enumerate().map(|(sid, neighbors)| {
+            neighbors.iter().map(|&tid| {
+                let key = (to_id(sid.min(tid))?, to_id(sid.max(tid))?);
+
+                Ok(eids[&key])
+            }).collect::<Result<Neighbors, _>>()
+        }).collect::<Result<Vec<_>, _>>()?;
+        result.adjacency = adjacency.into_iter().map(|neighbors| {
+            neighbors.into_iter().map(to_id).collect::<Result<Neighbors, _>>()
+        }).collect::<Result<Vec<_>, _>>()?;
 
         Ok(result)
     }
@@ -173,21 +566,15 @@ impl<'a, G: Graph> TryFrom<DepthFirst<'a, G>> for DefaultGraph {
     type Error = Error;
 
     fn try_from(traversal: DepthFirst<'a, G>) -> Result<Self, Self::Error> {
-        let mut result = DefaultGraph::new();
-
-        for step in traversal {
-            if result.is_empty() {
-                result.add_node(step.sid)?;
-            }
-
-            if !step.cut {
-                result.add_node(step.tid)?;
-            }
+        Self::from_steps(traversal)
+    }
+}
 
-            result.add_edge(step.sid, step.tid)?;
-        }
+impl<'a, G: Graph> TryFrom<BreadthFirst<'a, G>> for DefaultGraph {
+    type Error = Error;
 
-        Ok(result)
+    fn try_from(traversal: BreadthFirst<'a, G>) -> Result<Self, Self::Error> {
+        Self::from_steps(traversal)
     }
 }
 
@@ -213,11 +600,88 @@ impl TryFrom<Vec<(usize, usize)>> for DefaultGraph {
     }
 }
 
-impl PartialEq for DefaultGraph {
+/// Collects edges into a DefaultGraph, adding nodes as they're first seen.
+/// Unlike `TryFrom<Vec<(usize, usize)>>`, duplicate edges are skipped
+/// rather than reported.
+impl<S> FromIterator<(usize, usize)> for DefaultGraph<S>
+    where S: BuildHasher + Default {
+    fn from_iter<I: IntoIterator<Item=(usize, usize)>>(iter: I) -> Self {
+        let mut result = Self::default();
+
+        result.extend(iter);
+
+        result
+    }
+}
+
+/// Adds edges, creating nodes as they're first seen. Edges already present
+/// are skipped. Panics given a self-loop, or, with the `u32-ids` feature,
+/// an id that exceeds u32. Each edge is checked before the graph is
+/// changed, so a panic leaves the edges before it added and nothing of the
+/// offending edge. Use `add_node` and `add_edge` to handle these cases as
+/// errors instead.
+impl<S: BuildHasher> Extend<(usize, usize)> for DefaultGraph<S> {
+    fn extend<I: IntoIterator<Item=(usize, usize)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        self.edges.reserve(iter.size_hint().0);
+
+        for (sid, tid) in iter {
+            if sid == tid {
+                panic!("self loop: {}", sid);
+            }
+
+            for &id in &[ sid, tid ] {
+                if let Err(error) = to_id(id) {
+                    panic!("{}", error);
+                }
+            }
+
+            if !self.has_id(sid) {
+                self.add_node(sid).expect("add source");
+            }
+
+            if !self.has_id(tid) {
+                self.add_node(tid).expect("add target");
+            }
+
+            if !self.has_edge(sid, tid).expect("has edge") {
+                self.add_edge(sid, tid).expect("add edge");
+            }
+        }
+    }
+}
+
+/// Writes an adjacency list with one line per node, preceded by a summary
+/// line. Output stops after the first 32 nodes unless the alternate flag
+/// (`{:#}`) is used.
+impl<S: BuildHasher> fmt::Display for DefaultGraph<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = if f.alternate() { self.order() } else { DISPLAY_LIMIT };
+
+        write!(f, "DefaultGraph (order {}, size {})", self.order(), self.size())?;
+
+        for (id, neighbors) in self.ids.iter().zip(&self.adjacency).take(limit) {
+            write!(f, "\n{}:", id)?;
+
+            for neighbor in neighbors {
+                write!(f, " {}", neighbor)?;
+            }
+        }
+
+        if self.order() > limit {
+            write!(f, "\n... {} more nodes", self.order() - limit)?;
+        }
+
+        Ok(())
+    }
+}
+
+const DISPLAY_LIMIT: usize = 32;
+
+impl<S: BuildHasher> PartialEq for DefaultGraph<S> {
     fn eq(&self, other: &Self) -> bool {
-        if self.size() != other.size() {
-            return false;
-        } else if self.order() != other.order() {
+        if self.size() != other.size() || self.order() != other.order() {
             return false;
         }
 
@@ -241,10 +705,37 @@ impl PartialEq for DefaultGraph {
     }
 }
 
+impl<S: BuildHasher> Eq for DefaultGraph<S> { }
+
+/// Hashes sorted ids and edges, so that graphs equal regardless of the
+/// order in which nodes and edges were added hash alike.
+impl<S: BuildHasher> Hash for DefaultGraph<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut ids = self.ids().collect::<Vec<_>>();
+        let mut edges = self.edges()
+            .map(|(sid, tid)| (sid.min(tid), sid.max(tid)))
+            .collect::<Vec<_>>();
+
+        ids.sort_unstable();
+        edges.sort_unstable();
+        ids.hash(state);
+        edges.hash(state);
+    }
+}
+
 #[cfg(test)]
 mod try_from_adjacency {
     use super::*;
 
+    #[test]
+    fn self_loop() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 0 ]
+        ]);
+
+        assert_eq!(graph, Err(Error::SelfLoop(0)))
+    }
+
     #[test]
     fn missing_node() {
         let graph = DefaultGraph::try_from(vec![
@@ -279,6 +770,16 @@ mod try_from_adjacency {
 mod try_from_edges {
     use super::*;
 
+    #[test]
+    fn self_loop() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1),
+            (1, 1)
+        ]);
+
+        assert_eq!(graph, Err(Error::SelfLoop(1)))
+    }
+
     #[test]
     fn duplicate_edge() {
         let graph = DefaultGraph::try_from(vec![
@@ -296,59 +797,333 @@ mod try_from_edges {
             (1, 0)
         ]);
 
-        assert_eq!(graph, Err(Error::DuplicateEdge(1, 0)))
+        assert_eq!(graph, Err(Error::DuplicateEdge(1, 0)))
+    }
+
+    #[test]
+    fn valid() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1),
+            (1, 2),
+            (3, 4)
+        ]).unwrap();
+        let mut expected = DefaultGraph::new();
+
+        assert_eq!(expected.add_node(0), Ok(()));
+        assert_eq!(expected.add_node(1), Ok(()));
+        assert_eq!(expected.add_node(2), Ok(()));
+        assert_eq!(expected.add_node(3), Ok(()));
+        assert_eq!(expected.add_node(4), Ok(()));
+        assert_eq!(expected.add_edge(0, 1), Ok(()));
+        assert_eq!(expected.add_edge(1, 2), Ok(()));
+        assert_eq!(expected.add_edge(3, 4), Ok(()));
+
+        assert_eq!(graph, expected);
+    }
+}
+
+#[cfg(test)]
+mod from_iter {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let graph = Vec::new().into_iter().collect::<DefaultGraph>();
+
+        assert_eq!(graph, DefaultGraph::new())
+    }
+
+    #[test]
+    fn p3_from_map() {
+        let graph = (0..2).map(|i| (i, i + 1)).collect::<DefaultGraph>();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1, 2 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+
+    #[test]
+    fn duplicate_edge_reverse() {
+        let graph = vec![ (0, 1), (1, 0) ].into_iter()
+            .collect::<DefaultGraph>();
+
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1) ])
+    }
+}
+
+#[cfg(test)]
+mod extend {
+    use std::panic::{ self, AssertUnwindSafe };
+    use super::*;
+
+    #[test]
+    fn p2_to_p3() {
+        let mut graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap();
+
+        graph.extend(vec![ (1, 2) ]);
+
+        assert_eq!(graph, DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ]
+        ]).unwrap())
+    }
+
+    #[test]
+    #[should_panic(expected="self loop: 2")]
+    fn self_loop() {
+        let mut graph = DefaultGraph::new();
+
+        graph.extend(vec![ (2, 2) ]);
+    }
+
+    #[test]
+    fn self_loop_leaves_graph() {
+        let mut graph = DefaultGraph::new();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            graph.extend(vec![ (0, 1), (2, 2) ])
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1 ])
+    }
+
+    #[test]
+    #[cfg(feature = "u32-ids")]
+    fn id_overflow_leaves_graph() {
+        let mut graph = DefaultGraph::new();
+        let id = u32::MAX as usize + 1;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            graph.extend(vec![ (0, id) ])
+        }));
+
+        assert!(result.is_err());
+        assert!(graph.is_empty())
+    }
+
+    #[test]
+    fn existing_edge() {
+        let mut graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap();
+
+        graph.extend(vec![ (1, 0), (1, 2) ]);
+
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+}
+
+#[cfg(test)]
+mod from_graph {
+    use super::*;
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(DefaultGraph::from_graph(&graph), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn p2_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            (1, 0),
+            (2, 1)
+        ]).unwrap();
+        let copy = DefaultGraph::from_graph(&graph).unwrap();
+
+        assert_eq!(copy.ids().collect::<Vec<_>>(), [ 1, 0, 2 ]);
+        assert_eq!(copy.edges().collect::<Vec<_>>(), [ (1, 0), (2, 1) ]);
+        assert_eq!(copy, graph)
+    }
+}
+
+#[cfg(test)]
+mod from_steps {
+    use super::*;
+    use crate::traversal::BreadthFirst;
+
+    #[test]
+    fn empty() {
+        assert_eq!(DefaultGraph::from_steps(vec![ ]), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn unknown_cut() {
+        assert_eq!(
+            DefaultGraph::from_steps(vec![ Step::new(0, 1, true) ]),
+            Err(Error::UnknownId(1))
+        )
+    }
+
+    #[test]
+    fn breadth_first() {
+        let g1 = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (1, 2)
+        ]).unwrap();
+        let traversal = BreadthFirst::new(&g1, 0).unwrap();
+        let g2 = DefaultGraph::from_steps(traversal).unwrap();
+
+        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (0, 1), (0, 2), (1, 2) ])
+    }
+}
+
+#[cfg(test)]
+mod from_components {
+    use super::*;
+    use crate::selection::components;
+
+    #[test]
+    fn empty() {
+        let parts: Vec<DefaultGraph> = vec![ ];
+
+        assert_eq!(DefaultGraph::from_components(parts), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn overlapping() {
+        let parts = vec![
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (1, 2) ]).unwrap()
+        ];
+
+        assert_eq!(
+            DefaultGraph::from_components(parts),
+            Err(Error::DuplicateId(1))
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ],
+            vec![ ],
+            vec![ 4 ],
+            vec![ 3 ]
+        ]).unwrap();
+        let union = DefaultGraph::from_components(components(&graph)).unwrap();
+
+        assert_eq!(union, graph)
+    }
+}
+
+#[cfg(test)]
+mod try_from_depth_first {
+    use super::*;
+
+    #[test]
+    fn p3_internal() {
+        let g1 = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ]
+        ]).unwrap();
+        let traversal = DepthFirst::new(&g1, 1).unwrap();
+        let g2 = DefaultGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (1, 0), (1, 2) ])
+    }
+
+    #[test]
+    fn c3() {
+        let g1 = DefaultGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 1, 0 ]
+        ]).unwrap();
+        let traversal = DepthFirst::new(&g1, 0).unwrap();
+        let g2 = DefaultGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2), (2, 0) ])
+    }
+}
+
+#[cfg(test)]
+mod try_from_breadth_first {
+    use super::*;
+
+    #[test]
+    fn star_internal() {
+        let g1 = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (1, 3)
+        ]).unwrap();
+        let traversal = BreadthFirst::new(&g1, 1).unwrap();
+        let g2 = DefaultGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2.ids().collect::<Vec<_>>(), [ 1, 0, 2, 3 ]);
+        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (1, 0), (1, 2), (1, 3) ])
+    }
+}
+
+#[cfg(test)]
+mod with_capacity {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::with_capacity(3, 2);
+
+        assert_eq!(graph, DefaultGraph::new())
+    }
+
+    #[test]
+    fn reserves_storage() {
+        let graph = DefaultGraph::with_capacity(3, 2);
+
+        assert!(graph.ids.capacity() >= 3);
+        assert!(graph.adjacency.capacity() >= 3);
+        assert!(graph.edges.capacity() >= 2)
+    }
+}
+
+#[cfg(test)]
+mod with_hasher {
+    use super::*;
+    use crate::hash::FxBuildHasher;
+
+    #[test]
+    fn p2() {
+        let mut graph = DefaultGraph::with_hasher(FxBuildHasher::default());
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_node(1), Ok(()));
+        assert_eq!(graph.add_edge(0, 1), Ok(()));
+
+        assert_eq!(graph.neighbors(0).unwrap().collect::<Vec<_>>(), [ 1 ])
     }
 
     #[test]
-    fn valid() {
-        let graph = DefaultGraph::try_from(vec![
-            (0, 1),
-            (1, 2),
-            (3, 4)
-        ]).unwrap();
-        let mut expected = DefaultGraph::new();
-
-        assert_eq!(expected.add_node(0), Ok(()));
-        assert_eq!(expected.add_node(1), Ok(()));
-        assert_eq!(expected.add_node(2), Ok(()));
-        assert_eq!(expected.add_node(3), Ok(()));
-        assert_eq!(expected.add_node(4), Ok(()));
-        assert_eq!(expected.add_edge(0, 1), Ok(()));
-        assert_eq!(expected.add_edge(1, 2), Ok(()));
-        assert_eq!(expected.add_edge(3, 4), Ok(()));
+    fn from_iter() {
+        let graph = vec![ (0, 1) ].into_iter()
+            .collect::<DefaultGraph<FxBuildHasher>>();
 
-        assert_eq!(graph, expected);
+        assert_eq!(graph.has_edge(1, 0), Ok(true))
     }
 }
 
 #[cfg(test)]
-mod try_from_depth_first {
+mod shrink_to_fit {
     use super::*;
 
     #[test]
-    fn p3_internal() {
-        let g1 = DefaultGraph::try_from(vec![
-            vec![ 1 ],
-            vec![ 0, 2 ],
-            vec![ 1 ]
-        ]).unwrap();
-        let traversal = DepthFirst::new(&g1, 1).unwrap();
-        let g2 = DefaultGraph::try_from(traversal).unwrap();
+    fn p3() {
+        let mut graph = DefaultGraph::with_capacity(100, 100);
 
-        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (1, 0), (1, 2) ])
-    }
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_node(1), Ok(()));
+        assert_eq!(graph.add_node(2), Ok(()));
+        assert_eq!(graph.add_edge(0, 1), Ok(()));
+        assert_eq!(graph.add_edge(1, 2), Ok(()));
 
-    #[test]
-    fn c3() {
-        let g1 = DefaultGraph::try_from(vec![
-            vec![ 1, 2 ],
-            vec![ 0, 2 ],
-            vec![ 1, 0 ]
-        ]).unwrap();
-        let traversal = DepthFirst::new(&g1, 0).unwrap();
-        let g2 = DefaultGraph::try_from(traversal).unwrap();
+        graph.shrink_to_fit();
 
-        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2), (2, 0) ])
+        assert_eq!(graph.ids.capacity(), 3);
+        assert_eq!(graph.edges.capacity(), 2);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
     }
 }
 
@@ -366,6 +1141,26 @@ mod add_node {
     }
 }
 
+#[cfg(all(test, feature = "u32-ids"))]
+mod u32_ids {
+    use super::*;
+
+    #[test]
+    fn add_node_overflow() {
+        let mut graph = DefaultGraph::new();
+        let id = u32::MAX as usize + 1;
+
+        assert_eq!(graph.add_node(id), Err(Error::IdOverflow(id)))
+    }
+
+    #[test]
+    fn has_id_overflow() {
+        let graph = DefaultGraph::new();
+
+        assert!(!graph.has_id(u32::MAX as usize + 1))
+    }
+}
+
 #[cfg(test)]
 mod add_edge {
     use super::*;
@@ -390,6 +1185,15 @@ mod add_edge {
         assert_eq!(graph.add_edge(1, 0), Err(Error::DuplicateEdge(1, 0)))
     }
 
+    #[test]
+    fn self_loop() {
+        let mut graph = DefaultGraph::try_from(vec![
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(graph.add_edge(0, 0), Err(Error::SelfLoop(0)))
+    }
+
     #[test]
     fn missing_sid() {
         let mut graph = DefaultGraph::try_from(vec![
@@ -417,7 +1221,7 @@ mod is_empty {
     fn p0() {
         let graph = DefaultGraph::new();
 
-        assert_eq!(graph.is_empty(), true)
+        assert!(graph.is_empty())
     }
 
     #[test]
@@ -426,7 +1230,7 @@ mod is_empty {
             vec![ ]
         ]).unwrap();
 
-        assert_eq!(graph.is_empty(), false)
+        assert!(!graph.is_empty())
     }
 }
 
@@ -530,7 +1334,7 @@ mod has_node {
     fn given_outside() {
         let graph = DefaultGraph::new();
 
-        assert_eq!(graph.has_id(0), false)
+        assert!(!graph.has_id(0))
     }
 
     #[test]
@@ -539,7 +1343,7 @@ mod has_node {
             vec![ ]
         ]).unwrap();
 
-        assert_eq!(graph.has_id(0), true)
+        assert!(graph.has_id(0))
     }
 }
 
@@ -589,6 +1393,167 @@ mod edges {
     }
 }
 
+#[cfg(test)]
+mod chunked {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn zero_length() {
+        let graph = DefaultGraph::new();
+
+        graph.ids_chunked(0).count();
+    }
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(graph.ids_chunked(2).count(), 0);
+        assert_eq!(graph.edges_chunked(2).count(), 0)
+    }
+
+    #[test]
+    fn even() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5)
+        ]).unwrap();
+
+        assert_eq!(graph.ids_chunked(3).map(|chunk| {
+            chunk.to_vec()
+        }).collect::<Vec<_>>(), [
+            [ 0, 1, 2 ], [ 3, 4, 5 ]
+        ])
+    }
+
+    #[test]
+    fn covers_edges() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5)
+        ]).unwrap();
+        let chunks = graph.edges_chunked(2).collect::<Vec<_>>();
+
+        assert_eq!(chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(), [
+            2, 2, 1
+        ]);
+        assert_eq!(
+            chunks.into_iter().flatten().collect::<Vec<_>>(),
+            graph.edges().collect::<Vec<_>>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod edges_ordered {
+    use super::*;
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(graph.edges_ordered().collect::<Vec<_>>(), [ ])
+    }
+
+    #[test]
+    fn c3_reversed() {
+        let graph = DefaultGraph::try_from(vec![
+            (2, 1),
+            (1, 0),
+            (0, 2)
+        ]).unwrap();
+
+        assert_eq!(graph.edges_ordered().collect::<Vec<_>>(), [
+            (0, 1), (0, 2), (1, 2)
+        ])
+    }
+}
+
+#[cfg(test)]
+mod edge {
+    use super::*;
+
+    #[test]
+    fn outside() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(graph.edge(0), Err(Error::UnknownEdge(0)))
+    }
+
+    #[test]
+    fn inside() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1),
+            (2, 1)
+        ]).unwrap();
+
+        assert_eq!(graph.edge(1), Ok((2, 1)))
+    }
+}
+
+#[cfg(test)]
+mod edge_id {
+    use super::*;
+
+    #[test]
+    fn unknown_tid() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1)
+        ]).unwrap();
+
+        assert_eq!(graph.edge_id(0, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn missing() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1),
+            (1, 2)
+        ]).unwrap();
+
+        assert_eq!(graph.edge_id(0, 2), Err(Error::MissingEdge(0, 2)))
+    }
+
+    #[test]
+    fn reversed() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1),
+            (1, 2)
+        ]).unwrap();
+
+        assert_eq!(graph.edge_id(2, 1), Ok(1))
+    }
+
+    #[test]
+    fn adjacency() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 2, 1 ],
+            vec![ 2, 0 ],
+            vec![ 0, 1 ]
+        ]).unwrap();
+
+        for (eid, (sid, tid)) in graph.edges().enumerate() {
+            assert_eq!(graph.edge_id(sid, tid), Ok(eid));
+            assert_eq!(graph.edge_id(tid, sid), Ok(eid))
+        }
+    }
+
+    #[test]
+    fn after_rollback() {
+        let mut graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        graph.begin();
+        graph.add_edge(1, 2).unwrap_err();
+        graph.add_node(2).unwrap();
+        graph.add_edge(1, 2).unwrap();
+        graph.rollback();
+        graph.add_node(2).unwrap();
+        graph.add_edge(2, 0).unwrap();
+
+        assert_eq!(graph.edge_id(0, 2), Ok(1));
+        assert_eq!(graph.edge_id(1, 2), Err(Error::MissingEdge(1, 2)))
+    }
+}
+
 #[cfg(test)]
 mod has_edge {
     use super::*;
@@ -630,6 +1595,54 @@ mod has_edge {
     }
 }
 
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(graph.to_string(), "DefaultGraph (order 0, size 0)")
+    }
+
+    #[test]
+    fn p3() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(graph.to_string(), [
+            "DefaultGraph (order 4, size 2)",
+            "0: 1",
+            "1: 0 2",
+            "2: 1",
+            "3:"
+        ].join("\n"))
+    }
+
+    #[test]
+    fn truncated() {
+        let graph = (0..40).map(|i| (i, i + 1)).collect::<DefaultGraph>();
+        let string = graph.to_string();
+
+        assert_eq!(string.lines().count(), 34);
+        assert_eq!(string.lines().last(), Some("... 9 more nodes"))
+    }
+
+    #[test]
+    fn alternate() {
+        let graph = (0..40).map(|i| (i, i + 1)).collect::<DefaultGraph>();
+        let string = format!("{:#}", graph);
+
+        assert_eq!(string.lines().count(), 42);
+        assert_eq!(string.lines().last(), Some("40: 39"))
+    }
+}
+
 #[cfg(test)]
 mod eq {
     use super::*;
@@ -647,7 +1660,7 @@ mod eq {
             vec![ 1 ]
         ]).unwrap();
 
-        assert_eq!(c3 == p3, false)
+        assert_ne!(c3, p3)
     }
 
     #[test]
@@ -662,7 +1675,7 @@ mod eq {
             vec![ ],
         ]).unwrap();
 
-        assert_eq!(p2 == p2_p1, false)
+        assert_ne!(p2, p2_p1)
     }
 
     #[test]
@@ -674,7 +1687,7 @@ mod eq {
             (1, 0)
         ]).unwrap();
 
-        assert_eq!(g1 == g2, true)
+        assert_eq!(g1, g2)
     }
 
     #[test]
@@ -686,6 +1699,167 @@ mod eq {
             (0, 2)
         ]).unwrap();
 
-        assert_eq!(g1 == g2, false)
+        assert_ne!(g1, g2)
+    }
+}
+
+#[cfg(test)]
+mod hash {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn reordered() {
+        let g1 = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2)
+        ]).unwrap();
+        let g2 = DefaultGraph::try_from(vec![
+            (2, 1), (1, 0)
+        ]).unwrap();
+        let g3 = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2)
+        ]).unwrap();
+        let set = vec![ g1, g2, g3 ].into_iter().collect::<HashSet<_>>();
+
+        assert_eq!(set.len(), 2)
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn independent() {
+        let original = DefaultGraph::try_from(vec![
+            (0, 1)
+        ]).unwrap();
+        let mut copy = original.clone();
+
+        copy.add_node(2).unwrap();
+        copy.add_edge(1, 2).unwrap();
+
+        assert_eq!(original.order(), 2);
+        assert_eq!(copy.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+}
+
+#[cfg(test)]
+mod transaction {
+    use super::*;
+
+    #[test]
+    fn rollback_without_begin() {
+        let mut graph = DefaultGraph::new();
+
+        assert!(!graph.rollback());
+        assert!(!graph.commit())
+    }
+
+    #[test]
+    fn rollback_restores_neighbors() {
+        let mut graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        graph.begin();
+        graph.add_node(3).unwrap();
+        graph.add_edge(0, 2).unwrap();
+        graph.add_edge(3, 1).unwrap();
+
+        assert!(graph.rollback());
+        assert!(!graph.has_id(3));
+        assert_eq!(graph.neighbors(1).unwrap().collect::<Vec<_>>(), [ 0, 2 ]);
+        assert_eq!(graph.neighbors(2).unwrap().collect::<Vec<_>>(), [ 1 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ]);
+
+        graph.add_node(3).unwrap();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3 ])
+    }
+
+    #[test]
+    fn nested() {
+        let mut graph = DefaultGraph::new();
+
+        graph.begin();
+        graph.add_node(0).unwrap();
+        graph.begin();
+        graph.add_node(1).unwrap();
+
+        assert!(graph.rollback());
+        assert_eq!(graph.order(), 1);
+        assert!(graph.commit());
+        assert_eq!(graph.order(), 1)
+    }
+}
+
+#[cfg(test)]
+mod drain_events {
+    use super::*;
+
+    #[test]
+    fn unwatched() {
+        let mut graph = DefaultGraph::new();
+
+        graph.add_node(0).unwrap();
+
+        assert_eq!(graph.drain_events().count(), 0)
+    }
+
+    #[test]
+    fn failed_mutation() {
+        let mut graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        graph.watch();
+
+        assert_eq!(graph.add_node(0), Err(Error::DuplicateId(0)));
+        assert_eq!(graph.add_edge(1, 0), Err(Error::DuplicateEdge(1, 0)));
+        assert_eq!(graph.drain_events().count(), 0)
+    }
+
+    #[test]
+    fn extend() {
+        let mut graph = DefaultGraph::new();
+
+        graph.watch();
+        graph.extend(vec![ (0, 1), (1, 0) ]);
+
+        assert_eq!(graph.drain_events().collect::<Vec<_>>(), [
+            Event::NodeAdded(0),
+            Event::NodeAdded(1),
+            Event::EdgeAdded(0, 1)
+        ])
+    }
+
+    #[test]
+    fn rollback() {
+        let mut graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        graph.begin();
+        graph.add_node(2).unwrap();
+        graph.add_node(3).unwrap();
+        graph.add_edge(2, 1).unwrap();
+        graph.add_edge(3, 2).unwrap();
+        graph.watch();
+        graph.rollback();
+
+        assert_eq!(graph.drain_events().collect::<Vec<_>>(), [
+            Event::EdgeRemoved(3, 2),
+            Event::EdgeRemoved(2, 1),
+            Event::NodeRemoved(3),
+            Event::NodeRemoved(2)
+        ])
+    }
+
+    #[test]
+    fn unwatch() {
+        let mut graph = DefaultGraph::new();
+
+        graph.watch();
+        graph.add_node(0).unwrap();
+        graph.unwatch();
+        graph.add_node(1).unwrap();
+        graph.watch();
+
+        assert_eq!(graph.drain_events().count(), 0)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,258 @@
+use std::convert::TryFrom;
+use std::collections::HashMap;
+
+use super::{
+    Graph, WeightedGraph, Error, DefaultGraph, IdChunk, EdgeChunk
+};
+
+/// An undirected, weighted Graph. Nodes, neighbors, and edges are iterated in
+/// the order in which they're added.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, WeightedGraph, Error, DefaultWeightedGraph };
+///
+/// fn main() -> Result<(), Error> {
+///     let p3 = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 2.5),
+///         (1, 2, 4.0)
+///     ])?;
+///
+///     assert_eq!(p3.weight(2, 1), Ok(Some(&4.0)));
+///     assert_eq!(p3.weight(0, 2), Ok(None));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct DefaultWeightedGraph<W> {
+    graph: DefaultGraph,
+    weights: HashMap<(usize, usize), W>
+}
+
+impl<W> DefaultWeightedGraph<W> {
+    pub fn new() -> Self {
+        Self {
+            graph: DefaultGraph::new(),
+            weights: HashMap::new()
+        }
+    }
+
+    pub fn add_node(&mut self, id: usize) -> Result<(), Error> {
+        self.graph.add_node(id)
+    }
+
+    pub fn add_edge(
+        &mut self, sid: usize, tid: usize, weight: W
+    ) -> Result<(), Error> {
+        self.graph.add_edge(sid, tid)?;
+        self.weights.insert(key(sid, tid), weight);
+
+        Ok(())
+    }
+
+    /// Returns ids as chunks of length n. See `DefaultGraph::ids_chunked`.
+    pub fn ids_chunked(&self, n: usize) -> impl Iterator<Item=IdChunk<'_>> {
+        self.graph.ids_chunked(n)
+    }
+
+    /// Returns edges as chunks of length n, without weights. See
+    /// `DefaultGraph::edges_chunked`.
+    pub fn edges_chunked(
+        &self, n: usize
+    ) -> impl Iterator<Item=EdgeChunk<'_>> {
+        self.graph.edges_chunked(n)
+    }
+}
+
+impl<W> Default for DefaultWeightedGraph<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> Graph for DefaultWeightedGraph<W> {
+    fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        self.graph.ids()
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        self.graph.neighbors(id)
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.graph.has_id(id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        self.graph.degree(id)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        self.graph.edges()
+    }
+
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        self.graph.edge(eid)
+    }
+
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        self.graph.edge_id(sid, tid)
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        self.graph.has_edge(sid, tid)
+    }
+}
+
+impl<W> WeightedGraph for DefaultWeightedGraph<W> {
+    type Weight = W;
+
+    fn weight(&self, sid: usize, tid: usize) -> Result<Option<&W>, Error> {
+        if !self.graph.has_id(sid) {
+            return Err(Error::UnknownId(sid));
+        } else if !self.graph.has_id(tid) {
+            return Err(Error::UnknownId(tid));
+        }
+
+        Ok(self.weights.get(&key(sid, tid)))
+    }
+}
+
+impl<W> TryFrom<Vec<(usize, usize, W)>> for DefaultWeightedGraph<W> {
+    type Error = Error;
+
+    fn try_from(edges: Vec<(usize, usize, W)>) -> Result<Self, Self::Error> {
+        let mut result = Self::new();
+
+        for (sid, tid, weight) in edges {
+            if !result.has_id(sid) {
+                result.add_node(sid)?;
+            }
+
+            if !result.has_id(tid) {
+                result.add_node(tid)?;
+            }
+
+            result.add_edge(sid, tid, weight)?;
+        }
+
+        Ok(result)
+    }
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod try_from_edges {
+    use super::*;
+
+    #[test]
+    fn duplicate_edge() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (1, 0, 2)
+        ]);
+
+        assert_eq!(graph, Err(Error::DuplicateEdge(1, 0)))
+    }
+
+    #[test]
+    fn p3() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (1, 2, 2)
+        ]).unwrap();
+
+        assert_eq!(graph.order(), 3);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+}
+
+#[cfg(test)]
+mod add_edge {
+    use super::*;
+
+    #[test]
+    fn self_loop() {
+        let mut graph = DefaultWeightedGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_edge(0, 0, 1), Err(Error::SelfLoop(0)));
+        assert_eq!(graph.weight(0, 0), Ok(None))
+    }
+}
+
+#[cfg(test)]
+mod weight {
+    use super::*;
+
+    #[test]
+    fn unknown_tid() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(graph.weight(0, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn reversed() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (1, 2, 2)
+        ]).unwrap();
+
+        assert_eq!(graph.weight(2, 1), Ok(Some(&2)))
+    }
+
+    #[test]
+    fn missing() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (1, 2, 2)
+        ]).unwrap();
+
+        assert_eq!(graph.weight(0, 2), Ok(None))
+    }
+}
+
+#[cfg(test)]
+mod chunked {
+    use super::*;
+
+    #[test]
+    fn p3() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1.0),
+            (1, 2, 2.0)
+        ]).unwrap();
+
+        assert_eq!(graph.ids_chunked(2).map(|chunk| {
+            chunk.to_vec()
+        }).collect::<Vec<_>>(), [
+            vec![ 0, 1 ], vec![ 2 ]
+        ]);
+        assert_eq!(graph.edges_chunked(2).map(|chunk| {
+            chunk.to_vec()
+        }).collect::<Vec<_>>(), [
+            vec![ (0, 1), (1, 2) ]
+        ])
+    }
+}
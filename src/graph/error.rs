@@ -1,7 +1,73 @@
+use std::fmt;
+use std::error;
+
 #[derive(Debug,PartialEq,Eq)]
 pub enum Error {
     UnknownId(usize),
     DuplicateId(usize),
     MissingEdge(usize, usize),
-    DuplicateEdge(usize, usize)
-}
\ No newline at end of file
+    DuplicateEdge(usize, usize),
+    IdOverflow(usize),
+    DegreeMismatch(usize),
+    SelfLoop(usize),
+    UnknownEdge(usize),
+    NanWeight(usize, usize)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownId(id) => write!(f, "unknown id: {}", id),
+            Error::DuplicateId(id) => write!(f, "duplicate id: {}", id),
+            Error::MissingEdge(sid, tid) => {
+                write!(f, "missing edge: ({}, {})", sid, tid)
+            },
+            Error::DuplicateEdge(sid, tid) => {
+                write!(f, "duplicate edge: ({}, {})", sid, tid)
+            },
+            Error::IdOverflow(id) => {
+                write!(f, "id exceeds storage capacity: {}", id)
+            },
+            Error::DegreeMismatch(id) => {
+                write!(f, "degree mismatch: {}", id)
+            },
+            Error::SelfLoop(id) => write!(f, "self loop: {}", id),
+            Error::UnknownEdge(eid) => write!(f, "unknown edge: {}", eid),
+            Error::NanWeight(sid, tid) => {
+                write!(f, "NaN weight: ({}, {})", sid, tid)
+            }
+        }
+    }
+}
+
+impl error::Error for Error { }
+
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn unknown_id() {
+        assert_eq!(Error::UnknownId(1).to_string(), "unknown id: 1")
+    }
+
+    #[test]
+    fn duplicate_edge() {
+        assert_eq!(
+            Error::DuplicateEdge(0, 1).to_string(),
+            "duplicate edge: (0, 1)"
+        )
+    }
+
+    #[test]
+    fn nan_weight() {
+        assert_eq!(Error::NanWeight(2, 3).to_string(), "NaN weight: (2, 3)")
+    }
+
+    #[test]
+    fn boxed() {
+        let error: Box<dyn error::Error> = Box::new(Error::MissingEdge(1, 0));
+
+        assert_eq!(error.to_string(), "missing edge: (1, 0)")
+    }
+}
@@ -0,0 +1,9 @@
+/// A change to a mutable graph, as reported by `DefaultGraph::drain_events`.
+/// Edges are reported in the orientation in which they were added.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum Event {
+    NodeAdded(usize),
+    NodeRemoved(usize),
+    EdgeAdded(usize, usize),
+    EdgeRemoved(usize, usize)
+}
@@ -0,0 +1,252 @@
+use super::{ Graph, Error };
+
+/// Returns a view of graph restricted to the nodes for which predicate
+/// returns true, together with the edges between them. Nothing is copied;
+/// predicate is evaluated as the view is queried.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph, filter_nodes };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///     let view = filter_nodes(&graph, |id| id != 1);
+///
+///     assert_eq!(view.ids().collect::<Vec<_>>(), vec![ 0, 2 ]);
+///     assert_eq!(view.size(), 0);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn filter_nodes<G, P>(graph: &G, predicate: P) -> NodeFilter<'_, G, P>
+where G: Graph, P: Fn(usize) -> bool {
+    NodeFilter { graph, predicate }
+}
+
+/// Returns a view of graph with all of its nodes, but only the edges for
+/// which predicate returns true. Because edges are undirected, predicate
+/// should return the same result for (sid, tid) and (tid, sid).
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph, filter_edges };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///     let view = filter_edges(&graph, |sid, tid| sid.max(tid) < 2);
+///
+///     assert_eq!(view.edges().collect::<Vec<_>>(), vec![ (0, 1) ]);
+///     assert_eq!(view.degree(2), Ok(0));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn filter_edges<G, P>(graph: &G, predicate: P) -> EdgeFilter<'_, G, P>
+where G: Graph, P: Fn(usize, usize) -> bool {
+    EdgeFilter { graph, predicate }
+}
+
+pub struct NodeFilter<'a, G, P> {
+    graph: &'a G,
+    predicate: P
+}
+
+impl<'a, G, P> NodeFilter<'a, G, P>
+where G: Graph, P: Fn(usize) -> bool {
+    fn check(&self, id: usize) -> Result<(), Error> {
+        if self.has_id(id) {
+            Ok(())
+        } else {
+            Err(Error::UnknownId(id))
+        }
+    }
+}
+
+impl<'a, G, P> Graph for NodeFilter<'a, G, P>
+where G: Graph, P: Fn(usize) -> bool {
+    fn is_empty(&self) -> bool {
+        self.ids().next().is_none()
+    }
+
+    fn order(&self) -> usize {
+        self.ids().count()
+    }
+
+    fn size(&self) -> usize {
+        self.edges().count()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        Box::new(self.graph.ids().filter(move |&id| (self.predicate)(id)))
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        self.check(id)?;
+
+        Ok(Box::new(
+            self.graph.neighbors(id)?.filter(move |&id| (self.predicate)(id))
+        ))
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.graph.has_id(id) && (self.predicate)(id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        Ok(self.neighbors(id)?.count())
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        Box::new(self.graph.edges().filter(move |&(sid, tid)| {
+            (self.predicate)(sid) && (self.predicate)(tid)
+        }))
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        self.check(sid)?;
+        self.check(tid)?;
+
+        self.graph.has_edge(sid, tid)
+    }
+}
+
+pub struct EdgeFilter<'a, G, P> {
+    graph: &'a G,
+    predicate: P
+}
+
+impl<'a, G, P> Graph for EdgeFilter<'a, G, P>
+where G: Graph, P: Fn(usize, usize) -> bool {
+    fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.edges().count()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        self.graph.ids()
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        Ok(Box::new(self.graph.neighbors(id)?.filter(move |&neighbor| {
+            (self.predicate)(id, neighbor)
+        })))
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.graph.has_id(id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        Ok(self.neighbors(id)?.count())
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        Box::new(self.graph.edges().filter(move |&(sid, tid)| {
+            (self.predicate)(sid, tid)
+        }))
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        Ok(self.graph.has_edge(sid, tid)? && (self.predicate)(sid, tid))
+    }
+}
+
+#[cfg(test)]
+mod filter_nodes {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn c4() -> DefaultGraph {
+        DefaultGraph::try_from(vec![
+            vec![ 1, 3 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 0 ]
+        ]).unwrap()
+    }
+
+    #[test]
+    fn excluded_neighbors() {
+        let graph = c4();
+        let view = filter_nodes(&graph, |id| id != 0);
+
+        assert_eq!(view.neighbors(0).err(), Some(Error::UnknownId(0)));
+        assert_eq!(view.has_edge(0, 1), Err(Error::UnknownId(0)));
+        assert_eq!(view.degree(1), Ok(1))
+    }
+
+    #[test]
+    fn excluded_all() {
+        let graph = c4();
+        let view = filter_nodes(&graph, |_| false);
+
+        assert!(view.is_empty());
+        assert_eq!(view.size(), 0)
+    }
+
+    #[test]
+    fn c4_to_p3() {
+        let graph = c4();
+        let view = filter_nodes(&graph, |id| id != 3);
+
+        assert_eq!(view.order(), 3);
+        assert_eq!(view.size(), 2);
+        assert_eq!(view.neighbors(0).unwrap().collect::<Vec<_>>(), [ 1 ]);
+        assert_eq!(view.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+}
+
+#[cfg(test)]
+mod filter_edges {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::selection::components;
+
+    #[test]
+    fn unknown_id() {
+        let graph = DefaultGraph::new();
+        let view = filter_edges(&graph, |_, _| true);
+
+        assert_eq!(view.neighbors(0).err(), Some(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn components_of_c4_without_edge() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 3 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 0 ]
+        ]).unwrap();
+        let view = filter_edges(&graph, |sid, tid| sid.min(tid) != 1);
+
+        assert_eq!(view.size(), 3);
+        assert_eq!(view.has_edge(2, 1), Ok(false));
+        assert_eq!(components(&view).count(), 1);
+
+        let view = filter_edges(&graph, |sid, tid| sid + tid != 3);
+
+        assert_eq!(components(&view).count(), 2)
+    }
+}
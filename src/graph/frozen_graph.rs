@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use super::{ Graph, DefaultGraph, Error, IdChunk, EdgeChunk };
+
+/// An immutable, reference-counted Graph that is Send and Sync. Cloning is
+/// cheap and shares storage, so a FrozenGraph can be handed to any number
+/// of threads for parallel queries. Iterators returned through the Graph
+/// trait are not Send, so each thread should run its own queries.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use std::thread;
+/// use gamma::graph::{ Graph, Error, DefaultGraph, FrozenGraph };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = FrozenGraph::from(DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2)
+///     ])?);
+///     let handles = (0..3).map(|id| {
+///         let graph = graph.clone();
+///
+///         thread::spawn(move || graph.degree(id))
+///     }).collect::<Vec<_>>();
+///     let degrees = handles.into_iter().map(|handle| {
+///         handle.join().unwrap()
+///     }).collect::<Result<Vec<_>, _>>()?;
+///
+///     assert_eq!(degrees, vec![ 1, 2, 1 ]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+pub struct FrozenGraph {
+    graph: Arc<DefaultGraph>
+}
+
+impl FrozenGraph {
+    /// Returns a FrozenGraph with the nodes and edges of graph, preserving
+    /// iteration order.
+    pub fn from_graph<G: Graph>(graph: &G) -> Result<Self, Error> {
+        Ok(Self::from(DefaultGraph::from_graph(graph)?))
+    }
+
+    /// Returns ids as chunks of length n. See `DefaultGraph::ids_chunked`.
+    pub fn ids_chunked(&self, n: usize) -> impl Iterator<Item=IdChunk<'_>> {
+        self.graph.ids_chunked(n)
+    }
+
+    /// Returns edges as chunks of length n. See
+    /// `DefaultGraph::edges_chunked`.
+    pub fn edges_chunked(
+        &self, n: usize
+    ) -> impl Iterator<Item=EdgeChunk<'_>> {
+        self.graph.edges_chunked(n)
+    }
+}
+
+impl From<DefaultGraph> for FrozenGraph {
+    fn from(graph: DefaultGraph) -> Self {
+        Self {
+            graph: Arc::new(graph)
+        }
+    }
+}
+
+impl Graph for FrozenGraph {
+    fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        self.graph.ids()
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        self.graph.neighbors(id)
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.graph.has_id(id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        self.graph.degree(id)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        self.graph.edges()
+    }
+
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        self.graph.edge(eid)
+    }
+
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        self.graph.edge_id(sid, tid)
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        self.graph.has_edge(sid, tid)
+    }
+}
+
+#[cfg(test)]
+mod from_graph {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::CsrGraph;
+
+    #[test]
+    fn csr() {
+        let csr = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ]
+        ]).unwrap();
+        let graph = FrozenGraph::from_graph(&csr).unwrap();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1, 2 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+
+    #[test]
+    fn clone_shares_storage() {
+        let graph = FrozenGraph::from(DefaultGraph::new());
+        let copy = graph.clone();
+
+        assert!(Arc::ptr_eq(&graph.graph, &copy.graph))
+    }
+}
+
+#[cfg(test)]
+mod chunked {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn p3() {
+        let graph = FrozenGraph::from(DefaultGraph::try_from(vec![
+            (0, 1), (1, 2)
+        ]).unwrap());
+
+        assert_eq!(graph.ids_chunked(2).map(|chunk| {
+            chunk.to_vec()
+        }).collect::<Vec<_>>(), [
+            vec![ 0, 1 ], vec![ 2 ]
+        ]);
+        assert_eq!(graph.edges_chunked(1).map(|chunk| {
+            chunk.to_vec()
+        }).collect::<Vec<_>>(), [
+            [ (0, 1) ], [ (1, 2) ]
+        ])
+    }
+}
+
+#[cfg(test)]
+mod send_sync {
+    use super::*;
+    use crate::graph::{
+        CsrGraph, MultiGraph, DefaultWeightedGraph, StableGraph, IdMap, Event
+    };
+
+    fn assert_send_sync<T: Send + Sync>() { }
+
+    #[test]
+    fn graphs() {
+        assert_send_sync::<FrozenGraph>();
+        assert_send_sync::<DefaultGraph>();
+        assert_send_sync::<CsrGraph>();
+        assert_send_sync::<MultiGraph>();
+        assert_send_sync::<DefaultWeightedGraph<f64>>();
+        assert_send_sync::<StableGraph<String>>();
+    }
+
+    #[test]
+    fn values() {
+        assert_send_sync::<IdMap<String>>();
+        assert_send_sync::<Event>();
+        assert_send_sync::<Error>();
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap() {
+        assert_send_sync::<crate::graph::MmapGraph>();
+    }
+}
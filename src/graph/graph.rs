@@ -1,6 +1,9 @@
 pub use super::error::Error;
 
 /// An unweighted graph.
+///
+/// Self-loops are not supported. Implementations reject them during
+/// construction with `Error::SelfLoop`.
 pub trait Graph {
     /// Returns true if there are no nodes, or false otherwise.
     fn is_empty(&self) -> bool;
@@ -29,6 +32,44 @@ pub trait Graph {
     /// Returns an iterator over the edges of this graph.
     fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_>;
 
+    /// Returns an iterator over the edges of this graph in canonical order.
+    /// Each edge is given as (min, max), and edges are sorted.
+    fn edges_ordered(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        let mut edges = self.edges()
+            .map(|(sid, tid)| (sid.min(tid), sid.max(tid)))
+            .collect::<Vec<_>>();
+
+        edges.sort_unstable();
+
+        Box::new(edges.into_iter())
+    }
+
+    /// Returns the endpoints of the edge with id eid, or Error if not found.
+    /// Edge ids run from zero to one less than size, following the order of
+    /// edges(). The default implementation walks edges(), taking time linear
+    /// in size. DefaultGraph and CsrGraph answer without walking.
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        match self.edges().nth(eid) {
+            Some(edge) => Ok(edge),
+            None => Err(Error::UnknownEdge(eid))
+        }
+    }
+
+    /// Returns the id of the edge (sid, tid), in either orientation. Returns
+    /// Error if either sid or tid are not found, or if there is no such edge.
+    /// The default implementation walks edges(), taking time linear in size.
+    /// DefaultGraph and CsrGraph take time linear in the degree of an
+    /// endpoint.
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        if !self.has_edge(sid, tid)? {
+            return Err(Error::MissingEdge(sid, tid));
+        }
+
+        Ok(self.edges().position(|edge| {
+            edge == (sid, tid) || edge == (tid, sid)
+        }).expect("edge"))
+    }
+
     /// Returns true if the edge (sid, tid) exists, or false otherwise.
     /// Returns Error if either sid or tid are not found.
     fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error>;
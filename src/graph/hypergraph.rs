@@ -0,0 +1,323 @@
+use std::collections::{ HashMap, HashSet };
+use std::collections::hash_map::Entry;
+
+use super::{ Graph, Error, DefaultGraph };
+
+/// A hypergraph, in which each edge joins any number of nodes. Each
+/// hyperedge receives a stable id, assigned sequentially from zero as
+/// hyperedges are added.
+///
+/// Hypergraph does not implement Graph. Instead, `clique_expansion` and
+/// `star_expansion` convert it to a DefaultGraph for use with the rest of
+/// the crate.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error, Hypergraph };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut hypergraph = Hypergraph::new();
+///
+///     for id in 0..4 {
+///         hypergraph.add_node(id)?;
+///     }
+///
+///     assert_eq!(hypergraph.add_edge(vec![ 0, 1, 2 ]), Ok(0));
+///     assert_eq!(hypergraph.add_edge(vec![ 2, 3 ]), Ok(1));
+///     assert_eq!(hypergraph.degree(2), Ok(2));
+///
+///     let cliques = hypergraph.clique_expansion();
+///
+///     assert_eq!(cliques.size(), 4);
+///     assert_eq!(cliques.has_edge(0, 2), Ok(true));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Hypergraph {
+    indices: HashMap<usize, usize>,
+    ids: Vec<usize>,
+    incidence: Vec<Vec<usize>>,
+    edges: Vec<Vec<usize>>
+}
+
+impl Hypergraph {
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            ids: Vec::new(),
+            incidence: Vec::new(),
+            edges: Vec::new()
+        }
+    }
+
+    pub fn add_node(&mut self, id: usize) -> Result<(), Error> {
+        match self.indices.entry(id) {
+            Entry::Occupied(_) => return Err(Error::DuplicateId(id)),
+            Entry::Vacant(entry) => {
+                entry.insert(self.ids.len());
+            }
+        }
+
+        self.ids.push(id);
+        self.incidence.push(Vec::new());
+
+        Ok(())
+    }
+
+    /// Adds a hyperedge joining members, returning its id. Returns Error
+    /// if a member is not found or appears more than once. Identical
+    /// hyperedges are permitted.
+    pub fn add_edge(&mut self, members: Vec<usize>) -> Result<usize, Error> {
+        let mut seen = HashSet::new();
+
+        for &id in &members {
+            self.index_for(id)?;
+
+            if !seen.insert(id) {
+                return Err(Error::DuplicateId(id));
+            }
+        }
+
+        let eid = self.edges.len();
+
+        for &id in &members {
+            let index = self.indices[&id];
+
+            self.incidence[index].push(eid);
+        }
+
+        self.edges.push(members);
+
+        Ok(eid)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns the number of nodes.
+    pub fn order(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns the number of hyperedges.
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns an iterator over node ids, in the order they were added.
+    pub fn ids(&self) -> impl Iterator<Item=usize> + '_ {
+        self.ids.iter().cloned()
+    }
+
+    pub fn has_id(&self, id: usize) -> bool {
+        self.indices.contains_key(&id)
+    }
+
+    /// Returns an iterator over the members of each hyperedge, in id order.
+    pub fn edges(&self) -> impl Iterator<Item=&[usize]> + '_ {
+        self.edges.iter().map(|members| &members[..])
+    }
+
+    /// Returns the members of the hyperedge with id eid, or Error if not
+    /// found.
+    pub fn edge(&self, eid: usize) -> Result<&[usize], Error> {
+        match self.edges.get(eid) {
+            Some(members) => Ok(members),
+            None => Err(Error::UnknownEdge(eid))
+        }
+    }
+
+    /// Returns the ids of hyperedges containing id, or Error if id is not
+    /// found.
+    pub fn incident_edges(&self, id: usize) -> Result<&[usize], Error> {
+        Ok(&self.incidence[self.index_for(id)?])
+    }
+
+    /// Returns the number of hyperedges containing id, or Error if id is
+    /// not found.
+    pub fn degree(&self, id: usize) -> Result<usize, Error> {
+        Ok(self.incident_edges(id)?.len())
+    }
+
+    /// Returns the graph over the same nodes in which two nodes are joined
+    /// if some hyperedge contains both. Nodes appear in the order they were
+    /// added, and edges in the order first implied by a hyperedge.
+    pub fn clique_expansion(&self) -> DefaultGraph {
+        let mut result = self.nodes();
+
+        for members in &self.edges {
+            for (position, &sid) in members.iter().enumerate() {
+                for &tid in &members[(position + 1)..] {
+                    if !result.has_edge(sid, tid).expect("has edge") {
+                        result.add_edge(sid, tid).expect("add edge");
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the bipartite graph joining each node to a new node for
+    /// every hyperedge containing it. The node for hyperedge eid has id
+    /// offset + eid, where offset is one more than the largest node id, or
+    /// zero if there are no nodes.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Graph, Error, Hypergraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut hypergraph = Hypergraph::new();
+    ///
+    ///     for id in 0..3 {
+    ///         hypergraph.add_node(id)?;
+    ///     }
+    ///
+    ///     hypergraph.add_edge(vec![ 0, 1, 2 ])?;
+    ///
+    ///     let star = hypergraph.star_expansion();
+    ///
+    ///     assert_eq!(star.order(), 4);
+    ///     assert_eq!(star.degree(3), Ok(3));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn star_expansion(&self) -> DefaultGraph {
+        let offset = self.ids.iter().max().map_or(0, |&id| id + 1);
+        let mut result = self.nodes();
+
+        for (eid, members) in self.edges.iter().enumerate() {
+            result.add_node(offset + eid).expect("add node");
+
+            for &id in members {
+                result.add_edge(id, offset + eid).expect("add edge");
+            }
+        }
+
+        result
+    }
+
+    fn nodes(&self) -> DefaultGraph {
+        let mut result = DefaultGraph::new();
+
+        for &id in &self.ids {
+            result.add_node(id).expect("add node");
+        }
+
+        result
+    }
+
+    fn index_for(&self, id: usize) -> Result<usize, Error> {
+        match self.indices.get(&id) {
+            Some(index) => Ok(*index),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+}
+
+impl Default for Hypergraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod add_edge {
+    use super::*;
+
+    #[test]
+    fn unknown_member() {
+        let mut hypergraph = Hypergraph::new();
+
+        hypergraph.add_node(0).unwrap();
+
+        assert_eq!(hypergraph.add_edge(vec![ 0, 1 ]), Err(Error::UnknownId(1)));
+        assert_eq!(hypergraph.size(), 0)
+    }
+
+    #[test]
+    fn repeated_member() {
+        let mut hypergraph = Hypergraph::new();
+
+        hypergraph.add_node(0).unwrap();
+
+        assert_eq!(
+            hypergraph.add_edge(vec![ 0, 0 ]),
+            Err(Error::DuplicateId(0))
+        )
+    }
+
+    #[test]
+    fn identical() {
+        let mut hypergraph = Hypergraph::new();
+
+        hypergraph.add_node(0).unwrap();
+        hypergraph.add_node(1).unwrap();
+
+        assert_eq!(hypergraph.add_edge(vec![ 0, 1 ]), Ok(0));
+        assert_eq!(hypergraph.add_edge(vec![ 1, 0 ]), Ok(1));
+        assert_eq!(hypergraph.incident_edges(0), Ok(&[ 0, 1 ][..]));
+        assert_eq!(hypergraph.edge(1), Ok(&[ 1, 0 ][..]))
+    }
+}
+
+#[cfg(test)]
+mod clique_expansion {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Hypergraph::new().clique_expansion(), DefaultGraph::new())
+    }
+
+    #[test]
+    fn overlapping() {
+        let mut hypergraph = Hypergraph::new();
+
+        for id in 0..5 {
+            hypergraph.add_node(id).unwrap();
+        }
+
+        hypergraph.add_edge(vec![ 0, 1, 2 ]).unwrap();
+        hypergraph.add_edge(vec![ 1, 2, 3 ]).unwrap();
+        hypergraph.add_edge(vec![ 4 ]).unwrap();
+
+        let graph = hypergraph.clique_expansion();
+
+        assert_eq!(graph.order(), 5);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![
+            (0, 1), (0, 2), (1, 2), (1, 3), (2, 3)
+        ]);
+        assert_eq!(graph.degree(4), Ok(0))
+    }
+}
+
+#[cfg(test)]
+mod star_expansion {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Hypergraph::new().star_expansion(), DefaultGraph::new())
+    }
+
+    #[test]
+    fn sparse_ids() {
+        let mut hypergraph = Hypergraph::new();
+
+        hypergraph.add_node(7).unwrap();
+        hypergraph.add_node(2).unwrap();
+        hypergraph.add_edge(vec![ 7, 2 ]).unwrap();
+        hypergraph.add_edge(vec![ 2 ]).unwrap();
+
+        let graph = hypergraph.star_expansion();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), vec![ 7, 2, 8, 9 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![
+            (7, 8), (2, 8), (2, 9)
+        ])
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::hash::{ Hash, Hasher };
+
+use crate::hash::InternalBuildHasher;
+
+/// Assigns dense ids, starting from zero, to node values in the order in
+/// which they're inserted, and maps in both directions between them.
+///
+/// ```rust
+/// use gamma::graph::IdMap;
+///
+/// let mut map = IdMap::new();
+///
+/// assert_eq!(map.insert("C"), 0);
+/// assert_eq!(map.insert("O"), 1);
+/// assert_eq!(map.insert("C"), 0);
+///
+/// assert_eq!(map.id_of(&"O"), Some(1));
+/// assert_eq!(map.node_of(0), Some(&"C"));
+/// assert_eq!(map.len(), 2);
+/// ```
+#[derive(Debug,Clone)]
+pub struct IdMap<N> {
+    ids: HashMap<N, usize, InternalBuildHasher>,
+    nodes: Vec<N>
+}
+
+impl<N: Eq + Hash + Clone> IdMap<N> {
+    pub fn new() -> Self {
+        Self {
+            ids: HashMap::default(),
+            nodes: Vec::new()
+        }
+    }
+
+    /// Returns the id of node, assigning the next id if node is new.
+    pub fn insert(&mut self, node: N) -> usize {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+
+        let id = self.nodes.len();
+
+        self.ids.insert(node.clone(), id);
+        self.nodes.push(node);
+
+        id
+    }
+
+    /// Returns the id of node, if present.
+    pub fn id_of(&self, node: &N) -> Option<usize> {
+        self.ids.get(node).cloned()
+    }
+
+    /// Returns the node with the given id, if present.
+    pub fn node_of(&self, id: usize) -> Option<&N> {
+        self.nodes.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the nodes in id order.
+    pub fn nodes(&self) -> impl Iterator<Item=&N> + '_ {
+        self.nodes.iter()
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for IdMap<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: PartialEq> PartialEq for IdMap<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes
+    }
+}
+
+impl<N: Eq> Eq for IdMap<N> { }
+
+impl<N: Hash> Hash for IdMap<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.nodes.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod insert {
+    use super::*;
+
+    #[test]
+    fn repeated() {
+        let mut map = IdMap::new();
+
+        assert_eq!(map.insert("a"), 0);
+        assert_eq!(map.insert("b"), 1);
+        assert_eq!(map.insert("a"), 0);
+        assert_eq!(map.id_of(&"b"), Some(1));
+        assert_eq!(map.node_of(1), Some(&"b"));
+        assert_eq!(map.node_of(2), None)
+    }
+}
+
+#[cfg(test)]
+mod nodes {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let map = IdMap::<u8>::default();
+
+        assert!(map.is_empty());
+        assert_eq!(map.nodes().count(), 0)
+    }
+
+    #[test]
+    fn id_order() {
+        let mut map = IdMap::new();
+
+        map.insert('z');
+        map.insert('a');
+        map.insert('z');
+
+        assert_eq!(map.nodes().collect::<Vec<_>>(), [ &'z', &'a' ])
+    }
+}
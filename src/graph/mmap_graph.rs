@@ -0,0 +1,354 @@
+use std::fs::File;
+use std::collections::HashSet;
+use std::path::Path;
+use std::convert::TryFrom;
+
+use memmap2::Mmap;
+
+use super::{ Graph, Error };
+use crate::io;
+use crate::io::csr_file::{ MAGIC, VERSION };
+
+/// A read-only Graph over a memory-mapped file in the layout written by
+/// `io::write_csr`. Pages are loaded by the operating system on demand, so
+/// traversal and matching can run over graphs larger than available memory.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use std::fs::File;
+/// use gamma::graph::{ Graph, CsrGraph, MmapGraph };
+/// use gamma::io::{ write_csr, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let path = std::env::temp_dir().join("gamma_mmap_graph_doc.csr");
+///     let graph = CsrGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///
+///     write_csr(&graph, File::create(&path)?)?;
+///
+///     let mapped = MmapGraph::open(&path)?;
+///
+///     assert_eq!(mapped.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MmapGraph {
+    map: Mmap,
+    order: usize,
+    size: usize,
+    targets: usize
+}
+
+impl MmapGraph {
+    /// Maps the file at path. Returns Error if the file can't be mapped, if
+    /// its header or offsets are malformed, or if its adjacency is not
+    /// symmetric, repeats a neighbor, or disagrees with the header size.
+    /// Validation reads every neighbor list, checking each edge against the
+    /// list of its larger endpoint.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, io::Error> {
+        let file = File::open(path)?;
+        // Safety: the map is read-only. As with any mapping, the file must
+        // not be truncated or modified while the graph is in use.
+        let map = unsafe { Mmap::map(&file)? };
+
+        Self::from_map(map)
+    }
+
+    fn from_map(map: Mmap) -> Result<Self, io::Error> {
+        let invalid = || io::Error::InvalidFormat;
+
+        if map.len() < HEADER_LEN || &map[0..4] != MAGIC {
+            return Err(invalid());
+        }
+
+        let mut version = [ 0; 4 ];
+
+        version.copy_from_slice(&map[4..8]);
+
+        if u32::from_le_bytes(version) != VERSION {
+            return Err(invalid());
+        }
+
+        let order = to_usize(word(&map, 1)).ok_or_else(invalid)?;
+        let size = to_usize(word(&map, 2)).ok_or_else(invalid)?;
+        let count = to_usize(word(&map, 3)).ok_or_else(invalid)?;
+        let words = order.checked_add(1)
+            .and_then(|offsets| offsets.checked_add(count))
+            .and_then(|words| words.checked_mul(8))
+            .and_then(|bytes| bytes.checked_add(HEADER_LEN))
+            .ok_or_else(invalid)?;
+
+        if map.len() != words {
+            return Err(invalid());
+        }
+
+        let result = Self { map, order, size, targets: order + 1 };
+        let mut last = 0;
+
+        for index in 0..=order {
+            let offset = result.offset(index);
+
+            if offset < last || offset > count {
+                return Err(invalid());
+            }
+
+            last = offset;
+        }
+
+        if last != count {
+            return Err(invalid());
+        }
+
+        let mut lower = 0;
+
+        for id in 0..order {
+            let mut seen = HashSet::new();
+
+            for index in result.offset(id)..result.offset(id + 1) {
+                let target = result.target(index);
+
+                if target >= order || target == id || !seen.insert(target) {
+                    return Err(invalid());
+                }
+
+                if id < target {
+                    if !result.has_target(target, id) {
+                        return Err(invalid());
+                    }
+
+                    lower += 1;
+                }
+            }
+        }
+
+        // Every lower arc has a distinct reverse, so equal halves make the
+        // adjacency symmetric.
+        if lower != size || size.checked_mul(2) != Some(count) {
+            return Err(invalid());
+        }
+
+        Ok(result)
+    }
+
+    fn has_target(&self, id: usize, target: usize) -> bool {
+        (self.offset(id)..self.offset(id + 1)).any(|index| {
+            self.target(index) == target
+        })
+    }
+
+    fn offset(&self, index: usize) -> usize {
+        word(&self.map, 4 + index) as usize
+    }
+
+    fn target(&self, index: usize) -> usize {
+        word(&self.map, 4 + self.targets + index) as usize
+    }
+
+    fn range(&self, id: usize) -> Result<(usize, usize), Error> {
+        if id < self.order {
+            Ok((self.offset(id), self.offset(id + 1)))
+        } else {
+            Err(Error::UnknownId(id))
+        }
+    }
+}
+
+impl Graph for MmapGraph {
+    fn is_empty(&self) -> bool {
+        self.order == 0
+    }
+
+    fn order(&self) -> usize {
+        self.order
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        Box::new(0..self.order)
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        let (start, end) = self.range(id)?;
+
+        Ok(Box::new((start..end).map(move |index| self.target(index))))
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        id < self.order
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        let (start, end) = self.range(id)?;
+
+        Ok(end - start)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        Box::new(self.ids().flat_map(move |sid| {
+            self.neighbors(sid).expect("neighbors")
+                .filter(move |&tid| sid < tid)
+                .map(move |tid| (sid, tid))
+        }))
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        let mut neighbors = self.neighbors(sid)?;
+
+        if self.has_id(tid) {
+            Ok(neighbors.any(|id| id == tid))
+        } else {
+            Err(Error::UnknownId(tid))
+        }
+    }
+}
+
+const HEADER_LEN: usize = 32;
+
+fn word(bytes: &[u8], index: usize) -> u64 {
+    let start = index * 8;
+    let mut word = [ 0; 8 ];
+
+    word.copy_from_slice(&bytes[start..start + 8]);
+
+    u64::from_le_bytes(word)
+}
+
+fn to_usize(value: u64) -> Option<usize> {
+    usize::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use crate::graph::CsrGraph;
+    use crate::io::write_csr;
+    use super::*;
+
+    fn write(name: &str, graph: &CsrGraph) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+
+        write_csr(graph, File::create(&path).unwrap()).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn bad_magic() {
+        let path = std::env::temp_dir().join("gamma_mmap_bad_magic.csr");
+
+        fs::write(&path, [ 0u8; 40 ]).unwrap();
+
+        match MmapGraph::open(&path) {
+            Err(io::Error::InvalidFormat) => (),
+            _ => panic!("expected invalid file")
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap();
+        let path = write("gamma_mmap_truncated.csr", &graph);
+        let bytes = fs::read(&path).unwrap();
+
+        fs::write(&path, &bytes[..bytes.len() - 8]).unwrap();
+
+        match MmapGraph::open(&path) {
+            Err(io::Error::InvalidFormat) => (),
+            _ => panic!("expected invalid file")
+        }
+    }
+
+    fn raw(
+        name: &str, size: u64, offsets: &[u64], targets: &[u64]
+    ) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+
+        let order = offsets.len() as u64 - 1;
+        let count = targets.len() as u64;
+
+        for word in [ order, size, count ].iter().chain(offsets).chain(targets) {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+
+        fs::write(&path, bytes).unwrap();
+
+        path
+    }
+
+    fn assert_invalid(path: PathBuf) {
+        match MmapGraph::open(&path) {
+            Err(io::Error::InvalidFormat) => (),
+            _ => panic!("expected invalid file")
+        }
+    }
+
+    #[test]
+    fn valid_raw() {
+        let path = raw("gamma_mmap_valid_raw.csr", 1, &[ 0, 1, 2 ], &[ 1, 0 ]);
+
+        assert_eq!(MmapGraph::open(&path).unwrap().size(), 1)
+    }
+
+    #[test]
+    fn asymmetric() {
+        assert_invalid(raw(
+            "gamma_mmap_asymmetric.csr", 1, &[ 0, 1, 2, 2 ], &[ 1, 2 ]
+        ))
+    }
+
+    #[test]
+    fn duplicate_neighbor() {
+        assert_invalid(raw(
+            "gamma_mmap_duplicate_neighbor.csr", 1,
+            &[ 0, 2, 4 ], &[ 1, 1, 0, 0 ]
+        ))
+    }
+
+    #[test]
+    fn size_mismatch() {
+        assert_invalid(raw(
+            "gamma_mmap_size_mismatch.csr", 2, &[ 0, 1, 2 ], &[ 1, 0 ]
+        ))
+    }
+
+    #[test]
+    fn c3_p1() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 1, 0 ],
+            vec![ ]
+        ]).unwrap();
+        let path = write("gamma_mmap_c3_p1.csr", &graph);
+        let mapped = MmapGraph::open(&path).unwrap();
+
+        assert_eq!(mapped.order(), 4);
+        assert_eq!(mapped.size(), 3);
+        assert_eq!(mapped.neighbors(2).unwrap().collect::<Vec<_>>(), [ 1, 0 ]);
+        assert_eq!(mapped.degree(3), Ok(0));
+        assert_eq!(mapped.has_edge(0, 2), Ok(true));
+        assert_eq!(mapped.has_edge(0, 4), Err(Error::UnknownId(4)));
+        assert_eq!(
+            mapped.edges().collect::<Vec<_>>(),
+            graph.edges().collect::<Vec<_>>()
+        )
+    }
+}
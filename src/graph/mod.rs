@@ -1,7 +1,38 @@
+#[allow(clippy::module_inception)]
 mod graph;
+mod weighted_graph;
 mod error;
+mod event;
 mod default_graph;
+mod chunk;
+mod csr_graph;
+mod multi_graph;
+mod default_weighted_graph;
+mod filter;
+mod id_map;
+mod stable_graph;
+mod frozen_graph;
+mod temporal_graph;
+mod hypergraph;
+mod ordered_float;
+#[cfg(feature = "mmap")]
+mod mmap_graph;
 
 pub use graph::Graph;
+pub use weighted_graph::WeightedGraph;
 pub use error::Error;
-pub use default_graph::DefaultGraph;
\ No newline at end of file
+pub use event::Event;
+pub use default_graph::DefaultGraph;
+pub use chunk::{ IdChunk, IdChunkIter, EdgeChunk, EdgeChunkIter };
+pub use csr_graph::{ CsrGraph, CsrBuilder };
+pub use multi_graph::MultiGraph;
+pub use default_weighted_graph::DefaultWeightedGraph;
+pub use filter::{ filter_nodes, filter_edges, NodeFilter, EdgeFilter };
+pub use id_map::IdMap;
+pub use stable_graph::StableGraph;
+pub use frozen_graph::FrozenGraph;
+pub use temporal_graph::{ TemporalGraph, Snapshot };
+pub use hypergraph::Hypergraph;
+pub use ordered_float::{ OrderedFloat, ordered_weights };
+#[cfg(feature = "mmap")]
+pub use mmap_graph::MmapGraph;
\ No newline at end of file
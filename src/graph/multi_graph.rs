@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::{ Hash, Hasher };
+
+use std::convert::TryFrom;
+
+use super::{ Graph, Error, IdChunk, EdgeChunk };
+use crate::traversal::{ DepthFirst, BreadthFirst, Step };
+
+/// An undirected Graph permitting parallel edges. Each edge receives a
+/// stable id, assigned sequentially from zero as edges are added.
+///
+/// Through the Graph trait, a neighbor is reported once per edge joining it
+/// to the given node, so degree counts incident edges and size counts all
+/// edges, parallel or not.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error, MultiGraph };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut graph = MultiGraph::new();
+///
+///     graph.add_node(0)?;
+///     graph.add_node(1)?;
+///
+///     assert_eq!(graph.add_edge(0, 1), Ok(0));
+///     assert_eq!(graph.add_edge(1, 0), Ok(1));
+///
+///     assert_eq!(graph.neighbors(0)?.collect::<Vec<_>>(), vec![ 1, 1 ]);
+///     assert_eq!(graph.multiplicity(0, 1), Ok(2));
+///     assert_eq!(graph.edge(1), Ok((1, 0)));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct MultiGraph {
+    indices: HashMap<usize, usize>,
+    ids: Vec<usize>,
+    adjacency: Vec<Vec<(usize, usize)>>,
+    edges: Vec<(usize, usize)>
+}
+
+impl MultiGraph {
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            ids: Vec::new(),
+            adjacency: Vec::new(),
+            edges: Vec::new()
+        }
+    }
+
+    pub fn add_node(&mut self, id: usize) -> Result<(), Error> {
+        match self.indices.entry(id) {
+            Entry::Occupied(_) => return Err(Error::DuplicateId(id)),
+            Entry::Vacant(entry) => {
+                entry.insert(self.ids.len());
+            }
+        }
+
+        self.ids.push(id);
+        self.adjacency.push(Vec::new());
+
+        Ok(())
+    }
+
+    /// Returns a MultiGraph containing the nodes and edges visited by a
+    /// traversal, in the order they were visited.
+    pub fn from_steps<I: IntoIterator<Item=Step>>(
+        steps: I
+    ) -> Result<Self, Error> {
+        let mut result = Self::new();
+
+        for step in steps {
+            if result.is_empty() {
+                result.add_node(step.sid)?;
+            }
+
+            if !step.cut {
+                result.add_node(step.tid)?;
+            }
+
+            result.add_edge(step.sid, step.tid)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Adds an edge between sid and tid, returning its id. Parallel edges
+    /// are permitted, but self-loops are not.
+    pub fn add_edge(&mut self, sid: usize, tid: usize) -> Result<usize, Error> {
+        let source_index = self.index_for(sid)?;
+        let target_index = self.index_for(tid)?;
+        let eid = self.edges.len();
+
+        if sid == tid {
+            return Err(Error::SelfLoop(sid));
+        }
+
+        self.adjacency[source_index].push((tid, eid));
+        self.adjacency[target_index].push((sid, eid));
+        self.edges.push((sid, tid));
+
+        Ok(eid)
+    }
+
+    /// Returns the ids of all edges joining sid and tid, or Error if either
+    /// is not found.
+    pub fn edge_ids(
+        &self, sid: usize, tid: usize
+    ) -> Result<impl Iterator<Item=usize> + '_, Error> {
+        let index = self.index_for(sid)?;
+
+        self.index_for(tid)?;
+
+        Ok(self.adjacency[index].iter()
+            .filter(move |(id, _)| *id == tid)
+            .map(|&(_, eid)| eid))
+    }
+
+    /// Returns the number of edges joining sid and tid, or Error if either
+    /// is not found.
+    pub fn multiplicity(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        Ok(self.edge_ids(sid, tid)?.count())
+    }
+
+    /// Returns ids as chunks of length n, in iteration order. The last
+    /// chunk may be shorter. Panics if n is zero.
+    pub fn ids_chunked(&self, n: usize) -> impl Iterator<Item=IdChunk<'_>> {
+        self.ids.chunks(n).map(IdChunk::wide)
+    }
+
+    /// Returns edges as chunks of length n, in order of edge id, with each
+    /// parallel edge appearing once per id. The last chunk may be shorter.
+    /// Panics if n is zero.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Error, MultiGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut graph = MultiGraph::new();
+    ///
+    ///     graph.add_node(0)?;
+    ///     graph.add_node(1)?;
+    ///     graph.add_edge(0, 1)?;
+    ///     graph.add_edge(1, 0)?;
+    ///
+    ///     let chunks = graph.edges_chunked(1).map(|chunk| {
+    ///         chunk.to_vec()
+    ///     }).collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(chunks, vec![ vec![ (0, 1) ], vec![ (1, 0) ] ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn edges_chunked(
+        &self, n: usize
+    ) -> impl Iterator<Item=EdgeChunk<'_>> {
+        self.edges.chunks(n).map(EdgeChunk::wide)
+    }
+
+    fn index_for(&self, id: usize) -> Result<usize, Error> {
+        match self.indices.get(&id) {
+            Some(index) => Ok(*index),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+}
+
+impl<'a, G: Graph> TryFrom<DepthFirst<'a, G>> for MultiGraph {
+    type Error = Error;
+
+    fn try_from(traversal: DepthFirst<'a, G>) -> Result<Self, Self::Error> {
+        Self::from_steps(traversal)
+    }
+}
+
+impl<'a, G: Graph> TryFrom<BreadthFirst<'a, G>> for MultiGraph {
+    type Error = Error;
+
+    fn try_from(traversal: BreadthFirst<'a, G>) -> Result<Self, Self::Error> {
+        Self::from_steps(traversal)
+    }
+}
+
+impl Hash for MultiGraph {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ids.hash(state);
+        self.edges.hash(state);
+    }
+}
+
+impl Default for MultiGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Graph for MultiGraph {
+    fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    fn order(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn size(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        Box::new(self.ids.iter().cloned())
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        let index = self.index_for(id)?;
+
+        Ok(Box::new(self.adjacency[index].iter().map(|&(id, _)| id)))
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.indices.contains_key(&id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        let index = self.index_for(id)?;
+
+        Ok(self.adjacency[index].len())
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        Box::new(self.edges.iter().cloned())
+    }
+
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        match self.edges.get(eid) {
+            Some(&edge) => Ok(edge),
+            None => Err(Error::UnknownEdge(eid))
+        }
+    }
+
+    /// Returns the smallest id among edges joining sid and tid.
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        match self.edge_ids(sid, tid)?.next() {
+            Some(eid) => Ok(eid),
+            None => Err(Error::MissingEdge(sid, tid))
+        }
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        Ok(self.multiplicity(sid, tid)? > 0)
+    }
+}
+
+#[cfg(test)]
+mod from_steps {
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_cut() {
+        assert_eq!(
+            MultiGraph::from_steps(vec![ Step::new(0, 1, true) ]),
+            Err(Error::UnknownId(1))
+        )
+    }
+
+    #[test]
+    fn depth_first() {
+        let g1 = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+        let traversal = DepthFirst::new(&g1, 0).unwrap();
+        let g2 = MultiGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2), (2, 0) ])
+    }
+
+    #[test]
+    fn breadth_first() {
+        let g1 = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+        let traversal = BreadthFirst::new(&g1, 1).unwrap();
+        let g2 = MultiGraph::try_from(traversal).unwrap();
+
+        assert_eq!(g2.ids().collect::<Vec<_>>(), [ 1, 0, 2 ]);
+        assert_eq!(g2.size(), 3)
+    }
+}
+
+#[cfg(test)]
+mod add_edge {
+    use super::*;
+
+    #[test]
+    fn unknown_sid() {
+        let mut graph = MultiGraph::new();
+
+        assert_eq!(graph.add_edge(0, 1), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut graph = MultiGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_edge(0, 0), Err(Error::SelfLoop(0)))
+    }
+
+    #[test]
+    fn parallel() {
+        let mut graph = MultiGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_node(1), Ok(()));
+        assert_eq!(graph.add_edge(0, 1), Ok(0));
+        assert_eq!(graph.add_edge(0, 1), Ok(1));
+        assert_eq!(graph.add_edge(1, 0), Ok(2));
+
+        assert_eq!(graph.size(), 3);
+        assert_eq!(graph.degree(1), Ok(3));
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (0, 1), (1, 0) ])
+    }
+}
+
+#[cfg(test)]
+mod edge {
+    use super::*;
+
+    #[test]
+    fn outside() {
+        let graph = MultiGraph::new();
+
+        assert_eq!(graph.edge(0), Err(Error::UnknownEdge(0)))
+    }
+}
+
+#[cfg(test)]
+mod edge_ids {
+    use super::*;
+
+    #[test]
+    fn unknown_tid() {
+        let mut graph = MultiGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.edge_ids(0, 1).err(), Some(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn parallel_among_others() {
+        let mut graph = MultiGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_node(1), Ok(()));
+        assert_eq!(graph.add_node(2), Ok(()));
+        assert_eq!(graph.add_edge(0, 1), Ok(0));
+        assert_eq!(graph.add_edge(1, 2), Ok(1));
+        assert_eq!(graph.add_edge(1, 0), Ok(2));
+
+        assert_eq!(graph.edge_ids(0, 1).unwrap().collect::<Vec<_>>(), [ 0, 2 ]);
+        assert_eq!(graph.multiplicity(2, 1), Ok(1));
+        assert_eq!(graph.edge_id(1, 0), Ok(0));
+        assert_eq!(graph.edge_id(0, 2), Err(Error::MissingEdge(0, 2)));
+        assert_eq!(graph.has_edge(0, 2), Ok(false))
+    }
+}
+
+#[cfg(test)]
+mod chunked {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        let mut graph = MultiGraph::new();
+
+        for id in &[ 4, 2, 7 ] {
+            graph.add_node(*id).unwrap();
+        }
+
+        assert_eq!(graph.ids_chunked(2).map(|chunk| {
+            chunk.to_vec()
+        }).collect::<Vec<_>>(), [
+            vec![ 4, 2 ], vec![ 7 ]
+        ])
+    }
+}
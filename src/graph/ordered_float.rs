@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+use std::hash::{ Hash, Hasher };
+use std::ops::Add;
+
+use super::{ WeightedGraph, Error };
+
+/// An f64 that is never NaN, ordered by `f64::total_cmp`. Algorithms that
+/// need Ord weights, such as `shortest_path::dijkstra`, can then use
+/// floating point weights without panicking on an unordered comparison or
+/// depending on the order in which ties were found. Because the order is
+/// total, -0.0 sorts before, and is not equal to, 0.0.
+///
+/// ```rust
+/// use gamma::graph::OrderedFloat;
+///
+/// let a = OrderedFloat::new(1.5).unwrap();
+/// let b = OrderedFloat::new(0.5).unwrap();
+///
+/// assert_eq!((a + b).get(), 2.0);
+/// assert_eq!(a.max(b), a);
+/// assert_eq!(OrderedFloat::new(f64::NAN), None);
+/// ```
+#[derive(Debug,Clone,Copy,Default)]
+pub struct OrderedFloat(f64);
+
+impl OrderedFloat {
+    /// Returns value wrapped, or None if it is NaN.
+    pub fn new(value: f64) -> Option<Self> {
+        if value.is_nan() {
+            None
+        } else {
+            Some(Self(value))
+        }
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the sum of self and other, or None if it is NaN, as it is
+    /// for infinities of opposite sign.
+    ///
+    /// ```rust
+    /// use gamma::graph::OrderedFloat;
+    ///
+    /// let inf = OrderedFloat::new(f64::INFINITY).unwrap();
+    /// let neg_inf = OrderedFloat::new(f64::NEG_INFINITY).unwrap();
+    ///
+    /// assert_eq!(inf.checked_add(inf), Some(inf));
+    /// assert_eq!(inf.checked_add(neg_inf), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::new(self.0 + other.0)
+    }
+}
+
+impl PartialEq for OrderedFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedFloat { }
+
+impl PartialOrd for OrderedFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrderedFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state)
+    }
+}
+
+/// Sums two weights. Panics if the sum is NaN, as it is for infinities of
+/// opposite sign. Use `checked_add` to handle that case instead.
+impl Add for OrderedFloat {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("NaN sum")
+    }
+}
+
+/// Checks every weight of graph, returning a closure that gives each as an
+/// OrderedFloat. The closure suits entry points such as
+/// `shortest_path::dijkstra_with_weights`, so f64 weights can be used
+/// without copying the graph. Returns NanWeight for the first edge, in the
+/// order of `edges`, whose weight is NaN.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultWeightedGraph, ordered_weights };
+/// use gamma::shortest_path::dijkstra_with_weights;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 0.5),
+///         (1, 2, 0.25),
+///         (0, 2, 1.0)
+///     ])?;
+///     let weight = ordered_weights(&graph)?;
+///     let (cost, path) = dijkstra_with_weights(&graph, 0, 2, weight)?.unwrap();
+///
+///     assert_eq!(cost.get(), 0.75);
+///     assert_eq!(path, vec![ 0, 1, 2 ]);
+///
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, f64::NAN)
+///     ])?;
+///
+///     assert_eq!(ordered_weights(&graph).err(), Some(Error::NanWeight(0, 1)));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn ordered_weights<G>(
+    graph: &G
+) -> Result<impl Fn(usize, usize) -> OrderedFloat + '_, Error>
+where G: WeightedGraph<Weight=f64> {
+    for (sid, tid) in graph.edges() {
+        if graph.weight(sid, tid)?.is_some_and(|weight| weight.is_nan()) {
+            return Err(Error::NanWeight(sid, tid));
+        }
+    }
+
+    Ok(move |sid, tid| {
+        OrderedFloat(*graph.weight(sid, tid).expect("weight").expect("edge"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultWeightedGraph;
+    use crate::shortest_path::dijkstra_with_weights;
+
+    fn of(value: f64) -> OrderedFloat {
+        OrderedFloat::new(value).unwrap()
+    }
+
+    #[test]
+    fn signed_zero() {
+        assert!(of(-0.0) < of(0.0));
+        assert_ne!(of(-0.0), of(0.0))
+    }
+
+    #[test]
+    fn infinity() {
+        assert!(of(f64::INFINITY) > of(f64::MAX))
+    }
+
+    #[test]
+    #[should_panic(expected="NaN sum")]
+    fn add_opposite_infinities() {
+        let _ = of(f64::INFINITY) + of(f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn checked_add_opposite_infinities() {
+        assert_eq!(of(f64::NEG_INFINITY).checked_add(of(f64::INFINITY)), None)
+    }
+
+    #[test]
+    fn sort() {
+        let mut values = vec![ of(2.0), of(-1.0), of(0.5) ];
+
+        values.sort();
+
+        assert_eq!(values, vec![ of(-1.0), of(0.5), of(2.0) ])
+    }
+
+    #[test]
+    fn nan_weight_later_edge() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1.0),
+            (1, 2, f64::NAN)
+        ]).unwrap();
+
+        assert_eq!(ordered_weights(&graph).err(), Some(Error::NanWeight(1, 2)))
+    }
+
+    #[test]
+    fn dijkstra_ties() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 0.1),
+            (1, 3, 0.2),
+            (0, 2, 0.2),
+            (2, 3, 0.1)
+        ]).unwrap();
+        let weight = ordered_weights(&graph).unwrap();
+        let first = dijkstra_with_weights(&graph, 0, 3, &weight).unwrap();
+        let second = dijkstra_with_weights(&graph, 0, 3, &weight).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.unwrap().0, of(0.1) + of(0.2))
+    }
+}
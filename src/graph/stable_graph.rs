@@ -0,0 +1,202 @@
+use std::hash::Hash;
+
+use super::{ Graph, DefaultGraph, Error };
+use super::id_map::IdMap;
+
+/// An undirected Graph over node values of any Eq + Hash type. Each value
+/// is assigned a dense usize id when added, and keeps that id for the life
+/// of the graph. Through the Graph trait, a StableGraph can be passed to
+/// components, traversal, matching, and anything else that works with ids.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error, StableGraph };
+/// use gamma::selection::components;
+///
+/// fn main() -> Result<(), Error> {
+///     let mut graph = StableGraph::new();
+///     let c = graph.add_node("C")?;
+///     let o = graph.add_node("O")?;
+///     let n = graph.add_node("N")?;
+///
+///     graph.add_edge(c, o)?;
+///
+///     assert_eq!(graph.id(&"N"), Some(n));
+///     assert_eq!(graph.node(o), Ok(&"O"));
+///     assert_eq!(components(&graph).count(), 2);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq,Hash)]
+pub struct StableGraph<N> {
+    map: IdMap<N>,
+    graph: DefaultGraph
+}
+
+impl<N: Eq + Hash + Clone> StableGraph<N> {
+    pub fn new() -> Self {
+        Self {
+            map: IdMap::new(),
+            graph: DefaultGraph::new()
+        }
+    }
+
+    /// Adds node, returning its id. Returns Error if node is already
+    /// present.
+    pub fn add_node(&mut self, node: N) -> Result<usize, Error> {
+        if let Some(id) = self.map.id_of(&node) {
+            return Err(Error::DuplicateId(id));
+        }
+
+        // Ids are dense, so the next id is known before the map assigns it.
+        // Adding it to the graph first leaves both untouched on failure.
+        self.graph.add_node(self.map.len())?;
+
+        Ok(self.map.insert(node))
+    }
+
+    /// Adds an edge between the nodes with ids sid and tid.
+    pub fn add_edge(&mut self, sid: usize, tid: usize) -> Result<(), Error> {
+        self.graph.add_edge(sid, tid)
+    }
+
+    /// Returns the id of node, if present.
+    pub fn id(&self, node: &N) -> Option<usize> {
+        self.map.id_of(node)
+    }
+
+    /// Returns the node with the given id, or Error if id not found.
+    pub fn node(&self, id: usize) -> Result<&N, Error> {
+        match self.map.node_of(id) {
+            Some(node) => Ok(node),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for StableGraph<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> Graph for StableGraph<N> {
+    fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.order()
+    }
+
+    fn size(&self) -> usize {
+        self.graph.size()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        self.graph.ids()
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        self.graph.neighbors(id)
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.graph.has_id(id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        self.graph.degree(id)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        self.graph.edges()
+    }
+
+    fn edge(&self, eid: usize) -> Result<(usize, usize), Error> {
+        self.graph.edge(eid)
+    }
+
+    fn edge_id(&self, sid: usize, tid: usize) -> Result<usize, Error> {
+        self.graph.edge_id(sid, tid)
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        self.graph.has_edge(sid, tid)
+    }
+}
+
+#[cfg(test)]
+mod add_node {
+    use super::*;
+
+    #[test]
+    fn duplicate() {
+        let mut graph = StableGraph::new();
+
+        assert_eq!(graph.add_node('a'), Ok(0));
+        assert_eq!(graph.add_node('b'), Ok(1));
+        assert_eq!(graph.add_node('a'), Err(Error::DuplicateId(0)));
+        assert_eq!(graph.order(), 2)
+    }
+
+    #[test]
+    fn graph_error_leaves_map() {
+        let mut graph = StableGraph::new();
+
+        graph.graph.add_node(0).unwrap();
+
+        assert_eq!(graph.add_node('a'), Err(Error::DuplicateId(0)));
+        assert_eq!(graph.id(&'a'), None);
+        assert_eq!(graph.map.len(), 0)
+    }
+}
+
+#[cfg(test)]
+mod add_edge {
+    use super::*;
+
+    #[test]
+    fn unknown_tid() {
+        let mut graph = StableGraph::new();
+
+        graph.add_node('a').unwrap();
+
+        assert_eq!(graph.add_edge(0, 1), Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn p2() {
+        let mut graph = StableGraph::new();
+        let a = graph.add_node('a').unwrap();
+        let b = graph.add_node('b').unwrap();
+
+        assert_eq!(graph.add_edge(a, b), Ok(()));
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1) ])
+    }
+}
+
+#[cfg(test)]
+mod node {
+    use super::*;
+
+    #[test]
+    fn outside() {
+        let graph = StableGraph::<char>::new();
+
+        assert_eq!(graph.node(0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut graph = StableGraph::new();
+
+        graph.add_node(String::from("x")).unwrap();
+
+        let id = graph.id(&String::from("x")).unwrap();
+
+        assert_eq!(graph.node(id), Ok(&String::from("x")))
+    }
+}
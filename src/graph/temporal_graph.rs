@@ -0,0 +1,418 @@
+use std::collections::{ BinaryHeap, HashMap };
+use std::collections::hash_map::Entry;
+use std::cmp::Reverse;
+
+use super::{ Graph, Error };
+
+/// An undirected graph whose edges exist only during given intervals of
+/// time. Intervals are half-open, so an edge added with start and end is
+/// present at every time t with start <= t < end. An edge may be added
+/// repeatedly to give it several intervals.
+///
+/// TemporalGraph does not implement Graph itself. Instead, `snapshot`
+/// returns a Graph view of the edges present at a single time.
+///
+/// ```rust
+/// use gamma::graph::{ Graph, Error, TemporalGraph };
+///
+/// fn main() -> Result<(), Error> {
+///     let mut graph = TemporalGraph::new();
+///
+///     graph.add_node(0)?;
+///     graph.add_node(1)?;
+///     graph.add_node(2)?;
+///     graph.add_edge(0, 1, 0, 10)?;
+///     graph.add_edge(1, 2, 5, 8)?;
+///
+///     assert_eq!(graph.snapshot(3).size(), 1);
+///     assert_eq!(graph.snapshot(6).has_edge(1, 2), Ok(true));
+///     assert_eq!(graph.snapshot(8).has_edge(1, 2), Ok(false));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct TemporalGraph<T> {
+    indices: HashMap<usize, usize>,
+    ids: Vec<usize>,
+    adjacency: Vec<Vec<usize>>,
+    pairs: Vec<(usize, usize)>,
+    intervals: HashMap<(usize, usize), Vec<(T, T)>>
+}
+
+impl<T: Copy + Ord> TemporalGraph<T> {
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            ids: Vec::new(),
+            adjacency: Vec::new(),
+            pairs: Vec::new(),
+            intervals: HashMap::new()
+        }
+    }
+
+    pub fn add_node(&mut self, id: usize) -> Result<(), Error> {
+        match self.indices.entry(id) {
+            Entry::Occupied(_) => return Err(Error::DuplicateId(id)),
+            Entry::Vacant(entry) => {
+                entry.insert(self.ids.len());
+            }
+        }
+
+        self.ids.push(id);
+        self.adjacency.push(Vec::new());
+
+        Ok(())
+    }
+
+    /// Makes the edge (sid, tid) present from start until just before end.
+    /// Returns Error if either node is not found, or if sid and tid are the
+    /// same.
+    pub fn add_edge(
+        &mut self, sid: usize, tid: usize, start: T, end: T
+    ) -> Result<(), Error> {
+        let source_index = self.index_for(sid)?;
+        let target_index = self.index_for(tid)?;
+
+        if sid == tid {
+            return Err(Error::SelfLoop(sid));
+        }
+
+        match self.intervals.entry(key(sid, tid)) {
+            Entry::Occupied(mut entry) => entry.get_mut().push((start, end)),
+            Entry::Vacant(entry) => {
+                entry.insert(vec![ (start, end) ]);
+                self.adjacency[source_index].push(tid);
+                self.adjacency[target_index].push(sid);
+                self.pairs.push((sid, tid));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the intervals during which the edge (sid, tid) is present,
+    /// in the order they were added. The slice is empty if the edge was
+    /// never added. Returns Error if either node is not found.
+    pub fn intervals(&self, sid: usize, tid: usize) -> Result<&[(T, T)], Error> {
+        self.index_for(sid)?;
+        self.index_for(tid)?;
+
+        Ok(match self.intervals.get(&key(sid, tid)) {
+            Some(intervals) => intervals,
+            None => &[ ]
+        })
+    }
+
+    /// Returns a view with every node, but only the edges present at time.
+    pub fn snapshot(&self, time: T) -> Snapshot<'_, T> {
+        Snapshot { graph: self, time }
+    }
+
+    /// Returns the earliest time each node can be reached from source by a
+    /// time-respecting breadth-first search starting at time start. A walk
+    /// respects time if it crosses each edge while the edge is present and
+    /// never moves backward in time. Crossing an edge takes no time, so
+    /// several edges may be crossed at the same time. Nodes that can't be
+    /// reached are omitted. Returns Error if source is not found.
+    ///
+    /// ```rust
+    /// use gamma::graph::{ Error, TemporalGraph };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut graph = TemporalGraph::new();
+    ///
+    ///     for id in 0..3 {
+    ///         graph.add_node(id)?;
+    ///     }
+    ///
+    ///     graph.add_edge(0, 1, 5, 10)?;
+    ///     graph.add_edge(1, 2, 0, 3)?;
+    ///
+    ///     let arrivals = graph.earliest_arrivals(0, 0)?;
+    ///
+    ///     assert_eq!(arrivals[&1], 5);
+    ///     assert_eq!(arrivals.contains_key(&2), false);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn earliest_arrivals(
+        &self, source: usize, start: T
+    ) -> Result<HashMap<usize, T>, Error> {
+        self.index_for(source)?;
+
+        let mut result = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        heap.push(Reverse((start, source)));
+
+        while let Some(Reverse((time, id))) = heap.pop() {
+            if result.contains_key(&id) {
+                continue;
+            }
+
+            result.insert(id, time);
+
+            for &neighbor in &self.adjacency[self.indices[&id]] {
+                if result.contains_key(&neighbor) {
+                    continue;
+                }
+
+                let departure = self.intervals[&key(id, neighbor)].iter()
+                    .filter(|&&(_, end)| time < end)
+                    .map(|&(start, end)| (start.max(time), end))
+                    .filter(|&(departure, end)| departure < end)
+                    .map(|(departure, _)| departure)
+                    .min();
+
+                if let Some(departure) = departure {
+                    heap.push(Reverse((departure, neighbor)));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn index_for(&self, id: usize) -> Result<usize, Error> {
+        match self.indices.get(&id) {
+            Some(index) => Ok(*index),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+
+    fn present(&self, sid: usize, tid: usize, time: T) -> bool {
+        match self.intervals.get(&key(sid, tid)) {
+            Some(intervals) => intervals.iter()
+                .any(|&(start, end)| start <= time && time < end),
+            None => false
+        }
+    }
+}
+
+impl<T: Copy + Ord> Default for TemporalGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A view of a TemporalGraph at a single time. See
+/// `TemporalGraph::snapshot`.
+pub struct Snapshot<'a, T> {
+    graph: &'a TemporalGraph<T>,
+    time: T
+}
+
+impl<'a, T: Copy + Ord> Graph for Snapshot<'a, T> {
+    fn is_empty(&self) -> bool {
+        self.graph.ids.is_empty()
+    }
+
+    fn order(&self) -> usize {
+        self.graph.ids.len()
+    }
+
+    fn size(&self) -> usize {
+        self.edges().count()
+    }
+
+    fn ids(&self) -> Box<dyn Iterator<Item=usize> + '_> {
+        Box::new(self.graph.ids.iter().cloned())
+    }
+
+    fn neighbors(
+        &self, id: usize
+    ) -> Result<Box<dyn Iterator<Item=usize> + '_>, Error> {
+        let index = self.graph.index_for(id)?;
+
+        Ok(Box::new(self.graph.adjacency[index].iter()
+            .cloned()
+            .filter(move |&neighbor| {
+                self.graph.present(id, neighbor, self.time)
+            })))
+    }
+
+    fn has_id(&self, id: usize) -> bool {
+        self.graph.indices.contains_key(&id)
+    }
+
+    fn degree(&self, id: usize) -> Result<usize, Error> {
+        Ok(self.neighbors(id)?.count())
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item=(usize, usize)> + '_> {
+        Box::new(self.graph.pairs.iter()
+            .cloned()
+            .filter(move |&(sid, tid)| self.graph.present(sid, tid, self.time)))
+    }
+
+    fn has_edge(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        self.graph.index_for(sid)?;
+        self.graph.index_for(tid)?;
+
+        Ok(self.graph.present(sid, tid, self.time))
+    }
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod add_edge {
+    use super::*;
+
+    #[test]
+    fn unknown_tid() {
+        let mut graph = TemporalGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_edge(0, 1, 0, 1), Err(Error::UnknownId(1)))
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut graph = TemporalGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_edge(0, 0, 0, 1), Err(Error::SelfLoop(0)))
+    }
+
+    #[test]
+    fn repeated() {
+        let mut graph = TemporalGraph::new();
+
+        assert_eq!(graph.add_node(0), Ok(()));
+        assert_eq!(graph.add_node(1), Ok(()));
+        assert_eq!(graph.add_edge(0, 1, 0, 2), Ok(()));
+        assert_eq!(graph.add_edge(1, 0, 5, 7), Ok(()));
+        assert_eq!(graph.intervals(0, 1), Ok(&[ (0, 2), (5, 7) ][..]))
+    }
+}
+
+#[cfg(test)]
+mod snapshot {
+    use super::*;
+
+    fn graph() -> TemporalGraph<u32> {
+        let mut graph = TemporalGraph::new();
+
+        for id in 0..3 {
+            graph.add_node(id).unwrap();
+        }
+
+        graph.add_edge(0, 1, 0, 2).unwrap();
+        graph.add_edge(0, 1, 1, 4).unwrap();
+        graph.add_edge(1, 2, 3, 5).unwrap();
+
+        graph
+    }
+
+    #[test]
+    fn before_all() {
+        let graph = graph();
+        let snapshot = graph.snapshot(0);
+
+        assert_eq!(snapshot.order(), 3);
+        assert_eq!(snapshot.edges().collect::<Vec<_>>(), vec![ (0, 1) ])
+    }
+
+    #[test]
+    fn overlapping_intervals() {
+        let graph = graph();
+        let snapshot = graph.snapshot(1);
+
+        assert_eq!(snapshot.neighbors(0).unwrap().collect::<Vec<_>>(), [ 1 ]);
+        assert_eq!(snapshot.size(), 1)
+    }
+
+    #[test]
+    fn end_excluded() {
+        let graph = graph();
+        let snapshot = graph.snapshot(4);
+
+        assert_eq!(snapshot.has_edge(0, 1), Ok(false));
+        assert_eq!(snapshot.degree(2), Ok(1))
+    }
+
+    #[test]
+    fn unknown_id() {
+        let graph = graph();
+
+        assert_eq!(graph.snapshot(0).has_edge(0, 9), Err(Error::UnknownId(9)))
+    }
+}
+
+#[cfg(test)]
+mod earliest_arrivals {
+    use super::*;
+
+    #[test]
+    fn unknown_source() {
+        let graph = TemporalGraph::<u32>::new();
+
+        assert_eq!(graph.earliest_arrivals(0, 0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn source_only() {
+        let mut graph = TemporalGraph::<u32>::new();
+
+        graph.add_node(0).unwrap();
+
+        assert_eq!(
+            graph.earliest_arrivals(0, 3),
+            Ok(vec![ (0, 3) ].into_iter().collect())
+        )
+    }
+
+    #[test]
+    fn waits_for_edge() {
+        let mut graph = TemporalGraph::new();
+
+        for id in 0..4 {
+            graph.add_node(id).unwrap();
+        }
+
+        graph.add_edge(0, 1, 0, 2).unwrap();
+        graph.add_edge(1, 2, 6, 7).unwrap();
+        graph.add_edge(2, 3, 6, 9).unwrap();
+
+        let arrivals = graph.earliest_arrivals(0, 1).unwrap();
+
+        assert_eq!(arrivals[&1], 1);
+        assert_eq!(arrivals[&2], 6);
+        assert_eq!(arrivals[&3], 6)
+    }
+
+    #[test]
+    fn flattening_overstates_reach() {
+        let mut graph = TemporalGraph::new();
+
+        for id in 0..3 {
+            graph.add_node(id).unwrap();
+        }
+
+        graph.add_edge(1, 2, 0, 1).unwrap();
+        graph.add_edge(0, 1, 2, 3).unwrap();
+
+        let forward = graph.earliest_arrivals(0, 0).unwrap();
+        let backward = graph.earliest_arrivals(2, 0).unwrap();
+
+        assert!(!forward.contains_key(&2));
+        assert_eq!(backward[&0], 2)
+    }
+
+    #[test]
+    fn later_interval() {
+        let mut graph = TemporalGraph::new();
+
+        graph.add_node(0).unwrap();
+        graph.add_node(1).unwrap();
+        graph.add_edge(0, 1, 0, 2).unwrap();
+        graph.add_edge(0, 1, 10, 12).unwrap();
+
+        assert_eq!(graph.earliest_arrivals(0, 5).unwrap()[&1], 10)
+    }
+}
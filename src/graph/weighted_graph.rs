@@ -0,0 +1,12 @@
+use super::{ Graph, Error };
+
+/// A Graph whose edges carry a weight of type Weight.
+pub trait WeightedGraph: Graph {
+    type Weight;
+
+    /// Returns the weight of the edge (sid, tid), or None if there is no such
+    /// edge. Returns Error if either sid or tid are not found.
+    fn weight(
+        &self, sid: usize, tid: usize
+    ) -> Result<Option<&Self::Weight>, Error>;
+}
@@ -0,0 +1,125 @@
+//! Hashers for the hash-backed structures in this crate.
+//!
+//! DefaultGraph and Pairing accept any BuildHasher through their
+//! `with_hasher` constructors. Those built with `new` use
+//! `DefaultBuildHasher`, which is always std's RandomState, so enabling a
+//! feature never changes a public type. Pass `FxBuildHasher` to
+//! `with_hasher` for faster hashing of integer ids.
+//!
+//! The `fxhash` feature switches maps that are internal to the crate, such
+//! as the one inside IdMap, to `FxBuildHasher`.
+
+use std::hash::{ Hasher, BuildHasherDefault };
+use std::collections::hash_map::RandomState;
+
+/// The BuildHasher used by constructors that don't take one.
+pub type DefaultBuildHasher = RandomState;
+
+/// The BuildHasher for maps whose hasher isn't part of a public type.
+#[cfg(not(feature = "fxhash"))]
+pub(crate) type InternalBuildHasher = RandomState;
+
+/// The BuildHasher for maps whose hasher isn't part of a public type.
+#[cfg(feature = "fxhash")]
+pub(crate) type InternalBuildHasher = FxBuildHasher;
+
+/// A BuildHasher for FxHasher.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// A fast, non-cryptographic Hasher using the algorithm from rustc and
+/// Firefox. It is well suited to integer keys such as node ids, but offers
+/// no protection against collision attacks.
+#[derive(Debug,Default,Clone,Copy)]
+pub struct FxHasher {
+    hash: u64
+}
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FxHasher {
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [ 0; 8 ];
+
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.add_to_hash(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod fx_hasher {
+    use std::hash::BuildHasher;
+    use std::collections::HashMap;
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let builder = FxBuildHasher::default();
+
+        assert_eq!(builder.hash_one(42usize), builder.hash_one(42usize))
+    }
+
+    #[test]
+    fn distinct() {
+        let builder = FxBuildHasher::default();
+
+        assert_ne!(builder.hash_one(1usize), builder.hash_one(2usize))
+    }
+
+    #[test]
+    fn map() {
+        let mut map = HashMap::with_hasher(FxBuildHasher::default());
+
+        map.insert(0, 1);
+        map.insert(1, 0);
+
+        assert_eq!(map.get(&1), Some(&0))
+    }
+}
+
+#[cfg(test)]
+mod default_build_hasher {
+    use super::*;
+    use crate::graph::{ Graph, DefaultGraph };
+    use crate::matching::Pairing;
+
+    #[test]
+    fn random_state_under_every_feature() {
+        let graph: DefaultGraph = DefaultGraph::with_hasher(RandomState::new());
+        let pairing: Pairing = Pairing::with_hasher(RandomState::new());
+
+        assert!(graph.is_empty());
+        assert_eq!(pairing.order(), 0)
+    }
+}
@@ -0,0 +1,3 @@
+mod neighborhood_hash;
+
+pub use neighborhood_hash::neighborhood_hash;
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::hash::Hasher;
+
+use crate::graph::Graph;
+use crate::hash::FxHasher;
+
+/// Returns a hash for each node summarizing its neighborhood out to radius
+/// edges, by the refinement of Weisfeiler and Lehman. Each node starts
+/// from a hash of its degree. Each of radius rounds then replaces a node's
+/// hash with one of its previous hash and the sorted hashes of its
+/// neighbors.
+///
+/// Nodes related by an automorphism always receive equal hashes, so the
+/// hashes are suitable node invariants for seeding isomorphism searches
+/// and breaking ties in canonical orderings. Unequal nodes may also
+/// collide. Hashing uses `FxHasher`, so results are reproducible across
+/// runs.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::invariants::neighborhood_hash;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4)
+///     ])?;
+///     let hashes = neighborhood_hash(&graph, 2);
+///
+///     assert_eq!(hashes[&0], hashes[&4]);
+///     assert_eq!(hashes[&1], hashes[&3]);
+///     assert_ne!(hashes[&1], hashes[&2]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn neighborhood_hash<G: Graph>(
+    graph: &G, radius: usize
+) -> HashMap<usize, u64> {
+    let mut result = graph.ids()
+        .map(|id| {
+            let mut hasher = FxHasher::default();
+
+            hasher.write_usize(graph.degree(id).expect("degree"));
+
+            (id, hasher.finish())
+        })
+        .collect::<HashMap<_, _>>();
+
+    for _ in 0..radius {
+        result = graph.ids()
+            .map(|id| {
+                let mut neighbors = graph.neighbors(id).expect("neighbors")
+                    .map(|neighbor| result[&neighbor])
+                    .collect::<Vec<_>>();
+                let mut hasher = FxHasher::default();
+
+                neighbors.sort_unstable();
+                hasher.write_u64(result[&id]);
+
+                for hash in neighbors {
+                    hasher.write_u64(hash);
+                }
+
+                (id, hasher.finish())
+            })
+            .collect();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert!(neighborhood_hash(&DefaultGraph::new(), 3).is_empty())
+    }
+
+    #[test]
+    fn radius_zero_is_degree() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4)
+        ]).unwrap();
+        let hashes = neighborhood_hash(&graph, 0);
+
+        assert_eq!(hashes[&1], hashes[&2]);
+        assert_ne!(hashes[&0], hashes[&1])
+    }
+
+    #[test]
+    fn cycle_uniform() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)
+        ]).unwrap();
+        let hashes = neighborhood_hash(&graph, 4);
+        let uniform = graph.ids().all(|id| hashes[&id] == hashes[&0]);
+
+        assert!(uniform)
+    }
+
+    #[test]
+    fn ids_ignored() {
+        let a = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (1, 3) ]).unwrap();
+        let b = DefaultGraph::try_from(vec![ (9, 4), (4, 7), (4, 2) ]).unwrap();
+
+        assert_eq!(
+            neighborhood_hash(&a, 2)[&1],
+            neighborhood_hash(&b, 2)[&4]
+        )
+    }
+
+    #[test]
+    fn radius_separates_distant_structure() {
+        // 2 and 3 differ only in a leaf two edges from 2
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)
+        ]).unwrap();
+        let near = neighborhood_hash(&graph, 1);
+        let far = neighborhood_hash(&graph, 2);
+
+        assert_eq!(near[&2], near[&3]);
+        assert_ne!(far[&2], far[&3])
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::Write;
+
+use crate::graph::{ Graph, DefaultGraph };
+use super::Error;
+
+/// Parses an adjacency list into a DefaultGraph. Each line holds an id, a
+/// colon, and the ids of its neighbors separated by whitespace or commas.
+/// An edge may be listed from one end or both. Ids seen only as neighbors
+/// are added too. Text from `#` to the end of a line is a comment, and
+/// blank lines are skipped.
+///
+/// Nodes are added in the order they first appear, and edges in the order
+/// they're first listed.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::io::{ parse_adjacency, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = parse_adjacency("
+///         0: 1   # propane
+///         1: 0 2
+///         2: 1
+///     ")?;
+///
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn parse_adjacency(text: &str) -> Result<DefaultGraph, Error> {
+    let mut result = DefaultGraph::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let line = line.split('#').next().expect("line").trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let colon = line.find(':').ok_or(Error::InvalidLine(number))?;
+        let sid = parse_id(&line[..colon], number)?;
+
+        if !result.has_id(sid) {
+            result.add_node(sid)?;
+        }
+
+        let fields = line[colon + 1..]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|field| !field.is_empty());
+
+        for field in fields {
+            let tid = parse_id(field, number)?;
+
+            if !result.has_id(tid) {
+                result.add_node(tid)?;
+            }
+
+            if !result.has_edge(sid, tid)? {
+                result.add_edge(sid, tid)?;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Writes graph as an adjacency list readable by `parse_adjacency`, with
+/// one line per node listing all of its neighbors.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::DefaultGraph;
+/// use gamma::io::{ write_adjacency, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0 ],
+///         vec![ ]
+///     ])?;
+///     let mut bytes = Vec::new();
+///
+///     write_adjacency(&graph, &mut bytes)?;
+///
+///     assert_eq!(String::from_utf8(bytes).unwrap(), "0: 1\n1: 0\n2:\n");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn write_adjacency<G: Graph, W: Write>(
+    graph: &G, mut writer: W
+) -> Result<(), Error> {
+    for id in graph.ids() {
+        write!(writer, "{}:", id)?;
+
+        for neighbor in graph.neighbors(id)? {
+            write!(writer, " {}", neighbor)?;
+        }
+
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+fn parse_id(field: &str, number: usize) -> Result<usize, Error> {
+    field.trim().parse().map_err(|_| Error::InvalidLine(number))
+}
+
+#[cfg(test)]
+mod parse {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph;
+
+    #[test]
+    fn empty() {
+        let graph = parse_adjacency("\n# nothing\n").unwrap();
+
+        assert!(graph.is_empty())
+    }
+
+    #[test]
+    fn missing_colon() {
+        let result = parse_adjacency("0: 1\n1 0\n");
+
+        assert!(matches!(result, Err(Error::InvalidLine(2))))
+    }
+
+    #[test]
+    fn invalid_neighbor() {
+        let result = parse_adjacency("0: 1 x\n");
+
+        assert!(matches!(result, Err(Error::InvalidLine(1))))
+    }
+
+    #[test]
+    fn self_loop() {
+        let result = parse_adjacency("0: 0\n");
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::SelfLoop(0))))
+        )
+    }
+
+    #[test]
+    fn one_sided_and_commas() {
+        let graph = parse_adjacency("0: 1, 2 # trailing\n2: 3").unwrap();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [
+            (0, 1), (0, 2), (2, 3)
+        ])
+    }
+
+    #[test]
+    fn isolated() {
+        let graph = parse_adjacency("0:\n1:").unwrap();
+
+        assert_eq!(graph.order(), 2);
+        assert_eq!(graph.size(), 0)
+    }
+
+    #[test]
+    fn round_trip() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 3 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 0 ],
+            vec![ ]
+        ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_adjacency(&graph, &mut bytes).unwrap();
+
+        let text = String::from_utf8(bytes).unwrap();
+
+        assert_eq!(parse_adjacency(&text).unwrap(), graph)
+    }
+}
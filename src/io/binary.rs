@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{ BufReader, BufWriter, Read, Write };
+
+use crate::graph::{
+    self, Graph, WeightedGraph, DefaultGraph, DefaultWeightedGraph
+};
+use super::Error;
+use super::entries::Entries;
+
+const MAGIC: &[u8; 4] = b"GBIN";
+const VERSION: u32 = 1;
+const WEIGHTED: u8 = 1;
+// Declared counts come from untrusted input, so they cap preallocation
+// rather than set it. Larger graphs grow as they're read.
+const PREALLOCATE: usize = 1 << 16;
+
+/// Writes graph in a compact binary format suited to caching large graphs
+/// between runs. Unsigned integers are LEB128 varints:
+///
+/// - magic bytes `GBIN`
+/// - format version as little-endian u32 (currently 1)
+/// - flags byte: 1 if weights follow each edge, otherwise 0
+/// - order, then size
+/// - each id, in the order of `graph.ids()`
+/// - for each node, the count of neighbors later in that order, then the
+///   gaps between successive neighbor positions, starting from the node's
+///   own position; in weighted files each gap is followed by the weight as
+///   a little-endian f64
+///
+/// Each edge is stored once, so neighbor order isn't preserved.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, DefaultGraph };
+/// use gamma::io::{ write_binary, read_binary, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ])?;
+///     let mut bytes = Vec::new();
+///
+///     write_binary(&graph, &mut bytes)?;
+///
+///     assert_eq!(bytes.len(), 19);
+///     assert_eq!(read_binary(bytes.as_slice())?, graph);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn write_binary<G: Graph, W: Write>(
+    graph: &G, writer: W
+) -> Result<(), Error> {
+    write(graph, writer, 0, |_, _, _| Ok(()))
+}
+
+/// Writes graph in the format described by `write_binary`, with weights.
+pub fn write_weighted_binary<G, W>(graph: &G, writer: W) -> Result<(), Error>
+where G: WeightedGraph<Weight=f64>, W: Write {
+    write(graph, writer, WEIGHTED, |writer, sid, tid| {
+        match graph.weight(sid, tid)? {
+            Some(weight) => Ok(writer.write_all(&weight.to_le_bytes())?),
+            None => Err(Error::Graph(graph::Error::MissingEdge(sid, tid)))
+        }
+    })
+}
+
+/// Reads a graph written by `write_binary` or `write_weighted_binary`,
+/// ignoring any weights. Returns Error given a different format or
+/// version, or data that is truncated or inconsistent.
+pub fn read_binary<R: Read>(reader: R) -> Result<DefaultGraph, Error> {
+    let mut reader = BufReader::new(reader);
+    let flags = read_header(&mut reader)?;
+    let (order, size) = (read_varint(&mut reader)?, read_varint(&mut reader)?);
+    let mut result = DefaultGraph::with_capacity(
+        order.min(PREALLOCATE), size.min(PREALLOCATE)
+    );
+
+    read_body(&mut reader, flags, order, size, &mut result)?;
+
+    Ok(result)
+}
+
+/// Reads a graph written by `write_weighted_binary`. Returns Error as with
+/// `read_binary`, or if the file holds no weights.
+pub fn read_weighted_binary<R: Read>(
+    reader: R
+) -> Result<DefaultWeightedGraph<f64>, Error> {
+    let mut reader = BufReader::new(reader);
+    let flags = read_header(&mut reader)?;
+
+    if flags & WEIGHTED == 0 {
+        return Err(Error::InvalidFormat);
+    }
+
+    let (order, size) = (read_varint(&mut reader)?, read_varint(&mut reader)?);
+    let mut result = DefaultWeightedGraph::new();
+
+    read_body(&mut reader, flags, order, size, &mut result)?;
+
+    Ok(result)
+}
+
+fn write<G, W, F>(
+    graph: &G, writer: W, flags: u8, mut weight: F
+) -> Result<(), Error>
+where
+    G: Graph,
+    W: Write,
+    F: FnMut(&mut BufWriter<W>, usize, usize) -> Result<(), Error>
+{
+    let mut writer = BufWriter::new(writer);
+    let ids = graph.ids().collect::<Vec<_>>();
+    let positions = ids.iter().enumerate()
+        .map(|(position, &id)| (id, position))
+        .collect::<HashMap<_, _>>();
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&[ flags ])?;
+    write_varint(&mut writer, ids.len())?;
+    write_varint(&mut writer, graph.size())?;
+
+    for &id in &ids {
+        write_varint(&mut writer, id)?;
+    }
+
+    for (position, &id) in ids.iter().enumerate() {
+        let mut later = graph.neighbors(id)?
+            .map(|neighbor| positions[&neighbor])
+            .filter(|&neighbor| neighbor > position)
+            .collect::<Vec<_>>();
+        let mut last = position;
+
+        later.sort_unstable();
+        write_varint(&mut writer, later.len())?;
+
+        for neighbor in later {
+            write_varint(&mut writer, neighbor - last)?;
+            weight(&mut writer, id, ids[neighbor])?;
+
+            last = neighbor;
+        }
+    }
+
+    Ok(writer.flush()?)
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<u8, Error> {
+    let mut header = [ 0; 9 ];
+
+    reader.read_exact(&mut header)?;
+
+    if &header[0..4] != MAGIC || header[4..8] != VERSION.to_le_bytes() {
+        return Err(Error::InvalidFormat);
+    }
+
+    Ok(header[8])
+}
+
+fn read_body<R: Read, E: Entries>(
+    reader: &mut R, flags: u8, order: usize, size: usize, result: &mut E
+) -> Result<(), Error> {
+    let mut ids = Vec::with_capacity(order.min(PREALLOCATE));
+    let mut edges = 0;
+
+    for _ in 0..order {
+        let id = read_varint(reader)?;
+
+        result.add_node(id)?;
+        ids.push(id);
+    }
+
+    for position in 0..order {
+        let mut neighbor = position;
+
+        for _ in 0..read_varint(reader)? {
+            neighbor = neighbor.checked_add(read_varint(reader)?)
+                .filter(|&neighbor| neighbor < order)
+                .ok_or(Error::InvalidFormat)?;
+
+            let weight = if flags & WEIGHTED == 0 {
+                1.0
+            } else {
+                let mut bytes = [ 0; 8 ];
+
+                reader.read_exact(&mut bytes)?;
+
+                f64::from_le_bytes(bytes)
+            };
+
+            result.add_entry(ids[position], ids[neighbor], weight)?;
+            edges += 1;
+        }
+    }
+
+    if edges == size {
+        Ok(())
+    } else {
+        Err(Error::InvalidFormat)
+    }
+}
+
+fn write_varint<W: Write>(writer: &mut W, value: usize) -> Result<(), Error> {
+    let mut value = value as u64;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+
+        value >>= 7;
+
+        if value == 0 {
+            return Ok(writer.write_all(&[ byte ])?);
+        }
+
+        writer.write_all(&[ byte | 0x80 ])?;
+    }
+}
+
+fn read_varint<R: Read>(reader: &mut R) -> Result<usize, Error> {
+    let mut result = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let mut byte = [ 0 ];
+
+        reader.read_exact(&mut byte)?;
+
+        let bits = (byte[0] & 0x7f) as u64;
+
+        if shift == 63 && bits > 1 {
+            return Err(Error::InvalidFormat);
+        }
+
+        result |= bits << shift;
+
+        if byte[0] & 0x80 == 0 {
+            return usize::try_from(result).map_err(|_| Error::InvalidFormat);
+        }
+    }
+
+    Err(Error::InvalidFormat)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::io;
+    use super::*;
+
+    #[test]
+    fn varint() {
+        for &value in &[ 0, 1, 127, 128, 300, 1 << 32, usize::MAX ] {
+            let mut bytes = Vec::new();
+
+            write_varint(&mut bytes, value).unwrap();
+
+            assert_eq!(read_varint(&mut bytes.as_slice()).unwrap(), value)
+        }
+    }
+
+    #[test]
+    fn bad_magic() {
+        let result = read_binary(&b"GCSR\x01\x00\x00\x00\x00\x00\x00"[..]);
+
+        assert!(matches!(result, Err(Error::InvalidFormat)))
+    }
+
+    #[test]
+    fn bad_version() {
+        let result = read_binary(&b"GBIN\x02\x00\x00\x00\x00\x00\x00"[..]);
+
+        assert!(matches!(result, Err(Error::InvalidFormat)))
+    }
+
+    #[test]
+    fn self_loop() {
+        let bytes = b"GBIN\x01\x00\x00\x00\x00\x01\x01\x05\x01\x00";
+        let result = read_binary(&bytes[..]);
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::SelfLoop(5))))
+        )
+    }
+
+    #[test]
+    fn truncated() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_binary(&graph, &mut bytes).unwrap();
+        bytes.pop();
+
+        match read_binary(bytes.as_slice()) {
+            Err(Error::Io(error)) => {
+                assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof)
+            },
+            _ => panic!("expected eof")
+        }
+    }
+
+    #[test]
+    fn size_mismatch() {
+        let result = read_binary(&b"GBIN\x01\x00\x00\x00\x00\x00\x01"[..]);
+
+        assert!(matches!(result, Err(Error::InvalidFormat)))
+    }
+
+    #[test]
+    fn huge_order() {
+        let bytes = b"GBIN\x01\x00\x00\x00\x00\
+            \xff\xff\xff\xff\xff\xff\xff\x7f\x00";
+
+        match read_binary(&bytes[..]) {
+            Err(Error::Io(error)) => {
+                assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof)
+            },
+            _ => panic!("expected eof")
+        }
+    }
+
+    #[test]
+    fn neighbor_out_of_range() {
+        let bytes = b"GBIN\x01\x00\x00\x00\x00\x01\x01\x00\x01\x01";
+        let result = read_binary(&bytes[..]);
+
+        assert!(matches!(result, Err(Error::InvalidFormat)))
+    }
+
+    #[test]
+    fn unweighted_as_weighted() {
+        let mut bytes = Vec::new();
+
+        write_binary(&DefaultGraph::new(), &mut bytes).unwrap();
+
+        let result = read_weighted_binary(bytes.as_slice());
+
+        assert!(matches!(result, Err(Error::InvalidFormat)))
+    }
+
+    #[test]
+    fn sparse_ids() {
+        let graph = DefaultGraph::try_from(vec![
+            (1000, 7), (7, 3), (3, 1000), (42, 7)
+        ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_binary(&graph, &mut bytes).unwrap();
+
+        let copy = read_binary(bytes.as_slice()).unwrap();
+
+        assert_eq!(copy.ids().collect::<Vec<_>>(), [ 1000, 7, 3, 42 ]);
+        assert_eq!(copy, graph)
+    }
+
+    #[test]
+    fn weighted() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 0.5),
+            (2, 1, -3.25)
+        ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_weighted_binary(&graph, &mut bytes).unwrap();
+
+        let copy = read_weighted_binary(bytes.as_slice()).unwrap();
+
+        assert_eq!(copy.weight(1, 0), Ok(Some(&0.5)));
+        assert_eq!(copy.weight(1, 2), Ok(Some(&-3.25)));
+        assert_eq!(read_binary(bytes.as_slice()).unwrap().size(), 2)
+    }
+}
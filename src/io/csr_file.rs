@@ -0,0 +1,103 @@
+use std::io::Write;
+
+use crate::graph::{ Graph, CsrGraph };
+use super::Error;
+
+pub(crate) const MAGIC: &[u8; 4] = b"GCSR";
+pub(crate) const VERSION: u32 = 1;
+
+/// Writes graph in the binary CSR layout read by `MmapGraph`. All values
+/// are little-endian:
+///
+/// - magic bytes `GCSR`
+/// - format version as u32 (currently 1)
+/// - order, size, and neighbor count as u64
+/// - order + 1 neighbor offsets as u64
+/// - neighbor ids as u64
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::CsrGraph;
+/// use gamma::io::{ write_csr, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = CsrGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0 ]
+///     ])?;
+///     let mut bytes = Vec::new();
+///
+///     write_csr(&graph, &mut bytes)?;
+///
+///     assert_eq!(bytes.len(), 32 + 3 * 8 + 2 * 8);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn write_csr<W: Write>(graph: &CsrGraph, mut writer: W) -> Result<(), Error> {
+    let mut offset = 0;
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    writer.write_all(&(graph.order() as u64).to_le_bytes())?;
+    writer.write_all(&(graph.size() as u64).to_le_bytes())?;
+    writer.write_all(&(neighbor_count(graph) as u64).to_le_bytes())?;
+    writer.write_all(&(offset as u64).to_le_bytes())?;
+
+    for id in graph.ids() {
+        offset += graph.degree(id)?;
+
+        writer.write_all(&(offset as u64).to_le_bytes())?;
+    }
+
+    for id in graph.ids() {
+        for &neighbor in graph.neighbor_slice(id)? {
+            writer.write_all(&(neighbor as u64).to_le_bytes())?;
+        }
+    }
+
+    Ok(writer.flush()?)
+}
+
+fn neighbor_count(graph: &CsrGraph) -> usize {
+    graph.ids().map(|id| graph.degree(id).expect("degree")).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn p0() {
+        let graph = CsrGraph::try_from(Vec::<Vec<usize>>::new()).unwrap();
+        let mut bytes = Vec::new();
+
+        write_csr(&graph, &mut bytes).unwrap();
+
+        assert_eq!(&bytes[0..4], b"GCSR");
+        assert_eq!(&bytes[4..8], &[ 1, 0, 0, 0 ]);
+        assert_eq!(bytes.len(), 32 + 8)
+    }
+
+    #[test]
+    fn p2() {
+        let graph = CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_csr(&graph, &mut bytes).unwrap();
+
+        let words = bytes[8..].chunks(8).map(|chunk| {
+            let mut word = [ 0; 8 ];
+
+            word.copy_from_slice(chunk);
+
+            u64::from_le_bytes(word)
+        }).collect::<Vec<_>>();
+
+        assert_eq!(words, [ 2, 1, 2, 0, 1, 2, 1, 0 ])
+    }
+}
@@ -0,0 +1,268 @@
+use std::io::BufRead;
+
+use crate::graph::{ Graph, DefaultGraph, IdMap };
+use super::Error;
+
+/// Selects a CSV column by zero-based position or by header name.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub enum Column {
+    Index(usize),
+    Name(String)
+}
+
+/// Options for `read_csv`. The default reads comma-separated fields with a
+/// header row, taking the source from the first column and the target from
+/// the second.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub header: bool,
+    pub source: Column,
+    pub target: Column
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header: true,
+            source: Column::Index(0),
+            target: Column::Index(1)
+        }
+    }
+}
+
+/// Reads a CSV edge list into a DefaultGraph, returning it with an IdMap
+/// from node names to ids. Names are assigned ids in the order they first
+/// appear. Fields may be quoted with `"`, doubling quotes within. An edge
+/// listed more than once is added once, and blank lines are skipped.
+///
+/// Returns Error if a column is named without a header, a named column is
+/// missing from the header, a line has too few fields, or an edge joins a
+/// node to itself.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::io::{ read_csv, CsvOptions, Column, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let text = "weight;to;from\n1.5;O;C1\n1.0;C2;O\n";
+///     let options = CsvOptions {
+///         delimiter: ';',
+///         source: Column::Name("from".to_string()),
+///         target: Column::Name("to".to_string()),
+///         ..CsvOptions::default()
+///     };
+///     let (graph, map) = read_csv(text.as_bytes(), &options)?;
+///
+///     assert_eq!(map.nodes().collect::<Vec<_>>(), vec![ "C1", "O", "C2" ]);
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_csv<R: BufRead>(
+    reader: R, options: &CsvOptions
+) -> Result<(DefaultGraph, IdMap<String>), Error> {
+    let mut graph = DefaultGraph::new();
+    let mut map = IdMap::new();
+    let mut columns = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields = split(&line, options.delimiter)
+            .ok_or(Error::InvalidLine(number))?;
+        let (source, target) = match columns {
+            Some(columns) => columns,
+            None => {
+                let header = if options.header {
+                    Some(fields.as_slice())
+                } else {
+                    None
+                };
+
+                columns = Some((
+                    position(&options.source, header)?,
+                    position(&options.target, header)?
+                ));
+
+                if options.header {
+                    continue;
+                }
+
+                columns.expect("columns")
+            }
+        };
+        let sid = match fields.get(source) {
+            Some(name) => map.insert(name.clone()),
+            None => return Err(Error::InvalidLine(number))
+        };
+        let tid = match fields.get(target) {
+            Some(name) => map.insert(name.clone()),
+            None => return Err(Error::InvalidLine(number))
+        };
+
+        for &id in &[ sid, tid ] {
+            if !graph.has_id(id) {
+                graph.add_node(id)?;
+            }
+        }
+
+        if !graph.has_edge(sid, tid)? {
+            graph.add_edge(sid, tid)?;
+        }
+    }
+
+    Ok((graph, map))
+}
+
+fn position(
+    column: &Column, header: Option<&[String]>
+) -> Result<usize, Error> {
+    match (column, header) {
+        (Column::Index(index), _) => Ok(*index),
+        (Column::Name(name), Some(header)) => header.iter()
+            .position(|field| field == name)
+            .ok_or(Error::InvalidFormat),
+        (Column::Name(_), None) => Err(Error::InvalidFormat)
+    }
+}
+
+// Splits line into trimmed fields, or returns None given an unclosed quote.
+fn split(line: &str, delimiter: char) -> Option<Vec<String>> {
+    let mut result = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c != '"' {
+                field.push(c);
+            } else if chars.peek() == Some(&'"') {
+                field.push(chars.next().expect("quote"));
+            } else {
+                quoted = false;
+            }
+        } else if c == '"' {
+            quoted = true;
+        } else if c == delimiter {
+            result.push(field.trim().to_string());
+            field.clear();
+        } else {
+            field.push(c);
+        }
+    }
+
+    if quoted {
+        return None;
+    }
+
+    result.push(field.trim().to_string());
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph;
+
+    fn read(
+        text: &str, options: &CsvOptions
+    ) -> Result<(DefaultGraph, IdMap<String>), Error> {
+        read_csv(text.as_bytes(), options)
+    }
+
+    #[test]
+    fn header_only() {
+        let (graph, _) = read("a,b\n", &CsvOptions::default()).unwrap();
+
+        assert!(graph.is_empty())
+    }
+
+    #[test]
+    fn no_header() {
+        let options = CsvOptions { header: false, ..CsvOptions::default() };
+        let (graph, map) = read("a,b\nb,c\nb,a\n", &options).unwrap();
+
+        assert_eq!(map.nodes().collect::<Vec<_>>(), [ "a", "b", "c" ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1), (1, 2) ])
+    }
+
+    #[test]
+    fn name_without_header() {
+        let options = CsvOptions {
+            header: false,
+            source: Column::Name("a".to_string()),
+            ..CsvOptions::default()
+        };
+
+        assert!(
+            matches!(read("a,b\n", &options), Err(Error::InvalidFormat))
+        )
+    }
+
+    #[test]
+    fn missing_name() {
+        let options = CsvOptions {
+            target: Column::Name("to".to_string()),
+            ..CsvOptions::default()
+        };
+
+        assert!(
+            matches!(read("from,b\n", &options), Err(Error::InvalidFormat))
+        )
+    }
+
+    #[test]
+    fn short_line() {
+        let result = read("a,b\nx,y\nz\n", &CsvOptions::default());
+
+        assert!(matches!(result, Err(Error::InvalidLine(3))))
+    }
+
+    #[test]
+    fn self_loop() {
+        let result = read("a,b\nx,x\n", &CsvOptions::default());
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::SelfLoop(0))))
+        )
+    }
+
+    #[test]
+    fn quoted() {
+        let text = "a,b\n\"x, \"\"1\"\"\", y \n";
+        let (_, map) = read(text, &CsvOptions::default()).unwrap();
+
+        assert_eq!(map.nodes().collect::<Vec<_>>(), [ "x, \"1\"", "y" ])
+    }
+
+    #[test]
+    fn unclosed_quote() {
+        let result = read("a,b\n\"x,y\n", &CsvOptions::default());
+
+        assert!(matches!(result, Err(Error::InvalidLine(2))))
+    }
+
+    #[test]
+    fn tabs_and_indices() {
+        let options = CsvOptions {
+            delimiter: '\t',
+            header: false,
+            source: Column::Index(2),
+            target: Column::Index(0)
+        };
+        let (graph, map) = read("x\t9\ty\n", &options).unwrap();
+
+        assert_eq!(map.nodes().collect::<Vec<_>>(), [ "y", "x" ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (0, 1) ])
+    }
+}
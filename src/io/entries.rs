@@ -0,0 +1,43 @@
+use crate::graph::{ self, Graph, DefaultGraph, DefaultWeightedGraph };
+
+/// A graph under construction by a reader.
+pub(crate) trait Entries {
+    fn add_node(&mut self, id: usize) -> Result<(), graph::Error>;
+
+    /// Adds an edge with the given value, unless already present.
+    fn add_entry(
+        &mut self, sid: usize, tid: usize, value: f64
+    ) -> Result<(), graph::Error>;
+}
+
+impl Entries for DefaultGraph {
+    fn add_node(&mut self, id: usize) -> Result<(), graph::Error> {
+        DefaultGraph::add_node(self, id)
+    }
+
+    fn add_entry(
+        &mut self, sid: usize, tid: usize, _: f64
+    ) -> Result<(), graph::Error> {
+        if self.has_edge(sid, tid)? {
+            Ok(())
+        } else {
+            self.add_edge(sid, tid)
+        }
+    }
+}
+
+impl Entries for DefaultWeightedGraph<f64> {
+    fn add_node(&mut self, id: usize) -> Result<(), graph::Error> {
+        DefaultWeightedGraph::add_node(self, id)
+    }
+
+    fn add_entry(
+        &mut self, sid: usize, tid: usize, value: f64
+    ) -> Result<(), graph::Error> {
+        if self.has_edge(sid, tid)? {
+            Ok(())
+        } else {
+            self.add_edge(sid, tid, value)
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+use std::error;
+use std::io;
+
+use crate::graph;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    InvalidLine(usize),
+    InvalidFormat,
+    Graph(graph::Error)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(_) => write!(f, "io error"),
+            Error::InvalidLine(line) => write!(f, "invalid line: {}", line),
+            Error::InvalidFormat => write!(f, "invalid format"),
+            Error::Graph(_) => write!(f, "graph error")
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::InvalidLine(_) | Error::InvalidFormat => None,
+            Error::Graph(error) => Some(error)
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<graph::Error> for Error {
+    fn from(error: graph::Error) -> Self {
+        Error::Graph(error)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn invalid_line() {
+        assert_eq!(Error::InvalidLine(3).to_string(), "invalid line: 3")
+    }
+
+    #[test]
+    fn graph() {
+        assert_eq!(
+            Error::from(graph::Error::UnknownId(1)).to_string(),
+            "graph error"
+        )
+    }
+}
@@ -0,0 +1,310 @@
+//! A minimal JSON reader, enough to pull graph structure out of documents
+//! without a serialization dependency.
+
+use super::Error;
+
+const MAX_DEPTH: usize = 128;
+
+/// A parsed JSON value. Numbers keep their source text so that large
+/// integers survive without rounding.
+#[derive(Debug,Clone,PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>)
+}
+
+impl Value {
+    /// Returns the value under key, if self is an object holding it.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members.iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None
+        }
+    }
+
+    /// Returns self as a usize, if it's a non-negative integer in range.
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Value::Number(text) => text.parse().ok(),
+            _ => None
+        }
+    }
+}
+
+/// Parses text as a single JSON value. Returns `Error::InvalidFormat` if
+/// text isn't valid JSON or nests more than 128 levels deep.
+pub(crate) fn parse(text: &str) -> Result<Value, Error> {
+    let mut parser = Parser { bytes: text.as_bytes(), position: 0 };
+    let result = parser.value(0)?;
+
+    parser.whitespace();
+
+    if parser.position == parser.bytes.len() {
+        Ok(result)
+    } else {
+        Err(Error::InvalidFormat)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Result<u8, Error> {
+        let result = self.peek().ok_or(Error::InvalidFormat)?;
+
+        self.position += 1;
+
+        Ok(result)
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.next()? == byte {
+            Ok(())
+        } else {
+            Err(Error::InvalidFormat)
+        }
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, Error> {
+        for &byte in word.as_bytes() {
+            self.expect(byte)?;
+        }
+
+        Ok(value)
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::InvalidFormat);
+        }
+
+        self.whitespace();
+
+        match self.peek().ok_or(Error::InvalidFormat)? {
+            b'n' => self.literal("null", Value::Null),
+            b't' => self.literal("true", Value::Bool(true)),
+            b'f' => self.literal("false", Value::Bool(false)),
+            b'"' => Ok(Value::String(self.string()?)),
+            b'[' => self.array(depth),
+            b'{' => self.object(depth),
+            b'-' | b'0'..=b'9' => self.number(),
+            _ => Err(Error::InvalidFormat)
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<Value, Error> {
+        let mut result = Vec::new();
+
+        self.expect(b'[')?;
+        self.whitespace();
+
+        if self.peek() == Some(b']') {
+            self.position += 1;
+
+            return Ok(Value::Array(result));
+        }
+
+        loop {
+            result.push(self.value(depth + 1)?);
+            self.whitespace();
+
+            match self.next()? {
+                b',' => (),
+                b']' => return Ok(Value::Array(result)),
+                _ => return Err(Error::InvalidFormat)
+            }
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Result<Value, Error> {
+        let mut result = Vec::new();
+
+        self.expect(b'{')?;
+        self.whitespace();
+
+        if self.peek() == Some(b'}') {
+            self.position += 1;
+
+            return Ok(Value::Object(result));
+        }
+
+        loop {
+            self.whitespace();
+
+            let key = self.string()?;
+
+            self.whitespace();
+            self.expect(b':')?;
+            result.push((key, self.value(depth + 1)?));
+            self.whitespace();
+
+            match self.next()? {
+                b',' => (),
+                b'}' => return Ok(Value::Object(result)),
+                _ => return Err(Error::InvalidFormat)
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, Error> {
+        let start = self.position;
+
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') =
+            self.peek() {
+            self.position += 1;
+        }
+
+        let text = std::str::from_utf8(&self.bytes[start..self.position])
+            .map_err(|_| Error::InvalidFormat)?;
+
+        match text.parse::<f64>() {
+            Ok(_) => Ok(Value::Number(text.to_string())),
+            Err(_) => Err(Error::InvalidFormat)
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let mut result = Vec::new();
+
+        self.expect(b'"')?;
+
+        loop {
+            match self.next()? {
+                b'"' => break,
+                b'\\' => match self.next()? {
+                    b'"' => result.push(b'"'),
+                    b'\\' => result.push(b'\\'),
+                    b'/' => result.push(b'/'),
+                    b'b' => result.push(8),
+                    b'f' => result.push(12),
+                    b'n' => result.push(b'\n'),
+                    b'r' => result.push(b'\r'),
+                    b't' => result.push(b'\t'),
+                    b'u' => {
+                        let c = self.escape()?;
+                        let mut buffer = [ 0; 4 ];
+
+                        result.extend(c.encode_utf8(&mut buffer).as_bytes());
+                    },
+                    _ => return Err(Error::InvalidFormat)
+                },
+                byte if byte < 0x20 => return Err(Error::InvalidFormat),
+                byte => result.push(byte)
+            }
+        }
+
+        String::from_utf8(result).map_err(|_| Error::InvalidFormat)
+    }
+
+    // Reads the hex digits of a \u escape, combining surrogate pairs.
+    fn escape(&mut self) -> Result<char, Error> {
+        let high = self.hex()?;
+
+        let code = if (0xd800..0xdc00).contains(&high) {
+            self.expect(b'\\')?;
+            self.expect(b'u')?;
+
+            let low = self.hex()?;
+
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(Error::InvalidFormat);
+            }
+
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+
+        std::char::from_u32(code).ok_or(Error::InvalidFormat)
+    }
+
+    fn hex(&mut self) -> Result<u32, Error> {
+        let mut result = 0;
+
+        for _ in 0..4 {
+            let digit = (self.next()? as char).to_digit(16)
+                .ok_or(Error::InvalidFormat)?;
+
+            result = result * 16 + digit;
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid(text: &str) -> bool {
+        matches!(parse(text), Err(Error::InvalidFormat))
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(parse(" null ").unwrap(), Value::Null);
+        assert_eq!(parse("true").unwrap(), Value::Bool(true));
+        assert_eq!(parse("-1.5e3").unwrap(), Value::Number("-1.5e3".into()));
+        assert_eq!(parse("4294967295").unwrap().as_usize(), Some(4294967295))
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            parse(r#""a\"\\\n\u00e9\ud83d\ude00""#).unwrap(),
+            Value::String("a\"\\\n\u{e9}\u{1f600}".into())
+        )
+    }
+
+    #[test]
+    fn nested() {
+        let value = parse(r#"{ "a": [ 1, { "b": [] } ], "c": {} }"#).unwrap();
+
+        assert_eq!(value.get("a").unwrap().as_array().unwrap().len(), 2);
+        assert_eq!(value.get("c"), Some(&Value::Object(vec![ ])))
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(invalid(""));
+        assert!(invalid("[1,]"));
+        assert!(invalid("{\"a\" 1}"));
+        assert!(invalid("\"open"));
+        assert!(invalid("1 2"));
+        assert!(invalid("nul"));
+        assert!(invalid("\"\\ud800\""))
+    }
+
+    #[test]
+    fn too_deep() {
+        let text = "[".repeat(MAX_DEPTH + 2) + &"]".repeat(MAX_DEPTH + 2);
+
+        assert!(invalid(&text))
+    }
+}
@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{ BufRead, Write };
+
+use crate::graph::{
+    self, Graph, WeightedGraph, DefaultGraph, DefaultWeightedGraph
+};
+use super::{ Error, MAX_DECLARED_ORDER };
+use super::entries::Entries;
+
+/// Reads a Matrix Market coordinate file as the adjacency matrix of a
+/// DefaultGraph. Row and column i map to id i - 1, so ids run from zero to
+/// one less than the number of rows. Values are ignored.
+///
+/// Symmetric matrices list each edge once, while general matrices may list
+/// it twice; either way it's added once. Returns Error if the matrix isn't
+/// square, isn't in coordinate format, holds complex values, has a
+/// diagonal entry, has fewer entries than declared, or declares more than
+/// `MAX_DECLARED_ORDER` rows. Diagonal entries are self-loops, which every
+/// reader in this module rejects.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::io::{ read_matrix_market, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let text = "%%MatrixMarket matrix coordinate pattern symmetric\n\
+///         % propane\n3 3 2\n2 1\n3 2\n";
+///     let graph = read_matrix_market(text.as_bytes())?;
+///
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (1, 0), (2, 1) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_matrix_market<R: BufRead>(
+    reader: R
+) -> Result<DefaultGraph, Error> {
+    let mut result = DefaultGraph::new();
+
+    read(reader, &mut result)?;
+
+    Ok(result)
+}
+
+/// Reads a Matrix Market coordinate file as with `read_matrix_market`,
+/// keeping values as edge weights. Pattern matrices give each edge a weight
+/// of one. If an edge is listed twice, the first weight is kept.
+///
+/// ```rust
+/// use gamma::graph::WeightedGraph;
+/// use gamma::io::{ read_weighted_matrix_market, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let text = "%%MatrixMarket matrix coordinate real general\n\
+///         2 2 2\n1 2 0.5\n2 1 0.5\n";
+///     let graph = read_weighted_matrix_market(text.as_bytes())?;
+///
+///     assert_eq!(graph.weight(0, 1), Ok(Some(&0.5)));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_weighted_matrix_market<R: BufRead>(
+    reader: R
+) -> Result<DefaultWeightedGraph<f64>, Error> {
+    let mut result = DefaultWeightedGraph::new();
+
+    read(reader, &mut result)?;
+
+    Ok(result)
+}
+
+/// Writes graph as a symmetric pattern matrix in Matrix Market coordinate
+/// format. The node at position i of `graph.ids()` becomes row and column
+/// i + 1, and each edge is written once, in the lower triangle.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::DefaultGraph;
+/// use gamma::io::{ write_matrix_market, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1) ])?;
+///     let mut bytes = Vec::new();
+///
+///     write_matrix_market(&graph, &mut bytes)?;
+///
+///     assert_eq!(String::from_utf8(bytes).unwrap(), "\
+///         %%MatrixMarket matrix coordinate pattern symmetric\n\
+///         2 2 1\n\
+///         2 1\n"
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+pub fn write_matrix_market<G: Graph, W: Write>(
+    graph: &G, writer: W
+) -> Result<(), Error> {
+    write(graph, writer, "pattern", |_, _, _| Ok(()))
+}
+
+/// Writes graph as a symmetric real matrix in Matrix Market coordinate
+/// format, as with `write_matrix_market`, with each weight following its
+/// entry. Returns Error if an edge has no weight.
+pub fn write_weighted_matrix_market<G, W>(
+    graph: &G, writer: W
+) -> Result<(), Error>
+where G: WeightedGraph, G::Weight: Display, W: Write {
+    write(graph, writer, "real", |writer, sid, tid| {
+        match graph.weight(sid, tid)? {
+            Some(weight) => Ok(write!(writer, " {}", weight)?),
+            None => Err(Error::Graph(graph::Error::MissingEdge(sid, tid)))
+        }
+    })
+}
+
+fn read<R: BufRead, E: Entries>(
+    reader: R, result: &mut E
+) -> Result<(), Error> {
+    let mut lines = reader.lines().enumerate();
+    let pattern = match lines.next() {
+        Some((_, line)) => parse_banner(&line?)?,
+        None => return Err(Error::InvalidFormat)
+    };
+    let mut size = None;
+
+    for (index, line) in lines {
+        let line = line?;
+        let number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let (order, count) = match size {
+            Some(size) => size,
+            None => {
+                let (order, count) = parse_size(&fields, number)?;
+
+                for id in 0..order {
+                    result.add_node(id)?;
+                }
+
+                size = Some((order, count));
+
+                continue;
+            }
+        };
+
+        if count == 0 {
+            return Err(Error::InvalidLine(number));
+        }
+
+        size = Some((order, count - 1));
+
+        let sid = parse_index(fields.first(), order, number)?;
+        let tid = parse_index(fields.get(1), order, number)?;
+        let value = if pattern {
+            1.0
+        } else {
+            match fields.get(2).map(|field| field.parse::<f64>()) {
+                Some(Ok(value)) => value,
+                _ => return Err(Error::InvalidLine(number))
+            }
+        };
+
+        result.add_entry(sid, tid, value)?;
+    }
+
+    match size {
+        Some((_, 0)) => Ok(()),
+        _ => Err(Error::InvalidFormat)
+    }
+}
+
+// Returns true for a pattern matrix.
+fn parse_banner(line: &str) -> Result<bool, Error> {
+    let fields = line.split_whitespace()
+        .map(|field| field.to_lowercase())
+        .collect::<Vec<_>>();
+
+    if fields.len() != 5 ||
+        fields[0] != "%%matrixmarket" ||
+        fields[1] != "matrix" ||
+        fields[2] != "coordinate" {
+        return Err(Error::InvalidFormat);
+    }
+
+    let pattern = match fields[3].as_str() {
+        "pattern" => true,
+        "real" | "integer" => false,
+        _ => return Err(Error::InvalidFormat)
+    };
+
+    match fields[4].as_str() {
+        "general" | "symmetric" | "skew-symmetric" => Ok(pattern),
+        _ => Err(Error::InvalidFormat)
+    }
+}
+
+fn parse_size(fields: &[&str], number: usize) -> Result<(usize, usize), Error> {
+    let values = fields.iter()
+        .map(|field| field.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| Error::InvalidLine(number))?;
+
+    match *values.as_slice() {
+        [ rows, _, _ ] if rows > MAX_DECLARED_ORDER => {
+            Err(Error::InvalidLine(number))
+        },
+        [ rows, columns, count ] if rows == columns => Ok((rows, count)),
+        [ _, _, _ ] => Err(Error::InvalidFormat),
+        _ => Err(Error::InvalidLine(number))
+    }
+}
+
+fn parse_index(
+    field: Option<&&str>, order: usize, number: usize
+) -> Result<usize, Error> {
+    match field.map(|field| field.parse::<usize>()) {
+        Some(Ok(index)) if index >= 1 && index <= order => Ok(index - 1),
+        _ => Err(Error::InvalidLine(number))
+    }
+}
+
+fn write<G, W, F>(
+    graph: &G, mut writer: W, field: &str, mut value: F
+) -> Result<(), Error>
+where
+    G: Graph,
+    W: Write,
+    F: FnMut(&mut W, usize, usize) -> Result<(), Error>
+{
+    let positions = graph.ids().enumerate()
+        .map(|(position, id)| (id, position + 1))
+        .collect::<HashMap<_, _>>();
+    let order = graph.order();
+
+    writeln!(writer, "%%MatrixMarket matrix coordinate {} symmetric", field)?;
+    writeln!(writer, "{} {} {}", order, order, graph.size())?;
+
+    for (sid, tid) in graph.edges() {
+        let (s, t) = (positions[&sid], positions[&tid]);
+
+        write!(writer, "{} {}", s.max(t), s.min(t))?;
+        value(&mut writer, sid, tid)?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+
+    fn invalid_format(text: &str) -> bool {
+        matches!(read_matrix_market(text.as_bytes()), Err(Error::InvalidFormat))
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(invalid_format(""))
+    }
+
+    #[test]
+    fn array_format() {
+        let text = "%%MatrixMarket matrix array real general\n\
+            2 2\n0\n1\n1\n0\n";
+
+        assert!(invalid_format(text))
+    }
+
+    #[test]
+    fn complex() {
+        let text = "%%MatrixMarket matrix coordinate complex general\n1 1 0\n";
+
+        assert!(invalid_format(text))
+    }
+
+    #[test]
+    fn too_many_rows() {
+        let text = "%%MatrixMarket matrix coordinate pattern general\n\
+            99999999999 99999999999 0\n";
+
+        assert!(
+            matches!(read_matrix_market(text.as_bytes()), Err(Error::InvalidLine(2)))
+        )
+    }
+
+    #[test]
+    fn not_square() {
+        let text = "%%MatrixMarket matrix coordinate pattern general\n2 3 0\n";
+
+        assert!(invalid_format(text))
+    }
+
+    #[test]
+    fn missing_entries() {
+        let text = "%%MatrixMarket matrix coordinate pattern general\n\
+            2 2 2\n1 2\n";
+
+        assert!(invalid_format(text))
+    }
+
+    #[test]
+    fn index_out_of_range() {
+        let text = "%%MatrixMarket matrix coordinate pattern general\n\
+            2 2 1\n1 3\n";
+
+        let result = read_matrix_market(text.as_bytes());
+
+        assert!(matches!(result, Err(Error::InvalidLine(3))))
+    }
+
+    #[test]
+    fn diagonal() {
+        let text = "%%MatrixMarket matrix coordinate integer symmetric\n\
+            3 3 2\n2 1 1\n3 3 4\n";
+        let result = read_matrix_market(text.as_bytes());
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::SelfLoop(2))))
+        )
+    }
+
+    #[test]
+    fn weighted_diagonal() {
+        let text = "%%MatrixMarket matrix coordinate real general\n\
+            2 2 1\n1 1 0.5\n";
+        let result = read_weighted_matrix_market(text.as_bytes());
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::SelfLoop(0))))
+        )
+    }
+
+    #[test]
+    fn weighted_pattern() {
+        let text = "%%MatrixMarket matrix coordinate pattern symmetric\n\
+            2 2 1\n2 1\n";
+        let graph = read_weighted_matrix_market(text.as_bytes()).unwrap();
+
+        assert_eq!(graph.weight(1, 0), Ok(Some(&1.0)))
+    }
+
+    #[test]
+    fn weighted_missing_value() {
+        let text = "%%MatrixMarket matrix coordinate real symmetric\n\
+            2 2 1\n2 1\n";
+        let result = read_weighted_matrix_market(text.as_bytes());
+
+        assert!(matches!(result, Err(Error::InvalidLine(3))))
+    }
+
+    #[test]
+    fn round_trip() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 3 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 0 ],
+            vec![ ]
+        ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_matrix_market(&graph, &mut bytes).unwrap();
+
+        let copy = read_matrix_market(bytes.as_slice()).unwrap();
+
+        assert_eq!(copy.order(), 5);
+        assert_eq!(copy.size(), 4);
+
+        for (sid, tid) in graph.edges() {
+            assert_eq!(copy.has_edge(sid, tid), Ok(true))
+        }
+    }
+
+    #[test]
+    fn weighted_round_trip() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 2.5),
+            (1, 2, -1.0)
+        ]).unwrap();
+        let mut bytes = Vec::new();
+
+        write_weighted_matrix_market(&graph, &mut bytes).unwrap();
+
+        let copy = read_weighted_matrix_market(bytes.as_slice()).unwrap();
+
+        assert_eq!(copy.weight(0, 1), Ok(Some(&2.5)));
+        assert_eq!(copy.weight(2, 1), Ok(Some(&-1.0)))
+    }
+}
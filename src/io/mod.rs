@@ -0,0 +1,43 @@
+//! Readers and writers for graph file formats.
+//!
+//! Graphs in this crate have no self-loops, so every reader applies the
+//! same policy to an edge joining a node to itself: it returns
+//! `Error::Graph(graph::Error::SelfLoop(id))` rather than skipping the
+//! edge, whether the format calls it a loop, a repeated endpoint, or a
+//! diagonal entry.
+
+mod error;
+mod stream;
+mod adjacency;
+mod pajek;
+mod csv;
+mod matrix_market;
+mod entries;
+mod binary;
+mod json;
+mod node_link;
+pub(crate) mod csr_file;
+
+pub use error::Error;
+
+/// The largest number of nodes a Pajek `*Vertices` line or a Matrix Market
+/// size line may declare. Both formats create every declared node before
+/// reading any edges, so a short file could otherwise demand an unbounded
+/// allocation. Graphs beyond this order can be read as edge lists with
+/// `read_csr` or in the binary format, whose nodes are all listed.
+pub const MAX_DECLARED_ORDER: usize = 1 << 20;
+pub use stream::{ stream_edges, StreamEdges, read_csr };
+pub use csr_file::write_csr;
+pub use adjacency::{ parse_adjacency, write_adjacency };
+pub use pajek::read_pajek;
+pub use csv::{ read_csv, CsvOptions, Column };
+pub use matrix_market::{
+    read_matrix_market,
+    read_weighted_matrix_market,
+    write_matrix_market,
+    write_weighted_matrix_market
+};
+pub use binary::{
+    read_binary, read_weighted_binary, write_binary, write_weighted_binary
+};
+pub use node_link::{ to_node_link_json, from_node_link_json };
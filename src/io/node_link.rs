@@ -0,0 +1,224 @@
+use std::fmt::Write;
+
+use crate::graph::{ Graph, DefaultGraph };
+use super::Error;
+use super::json::{ self, Value };
+
+/// Returns graph as node-link JSON, the format used by d3 force layouts and
+/// networkx's `node_link_data`. Links refer to nodes by id.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::DefaultGraph;
+/// use gamma::io::{ to_node_link_json, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1) ])?;
+///
+///     assert_eq!(to_node_link_json(&graph), concat!(
+///         r#"{"directed":false,"multigraph":false,"graph":{},"#,
+///         r#""nodes":[{"id":0},{"id":1}],"#,
+///         r#""links":[{"source":0,"target":1}]}"#
+///     ));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn to_node_link_json<G: Graph>(graph: &G) -> String {
+    let mut result = String::new();
+
+    result.push_str(r#"{"directed":false,"multigraph":false,"graph":{},"#);
+    result.push_str(r#""nodes":["#);
+
+    for (i, id) in graph.ids().enumerate() {
+        if i > 0 {
+            result.push(',');
+        }
+
+        write!(result, r#"{{"id":{}}}"#, id).expect("write");
+    }
+
+    result.push_str(r#"],"links":["#);
+
+    for (i, (sid, tid)) in graph.edges().enumerate() {
+        if i > 0 {
+            result.push(',');
+        }
+
+        write!(result, r#"{{"source":{},"target":{}}}"#, sid, tid)
+            .expect("write");
+    }
+
+    result.push_str("]}");
+
+    result
+}
+
+/// Reads node-link JSON into a DefaultGraph. Node ids must be non-negative
+/// integers. Links may be listed under `links` or `edges`, and may refer to
+/// nodes by id or by object with an `id`. Other attributes are ignored, a
+/// link repeated in either direction is added once, and directed graphs
+/// are read as undirected.
+///
+/// Returns Error given malformed JSON, a missing `nodes` list, a
+/// non-integer id, or a link to an unknown node.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::io::{ from_node_link_json, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = from_node_link_json(r#"{
+///         "nodes": [ { "id": 0, "element": "C" }, { "id": 1 } ],
+///         "links": [ { "source": 0, "target": 1, "order": 2 } ]
+///     }"#)?;
+///
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn from_node_link_json(text: &str) -> Result<DefaultGraph, Error> {
+    let document = json::parse(text)?;
+    let nodes = document.get("nodes")
+        .and_then(Value::as_array)
+        .ok_or(Error::InvalidFormat)?;
+    let links = match document.get("links").or_else(|| document.get("edges")) {
+        Some(links) => links.as_array().ok_or(Error::InvalidFormat)?,
+        None => &[ ]
+    };
+    let mut result = DefaultGraph::with_capacity(nodes.len(), links.len());
+
+    for node in nodes {
+        result.add_node(node_id(node)?)?;
+    }
+
+    for link in links {
+        let sid = link.get("source").ok_or(Error::InvalidFormat)?;
+        let tid = link.get("target").ok_or(Error::InvalidFormat)?;
+        let (sid, tid) = (link_end(sid)?, link_end(tid)?);
+
+        if !result.has_edge(sid, tid)? {
+            result.add_edge(sid, tid)?;
+        }
+    }
+
+    Ok(result)
+}
+
+fn node_id(node: &Value) -> Result<usize, Error> {
+    node.get("id")
+        .and_then(Value::as_usize)
+        .ok_or(Error::InvalidFormat)
+}
+
+// d3 replaces link ends with node objects once a simulation has run.
+fn link_end(end: &Value) -> Result<usize, Error> {
+    match end {
+        Value::Object(_) => node_id(end),
+        _ => end.as_usize().ok_or(Error::InvalidFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph;
+
+    fn invalid(text: &str) -> bool {
+        matches!(from_node_link_json(text), Err(Error::InvalidFormat))
+    }
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        let text = to_node_link_json(&graph);
+
+        assert_eq!(from_node_link_json(&text).unwrap(), graph)
+    }
+
+    #[test]
+    fn missing_nodes() {
+        assert!(invalid(r#"{ "links": [] }"#))
+    }
+
+    #[test]
+    fn string_id() {
+        assert!(invalid(r#"{ "nodes": [ { "id": "a" } ] }"#))
+    }
+
+    #[test]
+    fn self_loop() {
+        let text = r#"{ "nodes": [ { "id": 3 } ], "links": [
+            { "source": 3, "target": 3 }
+        ] }"#;
+
+        assert!(
+            matches!(
+                from_node_link_json(text),
+                Err(Error::Graph(graph::Error::SelfLoop(3)))
+            )
+        )
+    }
+
+    #[test]
+    fn negative_id() {
+        assert!(invalid(r#"{ "nodes": [ { "id": -1 } ] }"#))
+    }
+
+    #[test]
+    fn unknown_target() {
+        let result = from_node_link_json(r#"{
+            "nodes": [ { "id": 0 } ],
+            "links": [ { "source": 0, "target": 1 } ]
+        }"#);
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::UnknownId(1))))
+        )
+    }
+
+    #[test]
+    fn edges_key_and_directed_pair() {
+        let graph = from_node_link_json(r#"{
+            "directed": true,
+            "nodes": [ { "id": 5 }, { "id": 3 } ],
+            "edges": [
+                { "source": 5, "target": 3 },
+                { "source": 3, "target": 5 }
+            ]
+        }"#).unwrap();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), [ 5, 3 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (5, 3) ])
+    }
+
+    #[test]
+    fn object_ends() {
+        let graph = from_node_link_json(r#"{
+            "nodes": [ { "id": 0 }, { "id": 1 } ],
+            "links": [
+                { "source": { "id": 0, "x": 1.5 }, "target": { "id": 1 } }
+            ]
+        }"#).unwrap();
+
+        assert_eq!(graph.size(), 1)
+    }
+
+    #[test]
+    fn round_trip() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 3 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 0 ],
+            vec![ ]
+        ]).unwrap();
+
+        let text = to_node_link_json(&graph);
+
+        assert_eq!(from_node_link_json(&text).unwrap(), graph)
+    }
+}
@@ -0,0 +1,260 @@
+use std::io::BufRead;
+
+use crate::graph::{ self, Graph, DefaultGraph, IdMap };
+use super::{ Error, MAX_DECLARED_ORDER };
+
+/// Reads a Pajek network (`.net`) into a DefaultGraph, returning it with an
+/// IdMap from vertex labels to ids. Vertices without a label are named by
+/// their number. Ids are assigned in vertex order starting from zero.
+///
+/// `*Edges` and `*Arcs` sections list one edge per line, while `*Edgeslist`
+/// and `*Arcslist` list a vertex followed by its neighbors. Arcs are read
+/// as undirected edges, and an edge listed more than once is added once.
+/// Weights and layout columns are ignored, as are lines beginning with `%`.
+/// Returns Error given a `*Matrix` section, an unknown vertex number, a
+/// repeated label, or a vertex count above `MAX_DECLARED_ORDER`.
+///
+/// ```rust
+/// use gamma::graph::Graph;
+/// use gamma::io::{ read_pajek, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let text = "*Vertices 3\n1 \"C1\"\n2 \"O\"\n3 \"C2\"\n\
+///         *Edges\n1 2\n2 3\n";
+///     let (graph, map) = read_pajek(text.as_bytes())?;
+///
+///     assert_eq!(map.id_of(&"O".to_string()), Some(1));
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_pajek<R: BufRead>(
+    reader: R
+) -> Result<(DefaultGraph, IdMap<String>), Error> {
+    let mut labels = Vec::<String>::new();
+    let mut section = Section::None;
+    let mut result = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+
+        if line.starts_with('*') {
+            let mut fields = line.split_whitespace();
+            let keyword = fields.next().expect("keyword").to_lowercase();
+
+            section = match keyword.as_str() {
+                "*vertices" => {
+                    let count = fields.next()
+                        .and_then(|field| field.parse::<usize>().ok())
+                        .filter(|&count| count <= MAX_DECLARED_ORDER)
+                        .ok_or(Error::InvalidLine(number))?;
+
+                    labels = (1..=count).map(|n| n.to_string()).collect();
+                    result = None;
+
+                    Section::Vertices
+                },
+                "*edges" | "*arcs" => Section::Edges,
+                "*edgeslist" | "*arcslist" => Section::Lists,
+                "*matrix" => return Err(Error::InvalidFormat),
+                _ => Section::None
+            };
+
+            continue;
+        }
+
+        match section {
+            Section::None => (),
+            Section::Vertices => {
+                let (vertex, rest) = split_vertex(line, number)?;
+                let label = parse_label(rest);
+
+                match labels.get_mut(vertex.wrapping_sub(1)) {
+                    Some(slot) => if let Some(label) = label {
+                        *slot = label
+                    },
+                    None => return Err(Error::InvalidLine(number))
+                }
+            },
+            Section::Edges | Section::Lists => {
+                if result.is_none() {
+                    result = Some(build_nodes(&labels)?);
+                }
+
+                let (graph, _) = result.as_mut().expect("result");
+                let limit = match section {
+                    Section::Edges => 2,
+                    _ => usize::MAX
+                };
+                let ids = line.split_whitespace()
+                    .take(limit)
+                    .map(|field| parse_vertex(field, labels.len(), number))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if ids.len() < 2 && section == Section::Edges {
+                    return Err(Error::InvalidLine(number));
+                }
+
+                for &tid in ids.iter().skip(1) {
+                    if !graph.has_edge(ids[0], tid)? {
+                        graph.add_edge(ids[0], tid)?;
+                    }
+                }
+            }
+        }
+    }
+
+    match result {
+        Some(result) => Ok(result),
+        None => build_nodes(&labels)
+    }
+}
+
+#[derive(Clone,Copy,PartialEq)]
+enum Section {
+    None,
+    Vertices,
+    Edges,
+    Lists
+}
+
+fn build_nodes(
+    labels: &[String]
+) -> Result<(DefaultGraph, IdMap<String>), Error> {
+    let mut graph = DefaultGraph::new();
+    let mut map = IdMap::new();
+
+    for label in labels {
+        if let Some(id) = map.id_of(label) {
+            return Err(Error::Graph(graph::Error::DuplicateId(id)));
+        }
+
+        graph.add_node(map.insert(label.clone()))?;
+    }
+
+    Ok((graph, map))
+}
+
+fn split_vertex(line: &str, number: usize) -> Result<(usize, &str), Error> {
+    let end = line.find(char::is_whitespace).unwrap_or(line.len());
+    let vertex = line[..end].parse().map_err(|_| Error::InvalidLine(number))?;
+
+    Ok((vertex, line[end..].trim_start()))
+}
+
+// Pajek numbers vertices from one.
+fn parse_vertex(
+    field: &str, count: usize, number: usize
+) -> Result<usize, Error> {
+    match field.parse::<usize>() {
+        Ok(vertex) if vertex >= 1 && vertex <= count => Ok(vertex - 1),
+        _ => Err(Error::InvalidLine(number))
+    }
+}
+
+fn parse_label(rest: &str) -> Option<String> {
+    if let Some(quoted) = rest.strip_prefix('"') {
+        quoted.find('"').map(|end| quoted[..end].to_string())
+    } else {
+        rest.split_whitespace().next().map(|label| label.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(text: &str) -> Result<(DefaultGraph, IdMap<String>), Error> {
+        read_pajek(text.as_bytes())
+    }
+
+    #[test]
+    fn empty() {
+        let (graph, map) = read("").unwrap();
+
+        assert!(graph.is_empty());
+        assert!(map.is_empty())
+    }
+
+    #[test]
+    fn unlabeled() {
+        let (graph, map) = read("*Vertices 2\n*Edges\n2 1 0.5\n").unwrap();
+
+        assert_eq!(map.nodes().collect::<Vec<_>>(), [ "1", "2" ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [ (1, 0) ])
+    }
+
+    #[test]
+    fn isolated_vertices() {
+        let (graph, _) = read("*Vertices 3\n").unwrap();
+
+        assert_eq!(graph.order(), 3)
+    }
+
+    #[test]
+    fn too_many_vertices() {
+        let result = read("*Vertices 99999999999\n");
+
+        assert!(matches!(result, Err(Error::InvalidLine(1))))
+    }
+
+    #[test]
+    fn unknown_vertex() {
+        let result = read("*Vertices 2\n*Edges\n1 3\n");
+
+        assert!(matches!(result, Err(Error::InvalidLine(3))))
+    }
+
+    #[test]
+    fn self_loop() {
+        let result = read("*Vertices 2\n*Edges\n2 2\n");
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::SelfLoop(1))))
+        )
+    }
+
+    #[test]
+    fn duplicate_label() {
+        let result = read("*Vertices 2\n1 a\n2 a\n");
+
+        assert!(
+            matches!(result, Err(Error::Graph(graph::Error::DuplicateId(0))))
+        )
+    }
+
+    #[test]
+    fn matrix() {
+        let result = read("*Vertices 2\n*Matrix\n0 1\n1 0\n");
+
+        assert!(matches!(result, Err(Error::InvalidFormat)))
+    }
+
+    #[test]
+    fn arcs_both_ways_with_comments() {
+        let text = "% ethane\n*Network x\n*vertices 2\n1 \"C a\" 0.1 0.2\n\
+            2 C2\n*Arcs\n1 2\n2 1\n";
+        let (graph, map) = read(text).unwrap();
+
+        assert_eq!(map.node_of(0), Some(&"C a".to_string()));
+        assert_eq!(map.node_of(1), Some(&"C2".to_string()));
+        assert_eq!(graph.size(), 1)
+    }
+
+    #[test]
+    fn edges_list() {
+        let text = "*Vertices 4\n*Edgeslist\n1 2 3 4\n2 3\n";
+        let (graph, _) = read(text).unwrap();
+
+        assert_eq!(graph.edges().collect::<Vec<_>>(), [
+            (0, 1), (0, 2), (0, 3), (1, 2)
+        ])
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::io::{ BufRead, BufReader, Read, Seek, SeekFrom };
+
+use crate::graph::{ self, CsrGraph, CsrBuilder };
+use super::Error;
+
+/// Returns an Iterator over the edges of an edge list, read one line at a
+/// time. Each line holds a source and target id separated by whitespace or
+/// a comma. Further columns, such as weights, are ignored. Blank lines and
+/// lines beginning with `#` or `%` are skipped.
+///
+/// ```rust
+/// use gamma::io::{ stream_edges, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let text = "# p3\n0 1\n1,2\n";
+///     let edges = stream_edges(text.as_bytes())
+///         .collect::<Result<Vec<_>, _>>()?;
+///
+///     assert_eq!(edges, vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn stream_edges<R: BufRead>(reader: R) -> StreamEdges<R> {
+    StreamEdges {
+        reader,
+        line: String::new(),
+        number: 0
+    }
+}
+
+pub struct StreamEdges<R> {
+    reader: R,
+    line: String,
+    number: usize
+}
+
+impl<R: BufRead> Iterator for StreamEdges<R> {
+    type Item = Result<(usize, usize), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            self.number += 1;
+
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => (),
+                Err(error) => return Some(Err(Error::Io(error)))
+            }
+
+            let line = self.line.trim();
+
+            if line.is_empty() || line.starts_with('#') || line.starts_with('%') {
+                continue;
+            }
+
+            return Some(parse_edge(line, self.number));
+        }
+    }
+}
+
+/// Reads an edge list (see `stream_edges`) into a CsrGraph in two passes.
+/// The first counts degrees and the second fills in neighbors, so memory
+/// use is bounded by the number of distinct ids and edges, whatever their
+/// values. Ids are relabeled densely: the distinct ids of the list, in
+/// ascending order, become 0 through one less than their count. A list
+/// whose ids already run from zero without gaps keeps its ids.
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use gamma::graph::Graph;
+/// use gamma::io::{ read_csr, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = read_csr(Cursor::new("0 1\n2 1\n10 2\n"))?;
+///
+///     assert_eq!(graph.edges().collect::<Vec<_>>(), vec![
+///         (0, 1), (1, 2), (2, 3)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_csr<R: Read + Seek>(mut reader: R) -> Result<CsrGraph, Error> {
+    let mut degrees = HashMap::new();
+
+    for edge in stream_edges(BufReader::new(&mut reader)) {
+        let (sid, tid) = edge?;
+
+        *degrees.entry(sid).or_insert(0) += 1;
+        *degrees.entry(tid).or_insert(0) += 1;
+    }
+
+    let mut ids = degrees.keys().cloned().collect::<Vec<_>>();
+
+    ids.sort_unstable();
+
+    let indices = ids.iter().enumerate()
+        .map(|(index, &id)| (id, index))
+        .collect::<HashMap<_,_>>();
+    let mut builder = CsrBuilder::new(
+        ids.iter().map(|id| degrees[id]).collect()
+    );
+
+    reader.seek(SeekFrom::Start(0))?;
+
+    for edge in stream_edges(BufReader::new(&mut reader)) {
+        let (sid, tid) = edge?;
+
+        match (indices.get(&sid), indices.get(&tid)) {
+            (Some(&source), Some(&target)) => {
+                builder.add_edge(source, target).map_err(|error| {
+                    relabel(error, &ids)
+                })?;
+            },
+            // the reader changed between passes
+            _ => return Err(Error::InvalidFormat)
+        }
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Returns error with dense indices replaced by the ids they stand for.
+fn relabel(error: graph::Error, ids: &[usize]) -> Error {
+    let id = |index: usize| ids.get(index).cloned().unwrap_or(index);
+
+    Error::Graph(match error {
+        graph::Error::SelfLoop(index) => graph::Error::SelfLoop(id(index)),
+        graph::Error::DuplicateEdge(source, target) => {
+            graph::Error::DuplicateEdge(id(source), id(target))
+        },
+        graph::Error::DegreeMismatch(index) => {
+            graph::Error::DegreeMismatch(id(index))
+        },
+        error => error
+    })
+}
+
+fn parse_edge(line: &str, number: usize) -> Result<(usize, usize), Error> {
+    let mut fields = line.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|field| !field.is_empty());
+    let mut id = || -> Result<usize, Error> {
+        match fields.next() {
+            Some(field) => field.parse().map_err(|_| Error::InvalidLine(number)),
+            None => Err(Error::InvalidLine(number))
+        }
+    };
+
+    Ok((id()?, id()?))
+}
+
+#[cfg(test)]
+mod stream_edges {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let edges = stream_edges("".as_bytes()).collect::<Vec<_>>();
+
+        assert!(edges.is_empty())
+    }
+
+    #[test]
+    fn comments_and_blanks() {
+        let text = "% header\n\n# comment\n0 1\n  \n1\t2 0.5\n";
+        let edges = stream_edges(text.as_bytes())
+            .collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(edges, [ (0, 1), (1, 2) ])
+    }
+
+    #[test]
+    fn missing_target() {
+        let mut edges = stream_edges("0 1\n2\n".as_bytes());
+
+        assert_eq!(edges.next().unwrap().unwrap(), (0, 1));
+
+        match edges.next() {
+            Some(Err(Error::InvalidLine(2))) => (),
+            _ => panic!("expected invalid line")
+        }
+    }
+
+    #[test]
+    fn negative_id() {
+        match stream_edges("0 -1\n".as_bytes()).next() {
+            Some(Err(Error::InvalidLine(1))) => (),
+            _ => panic!("expected invalid line")
+        }
+    }
+}
+
+#[cfg(test)]
+mod read_csr {
+    use std::io::Cursor;
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn empty() {
+        let graph = read_csr(Cursor::new("")).unwrap();
+
+        assert_eq!(graph, CsrGraph::try_from(Vec::<Vec<usize>>::new()).unwrap())
+    }
+
+    #[test]
+    fn largest_id() {
+        let graph = read_csr(Cursor::new("0 18446744073709551615\n")).unwrap();
+
+        assert_eq!(graph, CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap())
+    }
+
+    #[test]
+    fn gaps() {
+        let graph = read_csr(Cursor::new("5 1000000000000\n")).unwrap();
+
+        assert_eq!(graph.order(), 2);
+        assert_eq!(graph.size(), 1)
+    }
+
+    #[test]
+    fn self_loop() {
+        match read_csr(Cursor::new("0 1\n7 7\n")) {
+            Err(Error::Graph(graph::Error::SelfLoop(7))) => (),
+            _ => panic!("expected self loop")
+        }
+    }
+
+    #[test]
+    fn p3_p1() {
+        let graph = read_csr(Cursor::new("0 1\n1 2\n4 3\n")).unwrap();
+
+        assert_eq!(graph, CsrGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ],
+            vec![ 4 ],
+            vec![ 3 ]
+        ]).unwrap())
+    }
+
+    #[test]
+    fn duplicate_edge() {
+        match read_csr(Cursor::new("0 1\n1 0\n")) {
+            Err(Error::Graph(graph::Error::DuplicateEdge(1, 0))) => (),
+            _ => panic!("expected duplicate edge")
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::graph::{ Graph, Error };
+
+/// Returns coordinates placing the nodes in left on the line x = -1 and all
+/// other nodes on x = 1. Each side is spread evenly over y from -1 to 1 in
+/// the order of `graph.ids()`. Returns Error if an id in left is not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::layout::bipartite;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 1), (2, 3) ])?;
+///     let coordinates = bipartite(&graph, &[ 0, 2 ])?;
+///
+///     assert_eq!(coordinates[&0], (-1.0, -1.0));
+///     assert_eq!(coordinates[&2], (-1.0, 1.0));
+///     assert_eq!(coordinates[&1], (1.0, -1.0));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn bipartite<G: Graph>(
+    graph: &G, left: &[usize]
+) -> Result<HashMap<usize, (f64, f64)>, Error> {
+    if let Some(&id) = left.iter().find(|&&id| !graph.has_id(id)) {
+        return Err(Error::UnknownId(id));
+    }
+
+    let left = left.iter().cloned().collect::<HashSet<_>>();
+    let (lefts, rights) = graph.ids()
+        .partition::<Vec<_>, _>(|id| left.contains(id));
+    let mut result = HashMap::new();
+
+    column(&lefts, -1.0, &mut result);
+    column(&rights, 1.0, &mut result);
+
+    Ok(result)
+}
+
+fn column(ids: &[usize], x: f64, result: &mut HashMap<usize, (f64, f64)>) {
+    for (i, &id) in ids.iter().enumerate() {
+        let y = if ids.len() == 1 {
+            0.0
+        } else {
+            2.0 * i as f64 / (ids.len() - 1) as f64 - 1.0
+        };
+
+        result.insert(id, (x, y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_id() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(bipartite(&graph, &[ 2 ]), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn single_per_side() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let coordinates = bipartite(&graph, &[ 1 ]).unwrap();
+
+        assert_eq!(coordinates[&1], (-1.0, 0.0));
+        assert_eq!(coordinates[&0], (1.0, 0.0))
+    }
+
+    #[test]
+    fn empty_left() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let coordinates = bipartite(&graph, &[ ]).unwrap();
+
+        assert_eq!(coordinates[&0], (1.0, -1.0));
+        assert_eq!(coordinates[&1], (1.0, 0.0));
+        assert_eq!(coordinates[&2], (1.0, 1.0))
+    }
+}
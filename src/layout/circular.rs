@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use crate::graph::Graph;
+
+/// Returns coordinates placing the nodes of graph evenly on the unit
+/// circle, counterclockwise from (1, 0) in the order of `graph.ids()`. A
+/// lone node is placed at the origin.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::layout::circular;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3) ])?;
+///     let coordinates = circular(&graph);
+///     let (x, y) = coordinates[&2];
+///
+///     assert!((x + 1.0).abs() < 1e-9 && y.abs() < 1e-9);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn circular<G: Graph>(graph: &G) -> HashMap<usize, (f64, f64)> {
+    let order = graph.order();
+
+    graph.ids().enumerate().map(|(i, id)| {
+        if order == 1 {
+            return (id, (0.0, 0.0));
+        }
+
+        let angle = 2.0 * PI * i as f64 / order as f64;
+
+        (id, (angle.cos(), angle.sin()))
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert!(circular(&DefaultGraph::new()).is_empty())
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(circular(&graph)[&0], (0.0, 0.0))
+    }
+
+    #[test]
+    fn unit_radius() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4)
+        ]).unwrap();
+
+        for (x, y) in circular(&graph).values() {
+            assert!(((x * x + y * y).sqrt() - 1.0).abs() < 1e-9)
+        }
+    }
+}
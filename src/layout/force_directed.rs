@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+use crate::rng::Rng;
+use super::scale::rescale;
+
+/// Returns coordinates from the force-directed method of Fruchterman and
+/// Reingold. Neighbors attract, all pairs of nodes repel, and movement is
+/// limited by a temperature that cools over the given iterations. Nodes
+/// start at random positions drawn from seed, so the same seed gives the
+/// same layout. The result is centered on the origin and scaled to fit
+/// within -1 and 1 on both axes.
+///
+/// Each iteration takes time proportional to the square of order.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::layout::force_directed;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 0)
+///     ])?;
+///     let coordinates = force_directed(&graph, 50, 7);
+///
+///     assert_eq!(coordinates.len(), 4);
+///     assert_eq!(coordinates, force_directed(&graph, 50, 7));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn force_directed<G: Graph>(
+    graph: &G, iterations: usize, seed: u64
+) -> HashMap<usize, (f64, f64)> {
+    let ids = graph.ids().collect::<Vec<_>>();
+    let indices = ids.iter().enumerate()
+        .map(|(index, &id)| (id, index))
+        .collect::<HashMap<_, _>>();
+    let edges = graph.edges()
+        .map(|(sid, tid)| (indices[&sid], indices[&tid]))
+        .collect::<Vec<_>>();
+    let mut rng = Rng::new(seed);
+    let mut positions = ids.iter()
+        .map(|_| (rng.next_f64(), rng.next_f64()))
+        .collect::<Vec<_>>();
+    let k = (1.0 / ids.len().max(1) as f64).sqrt();
+    let start = 0.1;
+
+    for iteration in 0..iterations {
+        let temperature = start * (1.0 - iteration as f64 / iterations as f64);
+        let mut displacements = vec![ (0.0, 0.0); ids.len() ];
+
+        for i in 0..ids.len() {
+            for j in (i + 1)..ids.len() {
+                let (dx, dy, distance) = delta(&positions, i, j);
+                let force = k * k / distance;
+
+                push(&mut displacements, i, j, dx, dy, distance, force);
+            }
+        }
+
+        for &(i, j) in &edges {
+            let (dx, dy, distance) = delta(&positions, i, j);
+            let force = -distance * distance / k;
+
+            push(&mut displacements, i, j, dx, dy, distance, force);
+        }
+
+        for (position, (dx, dy)) in positions.iter_mut().zip(displacements) {
+            let length = (dx * dx + dy * dy).sqrt();
+
+            if length > 0.0 {
+                let step = length.min(temperature);
+
+                position.0 += dx / length * step;
+                position.1 += dy / length * step;
+            }
+        }
+    }
+
+    rescale(&mut positions);
+
+    ids.into_iter().zip(positions).collect()
+}
+
+// Returns the vector from j to i and its length, kept away from zero so
+// coincident nodes still repel.
+fn delta(positions: &[(f64, f64)], i: usize, j: usize) -> (f64, f64, f64) {
+    let dx = positions[i].0 - positions[j].0;
+    let dy = positions[i].1 - positions[j].1;
+
+    (dx, dy, (dx * dx + dy * dy).sqrt().max(1e-9))
+}
+
+// Moves i and j apart by force, or together if force is negative.
+fn push(
+    displacements: &mut [(f64, f64)],
+    i: usize, j: usize, dx: f64, dy: f64, distance: f64, force: f64
+) {
+    let (fx, fy) = (dx / distance * force, dy / distance * force);
+
+    displacements[i].0 += fx;
+    displacements[i].1 += fy;
+    displacements[j].0 -= fx;
+    displacements[j].1 -= fy;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+        ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn empty() {
+        assert!(force_directed(&DefaultGraph::new(), 10, 0).is_empty())
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(force_directed(&graph, 10, 0)[&0], (0.0, 0.0))
+    }
+
+    #[test]
+    fn zero_iterations() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(force_directed(&graph, 0, 3).len(), 2)
+    }
+
+    #[test]
+    fn within_bounds() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 5)
+        ]).unwrap();
+
+        for &(x, y) in force_directed(&graph, 100, 1).values() {
+            assert!(x.abs() <= 1.0 + 1e-9 && y.abs() <= 1.0 + 1e-9)
+        }
+    }
+
+    #[test]
+    fn neighbors_closer() {
+        // two triangles joined by a long path
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5),
+            (5, 6), (6, 7), (7, 8), (8, 6)
+        ]).unwrap();
+        let coordinates = force_directed(&graph, 200, 11);
+
+        assert!(
+            distance(coordinates[&0], coordinates[&1]) < distance(coordinates[&0], coordinates[&8])
+        )
+    }
+}
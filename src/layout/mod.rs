@@ -0,0 +1,10 @@
+mod circular;
+mod bipartite;
+mod force_directed;
+mod planar;
+mod scale;
+
+pub use circular::circular;
+pub use bipartite::bipartite;
+pub use force_directed::force_directed;
+pub use planar::planar;
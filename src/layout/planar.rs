@@ -0,0 +1,525 @@
+use std::collections::{ BTreeSet, HashMap, HashSet };
+
+use crate::graph::Graph;
+use crate::recognition::{ embed, Embedding };
+use super::scale::rescale;
+
+/// Returns straight-line coordinates in which no two edges of graph cross,
+/// or None if graph is not planar. A planar embedding is first extended
+/// with edges until every inner face is a triangle, then nodes are placed
+/// on a grid by the shift method of de Fraysseix, Pach, and Pollack. The
+/// result is centered on the origin and scaled to fit within [-1, 1] on
+/// both axes.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::layout::planar;
+///
+/// fn main() -> Result<(), Error> {
+///     let mut k5 = Vec::new();
+///
+///     for sid in 0..5 {
+///         for tid in (sid + 1)..5 {
+///             k5.push((sid, tid));
+///         }
+///     }
+///
+///     let k5 = DefaultGraph::try_from(k5)?;
+///     let k4 = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+///     ])?;
+///
+///     assert_eq!(planar(&k4).unwrap().len(), 4);
+///     assert_eq!(planar(&k5), None);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn planar<G: Graph>(graph: &G) -> Option<HashMap<usize, (f64, f64)>> {
+    let mut embedding = embed(graph)?;
+    let ids = embedding.ids().to_vec();
+    let mut positions = if ids.len() < 4 {
+        let defaults = [ (0, 0), (2, 0), (1, 1) ];
+
+        ids.iter().zip(defaults.iter()).map(|(&id, &position)| {
+            (id, position)
+        }).collect::<HashMap<_, _>>()
+    } else {
+        let outer = triangulate(&mut embedding);
+
+        shift(&canonical_ordering(&embedding, &outer))
+    };
+    let mut coordinates = ids.iter().map(|id| {
+        let (x, y) = positions.remove(id).expect("position");
+
+        (x as f64, y as f64)
+    }).collect::<Vec<_>>();
+
+    rescale(&mut coordinates);
+
+    Some(ids.into_iter().zip(coordinates).collect())
+}
+
+// Places nodes in canonical order, shifting the contour apart to make
+// room for each new node above it.
+fn shift(ordering: &[(usize, Vec<usize>)]) -> HashMap<usize, (i64, i64)> {
+    let mut left = HashMap::new();
+    let mut right = HashMap::new();
+    let mut delta_x = HashMap::new();
+    let mut y = HashMap::new();
+    let (v1, v2, v3) = (ordering[0].0, ordering[1].0, ordering[2].0);
+
+    delta_x.insert(v1, 0);
+    y.insert(v1, 0);
+    right.insert(v1, v3);
+    delta_x.insert(v2, 1);
+    y.insert(v2, 0);
+    delta_x.insert(v3, 1);
+    y.insert(v3, 1);
+    right.insert(v3, v2);
+
+    for (vk, contour) in &ordering[3..] {
+        let vk = *vk;
+        let (wp, wp1) = (contour[0], contour[1]);
+        let last = contour.len() - 1;
+        let (wq, wq1) = (contour[last], contour[last - 1]);
+        let multiple = contour.len() > 2;
+
+        *delta_x.get_mut(&wp1).expect("delta") += 1;
+        *delta_x.get_mut(&wq).expect("delta") += 1;
+
+        let span = contour[1..].iter().map(|w| delta_x[w]).sum::<i64>();
+        let offset = (-y[&wp] + span + y[&wq]) / 2;
+
+        delta_x.insert(vk, offset);
+        y.insert(vk, (y[&wp] + span + y[&wq]) / 2);
+        delta_x.insert(wq, span - offset);
+
+        if multiple {
+            *delta_x.get_mut(&wp1).expect("delta") -= offset;
+        }
+
+        right.insert(wp, vk);
+        right.insert(vk, wq);
+
+        if multiple {
+            left.insert(vk, wp1);
+            right.remove(&wq1);
+        }
+    }
+
+    let mut result = HashMap::new();
+    let mut stack = vec![ v1 ];
+
+    result.insert(v1, (0, y[&v1]));
+
+    while let Some(parent) = stack.pop() {
+        let x = result[&parent].0;
+
+        for tree in [ &left, &right ].iter() {
+            if let Some(&child) = tree.get(&parent) {
+                result.insert(child, (x + delta_x[&child], y[&child]));
+                stack.push(child);
+            }
+        }
+    }
+
+    result
+}
+
+// Returns nodes in an order where each node after the first two lies on
+// the outer face of the nodes before it, together with its neighbors
+// along that face.
+fn canonical_ordering(
+    embedding: &Embedding, outer: &[usize]
+) -> Vec<(usize, Vec<usize>)> {
+    let (v1, v2) = (outer[0], outer[1]);
+    let mut chords = HashMap::<usize, usize>::new();
+    let mut marked = HashSet::new();
+    let mut ready = outer.iter().cloned().collect::<BTreeSet<_>>();
+    let mut outer_ccw = HashMap::new();
+    let mut outer_cw = HashMap::new();
+    let mut previous = v2;
+
+    for &id in &outer[2..] {
+        outer_ccw.insert(previous, id);
+        previous = id;
+    }
+
+    outer_ccw.insert(previous, v1);
+    previous = v1;
+
+    for &id in outer[1..].iter().rev() {
+        outer_cw.insert(previous, id);
+        previous = id;
+    }
+
+    let on_outer = |x: usize, outer_ccw: &HashMap<usize, usize>,
+        marked: &HashSet<usize>| {
+        !marked.contains(&x) && (outer_ccw.contains_key(&x) || x == v1)
+    };
+    let outer_neighbor = |x: usize, y: usize,
+        outer_ccw: &HashMap<usize, usize>, outer_cw: &HashMap<usize, usize>| {
+        outer_ccw.get(&x) == Some(&y) || outer_cw.get(&x) == Some(&y)
+    };
+
+    for &v in outer {
+        for neighbor in embedding.neighbors_cw(v) {
+            if on_outer(neighbor, &outer_ccw, &marked) &&
+                !outer_neighbor(v, neighbor, &outer_ccw, &outer_cw) {
+                *chords.entry(v).or_insert(0) += 1;
+                ready.remove(&v);
+            }
+        }
+    }
+
+    let order = embedding.ids().len();
+    let mut result = vec![ (0, Vec::new()); order ];
+
+    result[0] = (v1, Vec::new());
+    result[1] = (v2, Vec::new());
+    ready.remove(&v1);
+    ready.remove(&v2);
+
+    for k in (2..order).rev() {
+        let v = *ready.iter().next().expect("ready node");
+        let (mut wp, mut wq) = (None, None);
+
+        ready.remove(&v);
+        marked.insert(v);
+
+        for neighbor in embedding.neighbors_cw(v) {
+            if marked.contains(&neighbor) ||
+                !on_outer(neighbor, &outer_ccw, &marked) {
+                continue;
+            }
+
+            if neighbor == v1 {
+                wp = Some(v1);
+            } else if neighbor == v2 {
+                wq = Some(v2);
+            } else if outer_cw.get(&neighbor) == Some(&v) {
+                wp = Some(neighbor);
+            } else {
+                wq = Some(neighbor);
+            }
+
+            if wp.is_some() && wq.is_some() {
+                break;
+            }
+        }
+
+        let (wp, wq) = (wp.expect("wp"), wq.expect("wq"));
+        let mut contour = vec![ wp ];
+        let mut neighbor = wp;
+
+        while neighbor != wq {
+            let next = embedding.ccw(v, neighbor);
+
+            contour.push(next);
+            outer_cw.insert(neighbor, next);
+            outer_ccw.insert(next, neighbor);
+            neighbor = next;
+        }
+
+        if contour.len() == 2 {
+            for w in [ wp, wq ].iter() {
+                let count = chords.get_mut(w).expect("chord");
+
+                *count -= 1;
+
+                if *count == 0 {
+                    ready.insert(*w);
+                }
+            }
+        } else {
+            let inner = &contour[1..contour.len() - 1];
+
+            for &w in inner {
+                ready.insert(w);
+
+                for neighbor in embedding.neighbors_cw(w) {
+                    if on_outer(neighbor, &outer_ccw, &marked) &&
+                        !outer_neighbor(w, neighbor, &outer_ccw, &outer_cw) {
+                        *chords.entry(w).or_insert(0) += 1;
+                        ready.remove(&w);
+
+                        if !inner.contains(&neighbor) {
+                            *chords.entry(neighbor).or_insert(0) += 1;
+                            ready.remove(&neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        result[k] = (v, contour);
+    }
+
+    result
+}
+
+// Adds edges joining components, making the embedding biconnected and
+// then splitting every face but the outer one into triangles. Returns the
+// outer face.
+fn triangulate(embedding: &mut Embedding) -> Vec<usize> {
+    for root in components(embedding).windows(2) {
+        embedding.add_half_edge_first(root[0], root[1]);
+        embedding.add_half_edge_first(root[1], root[0]);
+    }
+
+    let mut visited = HashSet::new();
+    let mut faces = Vec::new();
+    let mut outer = 0;
+
+    for v in embedding.ids().to_vec() {
+        for w in embedding.neighbors_cw(v) {
+            if let Some(face) = biconnect(embedding, v, w, &mut visited) {
+                if face.len() > faces.get(outer).map_or(0, Vec::len) {
+                    outer = faces.len();
+                }
+
+                faces.push(face);
+            }
+        }
+    }
+
+    for (index, face) in faces.iter().enumerate() {
+        if index != outer {
+            triangulate_face(embedding, face[0], face[1]);
+        }
+    }
+
+    faces.swap_remove(outer)
+}
+
+fn components(embedding: &Embedding) -> Vec<usize> {
+    let mut visited = HashSet::new();
+    let mut result = Vec::new();
+
+    for &root in embedding.ids() {
+        if !visited.insert(root) {
+            continue;
+        }
+
+        let mut stack = vec![ root ];
+
+        result.push(root);
+
+        while let Some(id) = stack.pop() {
+            for neighbor in embedding.neighbors_cw(id) {
+                if visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+// Walks the face on the right of (start, next), adding an edge whenever a
+// node repeats so that the face boundary becomes a simple cycle.
+fn biconnect(
+    embedding: &mut Embedding,
+    start: usize,
+    next: usize,
+    visited: &mut HashSet<(usize, usize)>
+) -> Option<Vec<usize>> {
+    if !visited.insert((start, next)) {
+        return None;
+    }
+
+    let (mut v1, mut v2) = (start, next);
+    let mut v3 = embedding.next_face_half_edge(v1, v2).1;
+    let mut face = vec![ start ];
+    let mut seen = face.iter().cloned().collect::<HashSet<_>>();
+
+    while v2 != start || v3 != next {
+        if seen.contains(&v2) {
+            embedding.add_half_edge_cw(v1, v3, Some(v2));
+            embedding.add_half_edge_ccw(v3, v1, Some(v2));
+            visited.insert((v2, v3));
+            visited.insert((v3, v1));
+            v2 = v1;
+        } else {
+            seen.insert(v2);
+            face.push(v2);
+        }
+
+        v1 = v2;
+
+        let (a, b) = embedding.next_face_half_edge(v2, v3);
+
+        v2 = a;
+        v3 = b;
+        visited.insert((v1, v2));
+    }
+
+    Some(face)
+}
+
+fn triangulate_face(embedding: &mut Embedding, v1: usize, v2: usize) {
+    let (mut v1, mut v2) = (v1, v2);
+    let mut v3 = embedding.next_face_half_edge(v1, v2).1;
+    let mut v4 = embedding.next_face_half_edge(v2, v3).1;
+
+    if v1 == v2 || v1 == v3 {
+        return;
+    }
+
+    while v1 != v4 {
+        if embedding.has_edge(v1, v3) {
+            v1 = v2;
+            v2 = v3;
+            v3 = v4;
+        } else {
+            embedding.add_half_edge_cw(v1, v3, Some(v2));
+            embedding.add_half_edge_ccw(v3, v1, Some(v2));
+            v2 = v3;
+            v3 = v4;
+        }
+
+        v4 = embedding.next_face_half_edge(v2, v3).1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    type Point = (f64, f64);
+
+    fn cross(o: Point, a: Point, b: Point) -> f64 {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    }
+
+    fn crosses(a: Point, b: Point, c: Point, d: Point) -> bool {
+        let d1 = cross(c, d, a);
+        let d2 = cross(c, d, b);
+        let d3 = cross(a, b, c);
+        let d4 = cross(a, b, d);
+
+        d1 * d2 < -1e-12 && d3 * d4 < -1e-12
+    }
+
+    fn assert_plane(edges: Vec<(usize, usize)>) {
+        let graph = DefaultGraph::try_from(edges).unwrap();
+        let positions = planar(&graph).unwrap();
+        let edges = graph.edges().collect::<Vec<_>>();
+
+        assert_eq!(positions.len(), graph.order());
+
+        for (i, &(s1, t1)) in edges.iter().enumerate() {
+            for &(s2, t2) in &edges[(i + 1)..] {
+                if s1 == s2 || s1 == t2 || t1 == s2 || t1 == t2 {
+                    continue;
+                }
+
+                assert!(!crosses(
+                    positions[&s1], positions[&t1],
+                    positions[&s2], positions[&t2]
+                ), "{:?} crosses {:?}", (s1, t1), (s2, t2));
+            }
+        }
+
+        for (i, a) in positions.values().enumerate() {
+            assert!(a.0.abs() <= 1.0 && a.1.abs() <= 1.0);
+
+            for b in positions.values().skip(i + 1) {
+                assert_ne!(a, b)
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(planar(&DefaultGraph::new()), Some(HashMap::new()))
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(planar(&graph).unwrap()[&0], (0.0, 0.0))
+    }
+
+    #[test]
+    fn p3() {
+        assert_plane(vec![ (0, 1), (1, 2) ])
+    }
+
+    #[test]
+    fn k4() {
+        assert_plane(vec![ (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3) ])
+    }
+
+    #[test]
+    fn k5() {
+        let mut edges = Vec::new();
+
+        for sid in 0..5 {
+            for tid in (sid + 1)..5 {
+                edges.push((sid, tid));
+            }
+        }
+
+        assert_eq!(planar(&DefaultGraph::try_from(edges).unwrap()), None)
+    }
+
+    #[test]
+    fn star() {
+        assert_plane(vec![ (0, 1), (0, 2), (0, 3), (0, 4), (0, 5) ])
+    }
+
+    #[test]
+    fn cube() {
+        assert_plane(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0),
+            (4, 5), (5, 6), (6, 7), (7, 4),
+            (0, 4), (1, 5), (2, 6), (3, 7)
+        ])
+    }
+
+    #[test]
+    fn wheel() {
+        assert_plane(vec![
+            (0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (0, 6),
+            (1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 1)
+        ])
+    }
+
+    #[test]
+    fn naphthalene() {
+        assert_plane(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 9), (9, 0),
+            (4, 5), (5, 6), (6, 7), (7, 8), (8, 9)
+        ])
+    }
+
+    #[test]
+    fn spiro() {
+        assert_plane(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)
+        ])
+    }
+
+    #[test]
+    fn disconnected() {
+        assert_plane(vec![
+            (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 6), (6, 3)
+        ])
+    }
+
+    #[test]
+    fn isolated() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ], vec![ ], vec![ ], vec![ ], vec![ ]
+        ]).unwrap();
+
+        assert_eq!(planar(&graph).unwrap().len(), 5)
+    }
+}
@@ -0,0 +1,24 @@
+/// Centers positions on the origin and scales them uniformly so that the
+/// farthest coordinate lies at -1 or 1.
+pub(crate) fn rescale(positions: &mut [(f64, f64)]) {
+    if positions.is_empty() {
+        return;
+    }
+
+    let n = positions.len() as f64;
+    let cx = positions.iter().map(|p| p.0).sum::<f64>() / n;
+    let cy = positions.iter().map(|p| p.1).sum::<f64>() / n;
+    let extent = positions.iter()
+        .map(|p| (p.0 - cx).abs().max((p.1 - cy).abs()))
+        .fold(0.0, f64::max);
+
+    for position in positions.iter_mut() {
+        position.0 -= cx;
+        position.1 -= cy;
+
+        if extent > 0.0 {
+            position.0 /= extent;
+            position.1 /= extent;
+        }
+    }
+}
@@ -2,6 +2,39 @@ pub mod graph;
 pub mod traversal;
 pub mod selection;
 pub mod matching;
+pub mod hash;
+pub mod io;
+pub mod metrics;
+pub mod shortest_path;
+pub mod path;
+pub mod tree;
+pub mod cycles;
+pub mod budget;
+pub mod ops;
+pub mod recognition;
+pub mod decomposition;
+pub mod rewrite;
+pub mod coloring;
+pub mod ordering;
+pub mod render;
+pub mod layout;
+pub mod generate;
+pub mod motifs;
+pub mod fingerprint;
+pub mod invariants;
+pub mod connectivity;
+
+mod error;
+mod rng;
+mod pool;
+
+pub use error::Error;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "bench-support")]
+pub mod bench;
 
 // https://github.com/rust-lang/cargo/issues/383#issuecomment-720873790
 #[cfg(doctest)]
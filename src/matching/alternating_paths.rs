@@ -0,0 +1,236 @@
+use std::hash::BuildHasher;
+
+use crate::graph::{ Graph, Error };
+use super::Pairing;
+
+/// Returns every simple path starting at from whose edges alternate between
+/// paired and unpaired, in depth-first order. Each path has at least one
+/// edge. Returns Error if from is not found.
+///
+/// The number of such paths can grow exponentially with graph size, so this
+/// function is intended for analysis of small neighborhoods.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::matching::{ alternating_paths, Pairing };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3)
+///     ])?;
+///     let mut pairing = Pairing::new();
+///
+///     pairing.pair(1, 2);
+///
+///     assert_eq!(alternating_paths(&graph, &pairing, 0)?, vec![
+///         vec![ 0, 1 ],
+///         vec![ 0, 1, 2 ],
+///         vec![ 0, 1, 2, 3 ]
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn alternating_paths<G, S>(
+    graph: &G, pairing: &Pairing<S>, from: usize
+) -> Result<Vec<Vec<usize>>, Error>
+where G: Graph, S: BuildHasher {
+    let mut result = Vec::new();
+    let mut path = vec![ from ];
+    let mut stack = vec![ graph.neighbors(from)?.collect::<Vec<_>>() ];
+
+    stack[0].reverse();
+
+    while let Some(neighbors) = stack.last_mut() {
+        let id = match neighbors.pop() {
+            Some(id) => id,
+            None => {
+                stack.pop();
+                path.pop();
+
+                continue;
+            }
+        };
+        let last = path[path.len() - 1];
+
+        if path.contains(&id) {
+            continue;
+        }
+
+        if path.len() > 1 {
+            let previous = path[path.len() - 2];
+
+            if is_paired(pairing, previous, last) == is_paired(pairing, last, id) {
+                continue;
+            }
+        }
+
+        path.push(id);
+        result.push(path.clone());
+
+        let mut next = graph.neighbors(id)?.collect::<Vec<_>>();
+
+        next.reverse();
+        stack.push(next);
+    }
+
+    Ok(result)
+}
+
+/// Returns every augmenting path starting at from: alternating paths
+/// joining from to another unpaired node. Returns an empty Vec if from is
+/// paired, or Error if from is not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::matching::{ augmenting_paths, Pairing };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3)
+///     ])?;
+///     let mut pairing = Pairing::new();
+///
+///     pairing.pair(1, 2);
+///
+///     assert_eq!(augmenting_paths(&graph, &pairing, 0)?, vec![
+///         vec![ 0, 1, 2, 3 ]
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn augmenting_paths<G, S>(
+    graph: &G, pairing: &Pairing<S>, from: usize
+) -> Result<Vec<Vec<usize>>, Error>
+where G: Graph, S: BuildHasher {
+    if !graph.has_id(from) {
+        return Err(Error::UnknownId(from));
+    }
+
+    if pairing.has_node(from) {
+        return Ok(Vec::new());
+    }
+
+    let paths = alternating_paths(graph, pairing, from)?;
+
+    Ok(paths.into_iter().filter(|path| {
+        !pairing.has_node(*path.last().expect("node"))
+    }).collect())
+}
+
+fn is_paired<S: BuildHasher>(
+    pairing: &Pairing<S>, sid: usize, tid: usize
+) -> bool {
+    pairing.has_node(sid) && pairing.mate(sid) == tid
+}
+
+#[cfg(test)]
+mod alternating {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_from() {
+        let graph = DefaultGraph::new();
+        let pairing = Pairing::new();
+
+        assert_eq!(
+            alternating_paths(&graph, &pairing, 0),
+            Err(Error::UnknownId(0))
+        )
+    }
+
+    #[test]
+    fn unpaired_p3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2)
+        ]).unwrap();
+        let pairing = Pairing::new();
+
+        assert_eq!(alternating_paths(&graph, &pairing, 1), Ok(vec![
+            vec![ 1, 0 ],
+            vec![ 1, 2 ]
+        ]))
+    }
+
+    #[test]
+    fn paired_from() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(1, 2);
+
+        assert_eq!(alternating_paths(&graph, &pairing, 1), Ok(vec![
+            vec![ 1, 0 ],
+            vec![ 1, 2 ],
+            vec![ 1, 2, 3 ]
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod augmenting {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_paired_from() {
+        let graph = DefaultGraph::new();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+
+        assert_eq!(
+            augmenting_paths(&graph, &pairing, 0),
+            Err(Error::UnknownId(0))
+        )
+    }
+
+    #[test]
+    fn paired_from() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(1, 2);
+
+        assert_eq!(augmenting_paths(&graph, &pairing, 1), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn maximum() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+
+        assert_eq!(augmenting_paths(&graph, &pairing, 0), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn c5_with_tail() {
+        // unmatched 0 on a pentagon 0-1-2-3-4, tail 3-5
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (3, 5)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(1, 2);
+        pairing.pair(3, 4);
+
+        assert_eq!(augmenting_paths(&graph, &pairing, 0), Ok(vec![
+            vec![ 0, 4, 3, 5 ]
+        ]))
+    }
+}
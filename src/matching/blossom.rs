@@ -1,20 +1,52 @@
-use crate::graph::{ Graph, DefaultGraph, Error };
-use super::pairing::Pairing;
+use std::hash::BuildHasher;
 
+use crate::graph::{ self, Graph, DefaultGraph };
+use super::pairing::Pairing;
+use super::Error;
+
+/// An odd cycle found during matching, and the operations needed to
+/// contract it into a single node and later lift a path through that node
+/// back into the original graph.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, DefaultGraph };
+/// use gamma::matching::{ Blossom, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3)
+///     ])?;
+///     // forest paths from 1 and 2 back to the shared root 0
+///     let blossom = Blossom::new(4, vec![ 1, 0 ], vec![ 2, 0 ])?;
+///     let contracted = blossom.contract_graph(&graph)?;
+///
+///     assert_eq!(contracted.edges().collect::<Vec<_>>(), vec![ (4, 3) ]);
+///     assert_eq!(blossom.lift(vec![ 4, 3 ], &graph)?, vec![ 0, 1, 2, 3 ]);
+///
+///     Ok(())
+/// }
+/// ```
 #[derive(Debug,PartialEq)]
 pub struct Blossom {
     id: usize,
-    path: Vec<usize>
+    path: Vec<usize>,
+    base: usize
 }
 
 impl Blossom {
+    /// Returns the Blossom closed by the forest paths left and right, each
+    /// running from a node back to its root, to be contracted into a node
+    /// with the given id. Returns Error if the paths share no node, or if
+    /// id is a node of the resulting cycle.
     pub fn new(
         id: usize, mut left: Vec<usize>, mut right: Vec<usize>
-    ) -> Self {
+    ) -> Result<Self, Error> {
         for i in 0..left.len() {
             for j in 0..right.len() {
                 if left[i] == right[j] {
                     let root = left[i];
+                    let base = i;
                     left = left[0..i].to_vec();
                     right = right[0..j].to_vec();
 
@@ -23,16 +55,28 @@ impl Blossom {
                     left.push(root);
                     left.append(&mut right);
 
-                    return Self { id, path: left }
+                    if left.contains(&id) {
+                        return Err(Error::Graph(graph::Error::DuplicateId(id)))
+                    }
+
+                    return Ok(Self { id, path: left, base })
                 }
             }
         }
-        
-        panic!("blossom root not found")
+
+        Err(Error::MissingRoot)
     }
 
-    pub fn contract_graph<'a, G: Graph>(
-        &self, graph: &'a G
+    /// Returns the nodes of this blossom's cycle, beginning with its root.
+    pub fn nodes(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Returns a copy of graph with this blossom's nodes replaced by a single
+    /// node. Edges into the blossom are merged. Returns Error if the blossom
+    /// id is already a node of graph.
+    pub fn contract_graph<G: Graph>(
+        &self, graph: &G
     ) -> Result<DefaultGraph, Error> {
         let mut result = DefaultGraph::new();
 
@@ -46,10 +90,8 @@ impl Blossom {
 
         for (sid, tid) in graph.edges() {
             if self.path.contains(&sid) {
-                if !self.path.contains(&tid) {
-                    if !result.has_edge(self.id, tid)? {
-                        result.add_edge(self.id, tid)?;
-                    }
+                if !self.path.contains(&tid) && !result.has_edge(self.id, tid)? {
+                    result.add_edge(self.id, tid)?;
                 }
             } else if self.path.contains(&tid) {
                 if !result.has_edge(sid, self.id)? {
@@ -63,8 +105,12 @@ impl Blossom {
         Ok(result)
     }
 
-    pub fn contract_pairing(&self, pairing: &Pairing) -> Pairing {
-        let mut result = Pairing::new();
+    /// Returns a copy of pairing with this blossom's nodes replaced by its
+    /// id. Pairs within the blossom are dropped.
+    pub fn contract_pairing<S: BuildHasher + Default>(
+        &self, pairing: &Pairing<S>
+    ) -> Pairing<S> {
+        let mut result = Pairing::default();
 
         for (sid, tid) in pairing.edges() {
             if self.path.contains(&sid) {
@@ -81,105 +127,103 @@ impl Blossom {
         result
     }
 
-    pub fn lift<'a, G: Graph>(
-        &self, path: Vec<usize>, graph: &'a G
-    ) -> Vec<usize> {
+    /// Returns path, found in the contracted graph, with this blossom's id
+    /// replaced by an even-length route through the blossom in graph. Paths
+    /// not passing through the blossom are returned unchanged. Returns Error
+    /// if graph has no edge joining path to the blossom.
+    pub fn lift<G: Graph>(
+        &self, path: Vec<usize>, graph: &G
+    ) -> Result<Vec<usize>, Error> {
         let index = match path.iter().position(|&pid| pid == self.id) {
             Some(index) => index,
-            None => return path
-            // None => panic!("path missing blossom id: {}", self.id)
+            None => return Ok(path)
         };
-        let left = path[0..index].to_vec();
-        let right = path[(index + 1)..].to_vec();
+        let left = &path[0..index];
+        let right = &path[(index + 1)..];
+        let base = self.path[self.base];
 
         if left.is_empty() && right.is_empty() {
-            self.path.to_vec()
-        } else if !left.is_empty() && right.is_empty() {
-            self.lift_left_blossom(left, graph)
-        } else if left.is_empty() && !right.is_empty() {
-            self.lift_blossom_right(right, graph)
-        } else {
-            self.lift_left_blossom_right(left, right, graph)
+            return Ok(self.path.to_vec())
         }
-    }
 
-    fn lift_left_blossom<'a, G: Graph>(
-        &self, mut left: Vec<usize>, graph: &'a G
-    ) -> Vec<usize> {
-        let sid = left.last().unwrap();
-        let mut copy = self.path.to_vec();
+        // An augmenting path begins with an unmatched edge, so the blossom
+        // is entered through an unmatched edge from an even index and left
+        // through the base's matched edge, or the reverse.
+        let mut result = left.to_vec();
 
-        while !graph.has_edge(*sid, copy[0]).unwrap() {
-            copy.rotate_right(1);
-        }
+        if index % 2 == 1 || right.is_empty() {
+            let &sid = left.last().expect("left");
 
-        left.append(&mut copy);
+            result.append(&mut self.walk(sid, graph)?);
 
-        left
-    }
+            if let Some(&tid) = right.first() {
+                self.check_edge(base, tid, graph)?;
+            }
+        } else {
+            let tid = right[0];
+            let mut walk = self.walk(tid, graph)?;
 
-    fn lift_blossom_right<'a, G: Graph>(
-        &self, mut right: Vec<usize>, graph: &'a G
-    ) -> Vec<usize> {
-        let tid = right[0];
-        let mut copy = self.path.to_vec();
+            if let Some(&sid) = left.last() {
+                self.check_edge(sid, base, graph)?;
+            }
 
-        while !graph.has_edge(*copy.last().unwrap(), tid).unwrap() {
-            copy.rotate_right(1);
+            walk.reverse();
+            result.append(&mut walk);
         }
 
-        copy.append(&mut right);
+        result.extend(right.iter());
 
-        copy
+        Ok(result)
     }
 
-    fn lift_left_blossom_right<'a, G: Graph>(
-        &self, left: Vec<usize>, right: Vec<usize>, graph: &'a G
-    ) -> Vec<usize> {
-        let &sid = left.last().unwrap();
-        let mut forward_blossom = self.path.to_vec();
-        let mut forward = left.to_vec();
-        
-        while !graph.has_edge(sid, forward_blossom[0]).unwrap() {
-            forward_blossom.rotate_right(1);
-        }
-    
-        let &tid = &right[0];
+    // Returns the even-length route around the cycle from a node adjacent to
+    // id to the base, which alternates beginning with a matched edge.
+    fn walk<G: Graph>(
+        &self, id: usize, graph: &G
+    ) -> Result<Vec<usize>, Error> {
+        let len = self.path.len();
+        let mut start = None;
 
-        for &bid in &forward_blossom {
-            forward.push(bid);
+        for (i, &bid) in self.path.iter().enumerate() {
+            if graph.has_edge(id, bid)? {
+                start = Some(i);
 
-            if graph.has_edge(bid, tid).unwrap() {
                 break;
             }
         }
 
-        forward.extend(right.iter());
-
-        if forward.len() % 2 == 0 {
-            return forward
-        }
-
-        let mut reverse = left.to_vec();
-        let mut reverse_blossom = self.path.to_vec();
-
-        reverse_blossom.reverse();
-
-        while !graph.has_edge(sid, reverse_blossom[0]).unwrap() {
-            reverse_blossom.rotate_right(1);
-        }
-
-        for &bid in &reverse_blossom {
-            reverse.push(bid);
+        let start = match start {
+            Some(start) => start,
+            None => {
+                return Err(Error::Graph(
+                    graph::Error::MissingEdge(id, self.id)
+                ))
+            }
+        };
+        let forward = (self.base + len - start) % len;
+        let mut result = Vec::new();
 
-            if graph.has_edge(bid, tid).unwrap() {
-                break;
+        if forward % 2 == 0 {
+            for step in 0..=forward {
+                result.push(self.path[(start + step) % len]);
+            }
+        } else {
+            for step in 0..=(len - forward) {
+                result.push(self.path[(start + len - step) % len]);
             }
         }
 
-        reverse.extend(right.iter());
+        Ok(result)
+    }
 
-        reverse
+    fn check_edge<G: Graph>(
+        &self, sid: usize, tid: usize, graph: &G
+    ) -> Result<(), Error> {
+        if graph.has_edge(sid, tid)? {
+            Ok(())
+        } else {
+            Err(Error::Graph(graph::Error::MissingEdge(sid, tid)))
+        }
     }
 }
 
@@ -188,14 +232,24 @@ mod new {
     use super::*;
 
     #[test]
-    #[should_panic(expected="blossom root not found")]
     fn different_roots() {
-        Blossom::new(1, vec![ 2, 1, 0 ], vec![ 5, 4, 3 ]);
+        assert_eq!(
+            Blossom::new(6, vec![ 2, 1, 0 ], vec![ 5, 4, 3 ]),
+            Err(Error::MissingRoot)
+        )
+    }
+
+    #[test]
+    fn id_inside() {
+        assert_eq!(
+            Blossom::new(1, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]),
+            Err(Error::Graph(graph::Error::DuplicateId(1)))
+        )
     }
 
     #[test]
     fn root_at_right() {
-        let blossom = Blossom::new(1, vec![ 2, 1, 0 ], vec![ 5, 4, 0 ]);
+        let blossom = Blossom::new(6, vec![ 2, 1, 0 ], vec![ 5, 4, 0 ]).unwrap();
 
         assert_eq!(blossom.path, vec![ 2, 1, 0, 4, 5 ])
     }
@@ -206,7 +260,7 @@ mod new {
             4, 3, 2, 1, 0
         ], vec![
             7, 6, 2, 1, 0
-        ]);
+        ]).unwrap();
 
         assert_eq!(blossom.path, vec![ 4, 3, 2, 6, 7 ])
     }
@@ -222,12 +276,12 @@ mod contract_graph {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 0), (3, 2), (3, 1)
         ]).unwrap();
-        let blossom = Blossom::new(4, vec![0], vec![ 1, 2, 0 ]);
+        let blossom = Blossom::new(4, vec![0], vec![ 1, 2, 0 ]).unwrap();
         let contracted = blossom.contract_graph(&graph);
 
-        assert_eq!(contracted, DefaultGraph::try_from(vec![
+        assert_eq!(contracted, Ok(DefaultGraph::try_from(vec![
             (3, 4)
-        ]))
+        ]).unwrap()))
     }
 
     #[test]
@@ -235,12 +289,12 @@ mod contract_graph {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 0), (2, 3), (1, 3)
         ]).unwrap();
-        let blossom = Blossom::new(4, vec![0], vec![ 1, 2, 0 ]);
+        let blossom = Blossom::new(4, vec![0], vec![ 1, 2, 0 ]).unwrap();
         let contracted = blossom.contract_graph(&graph);
 
-        assert_eq!(contracted, DefaultGraph::try_from(vec![
+        assert_eq!(contracted, Ok(DefaultGraph::try_from(vec![
             (3, 4)
-        ]))
+        ]).unwrap()))
     }
 
     #[test]
@@ -248,12 +302,12 @@ mod contract_graph {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (4, 5), (5, 6)
         ]).unwrap();
-        let blossom = Blossom::new(7, vec![ 4, 0, 1 ], vec![ 3, 2, 1 ]);
+        let blossom = Blossom::new(7, vec![ 4, 0, 1 ], vec![ 3, 2, 1 ]).unwrap();
         let contracted = blossom.contract_graph(&graph);
 
-        assert_eq!(contracted, DefaultGraph::try_from(vec![
+        assert_eq!(contracted, Ok(DefaultGraph::try_from(vec![
             (6, 5), (5, 7)
-        ]))
+        ]).unwrap()))
     }
 
     #[test]
@@ -261,12 +315,12 @@ mod contract_graph {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (5, 4), (5, 6)
         ]).unwrap();
-        let blossom = Blossom::new(7, vec![ 4, 0, 1 ], vec![ 3, 2, 1 ]);
+        let blossom = Blossom::new(7, vec![ 4, 0, 1 ], vec![ 3, 2, 1 ]).unwrap();
         let contracted = blossom.contract_graph(&graph);
 
-        assert_eq!(contracted, DefaultGraph::try_from(vec![
+        assert_eq!(contracted, Ok(DefaultGraph::try_from(vec![
             (6, 5), (5, 7)
-        ]))
+        ]).unwrap()))
     }
 
     #[test]
@@ -276,12 +330,12 @@ mod contract_graph {
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 8),
             (8, 2), (6, 1)
         ]).unwrap();
-        let blossom = Blossom::new(9, vec![ 8, 2, 3, 4 ], vec![ 7, 6, 5, 4 ]);
+        let blossom = Blossom::new(9, vec![ 8, 2, 3, 4 ], vec![ 7, 6, 5, 4 ]).unwrap();
         let contracted = blossom.contract_graph(&graph);
 
-        assert_eq!(contracted, DefaultGraph::try_from(vec![
+        assert_eq!(contracted, Ok(DefaultGraph::try_from(vec![
             (0, 1), (1, 9)
-        ]))
+        ]).unwrap()))
     }
 }
 
@@ -291,7 +345,7 @@ mod contract_pairing {
 
     #[test]
     fn sid_inside() {
-        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]);
+        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]).unwrap();
         let mut pairing = Pairing::new();
 
         pairing.pair(7, 8);
@@ -307,7 +361,7 @@ mod contract_pairing {
 
     #[test]
     fn tid_inside() {
-        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]);
+        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]).unwrap();
         let mut pairing = Pairing::new();
 
         pairing.pair(7, 8);
@@ -323,7 +377,7 @@ mod contract_pairing {
 
     #[test]
     fn sid_tid_inside() {
-        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]);
+        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]).unwrap();
         let mut pairing = Pairing::new();
 
         pairing.pair(7, 8);
@@ -347,12 +401,12 @@ mod lift {
         let graph = DefaultGraph::try_from(vec![
             (1, 2), (2, 3), (3, 4), (4, 5), (5, 1)
         ]).unwrap();
-        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]);
+        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]).unwrap();
         let path = vec![ 8, 9, 10, 11 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
             8, 9, 10, 11
-        ])
+        ]))
     }
 
     #[test]
@@ -360,12 +414,12 @@ mod lift {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
         ]).unwrap();
-        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]);
+        let blossom = Blossom::new(5, vec![ 2, 1, 0 ], vec![ 4, 3, 0 ]).unwrap();
         let path = vec![ 5 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
             2, 1, 0, 3, 4
-        ])
+        ]))
     }
 
     #[test]
@@ -373,12 +427,12 @@ mod lift {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1)
         ]).unwrap();
-        let blossom = Blossom::new(6, vec![ 1, 2, 3 ], vec![ 5, 4, 3 ]);
+        let blossom = Blossom::new(6, vec![ 1, 2, 3 ], vec![ 5, 4, 3 ]).unwrap();
         let path = vec![ 0, 6 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
-            0, 1, 2, 3, 4, 5
-        ])
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
+            0, 1, 2, 3
+        ]))
     }
 
     #[test]
@@ -386,12 +440,12 @@ mod lift {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1)
         ]).unwrap();
-        let blossom = Blossom::new(6, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]);
+        let blossom = Blossom::new(6, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]).unwrap();
         let path = vec![ 0, 6 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
-            0, 1, 2, 3, 4, 5
-        ])
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
+            0, 1, 5, 4
+        ]))
     }
 
     #[test]
@@ -399,37 +453,65 @@ mod lift {
         let graph = DefaultGraph::try_from(vec![
             (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1)
         ]).unwrap();
-        let blossom = Blossom::new(6, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]);
+        let blossom = Blossom::new(6, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]).unwrap();
         let path = vec![ 6, 0 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
-            2, 3, 4, 5, 1, 0
-        ])
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
+            4, 5, 1, 0
+        ]))
     }
 
     #[test]
     fn left_blossom_right() {
         let graph = DefaultGraph::try_from(vec![
-            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (3, 6)
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (4, 6)
         ]).unwrap();
-        let blossom = Blossom::new(7, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]);
+        let blossom = Blossom::new(7, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]).unwrap();
         let path = vec![ 0, 7, 6 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
-            0, 1, 5, 4, 3, 6
-        ])
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
+            0, 1, 5, 4, 6
+        ]))
     }
 
     #[test]
     fn left_blossom_right_shifted() {
         let graph = DefaultGraph::try_from(vec![
-            (0, 5), (5, 1), (1, 2), (2, 3), (3, 4), (4, 5), (3, 6)
+            (0, 5), (5, 1), (1, 2), (2, 3), (3, 4), (4, 5), (4, 6)
         ]).unwrap();
-        let blossom = Blossom::new(7, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]);
+        let blossom = Blossom::new(7, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]).unwrap();
         let path = vec![ 0, 7, 6 ];
 
-        assert_eq!(blossom.lift(path, &graph), vec![
-            0, 5, 1, 2, 3, 6
-        ])
+        assert_eq!(blossom.lift(path, &graph), Ok(vec![
+            0, 5, 1, 2, 3, 4, 6
+        ]))
+    }
+
+    #[test]
+    fn base_not_adjacent_to_mate() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 1), (3, 6)
+        ]).unwrap();
+        let blossom = Blossom::new(7, vec![ 2, 3, 4 ], vec![ 1, 5, 4 ]).unwrap();
+        let path = vec![ 0, 7, 6 ];
+
+        assert_eq!(
+            blossom.lift(path, &graph),
+            Err(Error::Graph(graph::Error::MissingEdge(4, 6)))
+        )
+    }
+
+    #[test]
+    fn unconnected() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (3, 4)
+        ]).unwrap();
+        let blossom = Blossom::new(5, vec![ 1, 0 ], vec![ 2, 0 ]).unwrap();
+        let path = vec![ 3, 5 ];
+
+        assert_eq!(
+            blossom.lift(path, &graph),
+            Err(Error::Graph(graph::Error::MissingEdge(3, 5)))
+        )
     }
-}
\ No newline at end of file
+}
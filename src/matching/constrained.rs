@@ -0,0 +1,222 @@
+use std::collections::{ HashMap, HashSet };
+use std::hash::BuildHasher;
+
+use crate::graph::{ self, Graph, filter_nodes, filter_edges };
+use super::{ maximum_matching, Pairing, Error };
+
+/// Performs a maximum matching over graph among the matchings that contain
+/// every edge in required and no edge in forbidden. Edges may be given in
+/// either orientation. Pairs already in pairing seed the search, except
+/// those that conflict with the constraints or are no longer edges of
+/// graph, which are removed first.
+///
+/// If the constraints leave no perfect matching, the result is still the
+/// largest matching that satisfies them, so callers such as kekulization
+/// can report which nodes remain unpaired.
+///
+/// Returns Error, leaving pairing unchanged, if a required edge is not in
+/// graph. Returns Infeasible if a required edge is also forbidden or
+/// shares a node with an earlier required edge.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use std::collections::BTreeSet;
+/// use gamma::graph::DefaultGraph;
+/// use gamma::matching::{ constrained_matching, Pairing, Error };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 0)
+///     ])?;
+///     let mut pairing = Pairing::new();
+///
+///     constrained_matching(&graph, &[ (1, 2) ], &[ ], &mut pairing)?;
+///
+///     assert_eq!(
+///         pairing.edges().collect::<BTreeSet<_>>(),
+///         [ (0, 3), (1, 2) ].iter().cloned().collect::<BTreeSet<_>>()
+///     );
+///     assert_eq!(
+///         constrained_matching(&graph, &[ (1, 2) ], &[ (2, 1) ], &mut pairing),
+///         Err(Error::Infeasible(1, 2))
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+pub fn constrained_matching<G, S>(
+    graph: &G,
+    required: &[(usize, usize)],
+    forbidden: &[(usize, usize)],
+    pairing: &mut Pairing<S>
+) -> Result<(), Error>
+where G: Graph, S: BuildHasher + Default + Clone {
+    let forbidden = forbidden.iter()
+        .map(|&(sid, tid)| key(sid, tid))
+        .collect::<HashSet<_>>();
+    let mut fixed = HashMap::new();
+
+    for &(sid, tid) in required {
+        if !graph.has_edge(sid, tid)? {
+            return Err(Error::Graph(graph::Error::MissingEdge(sid, tid)));
+        }
+
+        if forbidden.contains(&key(sid, tid))
+            || fixed.contains_key(&sid)
+            || fixed.contains_key(&tid) {
+            return Err(Error::Infeasible(sid, tid));
+        }
+
+        fixed.insert(sid, tid);
+        fixed.insert(tid, sid);
+    }
+
+    for (sid, tid) in pairing.edges().collect::<Vec<_>>() {
+        let valid = graph.has_id(sid) && graph.has_id(tid)
+            && graph.has_edge(sid, tid).expect("edge")
+            && !forbidden.contains(&key(sid, tid))
+            && !fixed.contains_key(&sid)
+            && !fixed.contains_key(&tid);
+
+        if !valid {
+            pairing.unpair(sid);
+        }
+    }
+
+    let free = filter_nodes(graph, |id| !fixed.contains_key(&id));
+    let allowed = filter_edges(&free, |sid, tid| {
+        !forbidden.contains(&key(sid, tid))
+    });
+
+    maximum_matching(&allowed, pairing);
+
+    for &(sid, tid) in required {
+        pairing.pair(sid, tid);
+    }
+
+    Ok(())
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::collections::BTreeSet;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn edges(pairing: &Pairing) -> BTreeSet<(usize, usize)> {
+        pairing.edges().collect()
+    }
+
+    fn benzene() -> DefaultGraph {
+        DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)
+        ]).unwrap()
+    }
+
+    #[test]
+    fn missing_required() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+
+        assert_eq!(
+            constrained_matching(&benzene(), &[ (0, 2) ], &[ ], &mut pairing),
+            Err(Error::Graph(graph::Error::MissingEdge(0, 2)))
+        );
+        assert_eq!(edges(&pairing), [ (0, 1) ].iter().cloned().collect())
+    }
+
+    #[test]
+    fn unknown_required() {
+        let mut pairing = Pairing::new();
+
+        assert_eq!(
+            constrained_matching(&benzene(), &[ (0, 9) ], &[ ], &mut pairing),
+            Err(Error::Graph(graph::Error::UnknownId(9)))
+        )
+    }
+
+    #[test]
+    fn overlapping_required() {
+        let mut pairing = Pairing::new();
+
+        assert_eq!(
+            constrained_matching(
+                &benzene(), &[ (0, 1), (2, 1) ], &[ ], &mut pairing
+            ),
+            Err(Error::Infeasible(2, 1))
+        )
+    }
+
+    #[test]
+    fn unconstrained() {
+        let mut pairing = Pairing::new();
+
+        constrained_matching(&benzene(), &[ ], &[ ], &mut pairing).unwrap();
+
+        assert_eq!(pairing.order(), 6)
+    }
+
+    #[test]
+    fn required_selects_kekule_structure() {
+        let mut pairing = Pairing::new();
+
+        constrained_matching(
+            &benzene(), &[ (1, 2) ], &[ ], &mut pairing
+        ).unwrap();
+
+        assert_eq!(
+            edges(&pairing),
+            [ (1, 2), (3, 4), (0, 5) ].iter().cloned().collect()
+        )
+    }
+
+    #[test]
+    fn forbidden_selects_kekule_structure() {
+        let mut pairing = Pairing::new();
+
+        constrained_matching(
+            &benzene(), &[ ], &[ (1, 0) ], &mut pairing
+        ).unwrap();
+
+        assert_eq!(
+            edges(&pairing),
+            [ (1, 2), (3, 4), (0, 5) ].iter().cloned().collect()
+        )
+    }
+
+    #[test]
+    fn conflicting_seed_removed() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+        pairing.pair(4, 5);
+        constrained_matching(
+            &benzene(), &[ (3, 4) ], &[ ], &mut pairing
+        ).unwrap();
+
+        assert_eq!(
+            edges(&pairing),
+            [ (1, 2), (3, 4), (0, 5) ].iter().cloned().collect()
+        )
+    }
+
+    #[test]
+    fn degrades_to_maximum() {
+        // path 0-1-2-3 with (1, 2) required leaves 0 and 3 unpaired
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        constrained_matching(&graph, &[ (1, 2) ], &[ ], &mut pairing).unwrap();
+
+        assert_eq!(edges(&pairing), [ (1, 2) ].iter().cloned().collect())
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+use std::error;
+
+use crate::graph;
+
+#[derive(Debug,PartialEq,Eq)]
+pub enum Error {
+    MissingRoot,
+    NotBipartite(usize, usize),
+    NotMaximum,
+    NotAlternating(usize),
+    Infeasible(usize, usize),
+    Graph(graph::Error)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingRoot => write!(f, "blossom root not found"),
+            Error::NotBipartite(sid, tid) => {
+                write!(f, "odd cycle through edge: ({}, {})", sid, tid)
+            },
+            Error::NotMaximum => write!(f, "pairing is not maximum"),
+            Error::NotAlternating(id) => {
+                write!(f, "path does not alternate at: {}", id)
+            },
+            Error::Infeasible(sid, tid) => {
+                write!(f, "required edge can't be matched: ({}, {})", sid, tid)
+            },
+            Error::Graph(_) => write!(f, "graph error")
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::MissingRoot => None,
+            Error::NotBipartite(_, _) => None,
+            Error::NotMaximum => None,
+            Error::NotAlternating(_) => None,
+            Error::Infeasible(_, _) => None,
+            Error::Graph(error) => Some(error)
+        }
+    }
+}
+
+impl From<graph::Error> for Error {
+    fn from(error: graph::Error) -> Self {
+        Error::Graph(error)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use super::*;
+
+    #[test]
+    fn missing_root() {
+        assert_eq!(Error::MissingRoot.to_string(), "blossom root not found")
+    }
+
+    #[test]
+    fn not_bipartite() {
+        assert_eq!(
+            Error::NotBipartite(1, 2).to_string(),
+            "odd cycle through edge: (1, 2)"
+        )
+    }
+
+    #[test]
+    fn not_alternating() {
+        assert_eq!(
+            Error::NotAlternating(4).to_string(),
+            "path does not alternate at: 4"
+        )
+    }
+
+    #[test]
+    fn infeasible() {
+        assert_eq!(
+            Error::Infeasible(0, 1).to_string(),
+            "required edge can't be matched: (0, 1)"
+        )
+    }
+
+    #[test]
+    fn graph() {
+        assert_eq!(
+            Error::Graph(graph::Error::UnknownId(3)).to_string(),
+            "graph error"
+        )
+    }
+}
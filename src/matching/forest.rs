@@ -1,16 +1,27 @@
 use std::collections::HashMap;
 use std::collections::hash_map::Entry::{ Occupied, Vacant };
+use std::hash::BuildHasher;
 
-#[derive(Debug,PartialEq)]
-pub struct Forest {
-    parents: HashMap<usize, Entry>,
+use crate::hash::DefaultBuildHasher;
+use crate::pool::Clear;
+
+#[derive(Debug)]
+pub struct Forest<S = DefaultBuildHasher> {
+    parents: HashMap<usize, Entry, S>,
     nodes: Vec<usize>
 }
 
+#[cfg(test)]
 impl Forest {
     pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+}
+
+impl<S: BuildHasher> Forest<S> {
+    pub fn with_hasher(hasher: S) -> Self {
         Self {
-            parents: HashMap::new(),
+            parents: HashMap::with_hasher(hasher),
             nodes: Vec::new()
         }
     }
@@ -33,7 +44,7 @@ impl Forest {
 
         match self.parents.entry(node) {
             Vacant(entry) => {
-                entry.insert(Entry { parent: Some(parent), parity: parity });
+                entry.insert(Entry { parent: Some(parent), parity });
                 self.nodes.push(node);
             },
             Occupied(_) => panic!("duplicate node: {}", node)
@@ -71,6 +82,13 @@ impl Forest {
     }
 }
 
+impl<S> Clear for Forest<S> {
+    fn clear(&mut self) {
+        self.parents.clear();
+        self.nodes.clear()
+    }
+}
+
 #[derive(Debug,PartialEq)]
 struct Entry {
     parent: Option<usize>,
@@ -249,4 +267,20 @@ mod even_nodes {
             HashSet::from_iter([ 0, 3, 2, 5, 6 ].iter().cloned())
         )
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod clear {
+    use super::*;
+
+    #[test]
+    fn p2() {
+        let mut forest = Forest::new();
+
+        forest.add_root(0);
+        forest.add_edge(0, 1);
+        forest.clear();
+
+        assert_eq!(forest.path(1), None);
+        assert_eq!(forest.even_nodes().count(), 0)
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::{ HashMap, HashSet, VecDeque };
+use std::collections::hash_map::Entry;
+use std::hash::BuildHasher;
+
+use crate::graph::{ self, Graph };
+use super::{ Pairing, Error };
+
+/// Returns a minimum vertex cover of bipartite graph, given a maximum
+/// pairing over it. By König's theorem the cover has one node per pair.
+/// Nodes are returned in the order of `graph.ids()`.
+///
+/// The cover is built from the nodes reachable by alternating paths from
+/// unpaired nodes on one side. Returns Error if graph is not bipartite, if
+/// a pair isn't an edge of graph, or if pairing is not maximum.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::DefaultGraph;
+/// use gamma::matching::{
+///     maximum_matching, konig_vertex_cover, Pairing, Error
+/// };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 3), (2, 3), (4, 3)
+///     ])?;
+///     let mut pairing = Pairing::new();
+///
+///     maximum_matching(&graph, &mut pairing);
+///
+///     assert_eq!(konig_vertex_cover(&graph, &pairing)?, vec![ 0, 3 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn konig_vertex_cover<G: Graph, S: BuildHasher>(
+    graph: &G, pairing: &Pairing<S>
+) -> Result<Vec<usize>, Error> {
+    let left = bipartition(graph)?;
+
+    for (sid, tid) in pairing.edges() {
+        if !graph.has_edge(sid, tid)? {
+            return Err(Error::Graph(graph::Error::MissingEdge(sid, tid)));
+        }
+    }
+
+    let mut reached = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    for id in graph.ids() {
+        if left[&id] && !pairing.has_node(id) {
+            reached.insert(id);
+            queue.push_back(id);
+        }
+    }
+
+    while let Some(id) = queue.pop_front() {
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            if !reached.insert(neighbor) {
+                continue;
+            } else if !pairing.has_node(neighbor) {
+                return Err(Error::NotMaximum);
+            }
+
+            let mate = pairing.mate(neighbor);
+
+            if reached.insert(mate) {
+                queue.push_back(mate);
+            }
+        }
+    }
+
+    Ok(graph.ids().filter(|id| left[id] != reached.contains(id)).collect())
+}
+
+// Maps each id to true if it's on the left, coloring each component from
+// its first node.
+fn bipartition<G: Graph>(graph: &G) -> Result<HashMap<usize, bool>, Error> {
+    let mut result = HashMap::new();
+
+    for root in graph.ids() {
+        if result.contains_key(&root) {
+            continue;
+        }
+
+        let mut queue = VecDeque::new();
+
+        result.insert(root, true);
+        queue.push_back(root);
+
+        while let Some(id) = queue.pop_front() {
+            let side = result[&id];
+
+            for neighbor in graph.neighbors(id).expect("neighbors") {
+                match result.entry(neighbor) {
+                    Entry::Occupied(entry) => if *entry.get() == side {
+                        return Err(Error::NotBipartite(id, neighbor));
+                    },
+                    Entry::Vacant(entry) => {
+                        entry.insert(!side);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod konig_vertex_cover {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::matching::maximum_matching;
+
+    #[test]
+    fn odd_cycle() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+        let pairing = Pairing::new();
+
+        assert_eq!(
+            konig_vertex_cover(&graph, &pairing),
+            Err(Error::NotBipartite(1, 2))
+        )
+    }
+
+    #[test]
+    fn pair_not_in_graph() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 2);
+
+        assert_eq!(
+            konig_vertex_cover(&graph, &pairing),
+            Err(Error::Graph(graph::Error::MissingEdge(0, 2)))
+        )
+    }
+
+    #[test]
+    fn not_maximum() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(1, 2);
+
+        assert_eq!(konig_vertex_cover(&graph, &pairing), Err(Error::NotMaximum))
+    }
+
+    #[test]
+    fn empty_pairing_edgeless() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ],
+            vec![ ]
+        ]).unwrap();
+        let pairing = Pairing::new();
+
+        assert_eq!(konig_vertex_cover(&graph, &pairing), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn c6_p2() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (6, 7)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        maximum_matching(&graph, &mut pairing);
+
+        let cover = konig_vertex_cover(&graph, &pairing).unwrap();
+
+        assert_eq!(cover.len(), pairing.order() / 2);
+
+        for (sid, tid) in graph.edges() {
+            assert!(cover.contains(&sid) || cover.contains(&tid))
+        }
+    }
+
+    #[test]
+    fn unbalanced() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 3), (1, 3), (2, 3), (2, 4), (5, 4)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        maximum_matching(&graph, &mut pairing);
+
+        assert_eq!(konig_vertex_cover(&graph, &pairing), Ok(vec![ 3, 4 ]))
+    }
+}
@@ -1,16 +1,27 @@
 use std::collections::{ HashMap, HashSet };
 use std::collections::hash_map::Entry::{ Occupied, Vacant };
+use std::hash::BuildHasher;
 
-pub struct Marker {
-    nodes: HashSet<usize>,
-    edges: HashMap<usize, Vec<usize>>
+use crate::hash::DefaultBuildHasher;
+use crate::pool::Clear;
+
+pub struct Marker<S = DefaultBuildHasher> {
+    nodes: HashSet<usize, S>,
+    edges: HashMap<usize, Vec<usize>, S>
 }
 
+#[cfg(test)]
 impl Marker {
     pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+}
+
+impl<S: BuildHasher> Marker<S> {
+    pub fn with_hasher(hasher: S) -> Self where S: Clone {
         Self {
-            nodes: HashSet::new(),
-            edges: HashMap::new()
+            nodes: HashSet::with_hasher(hasher.clone()),
+            edges: HashMap::with_hasher(hasher)
         }
     }
 
@@ -56,6 +67,13 @@ impl Marker {
     }
 }
 
+impl<S> Clear for Marker<S> {
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear()
+    }
+}
+
 #[cfg(test)]
 mod mark_node {
     use super::*;
@@ -101,7 +119,7 @@ mod has_node {
     fn outside() {
         let marker = Marker::new();
 
-        assert_eq!(marker.has_node(0), false)
+        assert!(!marker.has_node(0))
     }
 
     #[test]
@@ -110,7 +128,7 @@ mod has_node {
 
         marker.mark_node(0);
 
-        assert_eq!(marker.has_node(0), true)
+        assert!(marker.has_node(0))
     }
 }
 
@@ -122,7 +140,7 @@ mod has_edge {
     fn outside() {
         let marker = Marker::new();
 
-        assert_eq!(marker.has_edge(0, 1), false);
+        assert!(!marker.has_edge(0, 1));
     }
 
     #[test]
@@ -131,7 +149,7 @@ mod has_edge {
 
         marker.mark_edge(0, 1);
 
-        assert_eq!(marker.has_edge(0, 1), true);
+        assert!(marker.has_edge(0, 1));
     }
 
     #[test]
@@ -140,6 +158,22 @@ mod has_edge {
 
         marker.mark_edge(0, 1);
 
-        assert_eq!(marker.has_edge(1, 0), true);
+        assert!(marker.has_edge(1, 0));
+    }
+}
+#[cfg(test)]
+mod clear {
+    use super::*;
+
+    #[test]
+    fn marked() {
+        let mut marker = Marker::new();
+
+        marker.mark_node(0);
+        marker.mark_edge(0, 1);
+        marker.clear();
+
+        assert!(!marker.has_node(0));
+        assert!(!marker.has_edge(0, 1))
     }
-}
\ No newline at end of file
+}
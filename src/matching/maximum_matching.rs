@@ -1,8 +1,13 @@
-use crate::graph::{ Graph };
+use std::hash::BuildHasher;
+
+use crate::graph::{ Graph, DefaultGraph };
 use super::pairing::Pairing;
 use super::forest::Forest;
 use super::marker::Marker;
 use super::blossom::Blossom;
+use super::observer::Observer;
+use crate::budget::{ Budget, Interrupted };
+use crate::pool::Pool;
 
 /// Performs a maximum matching over the Graph.
 /// 
@@ -10,6 +15,9 @@ use super::blossom::Blossom;
 /// it may be helpful to try a greedy matching, falling back to maximum
 /// matching if the matching isn't perfect.
 /// 
+/// The Pairing's BuildHasher is also used for the search's internal
+/// bookkeeping.
+/// 
 /// For more on matching, see: *[The Maximum Matching Problem](https://depth-first.com/articles/2019/04/02/the-maximum-matching-problem/)*.
 /// 
 /// ```rust
@@ -34,21 +42,226 @@ use super::blossom::Blossom;
 ///     Ok(())
 /// }
 /// ```
-pub fn maximum_matching<'a, G: Graph>(
-    graph: &'a G, pairing: &'a mut Pairing
-) {
-    while let Some(path) = augmenting_path(graph, pairing) {
-        pairing.augment(path);
-        maximum_matching(graph, pairing);
+pub fn maximum_matching<'a, G, S>(graph: &'a G, pairing: &'a mut Pairing<S>)
+where G: Graph, S: BuildHasher + Default + Clone {
+    maximum_matching_with_observer(graph, pairing, &mut ())
+}
+
+/// Performs a maximum matching over the Graph, as `maximum_matching` does,
+/// reporting progress to observer. Each phase is one search for an
+/// augmenting path; the final phase finds none.
+pub fn maximum_matching_with_observer<'a, G, S, O>(
+    graph: &'a G, pairing: &'a mut Pairing<S>, observer: &mut O
+)
+where G: Graph, S: BuildHasher + Default + Clone, O: Observer {
+    run(graph, pairing, observer, &mut Budget::unlimited())
+        .expect("unlimited budget")
+}
+
+/// Performs a maximum matching over the Graph, as `maximum_matching` does,
+/// checking budget as the search proceeds. Returns Interrupted if budget is
+/// spent first, in which case pairing holds a valid, but possibly not
+/// maximum, matching. Nested blossoms are searched with an explicit stack
+/// rather than by recursion, so no input overflows the call stack. Each
+/// nested blossom still counts as one level of depth, so
+/// `Budget::with_depth` limits how deeply blossoms may nest, and with it
+/// the memory held by contracted graphs.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::matching::{ maximum_matching_with_budget, Pairing };
+/// use gamma::budget::{ Budget, Interrupted };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3)
+///     ])?;
+///     let mut pairing = Pairing::new();
+///     let mut budget = Budget::unlimited();
+///
+///     budget.token().cancel();
+///
+///     assert_eq!(
+///         maximum_matching_with_budget(&graph, &mut pairing, &mut budget),
+///         Err(Interrupted::Cancelled)
+///     );
+///     assert_eq!(pairing.order(), 0);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn maximum_matching_with_budget<'a, G, S>(
+    graph: &'a G, pairing: &'a mut Pairing<S>, budget: &mut Budget
+) -> Result<(), Interrupted>
+where G: Graph, S: BuildHasher + Default + Clone {
+    run(graph, pairing, &mut (), budget)
+}
+
+fn run<'a, G, S, O>(
+    graph: &'a G,
+    pairing: &'a mut Pairing<S>,
+    observer: &mut O,
+    budget: &mut Budget
+) -> Result<(), Interrupted>
+where G: Graph, S: BuildHasher + Default + Clone, O: Observer {
+    let mut context = Context {
+        observer,
+        forests: Pool::new(),
+        markers: Pool::new()
+    };
+
+    loop {
+        context.observer.phase_started();
+
+        let path = augmenting_path(graph, pairing, &mut context, budget);
+
+        context.observer.phase_finished();
+
+        match path? {
+            Some(path) => {
+                context.observer.augmenting_path(&path);
+                pairing.augment(path);
+            },
+            None => break Ok(())
+        }
+    }
+}
+
+/// State shared by every phase of a search, including the contracted
+/// searches of blossoms. Forests and Markers are recycled through pools.
+struct Context<'c, S, O> {
+    observer: &'c mut O,
+    forests: Pool<Forest<S>>,
+    markers: Pool<Marker<S>>
+}
+
+/// A blossom together with the graph and pairing it contracts to. Nested
+/// blossoms are kept as a stack of levels rather than by recursion, so the
+/// depth of nesting is bounded by memory, not by the call stack.
+struct Level<S> {
+    blossom: Blossom,
+    graph: DefaultGraph,
+    pairing: Pairing<S>
+}
+
+impl<S: BuildHasher + Default> Level<S> {
+    fn new<G: Graph>(
+        left: Vec<usize>, right: Vec<usize>, graph: &G, pairing: &Pairing<S>
+    ) -> Self {
+        let blossom = Blossom::new(free_id(graph), left, right)
+            .expect("blossom");
+        let contracted_graph = blossom.contract_graph(graph)
+            .expect("bad graph");
+        let contracted_pairing = blossom.contract_pairing(pairing);
+
+        Self {
+            blossom,
+            graph: contracted_graph,
+            pairing: contracted_pairing
+        }
+    }
+}
+
+/// The outcome of one search of a graph, before any blossom is contracted.
+enum Found {
+    Path(Vec<usize>),
+    Blossom(Vec<usize>, Vec<usize>)
+}
+
+fn augmenting_path<G, S, O>(
+    graph: &G,
+    pairing: &Pairing<S>,
+    context: &mut Context<S, O>,
+    budget: &mut Budget
+) -> Result<Option<Vec<usize>>, Interrupted>
+where G: Graph, S: BuildHasher + Default + Clone, O: Observer {
+    let mut levels = Vec::new();
+    let path = contract(graph, pairing, &mut levels, context, budget);
+
+    for _ in 0..levels.len() {
+        budget.leave();
+    }
+
+    Ok(path?.map(|path| lift(path, graph, levels)))
+}
+
+/// Searches graph, and then each contracted graph in turn, until a search
+/// ends without a blossom. Each blossom pushes one level, and one level of
+/// budget depth, so `Budget::with_depth` limits how deeply blossoms nest.
+fn contract<G, S, O>(
+    graph: &G,
+    pairing: &Pairing<S>,
+    levels: &mut Vec<Level<S>>,
+    context: &mut Context<S, O>,
+    budget: &mut Budget
+) -> Result<Option<Vec<usize>>, Interrupted>
+where G: Graph, S: BuildHasher + Default + Clone, O: Observer {
+    loop {
+        let found = match levels.last() {
+            Some(level) => find(&level.graph, &level.pairing, context, budget),
+            None => find(graph, pairing, context, budget)
+        };
+        let (left, right) = match found? {
+            Some(Found::Path(path)) => return Ok(Some(path)),
+            Some(Found::Blossom(left, right)) => (left, right),
+            None => return Ok(None)
+        };
+        let level = match levels.last() {
+            Some(level) => Level::new(left, right, &level.graph, &level.pairing),
+            None => Level::new(left, right, graph, pairing)
+        };
+
+        context.observer.blossom(level.blossom.nodes());
+        budget.enter()?;
+        levels.push(level);
     }
 }
 
-fn augmenting_path<'a, G: Graph>(
-    graph: &'a G, pairing: &'a mut Pairing
-) -> Option<Vec<usize>> {
-    let mut forest = Forest::new();
-    let mut marker = Marker::new();
+/// Lifts a path found in the innermost contracted graph back through each
+/// level to graph.
+fn lift<G: Graph, S>(
+    mut path: Vec<usize>, graph: &G, mut levels: Vec<Level<S>>
+) -> Vec<usize> {
+    while let Some(level) = levels.pop() {
+        path = match levels.last() {
+            Some(parent) => level.blossom.lift(path, &parent.graph),
+            None => level.blossom.lift(path, graph)
+        }.expect("lift");
+    }
+
+    path
+}
+
+fn find<G, S, O>(
+    graph: &G,
+    pairing: &Pairing<S>,
+    context: &mut Context<S, O>,
+    budget: &mut Budget
+) -> Result<Option<Found>, Interrupted>
+where G: Graph, S: BuildHasher + Default + Clone, O: Observer {
+    let mut forest = context.forests.take(
+        || Forest::with_hasher(S::default())
+    );
+    let mut marker = context.markers.take(
+        || Marker::with_hasher(S::default())
+    );
+    let result = search(graph, pairing, &mut forest, &mut marker, budget);
+
+    context.forests.give(forest);
+    context.markers.give(marker);
+
+    result
+}
 
+fn search<G, S>(
+    graph: &G,
+    pairing: &Pairing<S>,
+    forest: &mut Forest<S>,
+    marker: &mut Marker<S>,
+    budget: &mut Budget
+) -> Result<Option<Found>, Interrupted>
+where G: Graph, S: BuildHasher {
     for (sid, tid) in pairing.edges() {
         marker.mark_edge(sid, tid);
     }
@@ -59,22 +272,14 @@ fn augmenting_path<'a, G: Graph>(
         }
     }
 
-    loop {
-        let v = match some_v(&forest, &marker) {
-            Some(node) => node,
-            None => break
-        };
+    while let Some(v) = some_v(forest, marker) {
+        while let Some(w) = some_w(v, graph, marker) {
+            budget.check()?;
 
-        loop {
-            let w = match some_w(v, graph, &marker) {
-                Some(node) => node,
-                None => break
-            };
-            
             match forest.path(w) {
                 Some(path_w) => {
                     if path_w.len() % 2 == 1 {
-                        return even_path(v, path_w, graph, &forest, pairing)
+                        return Ok(Some(even_path(v, path_w, forest)))
                     }
                 },
                 None => {
@@ -89,14 +294,18 @@ fn augmenting_path<'a, G: Graph>(
         marker.mark_node(v);
     }
 
-    None
+    Ok(None)
 }
 
-fn some_v(forest: &Forest, marker: &Marker) -> Option<usize> {
+fn some_v<S: BuildHasher>(
+    forest: &Forest<S>, marker: &Marker<S>
+) -> Option<usize> {
     forest.even_nodes().find(|id| !marker.has_node(*id))
 }
 
-fn some_w<G: Graph>(v: usize, graph: &G, marker: &Marker) -> Option<usize> {
+fn some_w<G: Graph, S: BuildHasher>(
+    v: usize, graph: &G, marker: &Marker<S>
+) -> Option<usize> {
     graph.neighbors(v).expect("neighbors of v")
         .find(|&id| !marker.has_edge(v, id))
     // graph.neighbors(v)
@@ -104,36 +313,30 @@ fn some_w<G: Graph>(v: usize, graph: &G, marker: &Marker) -> Option<usize> {
     //     .find(|&id| !marker.has_edge(v, id))
 }
 
-fn even_path<G: Graph>(
-    v: usize,
-    mut path_w: Vec<usize>,
-    graph: &G,
-    forest: &Forest,
-    pairing: &Pairing
-) -> Option<Vec<usize>> {
+fn even_path<S: BuildHasher>(
+    v: usize, mut path_w: Vec<usize>, forest: &Forest<S>
+) -> Found {
     let mut path_v = forest.path(v).expect("v not in forest");
 
     if path_v.last() == path_w.last() {
-        process_blossom(path_v, path_w, graph, pairing)
+        Found::Blossom(path_v, path_w)
     } else {
         path_v.reverse();
         path_v.append(&mut path_w);
 
-        Some(path_v)
+        Found::Path(path_v)
     }
 }
 
-fn process_blossom<G:Graph>(
-    left: Vec<usize>, right: Vec<usize>, graph: &G, pairing: &Pairing
-) -> Option<Vec<usize>> {
+/// Returns an id not used by graph, for a contracted blossom. This is one
+/// more than the largest id unless that would overflow, in which case the
+/// smallest unused id is taken.
+fn free_id<G: Graph>(graph: &G) -> usize {
     let max_id = graph.ids().max().expect("no max id");
-    let blossom =  Blossom::new(max_id + 1, left, right);
-    let contracted_graph = blossom.contract_graph(graph).expect("bad graph");
-    let mut contracted_pairing = blossom.contract_pairing(&pairing);
 
-    match augmenting_path(&contracted_graph, &mut contracted_pairing) {
-        Some(path) => Some(blossom.lift(path, graph)),
-        None => None
+    match max_id.checked_add(1) {
+        Some(id) => id,
+        None => (0..).find(|&id| !graph.has_id(id)).expect("free id")
     }
 }
 
@@ -143,6 +346,8 @@ mod tests {
     use std::collections::HashMap;
     use std::convert::TryFrom;
     use crate::graph::DefaultGraph;
+    use crate::hash::FxBuildHasher;
+    use crate::matching::Stats;
 
     #[test]
     fn empty() {
@@ -157,6 +362,158 @@ mod tests {
         )
     }
 
+    #[test]
+    fn c5_stats() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+        let mut stats = Stats::default();
+
+        pairing.pair(1, 2);
+        pairing.pair(3, 4);
+        maximum_matching_with_observer(&graph, &mut pairing, &mut stats);
+
+        assert_eq!(stats.augmenting_paths, 0);
+        assert_eq!(stats.blossoms, 1);
+        assert_eq!(stats.phases.len(), 1)
+    }
+
+    #[test]
+    fn out_of_steps() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+        let mut budget = Budget::unlimited().with_steps(1);
+
+        assert_eq!(
+            maximum_matching_with_budget(&graph, &mut pairing, &mut budget),
+            Err(Interrupted::OutOfSteps)
+        );
+        assert_eq!(pairing.order(), 2);
+
+        let mut budget = Budget::unlimited();
+
+        assert_eq!(
+            maximum_matching_with_budget(&graph, &mut pairing, &mut budget),
+            Ok(())
+        );
+        assert_eq!(pairing.order(), 6)
+    }
+
+    #[test]
+    fn depth_limit() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+        let mut budget = Budget::unlimited().with_depth(0);
+
+        pairing.pair(1, 2);
+        pairing.pair(3, 4);
+
+        assert_eq!(
+            maximum_matching_with_budget(&graph, &mut pairing, &mut budget),
+            Err(Interrupted::LimitExceeded)
+        );
+        assert_eq!(pairing.order(), 4)
+    }
+
+    // A tower of triangles, each pair matched and joined to both nodes of
+    // the pair below, so every blossom contracts into the next.
+    fn tower(levels: usize) -> (DefaultGraph, Pairing) {
+        let mut graph = DefaultGraph::new();
+        let mut pairing = Pairing::new();
+
+        graph.add_node(0).unwrap();
+
+        for level in 0..levels {
+            let (sid, tid) = (2 * level + 1, 2 * level + 2);
+
+            graph.add_node(sid).unwrap();
+            graph.add_node(tid).unwrap();
+            graph.add_edge(sid, tid).unwrap();
+            pairing.pair(sid, tid);
+
+            if level == 0 {
+                graph.add_edge(0, sid).unwrap();
+                graph.add_edge(0, tid).unwrap();
+            } else {
+                for below in &[ sid - 2, sid - 1 ] {
+                    graph.add_edge(*below, sid).unwrap();
+                    graph.add_edge(*below, tid).unwrap();
+                }
+            }
+        }
+
+        (graph, pairing)
+    }
+
+    #[test]
+    fn nested_blossoms() {
+        let (graph, mut pairing) = tower(1000);
+        let mut stats = Stats::default();
+
+        maximum_matching_with_observer(&graph, &mut pairing, &mut stats);
+
+        assert_eq!(stats.blossoms, 1000);
+        assert_eq!(pairing.order(), 2000)
+    }
+
+    #[test]
+    fn nested_blossoms_depth_limit() {
+        let (graph, mut pairing) = tower(20);
+        let mut budget = Budget::unlimited().with_depth(19);
+
+        assert_eq!(
+            maximum_matching_with_budget(&graph, &mut pairing, &mut budget),
+            Err(Interrupted::LimitExceeded)
+        );
+
+        let mut budget = Budget::unlimited().with_depth(20);
+
+        assert_eq!(
+            maximum_matching_with_budget(&graph, &mut pairing, &mut budget),
+            Ok(())
+        )
+    }
+
+    #[test]
+    #[cfg(not(feature = "u32-ids"))]
+    fn c5_with_largest_ids() {
+        let max = usize::MAX;
+        let graph = DefaultGraph::try_from(vec![
+            (max - 4, max - 3),
+            (max - 3, max - 2),
+            (max - 2, max - 1),
+            (max - 1, max),
+            (max, max - 4)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(max - 3, max - 2);
+        pairing.pair(max - 1, max);
+        maximum_matching(&graph, &mut pairing);
+
+        assert_eq!(pairing.order(), 4)
+    }
+
+    #[test]
+    fn p4_with_hasher() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::with_hasher(FxBuildHasher::default());
+
+        maximum_matching(&graph, &mut pairing);
+
+        assert_eq!(
+            pairing.edges().collect::<HashMap<_,_>>(),
+            [ (0, 1), (2, 3) ].iter().cloned().collect::<HashMap<_,_>>()
+        )
+    }
+
     #[test]
     fn p2() {
         let graph = DefaultGraph::try_from(vec![
@@ -452,4 +809,35 @@ mod tests {
             ].iter().cloned().collect::<HashMap<_,_>>()
         )
     }
-}
\ No newline at end of file
+    #[test]
+    fn blossom_entered_away_from_base() {
+        let graph = DefaultGraph::try_from(vec![
+            (3, 4), (3, 5), (1, 5), (2, 1), (2, 3), (6, 3), (4, 2), (4, 6)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        maximum_matching(&graph, &mut pairing);
+
+        assert_eq!(pairing.order(), 6);
+
+        for (sid, tid) in pairing.edges() {
+            assert_eq!(graph.has_edge(sid, tid), Ok(true))
+        }
+    }
+
+    #[test]
+    fn blossom_entered_away_from_base_after_greedy() {
+        let graph = DefaultGraph::try_from(vec![
+            (7, 5), (7, 4), (7, 0), (0, 6), (2, 5), (0, 2), (4, 5), (4, 0)
+        ]).unwrap();
+        let mut pairing = crate::matching::greedy(&graph);
+
+        maximum_matching(&graph, &mut pairing);
+
+        assert_eq!(pairing.order(), 6);
+
+        for (sid, tid) in pairing.edges() {
+            assert_eq!(graph.has_edge(sid, tid), Ok(true))
+        }
+    }
+}
@@ -4,7 +4,23 @@ mod blossom;
 mod marker;
 mod maximum_matching;
 mod greedy;
+mod alternating_paths;
+mod observer;
+mod error;
+#[allow(clippy::module_inception)]
+mod konig_vertex_cover;
+mod constrained;
 
 pub use pairing::Pairing;
-pub use maximum_matching::maximum_matching;
-pub use greedy::greedy;
\ No newline at end of file
+pub use blossom::Blossom;
+pub use error::Error;
+pub use maximum_matching::{
+    maximum_matching,
+    maximum_matching_with_observer,
+    maximum_matching_with_budget
+};
+pub use observer::{ Observer, Stats };
+pub use greedy::greedy;
+pub use alternating_paths::{ alternating_paths, augmenting_paths };
+pub use konig_vertex_cover::konig_vertex_cover;
+pub use constrained::constrained_matching;
@@ -0,0 +1,71 @@
+use std::time::{ Duration, Instant };
+
+/// Receives progress events from `maximum_matching_with_observer`. Every
+/// method has an empty default, so implementors override only the events
+/// they need.
+pub trait Observer {
+    /// Called as the search for an augmenting path begins.
+    fn phase_started(&mut self) { }
+
+    /// Called as the search for an augmenting path ends, whether or not one
+    /// was found.
+    fn phase_finished(&mut self) { }
+
+    /// Called with each augmenting path found, before it's applied.
+    fn augmenting_path(&mut self, _path: &[usize]) { }
+
+    /// Called with the nodes of each blossom as it's contracted.
+    fn blossom(&mut self, _nodes: &[usize]) { }
+}
+
+impl Observer for () { }
+
+/// An Observer collecting counts and per-phase timing.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::matching::{ maximum_matching_with_observer, Pairing, Stats };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3)
+///     ])?;
+///     let mut pairing = Pairing::new();
+///     let mut stats = Stats::default();
+///
+///     maximum_matching_with_observer(&graph, &mut pairing, &mut stats);
+///
+///     assert_eq!(stats.augmenting_paths, 2);
+///     assert_eq!(stats.phases.len(), 3);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Default)]
+pub struct Stats {
+    pub augmenting_paths: usize,
+    pub blossoms: usize,
+    pub phases: Vec<Duration>,
+    started: Option<Instant>
+}
+
+impl Observer for Stats {
+    fn phase_started(&mut self) {
+        self.started = Some(Instant::now());
+    }
+
+    fn phase_finished(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.phases.push(started.elapsed());
+        }
+    }
+
+    fn augmenting_path(&mut self, _path: &[usize]) {
+        self.augmenting_paths += 1;
+    }
+
+    fn blossom(&mut self, _nodes: &[usize]) {
+        self.blossoms += 1;
+    }
+}
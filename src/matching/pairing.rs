@@ -1,15 +1,29 @@
-use std::collections::HashMap;
+use std::collections::{ HashMap, HashSet };
 use std::collections::hash_map::Entry::{ Occupied, Vacant };
+use std::hash::BuildHasher;
 
-#[derive(Debug,PartialEq)]
-pub struct Pairing {
-    pairs: HashMap<usize, usize>
+use crate::hash::DefaultBuildHasher;
+use crate::graph::Graph;
+use super::{ maximum_matching, Error };
+
+/// A set of node pairs, as produced by matching. Pairs are stored in a
+/// HashMap using the BuildHasher S.
+#[derive(Debug)]
+pub struct Pairing<S = DefaultBuildHasher> {
+    pairs: HashMap<usize, usize, S>
 }
 
 impl Pairing {
     pub fn new() -> Self {
+        Self::with_hasher(DefaultBuildHasher::default())
+    }
+}
+
+impl<S: BuildHasher> Pairing<S> {
+    /// Returns an empty Pairing that will use hasher to store pairs.
+    pub fn with_hasher(hasher: S) -> Self {
         Self {
-            pairs: HashMap::new()
+            pairs: HashMap::with_hasher(hasher)
         }
     }
 
@@ -44,6 +58,101 @@ impl Pairing {
         }
     }
 
+    /// Replaces this Pairing with its symmetric difference with the edges
+    /// of an alternating path or cycle through nodes. A cycle is given by
+    /// repeating its first node at the end. Edges of nodes already paired
+    /// are removed, and the rest are paired, so an augmenting path grows
+    /// the Pairing by one edge and an alternating cycle swaps its edges
+    /// while keeping the same order.
+    ///
+    /// Returns NotAlternating, leaving this Pairing unchanged, if the
+    /// result would not be a matching: a node repeats, an inner node has
+    /// no paired edge in nodes, or an end is paired outside of nodes.
+    /// Edges are not checked against any Graph.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use gamma::matching::{ Pairing, Error };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let mut pairing = Pairing::new();
+    ///
+    ///     pairing.pair(0, 1);
+    ///     pairing.pair(2, 3);
+    ///     pairing.symmetric_difference(&[ 0, 1, 2, 3, 0 ])?;
+    ///
+    ///     assert_eq!(
+    ///         pairing.edges().collect::<BTreeSet<_>>(),
+    ///         [ (0, 3), (1, 2) ].iter().cloned().collect::<BTreeSet<_>>()
+    ///     );
+    ///     assert_eq!(
+    ///         pairing.symmetric_difference(&[ 4, 5, 6 ]),
+    ///         Err(Error::NotAlternating(5))
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn symmetric_difference(&mut self, nodes: &[usize]) -> Result<(), Error> {
+        let cycle = nodes.len() > 2 && nodes[0] == nodes[nodes.len() - 1];
+        let nodes = if cycle { &nodes[..nodes.len() - 1] } else { nodes };
+        let mut seen = HashSet::new();
+
+        for &id in nodes {
+            if !seen.insert(id) {
+                return Err(Error::NotAlternating(id));
+            }
+        }
+
+        let mut edges = nodes.windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect::<Vec<_>>();
+
+        if cycle {
+            edges.push((nodes[nodes.len() - 1], nodes[0]));
+        }
+
+        let paired = edges.iter()
+            .map(|&(sid, tid)| self.pairs.get(&sid) == Some(&tid))
+            .collect::<Vec<_>>();
+
+        for (index, &id) in nodes.iter().enumerate() {
+            let before = match index {
+                0 if cycle => Some(paired[paired.len() - 1]),
+                0 => None,
+                _ => Some(paired[index - 1])
+            };
+            let after = paired.get(index).cloned()
+                .filter(|_| cycle || index + 1 < nodes.len());
+            let valid = match (before, after) {
+                (Some(before), Some(after)) => before != after,
+                (Some(false), None) | (None, Some(false)) => {
+                    !self.has_node(id)
+                },
+                _ => true
+            };
+
+            if !valid {
+                return Err(Error::NotAlternating(id));
+            }
+        }
+
+        for (&(sid, tid), &paired) in edges.iter().zip(paired.iter()) {
+            if paired {
+                self.pairs.remove(&sid);
+                self.pairs.remove(&tid);
+            }
+        }
+
+        for (&(sid, tid), &paired) in edges.iter().zip(paired.iter()) {
+            if !paired {
+                self.pair(sid, tid);
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn mate(&self, id: usize) -> usize {
         match self.pairs.get(&id) {
             Some(&mate) => mate,
@@ -51,6 +160,13 @@ impl Pairing {
         }
     }
 
+    /// Removes the pair containing id, if any.
+    pub(crate) fn unpair(&mut self, id: usize) {
+        if let Some(mate) = self.pairs.remove(&id) {
+            self.pairs.remove(&mate);
+        }
+    }
+
     fn insert(&mut self, sid: usize, tid: usize) {
         match self.pairs.entry(sid) {
             Occupied(mut entry) => {
@@ -69,6 +185,76 @@ impl Pairing {
     }
 }
 
+impl<S: BuildHasher + Default + Clone> Pairing<S> {
+    /// Restores this Pairing to a maximum matching over graph after edits
+    /// to the nodes in affected. Pairs touching an affected node are removed
+    /// if either node or the edge between them is no longer in graph. The
+    /// remaining pairs are kept and `maximum_matching` is run from them.
+    /// This is a warm start, not a local search. Fewer phases are needed
+    /// when the edits break few pairs, but each phase still searches from
+    /// every exposed node in graph. An augmenting path through an edited
+    /// edge may join exposed nodes far from affected.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use std::collections::BTreeSet;
+    /// use gamma::graph::{ Error, DefaultGraph };
+    /// use gamma::matching::{ maximum_matching, Pairing };
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let graph = DefaultGraph::try_from(vec![
+    ///         (0, 1), (1, 2), (2, 3)
+    ///     ])?;
+    ///     let mut pairing = Pairing::new();
+    ///
+    ///     maximum_matching(&graph, &mut pairing);
+    ///
+    ///     // replace edge (0, 1) with (0, 3)
+    ///     let graph = DefaultGraph::try_from(vec![
+    ///         (0, 3), (1, 2), (2, 3)
+    ///     ])?;
+    ///
+    ///     pairing.repair(&graph, &[ 0, 1, 3 ]);
+    ///
+    ///     assert_eq!(
+    ///         pairing.edges().collect::<BTreeSet<_>>(),
+    ///         [ (0, 3), (1, 2) ].iter().cloned().collect::<BTreeSet<_>>()
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn repair<G: Graph>(&mut self, graph: &G, affected: &[usize]) {
+        for &id in affected {
+            let mate = match self.pairs.get(&id) {
+                Some(&mate) => mate,
+                None => continue
+            };
+            let valid = graph.has_id(id) && graph.has_id(mate) &&
+                graph.has_edge(id, mate).expect("edge");
+
+            if !valid {
+                self.pairs.remove(&id);
+                self.pairs.remove(&mate);
+            }
+        }
+
+        maximum_matching(graph, self);
+    }
+}
+
+impl<S: BuildHasher + Default> Default for Pairing<S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<S: BuildHasher> PartialEq for Pairing<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pairs == other.pairs
+    }
+}
+
 #[cfg(test)]
 mod order {
     use super::*;
@@ -100,7 +286,7 @@ mod edges {
     fn default() {
         let pairing = Pairing::new();
 
-        assert_eq!(pairing.pairs.is_empty(), true)
+        assert!(pairing.pairs.is_empty())
     }
 
     #[test]
@@ -113,7 +299,7 @@ mod edges {
         assert_eq!(
             pairing.pairs,
             [ (0, 1), (2, 3), (1, 0), (3, 2) ]
-                .iter().cloned().collect::<HashMap<_,_>>()
+                .iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
         )
     }
 
@@ -126,7 +312,7 @@ mod edges {
 
         assert_eq!(
             pairing.pairs,
-            [ (0, 1), (1, 0) ].iter().cloned().collect::<HashMap<_,_>>()
+            [ (0, 1), (1, 0) ].iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
         )
     }
 
@@ -139,7 +325,7 @@ mod edges {
 
         assert_eq!(
             pairing.pairs,
-            [ (2, 3), (3, 2) ].iter().cloned().collect::<HashMap<_,_>>()
+            [ (2, 3), (3, 2) ].iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
         )
     }
 
@@ -153,7 +339,7 @@ mod edges {
 
         assert_eq!(
             pairing.pairs,
-            [ (1, 2), (2, 1) ].iter().cloned().collect::<HashMap<_,_>>()
+            [ (1, 2), (2, 1) ].iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
         )
     }
 
@@ -167,7 +353,7 @@ mod edges {
         assert_eq!(
             pairing.pairs,
             [ (0, 1), (2, 3), (1, 0), (3, 2) ]
-                .iter().cloned().collect::<HashMap<_,_>>()
+                .iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
         )
     }
 
@@ -182,7 +368,7 @@ mod edges {
         assert_eq!(
             pairing.pairs,
             [ (0, 1), (2, 3), (1, 0), (3, 2) ]
-                .iter().cloned().collect::<HashMap<_,_>>()
+                .iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
         )
     }
 }
@@ -201,6 +387,113 @@ mod augment {
     }
 }
 
+#[cfg(test)]
+mod symmetric_difference {
+    use super::*;
+
+    fn pairs(pairing: &Pairing) -> HashMap<usize, usize, DefaultBuildHasher> {
+        pairing.pairs.clone()
+    }
+
+    fn expected(edges: &[(usize, usize)]) -> HashMap<usize, usize, DefaultBuildHasher> {
+        edges.iter()
+            .flat_map(|&(sid, tid)| vec![ (sid, tid), (tid, sid) ])
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let mut pairing = Pairing::new();
+
+        assert_eq!(pairing.symmetric_difference(&[ ]), Ok(()));
+        assert_eq!(pairing.order(), 0)
+    }
+
+    #[test]
+    fn augmenting() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(1, 2);
+
+        assert_eq!(pairing.symmetric_difference(&[ 0, 1, 2, 3 ]), Ok(()));
+        assert_eq!(pairs(&pairing), expected(&[ (0, 1), (2, 3) ]))
+    }
+
+    #[test]
+    fn even_path() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+
+        assert_eq!(pairing.symmetric_difference(&[ 0, 1, 2 ]), Ok(()));
+        assert_eq!(pairs(&pairing), expected(&[ (1, 2) ]))
+    }
+
+    #[test]
+    fn diminishing() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+
+        assert_eq!(pairing.symmetric_difference(&[ 0, 1, 2, 3 ]), Ok(()));
+        assert_eq!(pairs(&pairing), expected(&[ (1, 2) ]))
+    }
+
+    #[test]
+    fn hexagon() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+        pairing.pair(4, 5);
+
+        assert_eq!(
+            pairing.symmetric_difference(&[ 0, 1, 2, 3, 4, 5, 0 ]),
+            Ok(())
+        );
+        assert_eq!(pairs(&pairing), expected(&[ (1, 2), (3, 4), (5, 0) ]))
+    }
+
+    #[test]
+    fn odd_cycle() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+
+        assert_eq!(
+            pairing.symmetric_difference(&[ 0, 1, 2, 0 ]),
+            Err(Error::NotAlternating(2))
+        );
+        assert_eq!(pairs(&pairing), expected(&[ (0, 1) ]))
+    }
+
+    #[test]
+    fn end_paired_outside() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 9);
+        pairing.pair(1, 2);
+
+        assert_eq!(
+            pairing.symmetric_difference(&[ 0, 1, 2, 3 ]),
+            Err(Error::NotAlternating(0))
+        )
+    }
+
+    #[test]
+    fn repeated_node() {
+        let mut pairing = Pairing::new();
+
+        pairing.pair(1, 2);
+
+        assert_eq!(
+            pairing.symmetric_difference(&[ 0, 1, 2, 1 ]),
+            Err(Error::NotAlternating(1))
+        )
+    }
+}
+
 #[cfg(test)]
 mod has_node {
     use super::*;
@@ -209,7 +502,7 @@ mod has_node {
     fn default() {
         let pairing = Pairing::new();
 
-        assert_eq!(pairing.has_node(0), false)
+        assert!(!pairing.has_node(0))
     }
 
     #[test]
@@ -218,7 +511,7 @@ mod has_node {
 
         pairing.pair(0, 1);
 
-        assert_eq!(pairing.has_node(0), true)
+        assert!(pairing.has_node(0))
     }
 
     #[test]
@@ -227,7 +520,7 @@ mod has_node {
 
         pairing.pair(0, 1);
 
-        assert_eq!(pairing.has_node(1), true)
+        assert!(pairing.has_node(1))
     }
 }
 
@@ -260,4 +553,69 @@ mod mate {
 
         assert_eq!(pairing.mate(1), 0)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod repair {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unaffected() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+        pairing.repair(&graph, &[ ]);
+
+        assert_eq!(pairing.order(), 4)
+    }
+
+    #[test]
+    fn deleted_node() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+        pairing.repair(&graph, &[ 3 ]);
+
+        assert_eq!(pairing.edges().collect::<Vec<_>>(), [ (0, 1) ])
+    }
+
+    #[test]
+    fn deleted_edge() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(2, 3);
+        pairing.repair(&graph, &[ 1, 2 ]);
+
+        assert_eq!(
+            pairing.pairs,
+            [ (0, 1), (1, 0), (2, 3), (3, 2) ]
+                .iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
+        );
+
+        let graph = DefaultGraph::try_from(vec![
+            (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        pairing.repair(&graph, &[ 0, 1 ]);
+
+        assert_eq!(
+            pairing.pairs,
+            [ (1, 2), (2, 1), (3, 0), (0, 3) ]
+                .iter().cloned().collect::<HashMap<_,_,DefaultBuildHasher>>()
+        )
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::{ HashMap, VecDeque };
+
+use crate::graph::Graph;
+use crate::rng::Rng;
+
+/// Estimated betweenness centrality. See `approx_betweenness`.
+#[derive(Debug,Clone,PartialEq)]
+pub struct Betweenness {
+    /// Estimated number of shortest paths through each node, counting each
+    /// unordered pair of endpoints once.
+    pub scores: HashMap<usize, f64>,
+    /// Number of source nodes sampled.
+    pub samples: usize,
+    pub order: usize
+}
+
+impl Betweenness {
+    /// Returns a bound on the absolute error of every score, holding
+    /// simultaneously with at least the given probability. The bound
+    /// follows from Hoeffding's inequality with a union bound over nodes,
+    /// and is zero when every node was sampled. Panics unless confidence
+    /// lies in [0, 1).
+    pub fn error_bound(&self, confidence: f64) -> f64 {
+        assert!((0.0..1.0).contains(&confidence), "confidence out of range");
+
+        if self.samples == self.order {
+            return 0.0;
+        } else if self.samples == 0 {
+            return f64::INFINITY;
+        }
+
+        let n = self.order as f64;
+        let k = self.samples as f64;
+        let range = (n - 2.0) / 2.0;
+        let alpha = (1.0 - confidence) / n;
+
+        n * range * ((2.0 / alpha).ln() / (2.0 * k)).sqrt()
+    }
+}
+
+/// Returns an estimate of betweenness centrality from shortest paths
+/// leaving up to samples source nodes, chosen without replacement by a
+/// generator seeded with seed. Time is proportional to samples times size,
+/// so large graphs can be handled with a sample much smaller than order.
+/// Scores are exact if samples is at least order.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::metrics::approx_betweenness;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3)
+///     ])?;
+///     let exact = approx_betweenness(&graph, 4, 0);
+///
+///     assert_eq!(exact.scores[&1], 2.0);
+///     assert_eq!(exact.error_bound(0.95), 0.0);
+///
+///     let estimate = approx_betweenness(&graph, 2, 0);
+///
+///     assert!(estimate.error_bound(0.95) > 0.0);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn approx_betweenness<G: Graph>(
+    graph: &G, samples: usize, seed: u64
+) -> Betweenness {
+    let mut ids = graph.ids().collect::<Vec<_>>();
+    let order = ids.len();
+    let samples = samples.min(order);
+    let mut scores = ids.iter().map(|&id| (id, 0.0)).collect::<HashMap<_, _>>();
+
+    Rng::new(seed).partial_shuffle(&mut ids, samples);
+
+    for &source in &ids[0..samples] {
+        for (id, dependency) in dependencies(graph, source) {
+            *scores.get_mut(&id).expect("score") += dependency;
+        }
+    }
+
+    if samples > 0 {
+        let scale = order as f64 / samples as f64 / 2.0;
+
+        for score in scores.values_mut() {
+            *score *= scale;
+        }
+    }
+
+    Betweenness { scores, samples, order }
+}
+
+// Brandes' accumulation of pair dependencies on source.
+fn dependencies<G: Graph>(graph: &G, source: usize) -> HashMap<usize, f64> {
+    let mut stack = Vec::new();
+    let mut predecessors = HashMap::<usize, Vec<usize>>::new();
+    let mut paths = HashMap::new();
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    paths.insert(source, 1.0);
+    distances.insert(source, 0);
+    queue.push_back(source);
+
+    while let Some(id) = queue.pop_front() {
+        let distance = distances[&id];
+        let count = paths[&id];
+
+        stack.push(id);
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            let next = *distances.entry(neighbor).or_insert_with(|| {
+                queue.push_back(neighbor);
+
+                distance + 1
+            });
+
+            if next == distance + 1 {
+                *paths.entry(neighbor).or_insert(0.0) += count;
+                predecessors.entry(neighbor).or_default().push(id);
+            }
+        }
+    }
+
+    let mut result = HashMap::new();
+
+    while let Some(id) = stack.pop() {
+        let dependency = *result.entry(id).or_insert(0.0);
+
+        for &predecessor in predecessors.get(&id).into_iter().flatten() {
+            let share = paths[&predecessor] / paths[&id] * (1.0 + dependency);
+
+            *result.entry(predecessor).or_insert(0.0) += share;
+        }
+    }
+
+    result.insert(source, 0.0);
+
+    result
+}
+
+#[cfg(test)]
+mod approx_betweenness {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+        let result = approx_betweenness(&graph, 10, 0);
+
+        assert!(result.scores.is_empty())
+    }
+
+    #[test]
+    fn no_samples() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let result = approx_betweenness(&graph, 0, 0);
+
+        assert_eq!(result.scores[&1], 0.0)
+    }
+
+    #[test]
+    fn star() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3)
+        ]).unwrap();
+        let result = approx_betweenness(&graph, 4, 1);
+
+        assert_eq!(result.scores[&0], 3.0);
+        assert_eq!(result.scores[&1], 0.0)
+    }
+
+    #[test]
+    fn c4_split_paths() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+        let result = approx_betweenness(&graph, 4, 0);
+
+        for id in 0..4 {
+            assert_eq!(result.scores[&id], 0.5)
+        }
+    }
+
+    #[test]
+    fn same_seed() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5)
+        ]).unwrap();
+
+        assert_eq!(
+            approx_betweenness(&graph, 3, 42),
+            approx_betweenness(&graph, 3, 42)
+        )
+    }
+}
+
+#[cfg(test)]
+mod error_bound {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(approx_betweenness(&graph, 10, 0).error_bound(0.9), 0.0)
+    }
+
+    #[test]
+    fn no_samples() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+
+        assert_eq!(
+            approx_betweenness(&graph, 0, 0).error_bound(0.9),
+            f64::INFINITY
+        )
+    }
+
+    #[test]
+    fn within_bound() {
+        let edges = (0..40).map(|id| (id, id + 1)).collect::<Vec<_>>();
+        let graph = DefaultGraph::try_from(edges).unwrap();
+        let exact = approx_betweenness(&graph, 41, 0);
+        let estimate = approx_betweenness(&graph, 10, 5);
+        let bound = estimate.error_bound(0.99);
+
+        for id in 0..41 {
+            let error = (exact.scores[&id] - estimate.scores[&id]).abs();
+
+            assert!(error <= bound)
+        }
+    }
+}
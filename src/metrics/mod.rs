@@ -0,0 +1,5 @@
+mod summary;
+mod betweenness;
+
+pub use summary::{ summarize, Summary };
+pub use betweenness::{ approx_betweenness, Betweenness };
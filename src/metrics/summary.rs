@@ -0,0 +1,304 @@
+use std::collections::{ HashMap, HashSet, VecDeque };
+use std::fmt;
+
+use crate::graph::Graph;
+use crate::shortest_path::distances;
+
+/// Summary statistics for a Graph. See `summarize`.
+#[derive(Debug,PartialEq)]
+pub struct Summary {
+    pub order: usize,
+    pub size: usize,
+    pub density: f64,
+    pub components: usize,
+    pub min_degree: usize,
+    pub max_degree: usize,
+    pub mean_degree: f64,
+    /// Length of the shortest cycle, or None if the graph is acyclic.
+    pub girth: Option<usize>,
+    /// Lower bound on the largest diameter of any component, found by a
+    /// double sweep of breadth-first searches within each component. The
+    /// component with the most nodes need not have the largest diameter.
+    pub diameter: usize,
+    pub triangles: usize
+}
+
+/// Returns a Summary of graph, characterizing it with a single call.
+///
+/// Girth is computed exactly with a breadth-first search from every node,
+/// so this function takes time proportional to order times size.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::metrics::summarize;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 2 ],
+///         vec![ 0, 2 ],
+///         vec![ 0, 1, 3 ],
+///         vec![ 2 ]
+///     ])?;
+///     let summary = summarize(&graph);
+///
+///     assert_eq!(summary.components, 1);
+///     assert_eq!(summary.girth, Some(3));
+///     assert_eq!(summary.diameter, 2);
+///     assert_eq!(summary.triangles, 1);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn summarize<G: Graph>(graph: &G) -> Summary {
+    let order = graph.order();
+    let size = graph.size();
+    let degrees = graph.ids()
+        .map(|id| graph.degree(id).expect("degree"))
+        .collect::<Vec<_>>();
+
+    Summary {
+        order,
+        size,
+        density: density(order, size),
+        components: components(graph).len(),
+        min_degree: degrees.iter().cloned().min().unwrap_or(0),
+        max_degree: degrees.iter().cloned().max().unwrap_or(0),
+        mean_degree: if order == 0 {
+            0.0
+        } else {
+            degrees.iter().sum::<usize>() as f64 / order as f64
+        },
+        girth: girth(graph),
+        diameter: diameter(graph),
+        triangles: triangles(graph)
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "order: {}", self.order)?;
+        writeln!(f, "size: {}", self.size)?;
+        writeln!(f, "density: {:.3}", self.density)?;
+        writeln!(f, "components: {}", self.components)?;
+        writeln!(
+            f, "degree: min {}, max {}, mean {:.2}",
+            self.min_degree, self.max_degree, self.mean_degree
+        )?;
+
+        match self.girth {
+            Some(girth) => writeln!(f, "girth: {}", girth)?,
+            None => writeln!(f, "girth: none")?
+        }
+
+        writeln!(f, "diameter (estimate): {}", self.diameter)?;
+        write!(f, "triangles: {}", self.triangles)
+    }
+}
+
+fn density(order: usize, size: usize) -> f64 {
+    if order < 2 {
+        0.0
+    } else {
+        2.0 * size as f64 / (order as f64 * (order - 1) as f64)
+    }
+}
+
+fn components<G: Graph>(graph: &G) -> Vec<usize> {
+    let mut visited = HashSet::new();
+    let mut result = Vec::new();
+
+    for id in graph.ids() {
+        if visited.insert(id) {
+            visited.extend(distances(graph, id).keys());
+            result.push(id);
+        }
+    }
+
+    result
+}
+
+fn farthest(distances: &HashMap<usize, usize>) -> (usize, usize) {
+    distances.iter()
+        .map(|(&id, &distance)| (distance, id))
+        .max()
+        .map(|(distance, id)| (id, distance))
+        .expect("root")
+}
+
+fn diameter<G: Graph>(graph: &G) -> usize {
+    components(graph).into_iter().map(|root| {
+        let (far, _) = farthest(&distances(graph, root));
+
+        farthest(&distances(graph, far)).1
+    }).max().unwrap_or(0)
+}
+
+fn girth<G: Graph>(graph: &G) -> Option<usize> {
+    let mut result: Option<usize> = None;
+
+    for root in graph.ids() {
+        let mut depths = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        depths.insert(root, (0, root));
+        queue.push_back(root);
+
+        while let Some(id) = queue.pop_front() {
+            let (depth, parent) = depths[&id];
+
+            for neighbor in graph.neighbors(id).expect("neighbors") {
+                if neighbor == parent {
+                    continue;
+                }
+
+                match depths.get(&neighbor) {
+                    Some(&(other, _)) => {
+                        let length = depth + other + 1;
+
+                        result = Some(result.map_or(length, |min| min.min(length)));
+                    },
+                    None => {
+                        depths.insert(neighbor, (depth + 1, id));
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn triangles<G: Graph>(graph: &G) -> usize {
+    let neighbors = graph.ids().map(|id| {
+        (id, graph.neighbors(id).expect("neighbors").collect::<HashSet<_>>())
+    }).collect::<HashMap<_, _>>();
+    let mut result = 0;
+
+    for (sid, tid) in graph.edges() {
+        result += neighbors[&sid].intersection(&neighbors[&tid]).count();
+    }
+
+    result / 3
+}
+
+#[cfg(test)]
+mod summarize {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(summarize(&graph), Summary {
+            order: 0,
+            size: 0,
+            density: 0.0,
+            components: 0,
+            min_degree: 0,
+            max_degree: 0,
+            mean_degree: 0.0,
+            girth: None,
+            diameter: 0,
+            triangles: 0
+        })
+    }
+
+    #[test]
+    fn p3_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(summarize(&graph), Summary {
+            order: 4,
+            size: 2,
+            density: 2.0 / 6.0,
+            components: 2,
+            min_degree: 0,
+            max_degree: 2,
+            mean_degree: 1.0,
+            girth: None,
+            diameter: 2,
+            triangles: 0
+        })
+    }
+
+    #[test]
+    fn k4() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3 ],
+            vec![ 0, 2, 3 ],
+            vec![ 0, 1, 3 ],
+            vec![ 0, 1, 2 ]
+        ]).unwrap();
+        let summary = summarize(&graph);
+
+        assert_eq!(summary.density, 1.0);
+        assert_eq!(summary.girth, Some(3));
+        assert_eq!(summary.diameter, 1);
+        assert_eq!(summary.triangles, 4)
+    }
+
+    #[test]
+    fn k4_p3() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3 ],
+            vec![ 0, 2, 3 ],
+            vec![ 0, 1, 3 ],
+            vec![ 0, 1, 2 ],
+            vec![ 5 ],
+            vec![ 4, 6 ],
+            vec![ 5 ]
+        ]).unwrap();
+
+        assert_eq!(summarize(&graph).diameter, 2)
+    }
+
+    #[test]
+    fn c6() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 5 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 4 ],
+            vec![ 3, 5 ],
+            vec![ 4, 0 ]
+        ]).unwrap();
+        let summary = summarize(&graph);
+
+        assert_eq!(summary.girth, Some(6));
+        assert_eq!(summary.diameter, 3)
+    }
+}
+
+#[cfg(test)]
+mod display {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p2() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ]
+        ]).unwrap();
+
+        assert_eq!(summarize(&graph).to_string(), "\
+order: 2
+size: 1
+density: 1.000
+components: 1
+degree: min 1, max 1, mean 1.00
+girth: none
+diameter (estimate): 1
+triangles: 0")
+    }
+}
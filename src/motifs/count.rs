@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+use super::{ Motif, Orbit };
+
+/// Returns the number of times each Motif with size nodes appears as an
+/// induced subgraph of graph. Every Motif of that size has an entry, even
+/// if its count is zero. Subgraphs are enumerated with the ESU algorithm
+/// of Wernicke, visiting each connected node set exactly once. Panics
+/// unless size is three or four.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::motifs::{ count, Motif };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3)
+///     ])?;
+///     let threes = count(&graph, 3);
+///     let fours = count(&graph, 4);
+///
+///     assert_eq!(threes[&Motif::Triangle], 1);
+///     assert_eq!(threes[&Motif::Path3], 2);
+///     assert_eq!(fours[&Motif::Paw], 1);
+///     assert_eq!(fours[&Motif::Square], 0);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn count<G: Graph>(graph: &G, size: usize) -> HashMap<Motif, usize> {
+    let mut result = Motif::of_size(size).iter()
+        .map(|&motif| (motif, 0))
+        .collect::<HashMap<_, _>>();
+
+    enumerate(graph, size, |_, motif, _| {
+        *result.get_mut(&motif).expect("motif") += 1;
+    });
+
+    result
+}
+
+/// Returns, for every node in graph, the number of induced subgraphs with
+/// size nodes in which it occupies each Orbit. Orbits with a count of zero
+/// are omitted, so isolated nodes map to an empty HashMap. These counts
+/// are sometimes called graphlet degrees. Panics unless size is three or
+/// four.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::motifs::{ count_orbits, Motif, Orbit };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 2), (0, 3)
+///     ])?;
+///     let orbits = count_orbits(&graph, 4);
+///     let center = Orbit { motif: Motif::Star, degree: 3 };
+///     let leaf = Orbit { motif: Motif::Star, degree: 1 };
+///
+///     assert_eq!(orbits[&0][&center], 1);
+///     assert_eq!(orbits[&1][&leaf], 1);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn count_orbits<G: Graph>(
+    graph: &G, size: usize
+) -> HashMap<usize, HashMap<Orbit, usize>> {
+    let mut result = graph.ids()
+        .map(|id| (id, HashMap::new()))
+        .collect::<HashMap<_, _>>();
+
+    enumerate(graph, size, |ids, motif, degrees| {
+        for (&id, &degree) in ids.iter().zip(degrees) {
+            let counts = result.get_mut(&id).expect("id");
+
+            *counts.entry(Orbit { motif, degree }).or_insert(0) += 1;
+        }
+    });
+
+    result
+}
+
+fn enumerate<G: Graph, F: FnMut(&[usize], Motif, &[usize])>(
+    graph: &G, size: usize, mut visit: F
+) {
+    assert!(size == 3 || size == 4, "motif size must be 3 or 4");
+
+    let ids = graph.ids().collect::<Vec<_>>();
+    let indices = ids.iter()
+        .enumerate()
+        .map(|(index, &id)| (id, index))
+        .collect::<HashMap<_, _>>();
+    let adjacency = ids.iter()
+        .map(|&id| {
+            let mut neighbors = graph.neighbors(id).expect("neighbors")
+                .map(|neighbor| indices[&neighbor])
+                .collect::<Vec<_>>();
+
+            neighbors.sort_unstable();
+
+            neighbors
+        })
+        .collect::<Vec<_>>();
+    let mut subgraph = Vec::with_capacity(size);
+    let mut visitor = |subgraph: &[usize]| {
+        let mut degrees = [ 0; 4 ];
+        let mut edges = 0;
+
+        for i in 0..subgraph.len() {
+            for j in (i + 1)..subgraph.len() {
+                if adjacency[subgraph[i]].binary_search(&subgraph[j]).is_ok() {
+                    degrees[i] += 1;
+                    degrees[j] += 1;
+                    edges += 1;
+                }
+            }
+        }
+
+        let degrees = &degrees[0..subgraph.len()];
+        let max = degrees.iter().cloned().max().expect("max");
+        let members = subgraph.iter().map(|&index| ids[index])
+            .collect::<Vec<_>>();
+
+        visit(&members, Motif::classify(subgraph.len(), edges, max), degrees);
+    };
+
+    for root in 0..ids.len() {
+        let extension = adjacency[root].iter()
+            .cloned()
+            .filter(|&index| index > root)
+            .collect::<Vec<_>>();
+
+        subgraph.push(root);
+        extend(&adjacency, &mut subgraph, extension, root, size, &mut visitor);
+        subgraph.pop();
+    }
+}
+
+fn extend<F: FnMut(&[usize])>(
+    adjacency: &[Vec<usize>],
+    subgraph: &mut Vec<usize>,
+    mut extension: Vec<usize>,
+    root: usize,
+    size: usize,
+    visit: &mut F
+) {
+    if subgraph.len() == size {
+        visit(subgraph);
+
+        return;
+    }
+
+    while let Some(next) = extension.pop() {
+        let mut child = extension.clone();
+
+        for &candidate in &adjacency[next] {
+            if candidate <= root || child.contains(&candidate) {
+                continue;
+            }
+
+            let near = subgraph.iter().any(|&member| {
+                member == candidate ||
+                adjacency[member].binary_search(&candidate).is_ok()
+            });
+
+            if !near {
+                child.push(candidate);
+            }
+        }
+
+        subgraph.push(next);
+        extend(adjacency, subgraph, child, root, size, visit);
+        subgraph.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+    use crate::graph::DefaultGraph;
+
+    fn complete(order: usize) -> DefaultGraph {
+        let mut edges = Vec::new();
+
+        for sid in 0..order {
+            for tid in (sid + 1)..order {
+                edges.push((sid, tid));
+            }
+        }
+
+        DefaultGraph::try_from(edges).unwrap()
+    }
+
+    fn choose(n: usize, k: usize) -> usize {
+        (0..k).fold(1, |result, i| result * (n - i) / (i + 1))
+    }
+
+    #[test]
+    #[should_panic(expected = "motif size must be 3 or 4")]
+    fn size_two() {
+        count(&DefaultGraph::new(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let counts = count(&DefaultGraph::new(), 4);
+
+        assert_eq!(counts.len(), 6);
+        assert!(counts.values().all(|&count| count == 0))
+    }
+
+    #[test]
+    fn path3() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let counts = count(&graph, 3);
+
+        assert_eq!(counts[&Motif::Path3], 1);
+        assert_eq!(counts[&Motif::Triangle], 0)
+    }
+
+    #[test]
+    fn path4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let counts = count(&graph, 4);
+
+        assert_eq!(counts[&Motif::Path4], 1);
+        assert_eq!(counts.values().sum::<usize>(), 1)
+    }
+
+    #[test]
+    fn square() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert_eq!(count(&graph, 3)[&Motif::Path3], 4);
+        assert_eq!(count(&graph, 4)[&Motif::Square], 1)
+    }
+
+    #[test]
+    fn diamond() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0), (0, 2)
+        ]).unwrap();
+
+        assert_eq!(count(&graph, 3)[&Motif::Triangle], 2);
+        assert_eq!(count(&graph, 4)[&Motif::Diamond], 1)
+    }
+
+    #[test]
+    fn clique() {
+        let graph = complete(6);
+
+        assert_eq!(count(&graph, 3)[&Motif::Triangle], choose(6, 3));
+        assert_eq!(count(&graph, 4)[&Motif::Clique4], choose(6, 4));
+        assert_eq!(count(&graph, 4).values().sum::<usize>(), choose(6, 4))
+    }
+
+    #[test]
+    fn star_with_five_leaves() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)
+        ]).unwrap();
+
+        assert_eq!(count(&graph, 3)[&Motif::Path3], choose(5, 2));
+        assert_eq!(count(&graph, 4)[&Motif::Star], choose(5, 3))
+    }
+
+    #[test]
+    fn disconnected_sets_ignored() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+
+        assert_eq!(count(&graph, 3).values().sum::<usize>(), 0);
+        assert_eq!(count(&graph, 4).values().sum::<usize>(), 0)
+    }
+
+    #[test]
+    fn orbits_paw() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3)
+        ]).unwrap();
+        let orbits = count_orbits(&graph, 4);
+        let orbit = |degree| Orbit { motif: Motif::Paw, degree };
+
+        assert_eq!(orbits[&0][&orbit(2)], 1);
+        assert_eq!(orbits[&1][&orbit(2)], 1);
+        assert_eq!(orbits[&2][&orbit(3)], 1);
+        assert_eq!(orbits[&3][&orbit(1)], 1)
+    }
+
+    #[test]
+    fn orbits_path3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (1, 3)
+        ]).unwrap();
+        let orbits = count_orbits(&graph, 3);
+        let end = Orbit { motif: Motif::Path3, degree: 1 };
+        let center = Orbit { motif: Motif::Path3, degree: 2 };
+
+        assert_eq!(orbits[&1][&center], 3);
+        assert_eq!(orbits[&0][&end], 2);
+        assert!(!orbits[&0].contains_key(&center))
+    }
+
+    #[test]
+    fn orbits_isolated() {
+        let mut graph = DefaultGraph::new();
+
+        graph.add_node(0).unwrap();
+
+        assert!(count_orbits(&graph, 3)[&0].is_empty())
+    }
+
+    #[test]
+    fn orbits_sum_to_counts() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 2), (4, 5), (5, 6)
+        ]).unwrap();
+        let counts = count(&graph, 4);
+        let orbits = count_orbits(&graph, 4);
+        let mut members = HashMap::new();
+
+        for counts in orbits.values() {
+            for (orbit, count) in counts {
+                *members.entry(orbit.motif).or_insert(0) += count;
+            }
+        }
+
+        for (motif, count) in counts {
+            assert_eq!(members.get(&motif).cloned().unwrap_or(0), 4 * count)
+        }
+    }
+}
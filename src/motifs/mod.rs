@@ -0,0 +1,5 @@
+mod motif;
+mod count;
+
+pub use motif::{ Motif, Orbit };
+pub use count::{ count, count_orbits };
@@ -0,0 +1,104 @@
+/// A connected graph on three or four nodes, as found by `count`.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,PartialOrd,Ord)]
+pub enum Motif {
+    /// Three nodes joined by two edges.
+    Path3,
+    Triangle,
+    /// Four nodes joined in a line by three edges.
+    Path4,
+    /// One node joined to three others that are not joined to each other.
+    Star,
+    /// Four nodes joined in a cycle.
+    Square,
+    /// A triangle with a pendant edge.
+    Paw,
+    /// A square with one chord.
+    Diamond,
+    Clique4
+}
+
+impl Motif {
+    /// Returns the Motifs with the given number of nodes, or an empty
+    /// slice if size is neither three nor four.
+    pub fn of_size(size: usize) -> &'static [Motif] {
+        match size {
+            3 => &[ Motif::Path3, Motif::Triangle ],
+            4 => &[
+                Motif::Path4, Motif::Star, Motif::Square, Motif::Paw,
+                Motif::Diamond, Motif::Clique4
+            ],
+            _ => &[ ]
+        }
+    }
+
+    /// Returns the number of nodes.
+    pub fn order(&self) -> usize {
+        match self {
+            Motif::Path3 | Motif::Triangle => 3,
+            _ => 4
+        }
+    }
+
+    /// Returns the number of edges.
+    pub fn size(&self) -> usize {
+        match self {
+            Motif::Path3 => 2,
+            Motif::Triangle | Motif::Path4 | Motif::Star => 3,
+            Motif::Square | Motif::Paw => 4,
+            Motif::Diamond => 5,
+            Motif::Clique4 => 6
+        }
+    }
+
+    pub(crate) fn classify(
+        order: usize, size: usize, max_degree: usize
+    ) -> Motif {
+        match (order, size, max_degree) {
+            (3, 2, _) => Motif::Path3,
+            (3, _, _) => Motif::Triangle,
+            (_, 3, 3) => Motif::Star,
+            (_, 3, _) => Motif::Path4,
+            (_, 4, 3) => Motif::Paw,
+            (_, 4, _) => Motif::Square,
+            (_, 5, _) => Motif::Diamond,
+            _ => Motif::Clique4
+        }
+    }
+}
+
+/// The position a node occupies within a Motif. Within every Motif, nodes
+/// are equivalent under symmetry exactly when they have the same degree in
+/// it, so degree alone identifies the position. For example, the pendant
+/// node of a Paw has degree 1, and the node joining it to the triangle
+/// has degree 3.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,PartialOrd,Ord)]
+pub struct Orbit {
+    pub motif: Motif,
+    pub degree: usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn of_size_other() {
+        assert!(Motif::of_size(5).is_empty())
+    }
+
+    #[test]
+    fn classify_round_trip() {
+        for &motif in Motif::of_size(3).iter().chain(Motif::of_size(4)) {
+            let max_degree = match motif {
+                Motif::Path3 | Motif::Path4 | Motif::Square => 2,
+                Motif::Triangle => 2,
+                Motif::Star | Motif::Paw | Motif::Diamond | Motif::Clique4 => 3
+            };
+
+            assert_eq!(
+                Motif::classify(motif.order(), motif.size(), max_degree),
+                motif
+            )
+        }
+    }
+}
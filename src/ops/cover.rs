@@ -0,0 +1,176 @@
+use crate::graph::{ Graph, DefaultGraph, Error };
+
+/// Returns the bipartite double cover of graph: two copies of each node,
+/// with every edge joining one copy of its source to the other copy of its
+/// target. The cover is connected exactly when graph is connected and has
+/// an odd cycle. Node id becomes ids 2 * id and 2 * id + 1.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::ops::bipartite_double_cover;
+/// use gamma::selection::component_count;
+///
+/// fn main() -> Result<(), Error> {
+///     let c3 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 0) ])?;
+///     let c4 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3), (3, 0) ])?;
+///
+///     assert_eq!(component_count(&bipartite_double_cover(&c3)?), 1);
+///     assert_eq!(component_count(&bipartite_double_cover(&c4)?), 2);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn bipartite_double_cover<G: Graph>(
+    graph: &G
+) -> Result<DefaultGraph, Error> {
+    cyclic_cover(graph, 2, |_, _| 1)
+}
+
+/// Returns the k-fold cover of graph derived from a voltage assignment in
+/// the cyclic group of order k. Node id becomes ids k * id through
+/// k * id + k - 1, one per layer. Each edge (sid, tid), as reported by
+/// `edges`, joins layer i of sid to layer (i + voltage(sid, tid)) % k of
+/// tid. Returns an empty graph if k is zero, or Error if a cover id
+/// overflows usize.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::ops::cyclic_cover;
+///
+/// fn main() -> Result<(), Error> {
+///     let p2 = DefaultGraph::try_from(vec![ (0, 1) ])?;
+///     let cover = cyclic_cover(&p2, 3, |_, _| 1)?;
+///
+///     assert_eq!(cover.edges().collect::<Vec<_>>(), vec![
+///         (0, 4), (1, 5), (2, 3)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn cyclic_cover<G: Graph, F>(
+    graph: &G, k: usize, voltage: F
+) -> Result<DefaultGraph, Error>
+where F: Fn(usize, usize) -> usize {
+    if k == 0 {
+        return Ok(DefaultGraph::new());
+    }
+
+    let lift = |id: usize, layer: usize| {
+        match id.checked_mul(k).and_then(|base| base.checked_add(layer)) {
+            Some(result) => Ok(result),
+            None => Err(Error::IdOverflow(id))
+        }
+    };
+    let mut result = DefaultGraph::with_capacity(
+        graph.order().checked_mul(k).unwrap_or(0),
+        graph.size().checked_mul(k).unwrap_or(0)
+    );
+
+    for id in graph.ids() {
+        for layer in 0..k {
+            result.add_node(lift(id, layer)?)?;
+        }
+    }
+
+    for (sid, tid) in graph.edges() {
+        let shift = voltage(sid, tid) % k;
+
+        for layer in 0..k {
+            result.add_edge(lift(sid, layer)?, lift(tid, (layer + shift) % k)?)?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod double_cover {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            bipartite_double_cover(&DefaultGraph::new()),
+            Ok(DefaultGraph::new())
+        )
+    }
+
+    #[test]
+    fn c3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+        let cover = bipartite_double_cover(&graph).unwrap();
+
+        assert_eq!(cover, DefaultGraph::try_from(vec![
+            (0, 3), (3, 4), (4, 1), (1, 2), (2, 5), (5, 0)
+        ]).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod cyclic {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn zero_layers() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(cyclic_cover(&graph, 0, |_, _| 0), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    #[cfg(not(feature = "u32-ids"))]
+    fn layer_overflow() {
+        let mut graph = DefaultGraph::new();
+        let id = usize::MAX / 3;
+
+        graph.add_node(id).unwrap();
+
+        // id * 3 is usize::MAX, so only the layer offset overflows
+        assert_eq!(
+            cyclic_cover(&graph, 3, |_, _| 0),
+            Err(Error::IdOverflow(id))
+        )
+    }
+
+    #[test]
+    #[cfg(not(feature = "u32-ids"))]
+    fn overflow() {
+        let mut graph = DefaultGraph::new();
+
+        graph.add_node(usize::MAX).unwrap();
+
+        assert_eq!(
+            cyclic_cover(&graph, 2, |_, _| 0),
+            Err(Error::IdOverflow(usize::MAX))
+        )
+    }
+
+    #[test]
+    fn zero_voltage() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let cover = cyclic_cover(&graph, 2, |_, _| 0).unwrap();
+
+        assert_eq!(cover.edges().collect::<Vec<_>>(), [ (0, 2), (1, 3) ])
+    }
+
+    #[test]
+    fn voltage_per_edge() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+        let cover = cyclic_cover(&graph, 3, |sid, tid| {
+            if (sid, tid) == (2, 0) { 1 } else { 0 }
+        }).unwrap();
+
+        // net voltage 1 around the triangle lifts it to a single 9-cycle
+        assert_eq!(cover.order(), 9);
+        assert_eq!(crate::selection::component_count(&cover), 1)
+    }
+}
@@ -0,0 +1,7 @@
+mod cover;
+mod orient;
+mod project;
+
+pub use cover::{ bipartite_double_cover, cyclic_cover };
+pub use orient::{ orient_acyclic, orient_eulerian };
+pub use project::project_bipartite;
@@ -0,0 +1,178 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::graph::{ Graph, Error };
+
+/// Returns the edges of graph as arcs pointing from earlier to later nodes
+/// in ordering, which yields an acyclic orientation. Nodes missing from
+/// ordering follow those present, in the order given by `Graph::ids`. Arcs
+/// are returned in the order of `Graph::edges`. Returns Error if ordering
+/// contains an unknown or repeated id.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::ops::orient_acyclic;
+///
+/// fn main() -> Result<(), Error> {
+///     let c3 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 0) ])?;
+///
+///     assert_eq!(orient_acyclic(&c3, &[ 2, 0, 1 ])?, vec![
+///         (0, 1), (2, 1), (2, 0)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn orient_acyclic<G: Graph>(
+    graph: &G, ordering: &[usize]
+) -> Result<Vec<(usize, usize)>, Error> {
+    let mut ranks = HashMap::new();
+
+    for (rank, &id) in ordering.iter().enumerate() {
+        if !graph.has_id(id) {
+            return Err(Error::UnknownId(id));
+        } else if ranks.insert(id, rank).is_some() {
+            return Err(Error::DuplicateId(id));
+        }
+    }
+
+    for id in graph.ids() {
+        let rank = ranks.len();
+
+        ranks.entry(id).or_insert(rank);
+    }
+
+    Ok(graph.edges().map(|(sid, tid)| {
+        if ranks[&sid] < ranks[&tid] { (sid, tid) } else { (tid, sid) }
+    }).collect())
+}
+
+/// Returns the edges of graph as arcs such that every node has as many
+/// arcs leaving as entering. Arcs are grouped by closed walk. Returns Error
+/// if a node has odd degree, in which case no such orientation exists.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::ops::orient_eulerian;
+///
+/// fn main() -> Result<(), Error> {
+///     let c3 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 0) ])?;
+///     let p2 = DefaultGraph::try_from(vec![ (0, 1) ])?;
+///
+///     assert_eq!(orient_eulerian(&c3)?, vec![ (0, 1), (1, 2), (2, 0) ]);
+///     assert_eq!(orient_eulerian(&p2), Err(Error::DegreeMismatch(0)));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn orient_eulerian<G: Graph>(
+    graph: &G
+) -> Result<Vec<(usize, usize)>, Error> {
+    for id in graph.ids() {
+        if graph.degree(id)? % 2 == 1 {
+            return Err(Error::DegreeMismatch(id));
+        }
+    }
+
+    let mut used = HashSet::new();
+    let mut result = Vec::with_capacity(graph.size());
+
+    for start in graph.ids() {
+        loop {
+            let mut current = start;
+            let mut extended = false;
+
+            // with every degree even, a walk can only get stuck at start
+            while let Some(next) = graph.neighbors(current)?
+                .find(|&next| !used.contains(&key(current, next))) {
+                used.insert(key(current, next));
+                result.push((current, next));
+                current = next;
+                extended = true;
+            }
+
+            if !extended {
+                break;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod acyclic {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_id() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(orient_acyclic(&graph, &[ 2 ]), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn duplicate_id() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(orient_acyclic(&graph, &[ 1, 1 ]), Err(Error::DuplicateId(1)))
+    }
+
+    #[test]
+    fn partial_ordering() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+
+        assert_eq!(orient_acyclic(&graph, &[ 3 ]), Ok(vec![
+            (0, 1), (1, 2), (3, 2)
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod eulerian {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(orient_eulerian(&DefaultGraph::new()), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn bowtie_balanced() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)
+        ]).unwrap();
+        let arcs = orient_eulerian(&graph).unwrap();
+        let mut balance = HashMap::new();
+
+        for &(sid, tid) in &arcs {
+            *balance.entry(sid).or_insert(0) += 1;
+            *balance.entry(tid).or_insert(0) -= 1;
+        }
+
+        assert_eq!(arcs.len(), 6);
+        assert!(balance.values().all(|&value| value == 0))
+    }
+
+    #[test]
+    fn two_cycles() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)
+        ]).unwrap();
+
+        assert_eq!(orient_eulerian(&graph), Ok(vec![
+            (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)
+        ]))
+    }
+}
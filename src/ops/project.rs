@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::graph::{ Graph, DefaultWeightedGraph, Error };
+
+/// Returns the projection of a bipartite graph onto the nodes in side.
+/// Two side nodes are joined if they share a neighbor outside of side.
+/// If weighted is true, each edge is weighted by the number of neighbors
+/// shared. Otherwise every weight is one. Nodes appear in the order of
+/// side, and the edges of each node follow that order as well. Edges
+/// between two side nodes are ignored. Returns Error if a side node is not
+/// found or appears twice.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, WeightedGraph, Error, DefaultGraph };
+/// use gamma::ops::project_bipartite;
+///
+/// fn main() -> Result<(), Error> {
+///     // authors 0, 1, and 2; papers 10 and 11
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 10), (1, 10), (0, 11), (1, 11), (2, 11)
+///     ])?;
+///     let authors = project_bipartite(&graph, &[ 0, 1, 2 ], true)?;
+///
+///     assert_eq!(authors.weight(0, 1), Ok(Some(&2)));
+///     assert_eq!(authors.weight(1, 2), Ok(Some(&1)));
+///     assert_eq!(authors.has_id(10), false);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn project_bipartite<G: Graph>(
+    graph: &G, side: &[usize], weighted: bool
+) -> Result<DefaultWeightedGraph<usize>, Error> {
+    let mut positions = HashMap::new();
+    let mut result = DefaultWeightedGraph::new();
+
+    for (position, &id) in side.iter().enumerate() {
+        if !graph.has_id(id) {
+            return Err(Error::UnknownId(id));
+        }
+
+        result.add_node(id)?;
+        positions.insert(id, position);
+    }
+
+    for (position, &sid) in side.iter().enumerate() {
+        let mut shared = HashMap::new();
+
+        for middle in graph.neighbors(sid)? {
+            if positions.contains_key(&middle) {
+                continue;
+            }
+
+            for tid in graph.neighbors(middle)? {
+                match positions.get(&tid) {
+                    Some(&other) if other > position => {
+                        *shared.entry(other).or_insert(0) += 1;
+                    },
+                    _ => ()
+                }
+            }
+        }
+
+        let mut shared = shared.into_iter().collect::<Vec<_>>();
+
+        shared.sort_unstable();
+
+        for (other, count) in shared {
+            result.add_edge(sid, side[other], if weighted { count } else { 1 })?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::{ DefaultGraph, WeightedGraph };
+
+    fn affiliation() -> DefaultGraph {
+        DefaultGraph::try_from(vec![
+            (0, 10), (1, 10), (2, 10), (1, 11), (2, 11), (3, 12)
+        ]).unwrap()
+    }
+
+    #[test]
+    fn unknown_id() {
+        assert_eq!(
+            project_bipartite(&affiliation(), &[ 0, 9 ], true),
+            Err(Error::UnknownId(9))
+        )
+    }
+
+    #[test]
+    fn duplicate_id() {
+        assert_eq!(
+            project_bipartite(&affiliation(), &[ 0, 0 ], true),
+            Err(Error::DuplicateId(0))
+        )
+    }
+
+    #[test]
+    fn weighted() {
+        let graph = project_bipartite(
+            &affiliation(), &[ 0, 1, 2, 3 ], true
+        ).unwrap();
+
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![
+            (0, 1), (0, 2), (1, 2)
+        ]);
+        assert_eq!(graph.weight(1, 2), Ok(Some(&2)));
+        assert_eq!(graph.degree(3), Ok(0))
+    }
+
+    #[test]
+    fn unweighted() {
+        let graph = project_bipartite(
+            &affiliation(), &[ 0, 1, 2, 3 ], false
+        ).unwrap();
+
+        assert_eq!(graph.weight(1, 2), Ok(Some(&1)))
+    }
+
+    #[test]
+    fn other_side() {
+        let graph = project_bipartite(
+            &affiliation(), &[ 12, 11, 10 ], true
+        ).unwrap();
+
+        assert_eq!(graph.ids().collect::<Vec<_>>(), vec![ 12, 11, 10 ]);
+        assert_eq!(graph.edges().collect::<Vec<_>>(), vec![ (11, 10) ]);
+        assert_eq!(graph.weight(10, 11), Ok(Some(&2)))
+    }
+
+    #[test]
+    fn edges_within_side_ignored() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 10), (1, 10)
+        ]).unwrap();
+        let projection = project_bipartite(&graph, &[ 0, 1 ], true).unwrap();
+
+        assert_eq!(projection.weight(0, 1), Ok(Some(&1)))
+    }
+}
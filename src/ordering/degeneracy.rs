@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+
+/// The degeneracy of a graph together with a smallest-last ordering. See
+/// `degeneracy`.
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Degeneracy {
+    /// The smallest k such that every subgraph has a node of degree at
+    /// most k.
+    pub number: usize,
+    /// Nodes in the order they're removed by repeatedly taking a node of
+    /// minimum degree. Each node has at most `number` neighbors later in
+    /// the ordering.
+    pub ordering: Vec<usize>
+}
+
+/// Returns the degeneracy of graph and a smallest-last ordering of its
+/// nodes, in time proportional to order plus size.
+///
+/// Sparse graphs such as molecules have small degeneracy, which bounds
+/// the work done by `coloring::greedy_coloring` and
+/// `selection::maximal_cliques`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::ordering::degeneracy;
+///
+/// fn main() -> Result<(), Error> {
+///     // triangle with a pendant
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3)
+///     ])?;
+///     let result = degeneracy(&graph);
+///
+///     assert_eq!(result.number, 2);
+///     assert_eq!(result.ordering[0], 3);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn degeneracy<G: Graph>(graph: &G) -> Degeneracy {
+    let ids = graph.ids().collect::<Vec<_>>();
+    let indices = ids.iter().enumerate()
+        .map(|(index, &id)| (id, index))
+        .collect::<HashMap<_, _>>();
+    let mut degrees = ids.iter()
+        .map(|&id| graph.degree(id).expect("degree"))
+        .collect::<Vec<_>>();
+    let mut buckets = vec![
+        Vec::new(); degrees.iter().max().map_or(0, |max| max + 1)
+    ];
+    let mut removed = vec![ false; ids.len() ];
+    let mut number = 0;
+    let mut ordering = Vec::with_capacity(ids.len());
+    let mut current = 0;
+
+    for index in (0..ids.len()).rev() {
+        buckets[degrees[index]].push(index);
+    }
+
+    while ordering.len() < ids.len() {
+        let index = loop {
+            match buckets[current].pop() {
+                Some(index) if !removed[index] && degrees[index] == current => {
+                    break index;
+                },
+                Some(_) => (),
+                None => current += 1
+            }
+        };
+
+        number = number.max(current);
+        removed[index] = true;
+        ordering.push(ids[index]);
+
+        for neighbor in graph.neighbors(ids[index]).expect("neighbors") {
+            let neighbor = indices[&neighbor];
+
+            if !removed[neighbor] {
+                degrees[neighbor] -= 1;
+                buckets[degrees[neighbor]].push(neighbor);
+            }
+        }
+
+        current = current.saturating_sub(1);
+    }
+
+    Degeneracy { number, ordering }
+}
+
+#[cfg(test)]
+mod degeneracy {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn later_neighbors<G: Graph>(graph: &G, ordering: &[usize]) -> usize {
+        ordering.iter().enumerate().map(|(i, &id)| {
+            graph.neighbors(id).unwrap()
+                .filter(|neighbor| ordering[i..].contains(neighbor))
+                .count()
+        }).max().unwrap_or(0)
+    }
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(degeneracy(&graph), Degeneracy {
+            number: 0,
+            ordering: vec![ ]
+        })
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(degeneracy(&graph), Degeneracy {
+            number: 0,
+            ordering: vec![ 0 ]
+        })
+    }
+
+    #[test]
+    fn tree() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (1, 3), (3, 4)
+        ]).unwrap();
+        let result = degeneracy(&graph);
+
+        assert_eq!(result.number, 1);
+        assert_eq!(later_neighbors(&graph, &result.ordering), 1)
+    }
+
+    #[test]
+    fn k4_with_tail() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5)
+        ]).unwrap();
+        let result = degeneracy(&graph);
+
+        assert_eq!(result.number, 3);
+        assert_eq!(&result.ordering[0..2], [ 5, 4 ]);
+        assert_eq!(later_neighbors(&graph, &result.ordering), 3)
+    }
+
+    #[test]
+    fn cube() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0),
+            (4, 5), (5, 6), (6, 7), (7, 4),
+            (0, 4), (1, 5), (2, 6), (3, 7)
+        ]).unwrap();
+        let result = degeneracy(&graph);
+
+        assert_eq!(result.number, 3);
+        assert_eq!(result.ordering.len(), 8)
+    }
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod degeneracy;
+
+pub use degeneracy::{ degeneracy, Degeneracy };
@@ -0,0 +1,123 @@
+use crate::graph::{ Graph, Error };
+
+/// Returns every path from source to target having at most max_len edges
+/// and no repeated nodes, in depth-first order. Returns Error if source or
+/// target are not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::path::all_simple_paths;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 3 ],
+///         vec![ 0, 2 ],
+///         vec![ 1, 3 ],
+///         vec![ 2, 0 ]
+///     ])?;
+///
+///     assert_eq!(all_simple_paths(&graph, 0, 2, 2)?, vec![
+///         vec![ 0, 1, 2 ],
+///         vec![ 0, 3, 2 ]
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn all_simple_paths<G: Graph>(
+    graph: &G, source: usize, target: usize, max_len: usize
+) -> Result<Vec<Vec<usize>>, Error> {
+    if !graph.has_id(target) {
+        return Err(Error::UnknownId(target));
+    }
+
+    let mut result = Vec::new();
+    let mut path = vec![ source ];
+    let mut stack = vec![ graph.neighbors(source)? ];
+
+    if source == target {
+        return Ok(vec![ path ]);
+    }
+
+    while let Some(neighbors) = stack.last_mut() {
+        match neighbors.next() {
+            Some(id) => {
+                if path.contains(&id) {
+                    continue;
+                }
+
+                if id == target {
+                    if path.len() > max_len {
+                        continue;
+                    }
+
+                    let mut found = path.clone();
+
+                    found.push(id);
+                    result.push(found);
+                } else if path.len() < max_len {
+                    path.push(id);
+                    stack.push(graph.neighbors(id)?);
+                }
+            },
+            None => {
+                stack.pop();
+                path.pop();
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn k4() -> DefaultGraph {
+        DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3 ],
+            vec![ 0, 2, 3 ],
+            vec![ 0, 1, 3 ],
+            vec![ 0, 1, 2 ]
+        ]).unwrap()
+    }
+
+    #[test]
+    fn unknown_source() {
+        assert_eq!(all_simple_paths(&k4(), 4, 0, 3), Err(Error::UnknownId(4)))
+    }
+
+    #[test]
+    fn source_is_target() {
+        assert_eq!(all_simple_paths(&k4(), 1, 1, 3), Ok(vec![ vec![ 1 ] ]))
+    }
+
+    #[test]
+    fn zero_length() {
+        assert_eq!(all_simple_paths(&k4(), 0, 1, 0), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn k4_limited() {
+        assert_eq!(all_simple_paths(&k4(), 0, 3, 2), Ok(vec![
+            vec![ 0, 1, 3 ],
+            vec![ 0, 2, 3 ],
+            vec![ 0, 3 ]
+        ]))
+    }
+
+    #[test]
+    fn k4_unlimited() {
+        assert_eq!(all_simple_paths(&k4(), 0, 3, 3), Ok(vec![
+            vec![ 0, 1, 2, 3 ],
+            vec![ 0, 1, 3 ],
+            vec![ 0, 2, 1, 3 ],
+            vec![ 0, 2, 3 ],
+            vec![ 0, 3 ]
+        ]))
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Graph, Error };
+
+/// Returns true if nodes is a non-empty sequence of distinct nodes, each
+/// joined to the next by an edge of graph. Returns Error if any node is not
+/// found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::path::is_path;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///
+///     assert_eq!(is_path(&graph, &[ 0, 1, 2 ])?, true);
+///     assert_eq!(is_path(&graph, &[ 0, 2 ])?, false);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn is_path<G: Graph>(graph: &G, nodes: &[usize]) -> Result<bool, Error> {
+    let mut visited = HashSet::new();
+
+    for &id in nodes {
+        if !graph.has_id(id) {
+            return Err(Error::UnknownId(id));
+        }
+
+        if !visited.insert(id) {
+            return Ok(false);
+        }
+    }
+
+    for pair in nodes.windows(2) {
+        if !graph.has_edge(pair[0], pair[1])? {
+            return Ok(false);
+        }
+    }
+
+    Ok(!nodes.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn c4() -> DefaultGraph {
+        DefaultGraph::try_from(vec![
+            vec![ 1, 3 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 0 ]
+        ]).unwrap()
+    }
+
+    #[test]
+    fn unknown_id() {
+        assert_eq!(is_path(&c4(), &[ 0, 4 ]), Err(Error::UnknownId(4)))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(is_path(&c4(), &[ ]), Ok(false))
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(is_path(&c4(), &[ 2 ]), Ok(true))
+    }
+
+    #[test]
+    fn repeated_node() {
+        assert_eq!(is_path(&c4(), &[ 0, 1, 2, 3, 0 ]), Ok(false))
+    }
+
+    #[test]
+    fn wraps_around() {
+        assert_eq!(is_path(&c4(), &[ 2, 3, 0, 1 ]), Ok(true))
+    }
+}
@@ -0,0 +1,7 @@
+mod is_path;
+mod all_simple_paths;
+mod path_to_edges;
+
+pub use is_path::is_path;
+pub use all_simple_paths::all_simple_paths;
+pub use path_to_edges::path_to_edges;
@@ -0,0 +1,24 @@
+/// Returns an Iterator over the edges joining consecutive nodes in path.
+///
+/// ```rust
+/// use gamma::path::path_to_edges;
+///
+/// assert_eq!(path_to_edges(&[ 2, 0, 1 ]).collect::<Vec<_>>(), vec![
+///     (2, 0), (0, 1)
+/// ]);
+/// ```
+pub fn path_to_edges(
+    path: &[usize]
+) -> impl Iterator<Item=(usize, usize)> + '_ {
+    path.windows(2).map(|pair| (pair[0], pair[1]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single() {
+        assert_eq!(path_to_edges(&[ 0 ]).count(), 0)
+    }
+}
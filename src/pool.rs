@@ -0,0 +1,75 @@
+//! A free list of scratch structures for algorithms that build and discard
+//! many of them. With the `pool` feature, structures given back are cleared
+//! and handed to the next taker, keeping their allocations. Without it,
+//! give drops its argument and take always builds anew.
+
+/// A structure that can be emptied in place, keeping its capacity.
+pub(crate) trait Clear {
+    fn clear(&mut self);
+}
+
+impl<T> Clear for Vec<T> {
+    fn clear(&mut self) {
+        Vec::clear(self)
+    }
+}
+
+#[derive(Debug,PartialEq)]
+pub(crate) struct Pool<T> {
+    free: Vec<T>
+}
+
+impl<T: Clear> Pool<T> {
+    pub fn new() -> Self {
+        Self { free: Vec::new() }
+    }
+
+    /// Returns a recycled, empty item if one is available, or the result of
+    /// make otherwise.
+    pub fn take<F: FnOnce() -> T>(&mut self, make: F) -> T {
+        self.free.pop().unwrap_or_else(make)
+    }
+
+    pub fn give(&mut self, mut item: T) {
+        if cfg!(feature = "pool") {
+            item.clear();
+            self.free.push(item)
+        }
+    }
+}
+
+#[cfg(test)]
+mod take {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let mut pool = Pool::<Vec<usize>>::new();
+
+        assert_eq!(pool.take(|| vec![ 1 ]), [ 1 ])
+    }
+
+    #[test]
+    #[cfg(feature = "pool")]
+    fn recycled() {
+        let mut pool = Pool::new();
+
+        pool.give(Vec::with_capacity(8));
+        pool.give(vec![ 1, 2, 3 ]);
+
+        let item = pool.take(Vec::new);
+
+        assert_eq!(item, [ ]);
+        assert!(item.capacity() >= 3)
+    }
+
+    #[test]
+    #[cfg(not(feature = "pool"))]
+    fn not_recycled() {
+        let mut pool = Pool::new();
+
+        pool.give(vec![ 1, 2, 3 ]);
+
+        assert_eq!(pool.take(Vec::new).capacity(), 0)
+    }
+}
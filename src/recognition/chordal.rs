@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+use crate::traversal::LexBfs;
+
+/// Returns a perfect elimination ordering of graph, or None if graph is not
+/// chordal. Each node's neighbors later in the ordering form a clique.
+pub(crate) fn perfect_elimination_ordering<G: Graph>(
+    graph: &G
+) -> Option<Vec<usize>> {
+    let mut result = LexBfs::new(graph).collect::<Vec<_>>();
+
+    result.reverse();
+
+    let positions = result.iter().enumerate()
+        .map(|(position, &id)| (id, position))
+        .collect::<HashMap<_,_>>();
+
+    for (position, &id) in result.iter().enumerate() {
+        let later = graph.neighbors(id).expect("neighbors")
+            .filter(|neighbor| positions[neighbor] > position)
+            .collect::<Vec<_>>();
+        let parent = match later.iter().min_by_key(|id| positions[id]) {
+            Some(&parent) => parent,
+            None => continue
+        };
+
+        for &other in &later {
+            if other != parent && !graph.has_edge(parent, other).expect("edge") {
+                return None;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn c4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert_eq!(perfect_elimination_ordering(&graph), None)
+    }
+
+    #[test]
+    fn c4_with_chord() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0), (0, 2)
+        ]).unwrap();
+
+        assert!(perfect_elimination_ordering(&graph).is_some())
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+/// A combinatorial embedding: for each node, a cyclic clockwise order of
+/// its neighbors, stored as links to the clockwise and counterclockwise
+/// neighbor of each neighbor.
+#[derive(Debug,Clone,Default)]
+pub(crate) struct Embedding {
+    ids: Vec<usize>,
+    rotations: HashMap<usize, HashMap<usize, (usize, usize)>>,
+    first: HashMap<usize, usize>
+}
+
+impl Embedding {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, id: usize) {
+        if !self.rotations.contains_key(&id) {
+            self.ids.push(id);
+            self.rotations.insert(id, HashMap::new());
+        }
+    }
+
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+
+    pub fn has_edge(&self, sid: usize, tid: usize) -> bool {
+        self.rotations[&sid].contains_key(&tid)
+    }
+
+    /// Returns the neighbor after tid, clockwise around sid.
+    pub fn cw(&self, sid: usize, tid: usize) -> usize {
+        self.rotations[&sid][&tid].0
+    }
+
+    /// Returns the neighbor before tid, clockwise around sid.
+    pub fn ccw(&self, sid: usize, tid: usize) -> usize {
+        self.rotations[&sid][&tid].1
+    }
+
+    /// Returns the neighbors of id in clockwise order.
+    pub fn neighbors_cw(&self, id: usize) -> Vec<usize> {
+        let mut result = Vec::new();
+
+        if let Some(&first) = self.first.get(&id) {
+            let mut neighbor = first;
+
+            loop {
+                result.push(neighbor);
+                neighbor = self.cw(id, neighbor);
+
+                if neighbor == first {
+                    break result;
+                }
+            }
+        } else {
+            result
+        }
+    }
+
+    /// Adds tid to the neighbors of sid, just clockwise of reference, or
+    /// as the only neighbor if reference is None.
+    pub fn add_half_edge_cw(
+        &mut self, sid: usize, tid: usize, reference: Option<usize>
+    ) {
+        let rotation = self.rotations.get_mut(&sid).expect("rotation");
+
+        match reference {
+            None => {
+                rotation.insert(tid, (tid, tid));
+                self.first.insert(sid, tid);
+            },
+            Some(reference) => {
+                let next = rotation[&reference].0;
+
+                rotation.get_mut(&reference).expect("reference").0 = tid;
+                rotation.get_mut(&next).expect("next").1 = tid;
+                rotation.insert(tid, (next, reference));
+            }
+        }
+    }
+
+    /// Adds tid to the neighbors of sid, just counterclockwise of
+    /// reference, or as the only neighbor if reference is None.
+    pub fn add_half_edge_ccw(
+        &mut self, sid: usize, tid: usize, reference: Option<usize>
+    ) {
+        match reference {
+            None => self.add_half_edge_cw(sid, tid, None),
+            Some(reference) => {
+                let previous = self.ccw(sid, reference);
+
+                self.add_half_edge_cw(sid, tid, Some(previous));
+
+                if self.first.get(&sid) == Some(&reference) {
+                    self.first.insert(sid, tid);
+                }
+            }
+        }
+    }
+
+    /// Adds tid as the first neighbor of sid.
+    pub fn add_half_edge_first(&mut self, sid: usize, tid: usize) {
+        let reference = self.first.get(&sid).cloned();
+
+        self.add_half_edge_ccw(sid, tid, reference);
+    }
+
+    /// Returns the half-edge following (sid, tid) around the face on its
+    /// right.
+    pub fn next_face_half_edge(
+        &self, sid: usize, tid: usize
+    ) -> (usize, usize) {
+        (tid, self.ccw(tid, sid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_around() {
+        let mut embedding = Embedding::new();
+
+        embedding.add_node(0);
+        embedding.add_half_edge_cw(0, 1, None);
+        embedding.add_half_edge_cw(0, 2, Some(1));
+        embedding.add_half_edge_ccw(0, 3, Some(1));
+
+        assert_eq!(embedding.neighbors_cw(0), [ 3, 1, 2 ]);
+
+        embedding.add_half_edge_first(0, 4);
+
+        assert_eq!(embedding.neighbors_cw(0), [ 4, 3, 1, 2 ]);
+        assert_eq!(embedding.ccw(0, 4), 2)
+    }
+}
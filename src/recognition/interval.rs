@@ -0,0 +1,335 @@
+use std::cmp::Ordering;
+use std::collections::{ HashMap, HashSet, VecDeque };
+
+use crate::graph::Graph;
+use super::chordal::perfect_elimination_ordering;
+
+/// The interval assigned to a node in an interval model. Bounds are
+/// inclusive positions in an ordering of the graph's maximal cliques, so
+/// two nodes are adjacent exactly when their intervals overlap.
+#[derive(Debug,PartialEq,Eq,Clone,Copy)]
+pub struct Interval {
+    pub id: usize,
+    pub start: usize,
+    pub end: usize
+}
+
+/// Returns true if graph is the intersection graph of a set of intervals
+/// on a line.
+pub fn is_interval<G: Graph>(graph: &G) -> bool {
+    interval_model(graph).is_some()
+}
+
+/// Returns an interval for each node of graph, in the order of
+/// `Graph::ids`, or None if graph is not an interval graph.
+///
+/// Following Gilmore and Hoffman, graph is an interval graph exactly when
+/// it is chordal and its complement is transitively orientable. The
+/// orientation places each pair of non-adjacent nodes left and right of
+/// each other, which orders the maximal cliques along the line. The
+/// complement is built explicitly, so time is cubic in order.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::{ interval_model, Interval };
+///
+/// fn main() -> Result<(), Error> {
+///     let p3 = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ])?;
+///
+///     assert_eq!(interval_model(&p3), Some(vec![
+///         Interval { id: 0, start: 0, end: 0 },
+///         Interval { id: 1, start: 0, end: 1 },
+///         Interval { id: 2, start: 1, end: 1 }
+///     ]));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn interval_model<G: Graph>(graph: &G) -> Option<Vec<Interval>> {
+    let ordering = perfect_elimination_ordering(graph)?;
+    let adjacency = graph.ids().map(|id| {
+        (id, graph.neighbors(id).expect("neighbors").collect::<HashSet<_>>())
+    }).collect::<HashMap<_,_>>();
+    let left = orient_complement(graph, &adjacency)?;
+    let mut cliques = maximal_cliques(&ordering, &adjacency);
+    let ranks = cliques.iter().map(|clique| {
+        cliques.iter()
+            .filter(|other| compare(other, clique, &left) == Ordering::Less)
+            .count()
+    }).collect::<Vec<_>>();
+    let mut ranked = cliques.drain(..).zip(ranks).collect::<Vec<_>>();
+
+    // ranks are distinct in an interval graph, and checked below if not
+    ranked.sort_by_key(|&(_, rank)| rank);
+    cliques = ranked.into_iter().map(|(clique, _)| clique).collect();
+
+    let mut bounds = HashMap::new();
+
+    for (position, clique) in cliques.iter().enumerate() {
+        for &id in clique {
+            let bound = bounds.entry(id).or_insert((position, position));
+
+            bound.1 = position;
+        }
+    }
+
+    let result = graph.ids().map(|id| {
+        let (start, end) = bounds[&id];
+
+        Interval { id, start, end }
+    }).collect::<Vec<_>>();
+
+    if is_model(&result, &adjacency) {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// Returns a maximum independent set of graph, or None if graph is not an
+/// interval graph. Given an interval model, repeatedly taking the interval
+/// that ends first and discarding those it overlaps is optimal.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::interval_independent_set;
+///
+/// fn main() -> Result<(), Error> {
+///     let p5 = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4)
+///     ])?;
+///
+///     assert_eq!(interval_independent_set(&p5), Some(vec![ 0, 2, 4 ]));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn interval_independent_set<G: Graph>(graph: &G) -> Option<Vec<usize>> {
+    let mut intervals = interval_model(graph)?;
+    let mut result = Vec::new();
+    let mut last = None;
+
+    intervals.sort_by_key(|interval| (interval.end, interval.start));
+
+    for interval in intervals {
+        if last.is_none_or(|end| interval.start > end) {
+            result.push(interval.id);
+            last = Some(interval.end);
+        }
+    }
+
+    Some(result)
+}
+
+type Adjacency = HashMap<usize, HashSet<usize>>;
+
+/// Returns a transitive orientation of the complement of graph as the set
+/// of arcs (u, v) meaning u lies left of v, or None if there is none. Uses
+/// Golumbic's algorithm: the implication class of an arbitrary remaining
+/// edge is oriented, checked for conflicts, and removed, until no edges
+/// remain.
+fn orient_complement<G: Graph>(
+    graph: &G, adjacency: &Adjacency
+) -> Option<HashSet<(usize, usize)>> {
+    let ids = graph.ids().collect::<Vec<_>>();
+    let mut remaining = HashSet::new();
+    let mut result = HashSet::new();
+
+    for (index, &sid) in ids.iter().enumerate() {
+        for &tid in &ids[index + 1..] {
+            if !adjacency[&sid].contains(&tid) {
+                remaining.insert(key(sid, tid));
+            }
+        }
+    }
+
+    while let Some(&(sid, tid)) = remaining.iter().min() {
+        let mut class = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        class.insert((sid, tid));
+        queue.push_back((sid, tid));
+
+        while let Some((a, b)) = queue.pop_front() {
+            for &other in &ids {
+                // (a, b) forces (a, other) when b and other are not joined
+                // by a remaining edge, and likewise at the head
+                let forced = [
+                    (other != b && remaining.contains(&key(a, other)) &&
+                        !remaining.contains(&key(b, other)), (a, other)),
+                    (other != a && remaining.contains(&key(other, b)) &&
+                        !remaining.contains(&key(a, other)), (other, b))
+                ];
+
+                for &(applies, arc) in &forced {
+                    if !applies || arc.0 == arc.1 {
+                        continue;
+                    } else if class.contains(&(arc.1, arc.0)) {
+                        return None;
+                    } else if class.insert(arc) {
+                        queue.push_back(arc);
+                    }
+                }
+            }
+        }
+
+        for &(a, b) in &class {
+            remaining.remove(&key(a, b));
+        }
+
+        result.extend(class);
+    }
+
+    Some(result)
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+/// Returns the maximal cliques of a chordal graph from a perfect
+/// elimination ordering, in the order their first node is eliminated.
+fn maximal_cliques(ordering: &[usize], adjacency: &Adjacency) -> Vec<Vec<usize>> {
+    let positions = ordering.iter().enumerate()
+        .map(|(position, &id)| (id, position))
+        .collect::<HashMap<_,_>>();
+    let candidates = ordering.iter().map(|&id| {
+        let mut clique = adjacency[&id].iter()
+            .filter(|neighbor| positions[neighbor] > positions[&id])
+            .cloned()
+            .collect::<Vec<_>>();
+
+        clique.push(id);
+        clique.sort_by_key(|id| positions[id]);
+
+        clique
+    }).collect::<Vec<_>>();
+    let mut result: Vec<Vec<usize>> = Vec::new();
+
+    for candidate in candidates {
+        let contained = result.iter().any(|clique| {
+            candidate.iter().all(|id| clique.contains(id))
+        });
+
+        if !contained {
+            result.push(candidate);
+        }
+    }
+
+    result
+}
+
+/// Orders cliques a and b by any pair of non-adjacent nodes, one from each.
+fn compare(a: &[usize], b: &[usize], left: &HashSet<(usize, usize)>) -> Ordering {
+    for &x in a {
+        for &y in b {
+            if left.contains(&(x, y)) {
+                return Ordering::Less;
+            } else if left.contains(&(y, x)) {
+                return Ordering::Greater;
+            }
+        }
+    }
+
+    Ordering::Equal
+}
+
+fn is_model(intervals: &[Interval], adjacency: &Adjacency) -> bool {
+    intervals.iter().enumerate().all(|(index, a)| {
+        intervals[index + 1..].iter().all(|b| {
+            let overlap = a.start <= b.end && b.start <= a.end;
+
+            overlap == adjacency[&a.id].contains(&b.id)
+        })
+    })
+}
+
+#[cfg(test)]
+mod model {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(interval_model(&DefaultGraph::new()), Some(vec![ ]))
+    }
+
+    #[test]
+    fn p1_p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ], vec![ ] ]).unwrap();
+
+        assert_eq!(interval_model(&graph), Some(vec![
+            Interval { id: 0, start: 0, end: 0 },
+            Interval { id: 1, start: 1, end: 1 }
+        ]))
+    }
+
+    #[test]
+    fn c4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert!(!is_interval(&graph))
+    }
+
+    #[test]
+    fn tripod_subdivided() {
+        // chordal, but its three leaves form an asteroidal triple
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (0, 3), (3, 4), (0, 5), (5, 6)
+        ]).unwrap();
+
+        assert!(!is_interval(&graph))
+    }
+
+    #[test]
+    fn caterpillar() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (1, 4), (1, 5), (2, 6)
+        ]).unwrap();
+
+        assert!(is_interval(&graph))
+    }
+
+    #[test]
+    fn fan() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (2, 3), (3, 4)
+        ]).unwrap();
+        let model = interval_model(&graph).unwrap();
+
+        assert_eq!(model.iter().map(|interval| interval.id).collect::<Vec<_>>(), [
+            0, 1, 2, 3, 4
+        ]);
+        assert_eq!(model[0].end - model[0].start, 2)
+    }
+}
+
+#[cfg(test)]
+mod independent_set {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn not_interval() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert_eq!(interval_independent_set(&graph), None)
+    }
+
+    #[test]
+    fn star() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3)
+        ]).unwrap();
+
+        assert_eq!(interval_independent_set(&graph).unwrap().len(), 3)
+    }
+}
@@ -0,0 +1,531 @@
+use std::collections::{ BTreeMap, BTreeSet, HashSet };
+
+use crate::graph::Graph;
+use crate::budget::{ Budget, Interrupted };
+
+type Adjacency = BTreeMap<usize, BTreeSet<usize>>;
+
+/// Returns true if pattern is a minor of graph: if pattern can be obtained
+/// from graph by deleting nodes and edges and contracting edges.
+///
+/// The search is exponential and intended for small fixed patterns such as
+/// K5 and K3,3. Nodes that can't take part in a model of pattern are
+/// removed first, so large sparse graphs reduce quickly.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::minor_contains;
+///
+/// fn main() -> Result<(), Error> {
+///     let k5 = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 2), (0, 3), (0, 4),
+///         (1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)
+///     ])?;
+///     let petersen = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///         (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+///         (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+///     ])?;
+///
+///     assert_eq!(minor_contains(&petersen, &k5), true);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn minor_contains<G: Graph, H: Graph>(graph: &G, pattern: &H) -> bool {
+    minor_contains_with_budget(graph, pattern, &mut Budget::unlimited())
+        .expect("unlimited budget")
+}
+
+/// Returns true if pattern is a minor of graph, as `minor_contains` does,
+/// checking budget at each level of the search. Returns Interrupted if
+/// budget is spent or its depth limit reached first, so untrusted input
+/// can be searched without risking the call stack.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::minor_contains_with_budget;
+/// use gamma::budget::{ Budget, Interrupted };
+///
+/// fn main() -> Result<(), Error> {
+///     let k3 = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 0) ])?;
+///     let c5 = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+///     ])?;
+///     let mut budget = Budget::unlimited().with_depth(1);
+///
+///     assert_eq!(
+///         minor_contains_with_budget(&c5, &k3, &mut budget),
+///         Err(Interrupted::LimitExceeded)
+///     );
+///
+///     let mut budget = Budget::unlimited().with_depth(10);
+///
+///     assert_eq!(minor_contains_with_budget(&c5, &k3, &mut budget), Ok(true));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn minor_contains_with_budget<G: Graph, H: Graph>(
+    graph: &G, pattern: &H, budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    let target = adjacency(pattern);
+    let min_degree = min_degree(&target);
+    let host = reduce(adjacency(graph), min_degree, true);
+
+    contract_search(host, &target, &mut HashSet::new(), budget)
+}
+
+/// Returns true if graph contains a subdivision of pattern: a subgraph
+/// obtained from pattern by replacing its edges with internally disjoint
+/// paths. Every topological minor is a minor, but not the reverse: the
+/// Petersen graph has K5 as a minor but not as a topological minor.
+///
+/// Like `minor_contains`, the search is exponential and intended for small
+/// fixed patterns.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::topological_minor_contains;
+///
+/// fn main() -> Result<(), Error> {
+///     let k33 = DefaultGraph::try_from(vec![
+///         (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)
+///     ])?;
+///     let petersen = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+///         (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+///         (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+///     ])?;
+///
+///     assert_eq!(topological_minor_contains(&petersen, &k33), true);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn topological_minor_contains<G: Graph, H: Graph>(
+    graph: &G, pattern: &H
+) -> bool {
+    let mut budget = Budget::unlimited();
+
+    topological_minor_contains_with_budget(graph, pattern, &mut budget)
+        .expect("unlimited budget")
+}
+
+/// Returns true if graph contains a subdivision of pattern, as
+/// `topological_minor_contains` does, checking budget at each level of the
+/// search. Returns Interrupted if budget is spent or its depth limit
+/// reached first.
+pub fn topological_minor_contains_with_budget<G: Graph, H: Graph>(
+    graph: &G, pattern: &H, budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    let target = adjacency(pattern);
+    let host = reduce(adjacency(graph), min_degree(&target), false);
+    let order = search_order(&target);
+    let mut edges = Vec::new();
+
+    for (index, &id) in order.iter().enumerate() {
+        for &other in &order[..index] {
+            if target[&id].contains(&other) {
+                edges.push((other, id));
+            }
+        }
+    }
+
+    let mut mapping = BTreeMap::new();
+    let mut used = HashSet::new();
+
+    branch_search(
+        &host, &target, &order, &edges, &mut mapping, &mut used, budget
+    )
+}
+
+fn adjacency<G: Graph>(graph: &G) -> Adjacency {
+    graph.ids().map(|id| {
+        (id, graph.neighbors(id).expect("neighbors").collect())
+    }).collect()
+}
+
+fn min_degree(adjacency: &Adjacency) -> usize {
+    adjacency.values().map(|neighbors| neighbors.len()).min().unwrap_or(0)
+}
+
+fn edge_count(adjacency: &Adjacency) -> usize {
+    adjacency.values().map(|neighbors| neighbors.len()).sum::<usize>() / 2
+}
+
+/// Removes nodes that no model of a pattern with the given minimum degree
+/// needs. Isolated nodes go if min_degree is at least one, and leaves if it
+/// is at least two. If suppress is set and min_degree is at least three,
+/// nodes of degree two are contracted into a neighbor.
+fn reduce(mut adjacency: Adjacency, min_degree: usize, suppress: bool) -> Adjacency {
+    loop {
+        let found = adjacency.iter().find(|(_, neighbors)| {
+            let degree = neighbors.len();
+
+            (degree == 0 && min_degree >= 1) ||
+            (degree == 1 && min_degree >= 2) ||
+            (degree == 2 && min_degree >= 3 && suppress)
+        }).map(|(&id, _)| id);
+
+        match found {
+            Some(id) => {
+                if adjacency[&id].len() == 2 {
+                    let &into = adjacency[&id].iter().next().expect("neighbor");
+
+                    adjacency = contract(&adjacency, into, id);
+                } else {
+                    remove(&mut adjacency, id);
+                }
+            },
+            None => return adjacency
+        }
+    }
+}
+
+fn remove(adjacency: &mut Adjacency, id: usize) {
+    for neighbor in adjacency.remove(&id).expect("node") {
+        adjacency.get_mut(&neighbor).expect("neighbor").remove(&id);
+    }
+}
+
+/// Returns a copy of adjacency with the edge (keep, other) contracted into
+/// keep. Parallel edges are merged.
+fn contract(adjacency: &Adjacency, keep: usize, other: usize) -> Adjacency {
+    let mut result = adjacency.clone();
+    let neighbors = result.remove(&other).expect("node");
+
+    for neighbor in neighbors {
+        let entry = result.get_mut(&neighbor).expect("neighbor");
+
+        entry.remove(&other);
+
+        if neighbor != keep {
+            entry.insert(keep);
+            result.get_mut(&keep).expect("keep").insert(neighbor);
+        }
+    }
+
+    result
+}
+
+fn contract_search(
+    host: Adjacency,
+    target: &Adjacency,
+    seen: &mut HashSet<Adjacency>,
+    budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    if host.len() < target.len() ||
+        edge_count(&host) < edge_count(target) ||
+        !seen.insert(host.clone()) {
+        return Ok(false);
+    }
+
+    let order = search_order(target);
+    let mut mapping = BTreeMap::new();
+
+    if subgraph_search(&host, target, &order, &mut mapping, budget)? {
+        return Ok(true);
+    }
+
+    let min_degree = min_degree(target);
+
+    for (&sid, neighbors) in &host {
+        for &tid in neighbors.range(sid + 1..) {
+            let next = reduce(contract(&host, sid, tid), min_degree, true);
+
+            if budget.descend(|budget| {
+                contract_search(next, target, seen, budget)
+            })? {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns the ids of target ordered so that, where possible, each follows
+/// one of its neighbors, highest degree first.
+fn search_order(target: &Adjacency) -> Vec<usize> {
+    let mut result = Vec::new();
+    let mut remaining = target.keys().cloned().collect::<BTreeSet<_>>();
+
+    while !remaining.is_empty() {
+        let next = remaining.iter().cloned().max_by_key(|id| {
+            let placed = target[id].iter()
+                .filter(|neighbor| !remaining.contains(neighbor))
+                .count();
+
+            (placed, target[id].len(), usize::MAX - id)
+        }).expect("remaining");
+
+        remaining.remove(&next);
+        result.push(next);
+    }
+
+    result
+}
+
+fn subgraph_search(
+    host: &Adjacency,
+    target: &Adjacency,
+    order: &[usize],
+    mapping: &mut BTreeMap<usize, usize>,
+    budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    let id = match order.get(mapping.len()) {
+        Some(&id) => id,
+        None => return Ok(true)
+    };
+
+    for (&candidate, neighbors) in host {
+        if neighbors.len() < target[&id].len() ||
+            mapping.values().any(|&mapped| mapped == candidate) {
+            continue;
+        }
+
+        let fits = target[&id].iter().all(|neighbor| {
+            match mapping.get(neighbor) {
+                Some(mapped) => neighbors.contains(mapped),
+                None => true
+            }
+        });
+
+        if fits {
+            mapping.insert(id, candidate);
+
+            if budget.descend(|budget| {
+                subgraph_search(host, target, order, mapping, budget)
+            })? {
+                return Ok(true);
+            }
+
+            mapping.remove(&id);
+        }
+    }
+
+    Ok(false)
+}
+
+fn branch_search(
+    host: &Adjacency,
+    target: &Adjacency,
+    order: &[usize],
+    edges: &[(usize, usize)],
+    mapping: &mut BTreeMap<usize, usize>,
+    used: &mut HashSet<usize>,
+    budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    let id = match order.get(mapping.len()) {
+        Some(&id) => id,
+        None => return route_search(host, mapping, edges, used, budget)
+    };
+
+    for (&candidate, neighbors) in host {
+        if neighbors.len() < target[&id].len() || used.contains(&candidate) {
+            continue;
+        }
+
+        mapping.insert(id, candidate);
+        used.insert(candidate);
+
+        if budget.descend(|budget| {
+            branch_search(host, target, order, edges, mapping, used, budget)
+        })? {
+            return Ok(true);
+        }
+
+        used.remove(&candidate);
+        mapping.remove(&id);
+    }
+
+    Ok(false)
+}
+
+fn route_search(
+    host: &Adjacency,
+    mapping: &BTreeMap<usize, usize>,
+    edges: &[(usize, usize)],
+    used: &mut HashSet<usize>,
+    budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    let (sid, tid) = match edges.first() {
+        Some(&(sid, tid)) => (mapping[&sid], mapping[&tid]),
+        None => return Ok(true)
+    };
+    let path = Path { last: sid, tid };
+
+    extend_path(host, mapping, path, &edges[1..], used, budget)
+}
+
+// The end of a partially routed path and the node it must reach.
+#[derive(Clone,Copy)]
+struct Path {
+    last: usize,
+    tid: usize
+}
+
+fn extend_path(
+    host: &Adjacency,
+    mapping: &BTreeMap<usize, usize>,
+    path: Path,
+    rest: &[(usize, usize)],
+    used: &mut HashSet<usize>,
+    budget: &mut Budget
+) -> Result<bool, Interrupted> {
+    for &next in &host[&path.last] {
+        if next == path.tid {
+            if budget.descend(|budget| {
+                route_search(host, mapping, rest, used, budget)
+            })? {
+                return Ok(true);
+            }
+        } else if !used.contains(&next) {
+            let path = Path { last: next, tid: path.tid };
+
+            used.insert(next);
+
+            if budget.descend(|budget| {
+                extend_path(host, mapping, path, rest, used, budget)
+            })? {
+                return Ok(true);
+            }
+
+            used.remove(&next);
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+fn complete(order: usize) -> crate::graph::DefaultGraph {
+    (0..order).flat_map(|sid| (sid + 1..order).map(move |tid| (sid, tid)))
+        .collect()
+}
+
+#[cfg(test)]
+fn k33() -> crate::graph::DefaultGraph {
+    (0..3).flat_map(|sid| (3..6).map(move |tid| (sid, tid))).collect()
+}
+
+#[cfg(test)]
+fn petersen() -> crate::graph::DefaultGraph {
+    vec![
+        (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+        (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+        (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+    ].into_iter().collect()
+}
+
+#[cfg(test)]
+fn cube() -> crate::graph::DefaultGraph {
+    vec![
+        (0, 1), (1, 2), (2, 3), (3, 0),
+        (4, 5), (5, 6), (6, 7), (7, 4),
+        (0, 4), (1, 5), (2, 6), (3, 7)
+    ].into_iter().collect()
+}
+
+#[cfg(test)]
+mod contains_minor {
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty_pattern() {
+        assert!(minor_contains(&complete(3), &DefaultGraph::new()))
+    }
+
+    #[test]
+    fn k5_in_k5() {
+        assert!(minor_contains(&complete(5), &complete(5)))
+    }
+
+    #[test]
+    fn k5_in_k33() {
+        assert!(!minor_contains(&k33(), &complete(5)))
+    }
+
+    #[test]
+    fn k33_in_k5() {
+        assert!(!minor_contains(&complete(5), &k33()))
+    }
+
+    #[test]
+    fn k33_in_petersen() {
+        assert!(minor_contains(&petersen(), &k33()))
+    }
+
+    #[test]
+    fn cube_is_planar() {
+        assert!(!minor_contains(&cube(), &complete(5)));
+        assert!(!minor_contains(&cube(), &k33()))
+    }
+
+    #[test]
+    fn k4_in_subdivided_k4_with_pendants() {
+        let graph = vec![
+            (0, 10), (10, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 11), (11, 3),
+            (3, 12), (12, 13)
+        ].into_iter().collect::<DefaultGraph>();
+
+        assert!(minor_contains(&graph, &complete(4)))
+    }
+}
+
+#[cfg(test)]
+mod contains_topological {
+    use super::*;
+
+    #[test]
+    fn k5_in_petersen() {
+        assert!(!topological_minor_contains(&petersen(), &complete(5)))
+    }
+
+    #[test]
+    fn k33_in_petersen() {
+        assert!(topological_minor_contains(&petersen(), &k33()))
+    }
+
+    #[test]
+    fn k4_in_cube() {
+        assert!(topological_minor_contains(&cube(), &complete(4)))
+    }
+
+    #[test]
+    fn k33_in_cube() {
+        assert!(!topological_minor_contains(&cube(), &k33()))
+    }
+
+    #[test]
+    fn budget_limit() {
+        let mut budget = Budget::unlimited().with_depth(2);
+
+        assert_eq!(
+            minor_contains_with_budget(&petersen(), &complete(5), &mut budget),
+            Err(Interrupted::LimitExceeded)
+        )
+    }
+
+    #[test]
+    fn topological_budget_steps() {
+        let mut budget = Budget::unlimited().with_steps(3);
+
+        assert_eq!(
+            topological_minor_contains_with_budget(
+                &petersen(), &k33(), &mut budget
+            ),
+            Err(Interrupted::OutOfSteps)
+        )
+    }
+
+    #[test]
+    fn cube_in_k3() {
+        assert!(!topological_minor_contains(&complete(3), &cube()))
+    }
+}
@@ -0,0 +1,21 @@
+mod chordal;
+mod minor;
+mod split;
+mod threshold;
+mod interval;
+mod embedding;
+mod planarity;
+
+pub use minor::{
+    minor_contains, minor_contains_with_budget,
+    topological_minor_contains, topological_minor_contains_with_budget
+};
+pub use split::{ is_split, split_partition, SplitPartition };
+pub use threshold::{ is_threshold, creation_sequence, Creation };
+pub use interval::{
+    is_interval, interval_model, interval_independent_set, Interval
+};
+pub use planarity::{ is_planar, planar_embedding };
+
+pub(crate) use embedding::Embedding;
+pub(crate) use planarity::embed;
@@ -0,0 +1,750 @@
+use std::collections::{ HashMap, HashSet };
+use std::collections::hash_map::Entry;
+
+use crate::graph::Graph;
+use super::embedding::Embedding;
+
+type Edge = (usize, usize);
+
+/// Returns true if graph can be drawn in the plane without crossing edges.
+/// Uses the left-right planarity test of de Fraysseix and Rosenstiehl, as
+/// described by Brandes, in time proportional to order plus size.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::is_planar;
+///
+/// fn main() -> Result<(), Error> {
+///     let mut k5 = Vec::new();
+///
+///     for sid in 0..5 {
+///         for tid in (sid + 1)..5 {
+///             k5.push((sid, tid));
+///         }
+///     }
+///
+///     let k5 = DefaultGraph::try_from(k5)?;
+///     let k4 = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+///     ])?;
+///
+///     assert_eq!(is_planar(&k4), true);
+///     assert_eq!(is_planar(&k5), false);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn is_planar<G: Graph>(graph: &G) -> bool {
+    LeftRight::new(graph).test()
+}
+
+/// Returns a planar embedding of graph, or None if graph is not planar.
+/// The embedding maps each id to its neighbors in clockwise order, such
+/// that walking each face (from a half-edge (v, w) to w's neighbor just
+/// counterclockwise of v) traces a face of a crossing-free drawing.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::planar_embedding;
+///
+/// fn main() -> Result<(), Error> {
+///     let star = DefaultGraph::try_from(vec![ (0, 1), (0, 2), (0, 3) ])?;
+///     let embedding = planar_embedding(&star).unwrap();
+///
+///     assert_eq!(embedding[&0].len(), 3);
+///     assert_eq!(embedding[&1], vec![ 0 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn planar_embedding<G: Graph>(
+    graph: &G
+) -> Option<HashMap<usize, Vec<usize>>> {
+    let embedding = embed(graph)?;
+
+    Some(embedding.ids().iter()
+        .map(|&id| (id, embedding.neighbors_cw(id)))
+        .collect())
+}
+
+/// Returns a planar Embedding of graph, or None if graph is not planar.
+pub(crate) fn embed<G: Graph>(graph: &G) -> Option<Embedding> {
+    let mut state = LeftRight::new(graph);
+
+    if state.test() {
+        Some(state.embedding())
+    } else {
+        None
+    }
+}
+
+#[derive(Debug,Clone,Copy,Default)]
+struct Interval {
+    low: Option<Edge>,
+    high: Option<Edge>
+}
+
+impl Interval {
+    fn new(edge: Edge) -> Self {
+        Self { low: Some(edge), high: Some(edge) }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.low.is_none() && self.high.is_none()
+    }
+}
+
+#[derive(Debug,Clone,Copy,Default)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+struct LeftRight {
+    ids: Vec<usize>,
+    adjacency: HashMap<usize, Vec<usize>>,
+    size: usize,
+    roots: Vec<usize>,
+    height: HashMap<usize, usize>,
+    parent_edge: HashMap<usize, Edge>,
+    oriented: HashSet<Edge>,
+    out: HashMap<usize, Vec<usize>>,
+    lowpt: HashMap<Edge, usize>,
+    lowpt2: HashMap<Edge, usize>,
+    nesting_depth: HashMap<Edge, isize>,
+    references: HashMap<Edge, Edge>,
+    side: HashMap<Edge, isize>,
+    stack: Vec<ConflictPair>,
+    stack_bottom: HashMap<Edge, usize>,
+    lowpt_edge: HashMap<Edge, Edge>,
+    left_ref: HashMap<usize, usize>,
+    right_ref: HashMap<usize, usize>
+}
+
+impl LeftRight {
+    fn new<G: Graph>(graph: &G) -> Self {
+        let ids = graph.ids().collect::<Vec<_>>();
+        let mut adjacency = HashMap::new();
+        let mut size = 0;
+
+        for &id in &ids {
+            let mut neighbors = Vec::new();
+
+            for neighbor in graph.neighbors(id).expect("neighbors") {
+                if neighbor != id && !neighbors.contains(&neighbor) {
+                    neighbors.push(neighbor);
+                }
+            }
+
+            size += neighbors.len();
+            adjacency.insert(id, neighbors);
+        }
+
+        Self {
+            out: ids.iter().map(|&id| (id, Vec::new())).collect(),
+            ids,
+            adjacency,
+            size: size / 2,
+            roots: Vec::new(),
+            height: HashMap::new(),
+            parent_edge: HashMap::new(),
+            oriented: HashSet::new(),
+            lowpt: HashMap::new(),
+            lowpt2: HashMap::new(),
+            nesting_depth: HashMap::new(),
+            references: HashMap::new(),
+            side: HashMap::new(),
+            stack: Vec::new(),
+            stack_bottom: HashMap::new(),
+            lowpt_edge: HashMap::new(),
+            left_ref: HashMap::new(),
+            right_ref: HashMap::new()
+        }
+    }
+
+    fn test(&mut self) -> bool {
+        let order = self.ids.len();
+
+        if order > 2 && self.size > 3 * order - 6 {
+            return false;
+        }
+
+        for index in 0..order {
+            let id = self.ids[index];
+
+            if let Entry::Vacant(entry) = self.height.entry(id) {
+                entry.insert(0);
+                self.roots.push(id);
+                self.orient(id);
+            }
+        }
+
+        self.sort_out_edges();
+
+        for index in 0..self.roots.len() {
+            if !self.check(self.roots[index]) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn embedding(&mut self) -> Embedding {
+        let edges = self.oriented.iter().cloned().collect::<Vec<_>>();
+
+        for edge in edges {
+            let sign = self.sign(edge);
+
+            *self.nesting_depth.get_mut(&edge).expect("depth") *= sign;
+        }
+
+        self.sort_out_edges();
+
+        let mut result = Embedding::new();
+
+        for &id in &self.ids {
+            let mut previous = None;
+
+            result.add_node(id);
+
+            for &neighbor in &self.out[&id] {
+                result.add_half_edge_cw(id, neighbor, previous);
+                previous = Some(neighbor);
+            }
+        }
+
+        for index in 0..self.roots.len() {
+            self.embed(self.roots[index], &mut result);
+        }
+
+        result
+    }
+
+    fn sort_out_edges(&mut self) {
+        let nesting_depth = &self.nesting_depth;
+
+        for (&id, out) in self.out.iter_mut() {
+            out.sort_by_key(|&neighbor| nesting_depth[&(id, neighbor)]);
+        }
+    }
+
+    // Orients edges away from root along a depth-first search, recording
+    // lowpoints and nesting depths. Uses an explicit stack so that long
+    // paths can't exhaust the call stack.
+    fn orient(&mut self, root: usize) {
+        let mut stack = vec![ (root, 0) ];
+
+        while let Some((v, index)) = stack.last_mut() {
+            let v = *v;
+
+            if *index == self.adjacency[&v].len() {
+                stack.pop();
+
+                if let Some(&(u, _)) = stack.last() {
+                    self.finish_edge((u, v));
+                }
+
+                continue;
+            }
+
+            let w = self.adjacency[&v][*index];
+
+            *index += 1;
+
+            if self.oriented.contains(&(v, w)) ||
+                self.oriented.contains(&(w, v)) {
+                continue;
+            }
+
+            let vw = (v, w);
+            let height = self.height[&v];
+
+            self.oriented.insert(vw);
+            self.out.get_mut(&v).expect("out").push(w);
+            self.lowpt.insert(vw, height);
+            self.lowpt2.insert(vw, height);
+
+            match self.height.get(&w).cloned() {
+                None => {
+                    self.parent_edge.insert(w, vw);
+                    self.height.insert(w, height + 1);
+                    stack.push((w, 0));
+                },
+                Some(target) => {
+                    self.lowpt.insert(vw, target);
+                    self.finish_edge(vw);
+                }
+            }
+        }
+    }
+
+    // Sets the nesting depth of vw, whose lowpoints are final, and folds
+    // them into the lowpoints of the edge leading to v.
+    fn finish_edge(&mut self, vw: Edge) {
+        let v = vw.0;
+        let height = self.height[&v];
+        let mut depth = 2 * self.lowpt[&vw] as isize;
+
+        if self.lowpt2[&vw] < height {
+            depth += 1;
+        }
+
+        self.nesting_depth.insert(vw, depth);
+
+        if let Some(&e) = self.parent_edge.get(&v) {
+            let (low, low2) = (self.lowpt[&vw], self.lowpt2[&vw]);
+            let (e_low, e_low2) = (self.lowpt[&e], self.lowpt2[&e]);
+
+            if low < e_low {
+                self.lowpt2.insert(e, e_low.min(low2));
+                self.lowpt.insert(e, low);
+            } else if low > e_low {
+                self.lowpt2.insert(e, e_low2.min(low));
+            } else {
+                self.lowpt2.insert(e, e_low2.min(low2));
+            }
+        }
+    }
+
+    // Tests the edges below root for left-right conflicts, returning false
+    // on the first that can't be resolved.
+    fn check(&mut self, root: usize) -> bool {
+        let mut stack = vec![ (root, 0) ];
+
+        while let Some((v, index)) = stack.last_mut() {
+            let v = *v;
+
+            if *index == self.out[&v].len() {
+                stack.pop();
+
+                if let Some(&e) = self.parent_edge.get(&v) {
+                    self.remove_back_edges(e);
+
+                    let (_, index) = stack.last().expect("parent");
+
+                    if !self.constrain(e, *index - 1) {
+                        return false;
+                    }
+                }
+
+                continue;
+            }
+
+            let w = self.out[&v][*index];
+            let ei = (v, w);
+
+            *index += 1;
+            self.stack_bottom.insert(ei, self.stack.len());
+
+            if self.parent_edge.get(&w) == Some(&ei) {
+                stack.push((w, 0));
+            } else {
+                let index = *index - 1;
+
+                self.lowpt_edge.insert(ei, ei);
+                self.stack.push(ConflictPair {
+                    left: Interval::default(),
+                    right: Interval::new(ei)
+                });
+
+                if !self.constrain(ei, index) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    // Integrates the finished edge ei, the index-th out of its source, into
+    // the constraints of the edge leading to its source.
+    fn constrain(&mut self, ei: Edge, index: usize) -> bool {
+        let v = ei.0;
+
+        if self.lowpt[&ei] < self.height[&v] {
+            let e = self.parent_edge[&v];
+
+            if index == 0 {
+                let low = self.lowpt_edge[&ei];
+
+                self.lowpt_edge.insert(e, low);
+            } else if !self.add_constraints(ei, e) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn conflicting(&self, interval: &Interval, edge: Edge) -> bool {
+        match interval.high {
+            Some(high) => self.lowpt[&high] > self.lowpt[&edge],
+            None => false
+        }
+    }
+
+    fn lowest(&self, pair: &ConflictPair) -> usize {
+        match (pair.left.low, pair.right.low) {
+            (None, Some(right)) => self.lowpt[&right],
+            (Some(left), None) => self.lowpt[&left],
+            (Some(left), Some(right)) => {
+                self.lowpt[&left].min(self.lowpt[&right])
+            },
+            (None, None) => unreachable!("empty conflict pair")
+        }
+    }
+
+    fn add_constraints(&mut self, ei: Edge, e: Edge) -> bool {
+        let mut pair = ConflictPair::default();
+
+        loop {
+            let mut q = self.stack.pop().expect("conflict pair");
+
+            if !q.left.is_empty() {
+                q.swap();
+            }
+
+            if !q.left.is_empty() {
+                return false;
+            }
+
+            let q_low = q.right.low.expect("low");
+
+            if self.lowpt[&q_low] > self.lowpt[&e] {
+                if pair.right.is_empty() {
+                    pair.right = q.right;
+                } else {
+                    self.set_ref(pair.right.low, q.right.high);
+                }
+
+                pair.right.low = q.right.low;
+            } else {
+                let low = self.lowpt_edge[&e];
+
+                self.references.insert(q_low, low);
+            }
+
+            if self.stack.len() == self.stack_bottom[&ei] {
+                break;
+            }
+        }
+
+        while let Some(top) = self.stack.last() {
+            if !self.conflicting(&top.left, ei) &&
+                !self.conflicting(&top.right, ei) {
+                break;
+            }
+
+            let mut q = self.stack.pop().expect("conflict pair");
+
+            if self.conflicting(&q.right, ei) {
+                q.swap();
+            }
+
+            if self.conflicting(&q.right, ei) {
+                return false;
+            }
+
+            self.set_ref(pair.right.low, q.right.high);
+
+            if q.right.low.is_some() {
+                pair.right.low = q.right.low;
+            }
+
+            if pair.left.is_empty() {
+                pair.left = q.left;
+            } else {
+                self.set_ref(pair.left.low, q.left.high);
+            }
+
+            pair.left.low = q.left.low;
+        }
+
+        if !pair.left.is_empty() || !pair.right.is_empty() {
+            self.stack.push(pair);
+        }
+
+        true
+    }
+
+    fn remove_back_edges(&mut self, e: Edge) {
+        let u = e.0;
+        let height = self.height[&u];
+
+        while let Some(top) = self.stack.last() {
+            if self.lowest(top) != height {
+                break;
+            }
+
+            let pair = self.stack.pop().expect("conflict pair");
+
+            if let Some(low) = pair.left.low {
+                self.side.insert(low, -1);
+            }
+        }
+
+        if let Some(mut pair) = self.stack.pop() {
+            while let Some(high) = pair.left.high.filter(|high| high.1 == u) {
+                pair.left.high = self.references.get(&high).cloned();
+            }
+
+            if pair.left.high.is_none() {
+                if let Some(low) = pair.left.low {
+                    self.set_ref(Some(low), pair.right.low);
+                    self.side.insert(low, -1);
+                    pair.left.low = None;
+                }
+            }
+
+            while let Some(high) = pair.right.high.filter(|high| high.1 == u) {
+                pair.right.high = self.references.get(&high).cloned();
+            }
+
+            if pair.right.high.is_none() {
+                if let Some(low) = pair.right.low {
+                    self.set_ref(Some(low), pair.left.low);
+                    self.side.insert(low, -1);
+                    pair.right.low = None;
+                }
+            }
+
+            self.stack.push(pair);
+        }
+
+        if self.lowpt[&e] < height {
+            let top = self.stack.last().expect("conflict pair");
+            let (left, right) = (top.left.high, top.right.high);
+            let reference = match (left, right) {
+                (Some(l), Some(r)) if self.lowpt[&l] > self.lowpt[&r] => left,
+                (Some(_), None) => left,
+                _ => right
+            };
+
+            self.set_ref(Some(e), reference);
+        }
+    }
+
+    fn set_ref(&mut self, edge: Option<Edge>, reference: Option<Edge>) {
+        let edge = edge.expect("edge");
+
+        match reference {
+            Some(reference) => self.references.insert(edge, reference),
+            None => self.references.remove(&edge)
+        };
+    }
+
+    fn sign(&mut self, edge: Edge) -> isize {
+        let mut chain = vec![ edge ];
+        let mut last = edge;
+
+        while let Some(&next) = self.references.get(&last) {
+            chain.push(next);
+            last = next;
+        }
+
+        let mut result = *self.side.get(&last).unwrap_or(&1);
+
+        for &link in chain.iter().rev().skip(1) {
+            let side = self.side.entry(link).or_insert(1);
+
+            *side *= result;
+            result = *side;
+            self.references.remove(&link);
+        }
+
+        result
+    }
+
+    fn embed(&mut self, root: usize, embedding: &mut Embedding) {
+        let mut stack = vec![ (root, 0) ];
+
+        while let Some((v, index)) = stack.last_mut() {
+            let v = *v;
+            let w = match self.out[&v].get(*index) {
+                Some(&w) => w,
+                None => {
+                    stack.pop();
+
+                    continue;
+                }
+            };
+            let ei = (v, w);
+
+            *index += 1;
+
+            if self.parent_edge.get(&w) == Some(&ei) {
+                embedding.add_half_edge_first(w, v);
+                self.left_ref.insert(v, w);
+                self.right_ref.insert(v, w);
+                stack.push((w, 0));
+            } else if *self.side.get(&ei).unwrap_or(&1) == 1 {
+                let reference = self.right_ref[&w];
+
+                embedding.add_half_edge_cw(w, v, Some(reference));
+            } else {
+                let reference = self.left_ref[&w];
+
+                embedding.add_half_edge_ccw(w, v, Some(reference));
+                self.left_ref.insert(w, v);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn complete(n: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+
+        for sid in 0..n {
+            for tid in (sid + 1)..n {
+                result.push((sid, tid));
+            }
+        }
+
+        result
+    }
+
+    fn complete_bipartite(m: usize, n: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+
+        for sid in 0..m {
+            for tid in m..(m + n) {
+                result.push((sid, tid));
+            }
+        }
+
+        result
+    }
+
+    // Euler's formula holds for each component if the rotation system
+    // describes a planar embedding.
+    fn faces(embedding: &HashMap<usize, Vec<usize>>) -> usize {
+        let mut seen = HashSet::new();
+        let mut result = 0;
+
+        for (&v, neighbors) in embedding {
+            for &w in neighbors {
+                if seen.contains(&(v, w)) {
+                    continue;
+                }
+
+                let (mut a, mut b) = (v, w);
+
+                result += 1;
+
+                while seen.insert((a, b)) {
+                    let around = &embedding[&b];
+                    let i = around.iter().position(|&x| x == a).unwrap();
+                    let next = around[(i + around.len() - 1) % around.len()];
+
+                    a = b;
+                    b = next;
+                }
+            }
+        }
+
+        result
+    }
+
+    fn check_euler(edges: Vec<(usize, usize)>) {
+        let graph = DefaultGraph::try_from(edges).unwrap();
+        let embedding = planar_embedding(&graph).unwrap();
+
+        assert_eq!(
+            graph.order() as isize - graph.size() as isize +
+                faces(&embedding) as isize,
+            2
+        )
+    }
+
+    #[test]
+    fn empty() {
+        assert!(is_planar(&DefaultGraph::new()))
+    }
+
+    #[test]
+    fn k4() {
+        check_euler(complete(4))
+    }
+
+    #[test]
+    fn k5() {
+        let graph = DefaultGraph::try_from(complete(5)).unwrap();
+
+        assert_eq!(planar_embedding(&graph), None)
+    }
+
+    #[test]
+    fn k33() {
+        let graph = DefaultGraph::try_from(complete_bipartite(3, 3)).unwrap();
+
+        assert!(!is_planar(&graph))
+    }
+
+    #[test]
+    fn k24() {
+        check_euler(complete_bipartite(2, 4))
+    }
+
+    #[test]
+    fn petersen() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0),
+            (0, 5), (1, 6), (2, 7), (3, 8), (4, 9),
+            (5, 7), (7, 9), (9, 6), (6, 8), (8, 5)
+        ]).unwrap();
+
+        assert!(!is_planar(&graph))
+    }
+
+    #[test]
+    fn cube() {
+        check_euler(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0),
+            (4, 5), (5, 6), (6, 7), (7, 4),
+            (0, 4), (1, 5), (2, 6), (3, 7)
+        ])
+    }
+
+    #[test]
+    fn naphthalene() {
+        check_euler(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 9), (9, 0),
+            (4, 5), (5, 6), (6, 7), (7, 8), (8, 9)
+        ])
+    }
+
+    #[test]
+    fn long_path() {
+        let graph = (0..100_000).map(|id| (id, id + 1))
+            .collect::<DefaultGraph>();
+
+        assert_eq!(planar_embedding(&graph).unwrap().len(), 100_001)
+    }
+
+    #[test]
+    fn icosahedron() {
+        check_euler(vec![
+            (0, 1), (0, 2), (0, 3), (0, 4), (0, 5),
+            (1, 2), (2, 3), (3, 4), (4, 5), (5, 1),
+            (1, 6), (2, 6), (2, 7), (3, 7), (3, 8),
+            (4, 8), (4, 9), (5, 9), (5, 10), (1, 10),
+            (6, 7), (7, 8), (8, 9), (9, 10), (10, 6),
+            (6, 11), (7, 11), (8, 11), (9, 11), (10, 11)
+        ])
+    }
+}
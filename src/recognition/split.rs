@@ -0,0 +1,133 @@
+use std::cmp::Reverse;
+
+use crate::graph::Graph;
+
+/// A partition of a split graph's nodes into a clique and an independent
+/// set. Either part may be empty.
+#[derive(Debug,PartialEq,Eq,Clone)]
+pub struct SplitPartition {
+    pub clique: Vec<usize>,
+    pub independent: Vec<usize>
+}
+
+/// Returns true if graph's nodes can be partitioned into a clique and an
+/// independent set.
+pub fn is_split<G: Graph>(graph: &G) -> bool {
+    split_partition(graph).is_some()
+}
+
+/// Returns a partition of graph into a clique and an independent set, or
+/// None if graph is not split. Uses the Hammer–Simeone degree sequence
+/// test: with degrees d1 >= d2 >= ... and m the largest i with di >= i - 1,
+/// the m highest-degree nodes form the clique when a partition exists.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::{ split_partition, SplitPartition };
+///
+/// fn main() -> Result<(), Error> {
+///     // a triangle with a pendant on each corner
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (0, 3), (1, 4), (2, 5)
+///     ])?;
+///
+///     assert_eq!(split_partition(&graph), Some(SplitPartition {
+///         clique: vec![ 0, 1, 2 ],
+///         independent: vec![ 3, 4, 5 ]
+///     }));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn split_partition<G: Graph>(graph: &G) -> Option<SplitPartition> {
+    let mut nodes = graph.ids().map(|id| {
+        (graph.degree(id).expect("degree"), id)
+    }).collect::<Vec<_>>();
+
+    // stable, so equal degrees keep the order of Graph::ids
+    nodes.sort_by_key(|&(degree, _)| Reverse(degree));
+
+    let m = nodes.iter().enumerate()
+        .filter(|(index, (degree, _))| *degree >= *index)
+        .map(|(index, _)| index + 1)
+        .max()
+        .unwrap_or(0);
+    let inside = nodes[..m].iter().map(|(degree, _)| degree).sum::<usize>();
+    let outside = nodes[m..].iter().map(|(degree, _)| degree).sum::<usize>();
+
+    if inside != m * m.saturating_sub(1) + outside {
+        return None;
+    }
+
+    Some(SplitPartition {
+        clique: nodes[..m].iter().map(|&(_, id)| id).collect(),
+        independent: nodes[m..].iter().map(|&(_, id)| id).collect()
+    })
+}
+
+#[cfg(test)]
+mod split_partition {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert_eq!(split_partition(&DefaultGraph::new()), Some(SplitPartition {
+            clique: vec![ ],
+            independent: vec![ ]
+        }))
+    }
+
+    #[test]
+    fn p1_p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ], vec![ ] ]).unwrap();
+
+        assert_eq!(split_partition(&graph), Some(SplitPartition {
+            clique: vec![ 0 ],
+            independent: vec![ 1 ]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod is_split {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+
+        assert!(is_split(&graph))
+    }
+
+    #[test]
+    fn c4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert!(!is_split(&graph))
+    }
+
+    #[test]
+    fn p2_p2() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+
+        assert!(!is_split(&graph))
+    }
+
+    #[test]
+    fn c5() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+        ]).unwrap();
+
+        assert!(!is_split(&graph))
+    }
+}
@@ -0,0 +1,152 @@
+use crate::graph::Graph;
+
+/// A step in building a threshold graph: adding a node with no neighbors,
+/// or adding a node joined to every node added before it.
+#[derive(Debug,PartialEq,Eq,Clone,Copy)]
+pub enum Creation {
+    Isolated(usize),
+    Dominating(usize)
+}
+
+/// Returns true if graph can be built by repeatedly adding an isolated or
+/// a dominating node.
+pub fn is_threshold<G: Graph>(graph: &G) -> bool {
+    creation_sequence(graph).is_some()
+}
+
+/// Returns the steps that build graph from nothing, in order, or None if
+/// graph is not a threshold graph. Nodes are peeled using degrees alone:
+/// at each step the remaining node of least degree must be isolated, or
+/// the remaining node of greatest degree must dominate the rest.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::recognition::{ creation_sequence, Creation::* };
+///
+/// fn main() -> Result<(), Error> {
+///     let star = DefaultGraph::try_from(vec![ (0, 1), (0, 2) ])?;
+///
+///     assert_eq!(creation_sequence(&star), Some(vec![
+///         Isolated(2), Isolated(1), Dominating(0)
+///     ]));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn creation_sequence<G: Graph>(graph: &G) -> Option<Vec<Creation>> {
+    let mut nodes = graph.ids().map(|id| {
+        (graph.degree(id).expect("degree"), id)
+    }).collect::<Vec<_>>();
+
+    nodes.sort_by_key(|&(degree, _)| degree);
+
+    let mut low = 0;
+    let mut high = nodes.len();
+    let mut removed_dominating = 0;
+    let mut result = Vec::with_capacity(nodes.len());
+
+    while low < high {
+        let remaining = high - low;
+        let (least, least_id) = nodes[low];
+        let (greatest, greatest_id) = nodes[high - 1];
+
+        if least == removed_dominating {
+            result.push(Creation::Isolated(least_id));
+            low += 1;
+        } else if greatest - removed_dominating == remaining - 1 {
+            result.push(Creation::Dominating(greatest_id));
+            removed_dominating += 1;
+            high -= 1;
+        } else {
+            return None;
+        }
+    }
+
+    result.reverse();
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod creation_sequence {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use Creation::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(creation_sequence(&DefaultGraph::new()), Some(vec![ ]))
+    }
+
+    #[test]
+    fn k3() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+
+        assert_eq!(creation_sequence(&graph), Some(vec![
+            Isolated(0), Dominating(1), Dominating(2)
+        ]))
+    }
+
+    #[test]
+    fn rebuilds_graph() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (0, 4)
+        ]).unwrap();
+        let sequence = creation_sequence(&graph).unwrap();
+        let mut rebuilt = DefaultGraph::new();
+
+        for step in sequence {
+            let (id, dominating) = match step {
+                Isolated(id) => (id, false),
+                Dominating(id) => (id, true)
+            };
+            let earlier = rebuilt.ids().collect::<Vec<_>>();
+
+            rebuilt.add_node(id).unwrap();
+
+            if dominating {
+                for other in earlier {
+                    rebuilt.add_edge(id, other).unwrap();
+                }
+            }
+        }
+
+        assert_eq!(rebuilt, graph)
+    }
+}
+
+#[cfg(test)]
+mod is_threshold {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+
+        assert!(!is_threshold(&graph))
+    }
+
+    #[test]
+    fn c4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert!(!is_threshold(&graph))
+    }
+
+    #[test]
+    fn p2_p2() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+
+        assert!(!is_threshold(&graph))
+    }
+}
@@ -0,0 +1,3 @@
+mod svg;
+
+pub use svg::{ svg, pairing_svg, steps_svg };
@@ -0,0 +1,190 @@
+use std::collections::{ HashMap, HashSet };
+use std::fmt::Write;
+use std::hash::BuildHasher;
+
+use crate::graph::Graph;
+use crate::matching::Pairing;
+use crate::traversal::Step;
+use crate::layout::circular;
+
+const SIZE: f64 = 400.0;
+const MARGIN: f64 = 24.0;
+const RADIUS: f64 = 10.0;
+const PLAIN: &str = r##"stroke="#999" stroke-width="1.5""##;
+const HIGHLIGHT: &str = r##"stroke="#d62728" stroke-width="4""##;
+const TREE: &str = r##"stroke="#1f77b4" stroke-width="4""##;
+const CUT: &str =
+    r##"stroke="#d62728" stroke-width="2" stroke-dasharray="6 4""##;
+
+/// Returns an SVG drawing of graph with nodes placed by
+/// `layout::circular`, each labeled with its id. Edges in
+/// highlights are drawn thick and red. Meant for debugging, such as
+/// comparing matchings by eye.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::render::svg;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ])?;
+///     let drawing = svg(&graph, &[ (2, 1) ]);
+///
+///     assert!(drawing.starts_with("<svg"));
+///     assert_eq!(drawing.matches("<line").count(), 2);
+///     assert_eq!(drawing.matches("#d62728").count(), 1);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn svg<G: Graph>(graph: &G, highlights: &[(usize, usize)]) -> String {
+    let highlights = highlights.iter()
+        .map(|&(sid, tid)| key(sid, tid))
+        .collect::<HashSet<_>>();
+
+    render(graph, |sid, tid| if highlights.contains(&key(sid, tid)) {
+        HIGHLIGHT
+    } else {
+        PLAIN
+    })
+}
+
+/// Returns an SVG drawing of graph as with `svg`, highlighting the edges of
+/// pairing.
+pub fn pairing_svg<G: Graph, S: BuildHasher>(
+    graph: &G, pairing: &Pairing<S>
+) -> String {
+    svg(graph, &pairing.edges().collect::<Vec<_>>())
+}
+
+/// Returns an SVG drawing of graph as with `svg`, showing the Steps of a
+/// traversal. Tree edges are thick and blue, and cut edges are dashed red.
+pub fn steps_svg<G: Graph, I: IntoIterator<Item=Step>>(
+    graph: &G, steps: I
+) -> String {
+    let steps = steps.into_iter()
+        .map(|step| (key(step.sid, step.tid), step.cut))
+        .collect::<HashMap<_, _>>();
+
+    render(graph, |sid, tid| match steps.get(&key(sid, tid)) {
+        Some(true) => CUT,
+        Some(false) => TREE,
+        None => PLAIN
+    })
+}
+
+fn render<G: Graph, F: Fn(usize, usize) -> &'static str>(
+    graph: &G, style: F
+) -> String {
+    let coordinates = circular(graph);
+    let mut result = String::new();
+
+    writeln!(
+        result,
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" "#,
+            r#"width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#
+        ),
+        SIZE
+    ).expect("write");
+
+    for (sid, tid) in graph.edges() {
+        let (x1, y1) = place(coordinates[&sid]);
+        let (x2, y2) = place(coordinates[&tid]);
+
+        writeln!(
+            result,
+            r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" {}/>"#,
+            x1, y1, x2, y2, style(sid, tid)
+        ).expect("write");
+    }
+
+    for id in graph.ids() {
+        let (x, y) = place(coordinates[&id]);
+
+        writeln!(
+            result,
+            concat!(
+                r#"<circle cx="{:.1}" cy="{:.1}" r="{}" "#,
+                r##"fill="#fff" stroke="#333"/>"##
+            ),
+            x, y, RADIUS
+        ).expect("write");
+        writeln!(
+            result,
+            concat!(
+                r#"<text x="{:.1}" y="{:.1}" font-size="10" "#,
+                r#"text-anchor="middle" dominant-baseline="central">{}</text>"#
+            ),
+            x, y, id
+        ).expect("write");
+    }
+
+    result.push_str("</svg>\n");
+
+    result
+}
+
+// Maps coordinates from the square between -1 and 1 into the drawing.
+fn place((x, y): (f64, f64)) -> (f64, f64) {
+    let half = SIZE / 2.0 - MARGIN;
+
+    (SIZE / 2.0 + x * half, SIZE / 2.0 + y * half)
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::traversal::DepthFirst;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+        let drawing = svg(&graph, &[ ]);
+
+        assert_eq!(drawing.lines().count(), 2)
+    }
+
+    #[test]
+    fn single_node_centered() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        let drawing = svg(&graph, &[ ]);
+
+        assert!(drawing.contains(r#"cx="200.0" cy="200.0""#))
+    }
+
+    #[test]
+    fn pairing() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+        let mut pairing = Pairing::new();
+
+        pairing.pair(0, 1);
+        pairing.pair(3, 2);
+
+        let drawing = pairing_svg(&graph, &pairing);
+
+        assert_eq!(drawing.matches(HIGHLIGHT).count(), 2)
+    }
+
+    #[test]
+    fn steps() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3)
+        ]).unwrap();
+        let traversal = DepthFirst::new(&graph, 0).unwrap();
+        let drawing = steps_svg(&graph, traversal);
+
+        assert_eq!(drawing.matches(TREE).count(), 3);
+        assert_eq!(drawing.matches(CUT).count(), 1);
+        assert_eq!(drawing.matches(PLAIN).count(), 0)
+    }
+}
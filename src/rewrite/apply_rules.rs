@@ -0,0 +1,320 @@
+use std::collections::{ HashMap, HashSet };
+use std::collections::hash_map::Entry;
+
+use crate::graph::{ Graph, DefaultGraph, Error };
+use super::Rule;
+
+/// Returns a copy of graph with rules applied at non-overlapping matches.
+/// Matches are sought for each rule in turn, over nodes not already taken
+/// by an earlier match, and then all substitutions are made at once. A
+/// match maps pattern nodes to distinct nodes of graph such that every
+/// pattern edge is present; graph may have further edges among them.
+///
+/// Surviving nodes and edges keep their order. New nodes receive ids above
+/// the largest id in graph, and are added after the surviving nodes.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, DefaultGraph, Error };
+/// use gamma::rewrite::{ Rule, apply_rules };
+///
+/// fn main() -> Result<(), Error> {
+///     // keep one end of an edge and delete the other, with all of its
+///     // edges; on P4 the matches (0, 1) and (2, 3) remove nodes 1 and 3
+///     let rule = Rule::new(
+///         DefaultGraph::try_from(vec![ (0, 1) ])?,
+///         DefaultGraph::try_from(vec![ vec![ ] ])?,
+///         vec![ (0, 0) ]
+///     )?;
+///     let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 3) ])?;
+///     let result = apply_rules(&graph, &[ rule ])?;
+///
+///     assert_eq!(result.ids().collect::<Vec<_>>(), vec![ 0, 2 ]);
+///     assert_eq!(result.edges().collect::<Vec<_>>(), vec![ ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn apply_rules<G: Graph>(
+    graph: &G, rules: &[Rule]
+) -> Result<DefaultGraph, Error> {
+    let mut taken = HashSet::new();
+    let mut matches = Vec::new();
+
+    for rule in rules {
+        let order = search_order(rule.pattern());
+
+        if order.is_empty() {
+            continue;
+        }
+
+        while let Some(found) = find(graph, rule.pattern(), &order, &taken) {
+            taken.extend(found.values().cloned());
+            matches.push((rule, found));
+        }
+    }
+
+    substitute(graph, &matches)
+}
+
+fn substitute<G: Graph>(
+    graph: &G, matches: &[(&Rule, HashMap<usize, usize>)]
+) -> Result<DefaultGraph, Error> {
+    let mut deleted_nodes = HashSet::new();
+    let mut deleted_edges = HashSet::new();
+    let mut added_nodes = Vec::new();
+    let mut added_edges = Vec::new();
+    let mut next_id = graph.ids().max().map_or(0, |id| id + 1);
+
+    for (rule, found) in matches {
+        let kept = rule.boundary().iter()
+            .map(|&(sid, tid)| (tid, found[&sid]))
+            .collect::<HashMap<_,_>>();
+        let kept_sources = rule.boundary().iter()
+            .map(|&(sid, _)| sid)
+            .collect::<HashSet<_>>();
+
+        for (sid, tid) in rule.pattern().edges() {
+            deleted_edges.insert(key(found[&sid], found[&tid]));
+        }
+
+        for id in rule.pattern().ids() {
+            if !kept_sources.contains(&id) {
+                deleted_nodes.insert(found[&id]);
+            }
+        }
+
+        let mut images = kept;
+
+        for id in rule.replacement().ids() {
+            if let Entry::Vacant(entry) = images.entry(id) {
+                entry.insert(next_id);
+                added_nodes.push(next_id);
+                next_id += 1;
+            }
+        }
+
+        for (sid, tid) in rule.replacement().edges() {
+            added_edges.push((images[&sid], images[&tid]));
+        }
+    }
+
+    let mut result = DefaultGraph::new();
+
+    for id in graph.ids().chain(added_nodes) {
+        if !deleted_nodes.contains(&id) {
+            result.add_node(id)?;
+        }
+    }
+
+    for (sid, tid) in graph.edges() {
+        if !deleted_nodes.contains(&sid) && !deleted_nodes.contains(&tid) &&
+            !deleted_edges.contains(&key(sid, tid)) {
+            result.add_edge(sid, tid)?;
+        }
+    }
+
+    for (sid, tid) in added_edges {
+        if !result.has_edge(sid, tid)? {
+            result.add_edge(sid, tid)?;
+        }
+    }
+
+    Ok(result)
+}
+
+fn key(sid: usize, tid: usize) -> (usize, usize) {
+    (sid.min(tid), sid.max(tid))
+}
+
+/// Returns the ids of pattern ordered so that each follows a neighbor
+/// where possible, keeping candidate sets small during the search.
+fn search_order(pattern: &DefaultGraph) -> Vec<usize> {
+    let mut result = Vec::new();
+    let mut placed = HashSet::new();
+
+    for root in pattern.ids() {
+        if !placed.insert(root) {
+            continue;
+        }
+
+        let start = result.len();
+
+        result.push(root);
+
+        let mut index = start;
+
+        while index < result.len() {
+            for neighbor in pattern.neighbors(result[index]).expect("neighbors") {
+                if placed.insert(neighbor) {
+                    result.push(neighbor);
+                }
+            }
+
+            index += 1;
+        }
+    }
+
+    result
+}
+
+fn find<G: Graph>(
+    graph: &G,
+    pattern: &DefaultGraph,
+    order: &[usize],
+    taken: &HashSet<usize>
+) -> Option<HashMap<usize, usize>> {
+    let mut mapping = HashMap::new();
+    let mut used = taken.clone();
+
+    if extend(graph, pattern, order, &mut mapping, &mut used) {
+        Some(mapping)
+    } else {
+        None
+    }
+}
+
+fn extend<G: Graph>(
+    graph: &G,
+    pattern: &DefaultGraph,
+    order: &[usize],
+    mapping: &mut HashMap<usize, usize>,
+    used: &mut HashSet<usize>
+) -> bool {
+    let id = match order.get(mapping.len()) {
+        Some(&id) => id,
+        None => return true
+    };
+    let anchor = pattern.neighbors(id).expect("neighbors")
+        .find_map(|neighbor| mapping.get(&neighbor).cloned());
+    let candidates: Box<dyn Iterator<Item=usize>> = match anchor {
+        Some(anchor) => graph.neighbors(anchor).expect("neighbors"),
+        None => graph.ids()
+    };
+
+    for candidate in candidates {
+        if used.contains(&candidate) {
+            continue;
+        }
+
+        let fits = pattern.neighbors(id).expect("neighbors").all(|neighbor| {
+            match mapping.get(&neighbor) {
+                Some(&mapped) => graph.has_edge(candidate, mapped).expect("edge"),
+                None => true
+            }
+        });
+
+        if fits {
+            mapping.insert(id, candidate);
+            used.insert(candidate);
+
+            if extend(graph, pattern, order, mapping, used) {
+                return true;
+            }
+
+            used.remove(&candidate);
+            mapping.remove(&id);
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+
+    fn subdivide() -> Rule {
+        Rule::new(
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (0, 2), (2, 1) ]).unwrap(),
+            vec![ (0, 0), (1, 1) ]
+        ).unwrap()
+    }
+
+    #[test]
+    fn no_rules() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(apply_rules(&graph, &[ ]), Ok(graph))
+    }
+
+    #[test]
+    fn empty_pattern() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let rule = Rule::new(
+            DefaultGraph::new(),
+            DefaultGraph::try_from(vec![ vec![ ] ]).unwrap(),
+            vec![ ]
+        ).unwrap();
+
+        assert_eq!(apply_rules(&graph, &[ rule ]), Ok(graph))
+    }
+
+    #[test]
+    fn subdivide_p3() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let result = apply_rules(&graph, &[ subdivide() ]).unwrap();
+
+        // matches must not overlap, so only (0, 1) is subdivided
+        assert_eq!(result.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3 ]);
+        assert_eq!(result.edges().collect::<Vec<_>>(), [ (1, 2), (0, 3), (3, 1) ])
+    }
+
+    #[test]
+    fn subdivide_p2_p2() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+        let result = apply_rules(&graph, &[ subdivide() ]).unwrap();
+
+        assert_eq!(result.order(), 6);
+        assert_eq!(result.size(), 4)
+    }
+
+    #[test]
+    fn delete_triangle_keep_boundary() {
+        // replace a triangle by a star centered on a new node
+        let rule = Rule::new(
+            DefaultGraph::try_from(vec![ (0, 1), (1, 2), (2, 0) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (0, 3), (1, 3), (2, 3) ]).unwrap(),
+            vec![ (0, 0), (1, 1), (2, 2) ]
+        ).unwrap();
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (2, 3)
+        ]).unwrap();
+        let result = apply_rules(&graph, &[ rule ]).unwrap();
+
+        assert_eq!(result.edges().collect::<Vec<_>>(), [
+            (2, 3), (0, 4), (1, 4), (2, 4)
+        ])
+    }
+
+    #[test]
+    fn deleted_node_drops_outside_edges() {
+        let rule = Rule::new(
+            DefaultGraph::try_from(vec![ vec![ ] ]).unwrap(),
+            DefaultGraph::new(),
+            vec![ ]
+        ).unwrap();
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(apply_rules(&graph, &[ rule ]), Ok(DefaultGraph::new()))
+    }
+
+    #[test]
+    fn boundary_edge_already_present() {
+        // an edge in the replacement between boundary nodes already joined
+        // in graph is not added twice
+        let rule = Rule::new(
+            DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (0, 2), (2, 1) ]).unwrap(),
+            vec![ (0, 0), (1, 1), (2, 2) ]
+        ).unwrap();
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0)
+        ]).unwrap();
+        let result = apply_rules(&graph, &[ rule ]).unwrap();
+
+        assert_eq!(result.size(), 2)
+    }
+}
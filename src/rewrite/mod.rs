@@ -0,0 +1,5 @@
+mod rule;
+mod apply_rules;
+
+pub use rule::Rule;
+pub use apply_rules::apply_rules;
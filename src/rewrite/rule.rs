@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Graph, DefaultGraph, Error };
+
+/// A graph transformation: wherever pattern occurs, it is replaced by
+/// replacement. Boundary pairs a pattern node with the replacement node
+/// that takes its place, so the match's connections to the rest of the
+/// graph survive the substitution. Pattern nodes outside the boundary are
+/// deleted along with all of their edges, and replacement nodes outside
+/// the boundary are created fresh. A rule with an empty pattern never
+/// applies.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ DefaultGraph, Error };
+/// use gamma::rewrite::Rule;
+///
+/// fn main() -> Result<(), Error> {
+///     // subdivide an edge
+///     let rule = Rule::new(
+///         DefaultGraph::try_from(vec![ (0, 1) ])?,
+///         DefaultGraph::try_from(vec![ (0, 2), (2, 1) ])?,
+///         vec![ (0, 0), (1, 1) ]
+///     )?;
+///
+///     assert_eq!(rule.boundary(), &[ (0, 0), (1, 1) ]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct Rule {
+    pattern: DefaultGraph,
+    replacement: DefaultGraph,
+    boundary: Vec<(usize, usize)>
+}
+
+impl Rule {
+    /// Returns a Rule, or Error if a boundary pair names an unknown node, or
+    /// a node appears in more than one pair.
+    pub fn new(
+        pattern: DefaultGraph,
+        replacement: DefaultGraph,
+        boundary: Vec<(usize, usize)>
+    ) -> Result<Self, Error> {
+        let mut sources = HashSet::new();
+        let mut targets = HashSet::new();
+
+        for &(sid, tid) in &boundary {
+            if !pattern.has_id(sid) {
+                return Err(Error::UnknownId(sid));
+            } else if !replacement.has_id(tid) {
+                return Err(Error::UnknownId(tid));
+            } else if !sources.insert(sid) {
+                return Err(Error::DuplicateId(sid));
+            } else if !targets.insert(tid) {
+                return Err(Error::DuplicateId(tid));
+            }
+        }
+
+        Ok(Self { pattern, replacement, boundary })
+    }
+
+    pub fn pattern(&self) -> &DefaultGraph {
+        &self.pattern
+    }
+
+    pub fn replacement(&self) -> &DefaultGraph {
+        &self.replacement
+    }
+
+    pub fn boundary(&self) -> &[(usize, usize)] {
+        &self.boundary
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn unknown_pattern_id() {
+        let rule = Rule::new(
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            vec![ (2, 0) ]
+        );
+
+        assert_eq!(rule, Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn unknown_replacement_id() {
+        let rule = Rule::new(
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            vec![ (0, 5) ]
+        );
+
+        assert_eq!(rule, Err(Error::UnknownId(5)))
+    }
+
+    #[test]
+    fn repeated_target() {
+        let rule = Rule::new(
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            DefaultGraph::try_from(vec![ (0, 1) ]).unwrap(),
+            vec![ (0, 0), (1, 0) ]
+        );
+
+        assert_eq!(rule, Err(Error::DuplicateId(0)))
+    }
+}
@@ -0,0 +1,109 @@
+//! A small seeded pseudorandom number generator, so that randomized
+//! algorithms are reproducible without an external dependency.
+
+/// SplitMix64. Fast and statistically sound for sampling, but not
+/// cryptographically secure.
+#[derive(Debug,Clone)]
+pub(crate) struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in [0, bound). Panics if bound is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "zero bound");
+
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Returns a number in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Moves a uniform sample of count items to the front of items, in
+    /// random order.
+    pub fn partial_shuffle<T>(&mut self, items: &mut [T], count: usize) {
+        for i in 0..count.min(items.len()) {
+            let j = i + self.below(items.len() - i);
+
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod next_u64 {
+    use super::*;
+
+    #[test]
+    fn same_seed() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64())
+        }
+    }
+}
+
+#[cfg(test)]
+mod below {
+    use super::*;
+
+    #[test]
+    fn within_bound() {
+        let mut rng = Rng::new(0);
+
+        for bound in 1..100 {
+            assert!(rng.below(bound) < bound)
+        }
+    }
+}
+
+#[cfg(test)]
+mod next_f64 {
+    use super::*;
+
+    #[test]
+    fn unit_interval() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..100 {
+            let x = rng.next_f64();
+
+            assert!((0.0..1.0).contains(&x))
+        }
+    }
+}
+
+#[cfg(test)]
+mod partial_shuffle {
+    use super::*;
+
+    #[test]
+    fn permutes() {
+        let mut rng = Rng::new(3);
+        let mut items = (0..10).collect::<Vec<_>>();
+
+        rng.partial_shuffle(&mut items, 4);
+        items.sort_unstable();
+
+        assert_eq!(items, (0..10).collect::<Vec<_>>())
+    }
+}
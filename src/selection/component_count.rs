@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+
+/// Returns the number of connected components in graph. Unlike counting
+/// the items of `components`, no subgraphs are built: edges are merged
+/// with a union-find over node indices.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::selection::component_count;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0 ],
+///         vec![ ]
+///     ])?;
+///
+///     assert_eq!(component_count(&graph), 2);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn component_count<G: Graph>(graph: &G) -> usize {
+    let indices = graph.ids()
+        .enumerate()
+        .map(|(index, id)| (id, index))
+        .collect::<HashMap<_,_>>();
+    let mut parents = (0..indices.len()).collect::<Vec<_>>();
+    let mut sizes = vec![ 1; indices.len() ];
+    let mut result = indices.len();
+
+    for (sid, tid) in graph.edges() {
+        let source = root(&mut parents, indices[&sid]);
+        let target = root(&mut parents, indices[&tid]);
+
+        if source == target {
+            continue;
+        }
+
+        let (small, large) = if sizes[source] < sizes[target] {
+            (source, target)
+        } else {
+            (target, source)
+        };
+
+        parents[small] = large;
+        sizes[large] += sizes[small];
+        result -= 1;
+    }
+
+    result
+}
+
+fn root(parents: &mut [usize], mut index: usize) -> usize {
+    while parents[index] != index {
+        parents[index] = parents[parents[index]];
+        index = parents[index];
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod component_count {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::selection::components;
+
+    #[test]
+    fn empty() {
+        assert_eq!(component_count(&DefaultGraph::new()), 0)
+    }
+
+    #[test]
+    fn p1_p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ], vec![ ] ]).unwrap();
+
+        assert_eq!(component_count(&graph), 2)
+    }
+
+    #[test]
+    fn c3_p2_sparse_ids() {
+        let graph = DefaultGraph::try_from(vec![
+            (10, 20), (20, 30), (30, 10), (7, 3)
+        ]).unwrap();
+
+        assert_eq!(component_count(&graph), 2)
+    }
+
+    #[test]
+    fn agrees_with_components() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (2, 3), (3, 4), (5, 6), (6, 7), (7, 5), (1, 8)
+        ]).unwrap();
+
+        assert_eq!(component_count(&graph), components(&graph).count())
+    }
+}
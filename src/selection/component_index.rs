@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use crate::graph::{ Graph, Error };
+
+/// Labels each node with its connected component, answering
+/// `same_component` and `component_size` in constant time. The index
+/// stays in sync as nodes and edges are added: joining two components
+/// relabels the members of the smaller one, so each node is relabeled at
+/// most log2(order) times. Edge removal is not supported; rebuild with
+/// `new` instead.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::selection::ComponentIndex;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (3, 4)
+///     ])?;
+///     let mut index = ComponentIndex::new(&graph);
+///
+///     assert_eq!(index.same_component(0, 2)?, true);
+///     assert_eq!(index.same_component(0, 3)?, false);
+///     assert_eq!(index.component_size(3)?, 2);
+///
+///     index.add_edge(2, 3)?;
+///
+///     assert_eq!(index.same_component(0, 4)?, true);
+///     assert_eq!(index.component_size(3)?, 5);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct ComponentIndex {
+    labels: HashMap<usize, usize>,
+    members: Vec<Vec<usize>>,
+    count: usize
+}
+
+impl ComponentIndex {
+    /// Returns the index of graph's components, in time proportional to
+    /// order plus size.
+    pub fn new<G: Graph>(graph: &G) -> Self {
+        let mut result = Self {
+            labels: HashMap::new(),
+            members: Vec::new(),
+            count: 0
+        };
+
+        for id in graph.ids() {
+            result.add_node(id).expect("add node");
+        }
+
+        for (sid, tid) in graph.edges() {
+            result.add_edge(sid, tid).expect("add edge");
+        }
+
+        result
+    }
+
+    /// Adds id as a component of its own. Returns Error if id is already
+    /// present.
+    pub fn add_node(&mut self, id: usize) -> Result<(), Error> {
+        match self.labels.entry(id) {
+            Entry::Occupied(_) => Err(Error::DuplicateId(id)),
+            Entry::Vacant(entry) => {
+                entry.insert(self.members.len());
+                self.members.push(vec![ id ]);
+                self.count += 1;
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Records an edge between sid and tid, merging their components.
+    /// Returns Error if either is not found.
+    pub fn add_edge(&mut self, sid: usize, tid: usize) -> Result<(), Error> {
+        let source = self.label(sid)?;
+        let target = self.label(tid)?;
+
+        if source == target {
+            return Ok(());
+        }
+
+        let (small, large) =
+            if self.members[source].len() < self.members[target].len() {
+                (source, target)
+            } else {
+                (target, source)
+            };
+        let moved = std::mem::take(&mut self.members[small]);
+
+        for &id in &moved {
+            self.labels.insert(id, large);
+        }
+
+        self.members[large].extend(moved);
+        self.count -= 1;
+
+        Ok(())
+    }
+
+    /// Returns true if a path joins sid and tid, or false otherwise.
+    /// Returns Error if either is not found.
+    pub fn same_component(&self, sid: usize, tid: usize) -> Result<bool, Error> {
+        Ok(self.label(sid)? == self.label(tid)?)
+    }
+
+    /// Returns the number of nodes in the component containing id, or
+    /// Error if id is not found.
+    pub fn component_size(&self, id: usize) -> Result<usize, Error> {
+        Ok(self.members[self.label(id)?].len())
+    }
+
+    /// Returns the members of the component containing id, in no
+    /// particular order, or Error if id is not found.
+    pub fn component(&self, id: usize) -> Result<&[usize], Error> {
+        Ok(&self.members[self.label(id)?])
+    }
+
+    /// Returns the number of components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn label(&self, id: usize) -> Result<usize, Error> {
+        match self.labels.get(&id) {
+            Some(&label) => Ok(label),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::selection::component_count;
+
+    #[test]
+    fn empty() {
+        let index = ComponentIndex::new(&DefaultGraph::new());
+
+        assert_eq!(index.count(), 0);
+        assert_eq!(index.component_size(0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn unknown_tid() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let mut index = ComponentIndex::new(&graph);
+
+        assert_eq!(index.same_component(0, 5), Err(Error::UnknownId(5)));
+        assert_eq!(index.add_edge(0, 5), Err(Error::UnknownId(5)))
+    }
+
+    #[test]
+    fn duplicate_node() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let mut index = ComponentIndex::new(&graph);
+
+        assert_eq!(index.add_node(1), Err(Error::DuplicateId(1)))
+    }
+
+    #[test]
+    fn isolated_node() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let mut index = ComponentIndex::new(&graph);
+
+        index.add_node(7).unwrap();
+
+        assert_eq!(index.component_size(7), Ok(1));
+        assert_eq!(index.component(7), Ok(&[ 7 ][..]));
+        assert_eq!(index.count(), 2)
+    }
+
+    #[test]
+    fn edge_within_component() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let mut index = ComponentIndex::new(&graph);
+
+        index.add_edge(0, 2).unwrap();
+
+        assert_eq!(index.count(), 1);
+        assert_eq!(index.component_size(1), Ok(3))
+    }
+
+    #[test]
+    fn matches_component_count() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (2, 3), (4, 5), (5, 6), (7, 8), (8, 9), (9, 7)
+        ]).unwrap();
+        let index = ComponentIndex::new(&graph);
+
+        assert_eq!(index.count(), component_count(&graph));
+        assert_eq!(index.component_size(8), Ok(3))
+    }
+}
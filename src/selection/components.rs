@@ -39,7 +39,7 @@ pub fn components<'a, G: Graph>(
     Components {
         visited: HashSet::new(),
         iter: graph.ids(),
-        graph: graph
+        graph
     }
 }
 
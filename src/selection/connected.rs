@@ -0,0 +1,147 @@
+use std::collections::{ HashSet, VecDeque };
+
+use crate::graph::{ Graph, Error };
+
+/// Returns true if a path joins sid and tid, or Error if either is not
+/// found. The search stops as soon as tid is reached.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::selection::connected;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (3, 4)
+///     ])?;
+///
+///     assert_eq!(connected(&graph, 0, 2), Ok(true));
+///     assert_eq!(connected(&graph, 0, 4), Ok(false));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn connected<G: Graph>(
+    graph: &G, sid: usize, tid: usize
+) -> Result<bool, Error> {
+    if !graph.has_id(sid) {
+        return Err(Error::UnknownId(sid));
+    } else if !graph.has_id(tid) {
+        return Err(Error::UnknownId(tid));
+    }
+
+    Ok(reach(graph, sid, Some(tid)) == Reach::Found)
+}
+
+/// Returns true if every node of graph is reachable from every other. A
+/// graph with fewer than two nodes is connected.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::selection::is_connected;
+///
+/// fn main() -> Result<(), Error> {
+///     let p3 = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ])?;
+///     let p2_p2 = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ])?;
+///
+///     assert_eq!(is_connected(&p3), true);
+///     assert_eq!(is_connected(&p2_p2), false);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn is_connected<G: Graph>(graph: &G) -> bool {
+    match graph.ids().next() {
+        Some(root) => reach(graph, root, None) == Reach::Count(graph.order()),
+        None => true
+    }
+}
+
+#[derive(Debug,PartialEq)]
+enum Reach {
+    Found,
+    Count(usize)
+}
+
+fn reach<G: Graph>(graph: &G, root: usize, target: Option<usize>) -> Reach {
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    visited.insert(root);
+    queue.push_back(root);
+
+    while let Some(id) = queue.pop_front() {
+        if Some(id) == target {
+            return Reach::Found;
+        }
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            if visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    Reach::Count(visited.len())
+}
+
+#[cfg(test)]
+mod connected_nodes {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_tid() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(connected(&graph, 0, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn same_node() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(connected(&graph, 0, 0), Ok(true))
+    }
+
+    #[test]
+    fn c3_p2() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0), (3, 4)
+        ]).unwrap();
+
+        assert_eq!(connected(&graph, 2, 1), Ok(true));
+        assert_eq!(connected(&graph, 4, 3), Ok(true));
+        assert_eq!(connected(&graph, 3, 0), Ok(false))
+    }
+}
+
+#[cfg(test)]
+mod whole_graph {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        assert!(is_connected(&DefaultGraph::new()))
+    }
+
+    #[test]
+    fn p1_p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ], vec![ ] ]).unwrap();
+
+        assert!(!is_connected(&graph))
+    }
+
+    #[test]
+    fn c4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+
+        assert!(is_connected(&graph))
+    }
+}
@@ -0,0 +1,107 @@
+use crate::graph::Graph;
+
+/// Returns an Iterator over the ids of nodes having exactly degree
+/// neighbors, in the order given by `Graph::ids`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::selection::nodes_with_degree;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///
+///     assert_eq!(nodes_with_degree(&graph, 2).collect::<Vec<_>>(), vec![ 1 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn nodes_with_degree<'a, G: Graph>(
+    graph: &'a G, degree: usize
+) -> Box<dyn Iterator<Item=usize> + 'a> {
+    Box::new(graph.ids().filter(move |&id| {
+        graph.degree(id).expect("degree") == degree
+    }))
+}
+
+/// Returns an Iterator over the ids of nodes without neighbors.
+pub fn isolated_nodes<'a, G: Graph>(
+    graph: &'a G
+) -> Box<dyn Iterator<Item=usize> + 'a> {
+    nodes_with_degree(graph, 0)
+}
+
+/// Returns an Iterator over the ids of nodes with exactly one neighbor.
+pub fn leaves<'a, G: Graph>(
+    graph: &'a G
+) -> Box<dyn Iterator<Item=usize> + 'a> {
+    nodes_with_degree(graph, 1)
+}
+
+#[cfg(test)]
+mod nodes_with_degree {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(nodes_with_degree(&graph, 0).count(), 0)
+    }
+
+    #[test]
+    fn star() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3 ],
+            vec![ 0 ],
+            vec![ 0 ],
+            vec![ 0 ]
+        ]).unwrap();
+
+        assert_eq!(nodes_with_degree(&graph, 3).collect::<Vec<_>>(), [ 0 ])
+    }
+}
+
+#[cfg(test)]
+mod isolated_nodes {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p2_p1_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ],
+            vec![ 2 ],
+            vec![ 1 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(isolated_nodes(&graph).collect::<Vec<_>>(), [ 0, 3 ])
+    }
+}
+
+#[cfg(test)]
+mod leaves {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p4() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2 ]
+        ]).unwrap();
+
+        assert_eq!(leaves(&graph).collect::<Vec<_>>(), [ 0, 3 ])
+    }
+}
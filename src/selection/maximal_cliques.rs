@@ -0,0 +1,158 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::graph::Graph;
+use crate::ordering::degeneracy;
+
+/// Returns every maximal clique of graph. Isolated nodes are returned as
+/// cliques of one.
+///
+/// Uses Bron-Kerbosch with pivoting, starting from each node in
+/// smallest-last order with only its later neighbors as candidates. On a
+/// graph with degeneracy d, this takes time roughly proportional to order
+/// times 3^(d/3), so sparse graphs are fast regardless of their order.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::selection::maximal_cliques;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3)
+///     ])?;
+///     let mut cliques = maximal_cliques(&graph);
+///
+///     for clique in cliques.iter_mut() {
+///         clique.sort_unstable();
+///     }
+///
+///     cliques.sort_unstable();
+///
+///     assert_eq!(cliques, vec![ vec![ 0, 1, 2 ], vec![ 2, 3 ] ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn maximal_cliques<G: Graph>(graph: &G) -> Vec<Vec<usize>> {
+    let adjacency = graph.ids().map(|id| {
+        (id, graph.neighbors(id).expect("neighbors").collect::<HashSet<_>>())
+    }).collect::<HashMap<_, _>>();
+    let ordering = degeneracy(graph).ordering;
+    let positions = ordering.iter().enumerate()
+        .map(|(position, &id)| (id, position))
+        .collect::<HashMap<_, _>>();
+    let mut result = Vec::new();
+
+    for (position, &id) in ordering.iter().enumerate() {
+        let (later, earlier) = graph.neighbors(id).expect("neighbors")
+            .partition(|neighbor| positions[neighbor] > position);
+
+        expand(vec![ id ], later, earlier, &adjacency, &mut result);
+    }
+
+    result
+}
+
+fn expand(
+    clique: Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    adjacency: &HashMap<usize, HashSet<usize>>,
+    result: &mut Vec<Vec<usize>>
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        result.push(clique);
+
+        return;
+    }
+
+    let pivot = candidates.iter().chain(excluded.iter())
+        .max_by_key(|&id| {
+            candidates.iter().filter(|c| adjacency[id].contains(c)).count()
+        })
+        .cloned()
+        .expect("pivot");
+    let branches = candidates.iter()
+        .filter(|id| !adjacency[&pivot].contains(id))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for id in branches {
+        let neighbors = &adjacency[&id];
+        let mut next = clique.clone();
+        let within = |ids: &[usize]| ids.iter()
+            .filter(|id| neighbors.contains(id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        next.push(id);
+        expand(next, within(&candidates), within(&excluded), adjacency, result);
+        candidates.retain(|&c| c != id);
+        excluded.push(id);
+    }
+}
+
+#[cfg(test)]
+mod maximal_cliques {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    fn sorted(mut cliques: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        for clique in cliques.iter_mut() {
+            clique.sort_unstable();
+        }
+
+        cliques.sort_unstable();
+
+        cliques
+    }
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(maximal_cliques(&graph), Vec::<Vec<usize>>::new())
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(maximal_cliques(&graph), [ [ 0 ] ])
+    }
+
+    #[test]
+    fn c5() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 0)
+        ]).unwrap();
+
+        assert_eq!(sorted(maximal_cliques(&graph)), [
+            [ 0, 1 ], [ 0, 4 ], [ 1, 2 ], [ 2, 3 ], [ 3, 4 ]
+        ])
+    }
+
+    #[test]
+    fn k4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+        ]).unwrap();
+
+        assert_eq!(sorted(maximal_cliques(&graph)), [ [ 0, 1, 2, 3 ] ])
+    }
+
+    #[test]
+    fn octahedron() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 2), (0, 3), (0, 4), (0, 5),
+            (1, 2), (1, 3), (1, 4), (1, 5),
+            (2, 4), (2, 5), (3, 4), (3, 5)
+        ]).unwrap();
+
+        assert_eq!(sorted(maximal_cliques(&graph)), [
+            [ 0, 2, 4 ], [ 0, 2, 5 ], [ 0, 3, 4 ], [ 0, 3, 5 ],
+            [ 1, 2, 4 ], [ 1, 2, 5 ], [ 1, 3, 4 ], [ 1, 3, 5 ]
+        ])
+    }
+}
@@ -1,3 +1,21 @@
 mod components;
+mod connected;
+#[allow(clippy::module_inception)]
+mod component_count;
+mod degree;
+mod weighted_components;
+mod prune;
+#[allow(clippy::module_inception)]
+mod maximal_cliques;
+mod component_index;
 
-pub use components::components;
\ No newline at end of file
+pub use components::components;
+pub use connected::{ connected, is_connected };
+pub use component_count::component_count;
+pub use weighted_components::{
+    weighted_components, weighted_subgraph, WeightedComponents
+};
+pub use degree::{ nodes_with_degree, isolated_nodes, leaves };
+pub use prune::{ max_degree_subgraph, prune_leaves };
+pub use maximal_cliques::maximal_cliques;
+pub use component_index::ComponentIndex;
@@ -0,0 +1,169 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::graph::{ Graph, DefaultGraph };
+
+/// Returns the subgraph left after repeatedly deleting nodes with more
+/// than k neighbors. Deleting a node lowers the degree of its neighbors,
+/// so fewer nodes may be deleted than had degree above k at the start.
+/// Nodes and edges keep the order given by graph.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::selection::max_degree_subgraph;
+///
+/// fn main() -> Result<(), Error> {
+///     let star = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 2), (0, 3), (3, 4)
+///     ])?;
+///     let result = max_degree_subgraph(&star, 2);
+///
+///     assert_eq!(result.ids().collect::<Vec<_>>(), vec![ 1, 2, 3, 4 ]);
+///     assert_eq!(result.edges().collect::<Vec<_>>(), vec![ (3, 4) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn max_degree_subgraph<G: Graph>(graph: &G, k: usize) -> DefaultGraph {
+    peel(graph, |degree| degree > k)
+}
+
+/// Returns the 2-core of graph: the subgraph left after repeatedly deleting
+/// nodes with fewer than two neighbors. Trees vanish entirely, leaving
+/// only ring nodes and the chains between rings. Nodes and edges keep the
+/// order given by graph.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::selection::prune_leaves;
+///
+/// fn main() -> Result<(), Error> {
+///     // cyclopropane with an ethyl substituent
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0), (2, 3), (3, 4)
+///     ])?;
+///     let core = prune_leaves(&graph);
+///
+///     assert_eq!(core.ids().collect::<Vec<_>>(), vec![ 0, 1, 2 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn prune_leaves<G: Graph>(graph: &G) -> DefaultGraph {
+    peel(graph, |degree| degree < 2)
+}
+
+fn peel<G: Graph, F: Fn(usize) -> bool>(graph: &G, remove: F) -> DefaultGraph {
+    let mut degrees = graph.ids().map(|id| {
+        (id, graph.degree(id).expect("degree"))
+    }).collect::<HashMap<_,_>>();
+    let mut stack = graph.ids()
+        .filter(|id| remove(degrees[id]))
+        .collect::<Vec<_>>();
+    let mut removed = HashSet::new();
+
+    while let Some(id) = stack.pop() {
+        if removed.contains(&id) || !remove(degrees[&id]) {
+            continue;
+        }
+
+        removed.insert(id);
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            if removed.contains(&neighbor) {
+                continue;
+            }
+
+            let degree = degrees.get_mut(&neighbor).expect("degree");
+
+            *degree -= 1;
+
+            if remove(*degree) {
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    let mut result = DefaultGraph::new();
+
+    for id in graph.ids().filter(|id| !removed.contains(id)) {
+        result.add_node(id).expect("add node");
+    }
+
+    for (sid, tid) in graph.edges() {
+        if !removed.contains(&sid) && !removed.contains(&tid) {
+            result.add_edge(sid, tid).expect("add edge");
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod max_degree {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn p0() {
+        assert_eq!(max_degree_subgraph(&DefaultGraph::new(), 0), DefaultGraph::new())
+    }
+
+    #[test]
+    fn k4_with_k_two() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+        ]).unwrap();
+
+        // deleting any one node leaves a triangle of degree-two nodes
+        assert_eq!(max_degree_subgraph(&graph, 2).order(), 3)
+    }
+
+    #[test]
+    fn k_zero() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2)
+        ]).unwrap();
+        let result = max_degree_subgraph(&graph, 0);
+
+        assert_eq!(result.size(), 0);
+        assert!(result.order() <= 2)
+    }
+}
+
+#[cfg(test)]
+mod leaves {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn isolated() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+
+        assert_eq!(prune_leaves(&graph), DefaultGraph::new())
+    }
+
+    #[test]
+    fn tree() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (1, 3), (3, 4)
+        ]).unwrap();
+
+        assert_eq!(prune_leaves(&graph), DefaultGraph::new())
+    }
+
+    #[test]
+    fn rings_joined_by_chain() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 0),
+            (2, 3), (3, 4),
+            (4, 5), (5, 6), (6, 4),
+            (6, 7)
+        ]).unwrap();
+        let core = prune_leaves(&graph);
+
+        assert_eq!(core.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3, 4, 5, 6 ]);
+        assert_eq!(core.size(), 8)
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::{ HashMap, HashSet };
+use std::vec::IntoIter;
+
+use crate::graph::{ WeightedGraph, DefaultWeightedGraph, Error };
+use super::ComponentIndex;
+
+/// Returns the connected components of a WeightedGraph, each carrying the
+/// weights of its edges in the original graph.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, WeightedGraph, Error, DefaultWeightedGraph };
+/// use gamma::selection::weighted_components;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 2.5),
+///         (2, 3, 4.0)
+///     ])?;
+///     let components = weighted_components(&graph).collect::<Vec<_>>();
+///
+///     assert_eq!(components.len(), 2);
+///     assert_eq!(components[1].weight(3, 2), Ok(Some(&4.0)));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn weighted_components<G>(graph: &G) -> WeightedComponents<'_, G>
+where G: WeightedGraph, G::Weight: Clone {
+    let index = ComponentIndex::new(graph);
+    let mut roots = Vec::new();
+    let mut labels = HashMap::new();
+    let mut nodes = HashMap::new();
+    let mut edges = HashMap::new();
+
+    for id in graph.ids() {
+        if !labels.contains_key(&id) {
+            for &member in index.component(id).expect("component") {
+                labels.insert(member, id);
+            }
+
+            roots.push(id);
+        }
+
+        nodes.entry(labels[&id]).or_insert_with(Vec::new).push(id);
+    }
+
+    for (sid, tid) in graph.edges() {
+        edges.entry(labels[&sid]).or_insert_with(Vec::new).push((sid, tid));
+    }
+
+    WeightedComponents {
+        roots: roots.into_iter(),
+        nodes,
+        edges,
+        graph
+    }
+}
+
+/// Iterator over the components of a WeightedGraph, in the order of their
+/// first node. See `weighted_components`.
+pub struct WeightedComponents<'a, G: WeightedGraph> {
+    roots: IntoIter<usize>,
+    nodes: HashMap<usize, Vec<usize>>,
+    edges: HashMap<usize, Vec<(usize, usize)>>,
+    graph: &'a G
+}
+
+impl<'a, G> Iterator for WeightedComponents<'a, G>
+where G: WeightedGraph, G::Weight: Clone {
+    type Item = DefaultWeightedGraph<G::Weight>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let root = self.roots.next()?;
+        let mut result = DefaultWeightedGraph::new();
+
+        for id in self.nodes.remove(&root).expect("nodes") {
+            result.add_node(id).expect("add node");
+        }
+
+        for (sid, tid) in self.edges.remove(&root).unwrap_or_default() {
+            let weight = self.graph.weight(sid, tid)
+                .expect("weight")
+                .expect("missing weight")
+                .clone();
+
+            result.add_edge(sid, tid, weight).expect("add edge");
+        }
+
+        Some(result)
+    }
+}
+
+/// Returns the subgraph of graph induced by ids, carrying the original edge
+/// weights. Nodes are added in the order given, and edges in the order
+/// graph reports them. Returns Error if an id is not found or repeated.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, WeightedGraph, Error, DefaultWeightedGraph };
+/// use gamma::selection::weighted_subgraph;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 1),
+///         (1, 2, 2),
+///         (2, 0, 3)
+///     ])?;
+///     let subgraph = weighted_subgraph(&graph, &[ 2, 0 ])?;
+///
+///     assert_eq!(subgraph.edges().collect::<Vec<_>>(), vec![ (2, 0) ]);
+///     assert_eq!(subgraph.weight(0, 2), Ok(Some(&3)));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn weighted_subgraph<G>(
+    graph: &G, ids: &[usize]
+) -> Result<DefaultWeightedGraph<G::Weight>, Error>
+where G: WeightedGraph, G::Weight: Clone {
+    let mut result = DefaultWeightedGraph::new();
+    let members = ids.iter().cloned().collect::<HashSet<_>>();
+
+    for &id in ids {
+        if !graph.has_id(id) {
+            return Err(Error::UnknownId(id));
+        }
+
+        result.add_node(id)?;
+    }
+
+    for (sid, tid) in graph.edges() {
+        if members.contains(&sid) && members.contains(&tid) {
+            let weight = match graph.weight(sid, tid)? {
+                Some(weight) => weight.clone(),
+                None => return Err(Error::MissingEdge(sid, tid))
+            };
+
+            result.add_edge(sid, tid, weight)?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod components_weighted {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultWeightedGraph::<u8>::new();
+
+        assert_eq!(weighted_components(&graph).count(), 0)
+    }
+
+    #[test]
+    fn p2_p1() {
+        let mut graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 7)
+        ]).unwrap();
+
+        graph.add_node(2).unwrap();
+
+        let components = weighted_components(&graph).collect::<Vec<_>>();
+        let mut p1 = DefaultWeightedGraph::new();
+
+        p1.add_node(2).unwrap();
+
+        assert_eq!(components, vec![
+            DefaultWeightedGraph::try_from(vec![ (0, 1, 7) ]).unwrap(),
+            p1
+        ])
+    }
+
+    #[test]
+    fn c3_c4() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1), (1, 2, 2), (2, 0, 3),
+            (3, 4, 4), (4, 5, 5), (5, 6, 6), (6, 3, 7)
+        ]).unwrap();
+        let components = weighted_components(&graph).collect::<Vec<_>>();
+
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].size(), 3);
+        assert_eq!(components[0].weight(0, 2), Ok(Some(&3)));
+        assert_eq!(components[1].size(), 4);
+        assert_eq!(components[1].weight(3, 6), Ok(Some(&7)))
+    }
+}
+
+#[cfg(test)]
+mod subgraph {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::Graph;
+
+    #[test]
+    fn unknown_id() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 7)
+        ]).unwrap();
+
+        assert_eq!(weighted_subgraph(&graph, &[ 0, 2 ]), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn duplicate_id() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 7)
+        ]).unwrap();
+
+        assert_eq!(
+            weighted_subgraph(&graph, &[ 0, 0 ]),
+            Err(Error::DuplicateId(0))
+        )
+    }
+
+    #[test]
+    fn p3_ends() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 7), (1, 2, 8)
+        ]).unwrap();
+        let subgraph = weighted_subgraph(&graph, &[ 0, 2 ]).unwrap();
+
+        assert_eq!(subgraph.order(), 2);
+        assert_eq!(subgraph.size(), 0)
+    }
+}
@@ -0,0 +1,427 @@
+use std::collections::{ BinaryHeap, HashMap };
+use std::cmp::Reverse;
+use std::ops::Add;
+
+use crate::graph::{ WeightedGraph, Error };
+use super::dijkstra::WeightedPath;
+
+const WITNESS_LIMIT: usize = 64;
+
+/// A [contraction hierarchy](https://en.wikipedia.org/wiki/Contraction_hierarchies)
+/// over a static weighted graph. Nodes are contracted one at a time in
+/// order of importance, adding shortcut edges that preserve distances
+/// among the nodes that remain. Queries then search upward from both ends,
+/// settling far fewer nodes than `dijkstra`. Weights must not be negative.
+/// The hierarchy does not track later changes to the graph.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultWeightedGraph };
+/// use gamma::shortest_path::ContractionHierarchy;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 1),
+///         (1, 2, 1),
+///         (0, 2, 5),
+///         (2, 3, 1)
+///     ])?;
+///     let hierarchy = ContractionHierarchy::build(&graph);
+///
+///     assert_eq!(hierarchy.query(0, 3)?, Some((3, vec![ 0, 1, 2, 3 ])));
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct ContractionHierarchy<W> {
+    ids: Vec<usize>,
+    indices: HashMap<usize, usize>,
+    upward: Vec<Vec<(usize, W)>>,
+    middles: HashMap<(usize, usize), usize>
+}
+
+impl<W> ContractionHierarchy<W>
+where
+    W: Copy + Ord + Add<Output=W> + Default
+{
+    /// Contracts every node of graph. Nodes are ordered lazily by edge
+    /// difference, the number of shortcuts contraction would add less the
+    /// number of edges it removes, plus the number of neighbors already
+    /// contracted. Witness searches are bounded, so a few redundant
+    /// shortcuts may be added, but distances are always exact.
+    pub fn build<G>(graph: &G) -> Self
+    where
+        G: WeightedGraph<Weight=W>
+    {
+        let ids = graph.ids().collect::<Vec<_>>();
+        let indices = ids.iter().enumerate()
+            .map(|(index, &id)| (id, index))
+            .collect::<HashMap<_, _>>();
+        let mut overlay = vec![ HashMap::new(); ids.len() ];
+
+        for (sid, tid) in graph.edges() {
+            let weight = *graph.weight(sid, tid)
+                .expect("weight").expect("edge");
+            let (s, t) = (indices[&sid], indices[&tid]);
+
+            overlay[s].insert(t, (weight, None));
+            overlay[t].insert(s, (weight, None));
+        }
+
+        let mut contractor = Contractor {
+            overlay,
+            contracted: vec![ false; ids.len() ],
+            removed_neighbors: vec![ 0; ids.len() ]
+        };
+        let mut heap = (0..ids.len())
+            .map(|index| Reverse((contractor.priority(index), index)))
+            .collect::<BinaryHeap<_>>();
+        let mut rank = vec![ 0; ids.len() ];
+        let mut next = 0;
+
+        while let Some(Reverse((_, index))) = heap.pop() {
+            let priority = contractor.priority(index);
+
+            if let Some(&Reverse((top, _))) = heap.peek() {
+                if priority > top {
+                    heap.push(Reverse((priority, index)));
+
+                    continue;
+                }
+            }
+
+            contractor.contract(index);
+            rank[index] = next;
+            next += 1;
+        }
+
+        let mut upward = vec![ Vec::new(); ids.len() ];
+        let mut middles = HashMap::new();
+
+        for (index, edges) in contractor.overlay.iter().enumerate() {
+            for (&neighbor, &(weight, middle)) in edges {
+                if rank[neighbor] > rank[index] {
+                    upward[index].push((neighbor, weight));
+                }
+
+                if let Some(middle) = middle {
+                    middles.insert((index, neighbor), middle);
+                }
+            }
+        }
+
+        Self { ids, indices, upward, middles }
+    }
+
+    /// Returns the cost and nodes of a least-cost path from source to
+    /// target, or None if target can't be reached. Returns Error if source
+    /// or target are not found.
+    pub fn query(
+        &self, source: usize, target: usize
+    ) -> Result<Option<WeightedPath<W>>, Error> {
+        let source = self.index(source)?;
+        let target = self.index(target)?;
+        let forward = self.search(source);
+        let backward = self.search(target);
+        let meeting = forward.iter()
+            .filter_map(|(index, &(cost, _))| {
+                backward.get(index).map(|&(other, _)| (cost + other, *index))
+            })
+            .min();
+        let (cost, meeting) = match meeting {
+            Some(meeting) => meeting,
+            None => return Ok(None)
+        };
+        let mut hops = climb(&forward, meeting);
+
+        hops.reverse();
+        hops.pop();
+        hops.extend(climb(&backward, meeting));
+
+        let mut nodes = vec![ self.ids[hops[0]] ];
+
+        for pair in hops.windows(2) {
+            self.unpack(pair[0], pair[1], &mut nodes);
+        }
+
+        Ok(Some((cost, nodes)))
+    }
+
+    fn index(&self, id: usize) -> Result<usize, Error> {
+        match self.indices.get(&id) {
+            Some(&index) => Ok(index),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+
+    fn search(&self, root: usize) -> HashMap<usize, (W, Option<usize>)> {
+        let mut result = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        heap.push(Reverse((W::default(), root, None)));
+
+        while let Some(Reverse((cost, index, parent))) = heap.pop() {
+            if result.contains_key(&index) {
+                continue;
+            }
+
+            result.insert(index, (cost, parent));
+
+            for &(neighbor, weight) in &self.upward[index] {
+                if !result.contains_key(&neighbor) {
+                    heap.push(Reverse((cost + weight, neighbor, Some(index))));
+                }
+            }
+        }
+
+        result
+    }
+
+    fn unpack(&self, sid: usize, tid: usize, nodes: &mut Vec<usize>) {
+        let mut stack = vec![ (sid, tid) ];
+
+        while let Some((sid, tid)) = stack.pop() {
+            match self.middles.get(&(sid, tid)) {
+                Some(&middle) => {
+                    stack.push((middle, tid));
+                    stack.push((sid, middle));
+                },
+                None => nodes.push(self.ids[tid])
+            }
+        }
+    }
+}
+
+fn climb<W>(
+    tree: &HashMap<usize, (W, Option<usize>)>, start: usize
+) -> Vec<usize> {
+    let mut result = vec![ start ];
+
+    while let Some(&(_, Some(parent))) = tree.get(result.last().expect("hop")) {
+        result.push(parent);
+    }
+
+    result
+}
+
+struct Contractor<W> {
+    overlay: Vec<HashMap<usize, (W, Option<usize>)>>,
+    contracted: Vec<bool>,
+    removed_neighbors: Vec<usize>
+}
+
+impl<W> Contractor<W>
+where
+    W: Copy + Ord + Add<Output=W> + Default
+{
+    fn priority(&self, index: usize) -> isize {
+        let shortcuts = self.shortcuts(index).len() as isize;
+        let degree = self.neighbors(index).count() as isize;
+
+        shortcuts - degree + self.removed_neighbors[index] as isize
+    }
+
+    fn contract(&mut self, index: usize) {
+        for (sid, tid, weight) in self.shortcuts(index) {
+            let better = match self.overlay[sid].get(&tid) {
+                Some(&(known, _)) => weight < known,
+                None => true
+            };
+
+            if better {
+                self.overlay[sid].insert(tid, (weight, Some(index)));
+                self.overlay[tid].insert(sid, (weight, Some(index)));
+            }
+        }
+
+        for neighbor in self.neighbors(index).collect::<Vec<_>>() {
+            self.removed_neighbors[neighbor] += 1;
+        }
+
+        self.contracted[index] = true;
+    }
+
+    fn neighbors(&self, index: usize) -> impl Iterator<Item=usize> + '_ {
+        self.overlay[index].keys()
+            .cloned()
+            .filter(move |&neighbor| !self.contracted[neighbor])
+    }
+
+    fn shortcuts(&self, index: usize) -> Vec<(usize, usize, W)> {
+        let neighbors = self.neighbors(index)
+            .map(|neighbor| (neighbor, self.overlay[index][&neighbor].0))
+            .collect::<Vec<_>>();
+        let mut result = Vec::new();
+
+        for (position, &(sid, first)) in neighbors.iter().enumerate() {
+            let targets = &neighbors[(position + 1)..];
+            let limit = match targets.iter().map(|&(_, second)| second).max() {
+                Some(second) => first + second,
+                None => continue
+            };
+            let witnesses = self.witnesses(sid, index, limit);
+
+            for &(tid, second) in targets {
+                let via = first + second;
+                let witnessed = match witnesses.get(&tid) {
+                    Some(&cost) => cost <= via,
+                    None => false
+                };
+
+                if !witnessed {
+                    result.push((sid, tid, via));
+                }
+            }
+        }
+
+        result
+    }
+
+    fn witnesses(
+        &self, root: usize, avoid: usize, limit: W
+    ) -> HashMap<usize, W> {
+        let mut result = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        heap.push(Reverse((W::default(), root)));
+
+        while let Some(Reverse((cost, index))) = heap.pop() {
+            if cost > limit || result.len() == WITNESS_LIMIT {
+                break;
+            }
+
+            if result.contains_key(&index) {
+                continue;
+            }
+
+            result.insert(index, cost);
+
+            for neighbor in self.neighbors(index) {
+                if neighbor != avoid && !result.contains_key(&neighbor) {
+                    let weight = self.overlay[index][&neighbor].0;
+
+                    heap.push(Reverse((cost + weight, neighbor)));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultWeightedGraph;
+    use crate::rng::Rng;
+    use crate::shortest_path::dijkstra;
+
+    #[test]
+    fn unknown_source() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+        let hierarchy = ContractionHierarchy::build(&graph);
+
+        assert_eq!(hierarchy.query(2, 0), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn source_is_target() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+        let hierarchy = ContractionHierarchy::build(&graph);
+
+        assert_eq!(hierarchy.query(1, 1), Ok(Some((0, vec![ 1 ]))))
+    }
+
+    #[test]
+    fn unreachable() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (2, 3, 1)
+        ]).unwrap();
+        let hierarchy = ContractionHierarchy::build(&graph);
+
+        assert_eq!(hierarchy.query(0, 3), Ok(None))
+    }
+
+    #[test]
+    fn detour() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 10),
+            (0, 2, 1),
+            (2, 3, 2),
+            (3, 1, 3),
+            (1, 4, 1)
+        ]).unwrap();
+        let hierarchy = ContractionHierarchy::build(&graph);
+
+        assert_eq!(
+            hierarchy.query(0, 4),
+            Ok(Some((7, vec![ 0, 2, 3, 1, 4 ])))
+        );
+        assert_eq!(
+            hierarchy.query(4, 0),
+            Ok(Some((7, vec![ 4, 1, 3, 2, 0 ])))
+        )
+    }
+
+    #[test]
+    fn long_path() {
+        let edges = (0..500).map(|id| (id, id + 1, 1)).collect::<Vec<_>>();
+        let graph = DefaultWeightedGraph::try_from(edges).unwrap();
+        let hierarchy = ContractionHierarchy::build(&graph);
+        let (cost, nodes) = hierarchy.query(0, 500).unwrap().unwrap();
+
+        assert_eq!(cost, 500);
+        assert_eq!(nodes, (0..=500).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn matches_dijkstra() {
+        let mut rng = Rng::new(5);
+        let mut graph = DefaultWeightedGraph::new();
+
+        for id in 0..60 {
+            graph.add_node(id).unwrap();
+        }
+
+        for sid in 0..60 {
+            for tid in (sid + 1)..60 {
+                if rng.next_f64() < 0.08 {
+                    graph.add_edge(sid, tid, 1 + rng.below(20)).unwrap();
+                }
+            }
+        }
+
+        let hierarchy = ContractionHierarchy::build(&graph);
+
+        for source in 0..60 {
+            for target in 0..60 {
+                let expected = dijkstra(&graph, source, target).unwrap();
+                let actual = hierarchy.query(source, target).unwrap();
+
+                assert_eq!(
+                    actual.as_ref().map(|path| path.0),
+                    expected.map(|path| path.0)
+                );
+
+                if let Some((cost, nodes)) = actual {
+                    let total = nodes.windows(2)
+                        .map(|pair| {
+                            *graph.weight(pair[0], pair[1]).unwrap().unwrap()
+                        })
+                        .sum::<usize>();
+
+                    assert_eq!(nodes[0], source);
+                    assert_eq!(*nodes.last().unwrap(), target);
+                    assert_eq!(total, cost)
+                }
+            }
+        }
+    }
+}
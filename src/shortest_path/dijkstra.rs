@@ -0,0 +1,234 @@
+use std::collections::{ BinaryHeap, HashMap, HashSet };
+use std::cmp::Reverse;
+use std::ops::Add;
+
+use crate::graph::{ Graph, WeightedGraph, Error };
+
+/// A path given as its total cost followed by its nodes, in order.
+pub type WeightedPath<W> = (W, Vec<usize>);
+
+/// Returns the cost and nodes of a least-cost path from source to target
+/// using [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm),
+/// or None if target can't be reached. Weights must not be negative.
+/// Returns Error if source or target are not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultWeightedGraph };
+/// use gamma::shortest_path::dijkstra;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 1),
+///         (1, 2, 1),
+///         (0, 2, 5)
+///     ])?;
+///
+///     assert_eq!(dijkstra(&graph, 0, 2)?, Some((2, vec![ 0, 1, 2 ])));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn dijkstra<G>(
+    graph: &G, source: usize, target: usize
+) -> Result<Option<WeightedPath<G::Weight>>, Error>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Ord + Add<Output=G::Weight> + Default
+{
+    dijkstra_with_weights(graph, source, target, |sid, tid| {
+        *graph.weight(sid, tid).expect("weight").expect("edge")
+    })
+}
+
+/// Returns the cost and nodes of a least-cost path from source to target,
+/// as `dijkstra` does, taking the weight of each edge (sid, tid) from
+/// weight rather than from a WeightedGraph. Weights kept outside the graph
+/// can then be used without copying them into one. Weights must not be
+/// negative. Returns Error if source or target are not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use std::collections::HashMap;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::shortest_path::dijkstra_with_weights;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (0, 2)
+///     ])?;
+///     let lengths = [ ((0, 1), 1), ((1, 2), 1), ((0, 2), 5) ]
+///         .iter().cloned().collect::<HashMap<_, _>>();
+///     let weight = |sid: usize, tid: usize| {
+///         lengths[&(sid.min(tid), sid.max(tid))]
+///     };
+///
+///     assert_eq!(
+///         dijkstra_with_weights(&graph, 0, 2, weight)?,
+///         Some((2, vec![ 0, 1, 2 ]))
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+pub fn dijkstra_with_weights<G, W, F>(
+    graph: &G, source: usize, target: usize, weight: F
+) -> Result<Option<WeightedPath<W>>, Error>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output=W> + Default,
+    F: Fn(usize, usize) -> W
+{
+    check(graph, source, target)?;
+
+    Ok(search(
+        graph, &weight, source, target, &HashSet::new(), &HashSet::new()
+    ))
+}
+
+pub(crate) fn check<G: Graph>(
+    graph: &G, source: usize, target: usize
+) -> Result<(), Error> {
+    if !graph.has_id(source) {
+        Err(Error::UnknownId(source))
+    } else if !graph.has_id(target) {
+        Err(Error::UnknownId(target))
+    } else {
+        Ok(())
+    }
+}
+
+/// Searches as dijkstra does, ignoring blocked nodes and edges. Blocked
+/// edges are given in both orientations.
+pub(crate) fn search<G, W, F>(
+    graph: &G,
+    weight: &F,
+    source: usize,
+    target: usize,
+    blocked_nodes: &HashSet<usize>,
+    blocked_edges: &HashSet<(usize, usize)>
+) -> Option<WeightedPath<W>>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output=W> + Default,
+    F: Fn(usize, usize) -> W
+{
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut done = HashSet::new();
+    let mut heap = BinaryHeap::new();
+
+    costs.insert(source, W::default());
+    heap.push(Reverse((W::default(), source)));
+
+    while let Some(Reverse((cost, id))) = heap.pop() {
+        if !done.insert(id) {
+            continue;
+        }
+
+        if id == target {
+            let mut nodes = vec![ target ];
+
+            while let Some(&parent) = parents.get(nodes.last().expect("node")) {
+                nodes.push(parent);
+            }
+
+            nodes.reverse();
+
+            return Some((cost, nodes));
+        }
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            if done.contains(&neighbor)
+                || blocked_nodes.contains(&neighbor)
+                || blocked_edges.contains(&(id, neighbor)) {
+                continue;
+            }
+
+            let next = cost + weight(id, neighbor);
+            let better = match costs.get(&neighbor) {
+                Some(&known) => next < known,
+                None => true
+            };
+
+            if better {
+                costs.insert(neighbor, next);
+                parents.insert(neighbor, id);
+                heap.push(Reverse((next, neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::{ DefaultGraph, DefaultWeightedGraph };
+
+    #[test]
+    fn unknown_target() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(dijkstra(&graph, 0, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn source_is_target() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(dijkstra(&graph, 1, 1), Ok(Some((0, vec![ 1 ]))))
+    }
+
+    #[test]
+    fn unreachable() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (2, 3, 1)
+        ]).unwrap();
+
+        assert_eq!(dijkstra(&graph, 0, 3), Ok(None))
+    }
+
+    #[test]
+    fn detour() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 10),
+            (0, 2, 1),
+            (2, 3, 2),
+            (3, 1, 3),
+            (1, 4, 1)
+        ]).unwrap();
+
+        assert_eq!(dijkstra(&graph, 0, 4), Ok(Some((7, vec![ 0, 2, 3, 1, 4 ]))))
+    }
+
+    #[test]
+    fn weights_closure_unknown_source() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(
+            dijkstra_with_weights(&graph, 3, 0, |_, _| 1),
+            Err(Error::UnknownId(3))
+        )
+    }
+
+    #[test]
+    fn weights_closure_directional_lookup() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (0, 2)
+        ]).unwrap();
+        let weight = |sid: usize, tid: usize| sid + tid;
+
+        assert_eq!(
+            dijkstra_with_weights(&graph, 0, 2, weight),
+            Ok(Some((2, vec![ 0, 2 ])))
+        )
+    }
+}
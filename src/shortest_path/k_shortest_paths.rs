@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::ops::Add;
+
+use crate::graph::{ Graph, WeightedGraph, Error };
+use super::dijkstra::{ check, search, WeightedPath };
+
+/// Returns up to k loopless paths from source to target in order of
+/// increasing cost, using [Yen's algorithm](https://en.wikipedia.org/wiki/Yen%27s_algorithm).
+/// Paths of equal cost are ordered by their node sequences. Weights must not
+/// be negative. Returns Error if source or target are not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultWeightedGraph };
+/// use gamma::shortest_path::k_shortest_paths;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 1),
+///         (1, 3, 1),
+///         (0, 2, 2),
+///         (2, 3, 2)
+///     ])?;
+///
+///     assert_eq!(k_shortest_paths(&graph, 0, 3, 3)?, vec![
+///         (2, vec![ 0, 1, 3 ]),
+///         (4, vec![ 0, 2, 3 ])
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn k_shortest_paths<G>(
+    graph: &G, source: usize, target: usize, k: usize
+) -> Result<Vec<WeightedPath<G::Weight>>, Error>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Ord + Add<Output=G::Weight> + Default
+{
+    k_shortest_paths_with_weights(graph, source, target, k, |sid, tid| {
+        *graph.weight(sid, tid).expect("weight").expect("edge")
+    })
+}
+
+/// Returns up to k loopless paths from source to target in order of
+/// increasing cost, as `k_shortest_paths` does, taking the weight of each
+/// edge (sid, tid) from weight rather than from a WeightedGraph. Weights
+/// must not be negative. Returns Error if source or target are not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::shortest_path::k_shortest_paths_with_weights;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 3), (0, 2), (2, 3)
+///     ])?;
+///     let weight = |sid: usize, tid: usize| sid.max(tid);
+///     let paths = k_shortest_paths_with_weights(&graph, 0, 3, 2, weight)?;
+///
+///     assert_eq!(paths, vec![
+///         (4, vec![ 0, 1, 3 ]),
+///         (5, vec![ 0, 2, 3 ])
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn k_shortest_paths_with_weights<G, W, F>(
+    graph: &G, source: usize, target: usize, k: usize, weight: F
+) -> Result<Vec<WeightedPath<W>>, Error>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output=W> + Default,
+    F: Fn(usize, usize) -> W
+{
+    check(graph, source, target)?;
+
+    let mut result = Vec::new();
+    let mut candidates: Vec<WeightedPath<W>> = Vec::new();
+
+    if k == 0 {
+        return Ok(result);
+    }
+
+    match search(
+        graph, &weight, source, target, &HashSet::new(), &HashSet::new()
+    ) {
+        Some(path) => result.push(path),
+        None => return Ok(result)
+    }
+
+    while result.len() < k {
+        let previous = result.last().expect("path").1.clone();
+
+        for index in 0..previous.len() - 1 {
+            let spur = previous[index];
+            let root = &previous[..=index];
+            let mut blocked_edges = HashSet::new();
+
+            for (_, nodes) in result.iter() {
+                if nodes.len() > index + 1 && &nodes[..=index] == root {
+                    blocked_edges.insert((nodes[index], nodes[index + 1]));
+                    blocked_edges.insert((nodes[index + 1], nodes[index]));
+                }
+            }
+
+            let blocked_nodes = root[..index].iter().cloned().collect();
+            let (cost, tail) = match search(
+                graph, &weight, spur, target, &blocked_nodes, &blocked_edges
+            ) {
+                Some(path) => path,
+                None => continue
+            };
+            let mut nodes = root[..index].to_vec();
+
+            nodes.extend(tail);
+
+            let candidate = (cost + path_cost(&weight, root), nodes);
+
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        result.push(candidates.pop().expect("candidate"));
+    }
+
+    Ok(result)
+}
+
+fn path_cost<W, F>(weight: &F, nodes: &[usize]) -> W
+where
+    W: Copy + Add<Output=W> + Default,
+    F: Fn(usize, usize) -> W
+{
+    nodes.windows(2).fold(W::default(), |cost, pair| {
+        cost + weight(pair[0], pair[1])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultWeightedGraph;
+
+    #[test]
+    fn unknown_source() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(k_shortest_paths(&graph, 2, 0, 1), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn k_zero() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(k_shortest_paths(&graph, 0, 1, 0), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn unreachable() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (2, 3, 1)
+        ]).unwrap();
+
+        assert_eq!(k_shortest_paths(&graph, 0, 3, 2), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn fewer_than_k() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (1, 2, 1)
+        ]).unwrap();
+
+        assert_eq!(k_shortest_paths(&graph, 0, 2, 5), Ok(vec![
+            (2, vec![ 0, 1, 2 ])
+        ]))
+    }
+
+    #[test]
+    fn two_at_equal_cost() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 3),
+            (0, 2, 2),
+            (1, 3, 4),
+            (2, 1, 1),
+            (2, 3, 2),
+            (2, 4, 3),
+            (3, 4, 2),
+            (3, 5, 1),
+            (4, 5, 2)
+        ]).unwrap();
+
+        assert_eq!(k_shortest_paths(&graph, 0, 5, 3), Ok(vec![
+            (5, vec![ 0, 2, 3, 5 ]),
+            (7, vec![ 0, 1, 2, 3, 5 ]),
+            (7, vec![ 0, 2, 4, 5 ])
+        ]))
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use crate::graph::{ Graph, Error };
+use crate::rng::Rng;
+use super::multi_source_bfs::distances as bfs;
+
+/// Precomputed distances from a few landmark nodes, answering repeated
+/// distance queries without searching the graph. By the triangle
+/// inequality, the distance between a and b is at least
+/// |d(l, a) - d(l, b)| and at most d(l, a) + d(l, b) for every landmark l.
+/// Both bounds are exact whenever a landmark lies on a shortest path
+/// between a and b.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::shortest_path::Landmarks;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 3), (3, 4)
+///     ])?;
+///     let landmarks = Landmarks::build(&graph, 1, 7);
+///
+///     assert_eq!(landmarks.estimate(1, 3)?, Some(2));
+///     assert_eq!(landmarks.lower_bound(1, 3)?, 2);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct Landmarks {
+    landmarks: Vec<usize>,
+    distances: HashMap<usize, Vec<Option<usize>>>
+}
+
+impl Landmarks {
+    /// Chooses up to count landmarks and records every node's distance in
+    /// edges to each of them. The first landmark is chosen at random by a
+    /// generator seeded with seed. Each later landmark is the node farthest
+    /// from those already chosen, preferring nodes none of them reach, so
+    /// that every component gets a landmark when count allows. Time is
+    /// proportional to count times size.
+    pub fn build<G: Graph>(graph: &G, count: usize, seed: u64) -> Self {
+        let ids = graph.ids().collect::<Vec<_>>();
+        let count = count.min(ids.len());
+        let mut landmarks = Vec::with_capacity(count);
+        let mut distances = ids.iter()
+            .map(|&id| (id, Vec::with_capacity(count)))
+            .collect::<HashMap<_, _>>();
+        let mut nearest = vec![ None; ids.len() ];
+
+        if count > 0 {
+            landmarks.push(ids[Rng::new(seed).below(ids.len())]);
+        }
+
+        while let Some(&landmark) = landmarks.last() {
+            let reached = bfs(graph, landmark);
+
+            for (index, id) in ids.iter().enumerate() {
+                let distance = reached.get(id).cloned();
+
+                distances.get_mut(id).expect("id").push(distance);
+                nearest[index] = match (nearest[index], distance) {
+                    (Some(a), Some(b)) => Some(usize::min(a, b)),
+                    (a, b) => a.or(b)
+                };
+            }
+
+            if landmarks.len() == count {
+                break;
+            }
+
+            let (index, _) = nearest.iter()
+                .enumerate()
+                .max_by_key(|&(index, distance)| {
+                    (distance.is_none(), *distance, std::cmp::Reverse(index))
+                })
+                .expect("node");
+
+            landmarks.push(ids[index]);
+        }
+
+        Self { landmarks, distances }
+    }
+
+    /// Returns the landmarks, in the order they were chosen.
+    pub fn landmarks(&self) -> &[usize] {
+        &self.landmarks
+    }
+
+    /// Returns an upper bound on the distance between a and b, or None if
+    /// no landmark reaches both. Returns Error if a or b are not found.
+    pub fn estimate(&self, a: usize, b: usize) -> Result<Option<usize>, Error> {
+        if a == b {
+            return self.get(a).map(|_| Some(0));
+        }
+
+        let result = self.get(a)?.iter().zip(self.get(b)?)
+            .filter_map(|pair| match pair {
+                (Some(da), Some(db)) => Some(da + db),
+                _ => None
+            })
+            .min();
+
+        Ok(result)
+    }
+
+    /// Returns a lower bound on the distance between a and b, or zero if
+    /// no landmark reaches both. Returns Error if a or b are not found.
+    pub fn lower_bound(&self, a: usize, b: usize) -> Result<usize, Error> {
+        let result = self.get(a)?.iter().zip(self.get(b)?)
+            .filter_map(|pair| match pair {
+                (&Some(da), &Some(db)) => Some(da.max(db) - da.min(db)),
+                _ => None
+            })
+            .max();
+
+        Ok(result.unwrap_or(0))
+    }
+
+    fn get(&self, id: usize) -> Result<&[Option<usize>], Error> {
+        match self.distances.get(&id) {
+            Some(distances) => Ok(distances),
+            None => Err(Error::UnknownId(id))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        let landmarks = Landmarks::build(&DefaultGraph::new(), 3, 1);
+
+        assert!(landmarks.landmarks().is_empty());
+        assert_eq!(landmarks.estimate(0, 1), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn unknown_b() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let landmarks = Landmarks::build(&graph, 1, 1);
+
+        assert_eq!(landmarks.lower_bound(0, 5), Err(Error::UnknownId(5)))
+    }
+
+    #[test]
+    fn same_node() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let landmarks = Landmarks::build(&graph, 0, 1);
+
+        assert_eq!(landmarks.estimate(1, 1), Ok(Some(0)))
+    }
+
+    #[test]
+    fn no_landmarks() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+        let landmarks = Landmarks::build(&graph, 0, 1);
+
+        assert_eq!(landmarks.estimate(0, 1), Ok(None));
+        assert_eq!(landmarks.lower_bound(0, 1), Ok(0))
+    }
+
+    #[test]
+    fn count_exceeds_order() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let landmarks = Landmarks::build(&graph, 10, 1);
+        let mut chosen = landmarks.landmarks().to_vec();
+
+        chosen.sort_unstable();
+
+        assert_eq!(chosen, vec![ 0, 1, 2 ])
+    }
+
+    #[test]
+    fn one_landmark_per_component() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (3, 4), (5, 6)
+        ]).unwrap();
+        let landmarks = Landmarks::build(&graph, 3, 4);
+
+        assert!(landmarks.estimate(0, 2).unwrap().is_some());
+        assert_eq!(landmarks.estimate(3, 4), Ok(Some(1)));
+        assert_eq!(landmarks.estimate(5, 6), Ok(Some(1)));
+        assert_eq!(landmarks.estimate(0, 5), Ok(None))
+    }
+
+    #[test]
+    fn bounds_hold_on_cycle() {
+        let edges = (0..20).map(|id| (id, (id + 1) % 20)).collect::<Vec<_>>();
+        let graph = DefaultGraph::try_from(edges).unwrap();
+        let landmarks = Landmarks::build(&graph, 3, 9);
+
+        for a in 0..20 {
+            for b in 0..20 {
+                let gap = (a as isize - b as isize).unsigned_abs();
+                let exact = gap.min(20 - gap);
+                let upper = landmarks.estimate(a, b).unwrap().unwrap();
+                let lower = landmarks.lower_bound(a, b).unwrap();
+
+                assert!(lower <= exact && exact <= upper)
+            }
+        }
+    }
+
+    #[test]
+    fn farthest_second_landmark() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4)
+        ]).unwrap();
+        let landmarks = Landmarks::build(&graph, 2, 1);
+        let first = landmarks.landmarks()[0];
+        let second = landmarks.landmarks()[1];
+
+        assert_eq!(second, if first < 2 { 4 } else { 0 })
+    }
+}
@@ -0,0 +1,17 @@
+mod tree;
+mod dijkstra;
+mod k_shortest_paths;
+#[allow(clippy::module_inception)]
+mod multi_source_bfs;
+mod landmarks;
+mod contraction;
+
+pub use tree::tree;
+pub use dijkstra::{ dijkstra, dijkstra_with_weights, WeightedPath };
+pub use k_shortest_paths::{
+    k_shortest_paths, k_shortest_paths_with_weights
+};
+pub use multi_source_bfs::multi_source_bfs;
+pub(crate) use multi_source_bfs::distances;
+pub use landmarks::Landmarks;
+pub use contraction::ContractionHierarchy;
@@ -0,0 +1,177 @@
+use std::collections::{ HashMap, VecDeque };
+use std::collections::hash_map::Entry;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::graph::{ Graph, Error };
+
+/// Returns, for each id in sources, a map from every node reachable from it
+/// to its distance in edges. Maps are returned in the order of sources.
+/// Returns Error if any source is not found.
+///
+/// With the `rayon` feature, each source is searched on its own thread and
+/// graph must also be Sync.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::shortest_path::multi_source_bfs;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///     let distances = multi_source_bfs(&graph, &[ 0, 1 ])?;
+///
+///     assert_eq!(distances[0][&2], 2);
+///     assert_eq!(distances[1][&2], 1);
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(not(feature = "rayon"))]
+pub fn multi_source_bfs<G: Graph>(
+    graph: &G, sources: &[usize]
+) -> Result<Vec<HashMap<usize, usize>>, Error> {
+    check_sources(graph, sources)?;
+
+    Ok(sources.iter().map(|&source| distances(graph, source)).collect())
+}
+
+/// Returns, for each id in sources, a map from every node reachable from it
+/// to its distance in edges. Maps are returned in the order of sources.
+/// Returns Error if any source is not found.
+///
+/// With the `rayon` feature, each source is searched on its own thread and
+/// graph must also be Sync.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::shortest_path::multi_source_bfs;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1 ],
+///         vec![ 0, 2 ],
+///         vec![ 1 ]
+///     ])?;
+///     let distances = multi_source_bfs(&graph, &[ 0, 1 ])?;
+///
+///     assert_eq!(distances[0][&2], 2);
+///     assert_eq!(distances[1][&2], 1);
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "rayon")]
+pub fn multi_source_bfs<G: Graph + Sync>(
+    graph: &G, sources: &[usize]
+) -> Result<Vec<HashMap<usize, usize>>, Error> {
+    check_sources(graph, sources)?;
+
+    Ok(sources.par_iter().map(|&source| distances(graph, source)).collect())
+}
+
+fn check_sources<G: Graph>(graph: &G, sources: &[usize]) -> Result<(), Error> {
+    match sources.iter().find(|&&id| !graph.has_id(id)) {
+        Some(&id) => Err(Error::UnknownId(id)),
+        None => Ok(())
+    }
+}
+
+pub(crate) fn distances<G: Graph>(graph: &G, root: usize) -> HashMap<usize, usize> {
+    let mut result = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    result.insert(root, 0);
+    queue.push_back(root);
+
+    while let Some(id) = queue.pop_front() {
+        let distance = result[&id];
+
+        for neighbor in graph.neighbors(id).expect("neighbors") {
+            if let Entry::Vacant(entry) = result.entry(neighbor) {
+                entry.insert(distance + 1);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod multi_source_bfs {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_source() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(
+            multi_source_bfs(&graph, &[ 0, 2 ]),
+            Err(Error::UnknownId(2))
+        )
+    }
+
+    #[test]
+    fn no_sources() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(multi_source_bfs(&graph, &[ ]), Ok(vec![ ]))
+    }
+
+    #[test]
+    fn p2_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0 ],
+            vec![ ]
+        ]).unwrap();
+        let distances = multi_source_bfs(&graph, &[ 2, 0 ]).unwrap();
+
+        assert_eq!(distances[0], vec![ (2, 0) ].into_iter().collect());
+        assert_eq!(distances[1], vec![ (0, 0), (1, 1) ].into_iter().collect())
+    }
+
+    #[test]
+    fn c6_every_source() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)
+        ]).unwrap();
+        let sources = graph.ids().collect::<Vec<_>>();
+        let distances = multi_source_bfs(&graph, &sources).unwrap();
+
+        for (&source, map) in sources.iter().zip(&distances) {
+            assert_eq!(map[&((source + 3) % 6)], 3)
+        }
+    }
+}
+
+#[cfg(test)]
+mod distances {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p3_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(
+            distances(&graph, 0),
+            vec![ (0, 0), (1, 1), (2, 2) ].into_iter().collect()
+        )
+    }
+}
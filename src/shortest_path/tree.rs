@@ -0,0 +1,83 @@
+use crate::graph::{ Graph, Error, DefaultGraph };
+use crate::traversal::BreadthFirst;
+
+/// Returns the shortest path tree rooted at root as a DefaultGraph. It
+/// contains each node reachable from root, joined to its parent in a
+/// breadth-first traversal. Returns Error if root is not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::shortest_path::tree;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 3 ],
+///         vec![ 0, 2 ],
+///         vec![ 1, 3 ],
+///         vec![ 2, 0 ]
+///     ])?;
+///     let tree = tree(&graph, 0)?;
+///
+///     assert_eq!(tree.edges().collect::<Vec<_>>(), vec![
+///         (0, 1), (0, 3), (1, 2)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn tree<G: Graph>(graph: &G, root: usize) -> Result<DefaultGraph, Error> {
+    let traversal = BreadthFirst::new(graph, root)?;
+    let mut result = DefaultGraph::new();
+
+    result.add_node(root)?;
+
+    for step in traversal {
+        if !step.cut {
+            result.add_node(step.tid)?;
+            result.add_edge(step.sid, step.tid)?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(tree(&graph, 0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(tree(&graph, 0).unwrap().ids().collect::<Vec<_>>(), [ 0 ])
+    }
+
+    #[test]
+    fn c5_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 4 ],
+            vec![ 0, 2 ],
+            vec![ 1, 3 ],
+            vec![ 2, 4 ],
+            vec![ 3, 0 ],
+            vec![ ]
+        ]).unwrap();
+        let tree = tree(&graph, 2).unwrap();
+
+        assert_eq!(tree.ids().collect::<Vec<_>>(), [ 2, 1, 3, 0, 4 ]);
+        assert_eq!(tree.edges().collect::<Vec<_>>(), [
+            (2, 1), (2, 3), (1, 0), (3, 4)
+        ])
+    }
+}
@@ -30,23 +30,6 @@ use super::Step;
 ///     Ok(())
 /// }
 /// ```
-// pub fn breadth_first<'a, G>(
-//     graph: &'a G, root: usize
-// ) -> Result<BreadthFirst<'a, G>, Error>
-// where G: Graph {
-//     let mut nodes = HashSet::new();
-//     let mut queue = VecDeque::new();
-
-//     for neighbor in graph.neighbors(root)? {
-//         queue.push_front((root, *neighbor));
-//     }
-
-//     nodes.insert(root);
-
-//     Ok(BreadthFirst { nodes, queue, graph })
-// }
-
-/// #[derive(Debug,PartialEq)]
 pub struct BreadthFirst<'a, G> {
     nodes: HashSet<usize>,
     queue: VecDeque<(usize, usize)>,
@@ -66,6 +49,60 @@ impl<'a, G: Graph> BreadthFirst<'a, G> {
     
         Ok(Self { nodes, queue, graph })
     }
+
+    /// Returns the nodes reachable from root grouped by distance, starting
+    /// with root itself. Returns Error if root is not found.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use gamma::graph::{ Error, DefaultGraph };
+    /// use gamma::traversal::BreadthFirst;
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let graph = DefaultGraph::try_from(vec![
+    ///         vec![ 1, 3 ],
+    ///         vec![ 0, 2 ],
+    ///         vec![ 1, 3 ],
+    ///         vec![ 2, 0 ]
+    ///     ])?;
+    ///
+    ///     assert_eq!(BreadthFirst::layers(&graph, 0)?, vec![
+    ///         vec![ 0 ],
+    ///         vec![ 1, 3 ],
+    ///         vec![ 2 ]
+    ///     ]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn layers(graph: &'a G, root: usize) -> Result<Vec<Vec<usize>>, Error> {
+        let mut nodes = HashSet::new();
+        let mut layer = vec![ root ];
+        let mut result = Vec::new();
+
+        if !graph.has_id(root) {
+            return Err(Error::UnknownId(root));
+        }
+
+        nodes.insert(root);
+
+        while !layer.is_empty() {
+            let mut next = Vec::new();
+
+            for &id in layer.iter() {
+                for neighbor in graph.neighbors(id)? {
+                    if nodes.insert(neighbor) {
+                        next.push(neighbor);
+                    }
+                }
+            }
+
+            result.push(layer);
+            layer = next;
+        }
+
+        Ok(result)
+    }
 }
 
 impl<'a, G> Iterator for BreadthFirst<'a, G>
@@ -399,4 +436,44 @@ mod tests {
             Step::new(3, 4, true)
         ]);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod layers {
+    use super::*;
+    use std::convert::TryFrom;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(BreadthFirst::layers(&graph, 0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(BreadthFirst::layers(&graph, 0), Ok(vec![ vec![ 0 ] ]))
+    }
+
+    #[test]
+    fn s3_primary_p1() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2, 3 ],
+            vec![ 0 ],
+            vec![ 0 ],
+            vec![ 0 ],
+            vec![ ]
+        ]).unwrap();
+
+        assert_eq!(BreadthFirst::layers(&graph, 1), Ok(vec![
+            vec![ 1 ],
+            vec![ 0 ],
+            vec![ 2, 3 ]
+        ]))
+    }
+}
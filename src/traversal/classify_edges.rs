@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+use super::DepthFirst;
+
+/// The role of an edge in a depth-first traversal.
+#[derive(Eq,PartialEq,Hash,Debug,Clone,Copy)]
+pub enum EdgeClass {
+    /// A tree edge whose removal disconnects its component.
+    Bridge,
+    /// A tree edge that is a member of at least one cycle.
+    Tree,
+    /// A non-tree edge closing a cycle. Equivalent to a Step with `cut`.
+    Back
+}
+
+impl EdgeClass {
+    /// Returns true if this edge is a member of at least one cycle.
+    pub fn is_cycle_member(&self) -> bool {
+        *self != EdgeClass::Bridge
+    }
+}
+
+/// Returns every edge of graph with its EdgeClass, in the order visited by
+/// depth-first traversals rooted at each unvisited node in turn. Edges are
+/// oriented as they are traversed.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::traversal::{ classify_edges, EdgeClass };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         vec![ 1, 2 ],
+///         vec![ 0, 2 ],
+///         vec![ 0, 1, 3 ],
+///         vec![ 2 ]
+///     ])?;
+///
+///     assert_eq!(classify_edges(&graph), vec![
+///         (0, 1, EdgeClass::Tree),
+///         (1, 2, EdgeClass::Tree),
+///         (2, 0, EdgeClass::Back),
+///         (2, 3, EdgeClass::Bridge)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn classify_edges<G: Graph>(graph: &G) -> Vec<(usize, usize, EdgeClass)> {
+    let mut order = HashMap::new();
+    let mut steps = Vec::new();
+
+    for root in graph.ids() {
+        if order.contains_key(&root) {
+            continue;
+        }
+
+        order.insert(root, order.len());
+
+        for step in DepthFirst::new(graph, root).expect("root") {
+            if !step.cut {
+                order.insert(step.tid, order.len());
+            }
+
+            steps.push(step);
+        }
+    }
+
+    let mut low = order.clone();
+
+    for step in steps.iter().filter(|step| step.cut) {
+        let (sid, tid) = (order[&step.sid], order[&step.tid]);
+
+        low.entry(step.sid).and_modify(|low| *low = tid.min(*low));
+        low.entry(step.tid).and_modify(|low| *low = sid.min(*low));
+    }
+
+    for step in steps.iter().rev().filter(|step| !step.cut) {
+        let child = low[&step.tid];
+
+        low.entry(step.sid).and_modify(|low| *low = child.min(*low));
+    }
+
+    steps.into_iter().map(|step| {
+        let class = if step.cut {
+            EdgeClass::Back
+        } else if low[&step.tid] > order[&step.sid] {
+            EdgeClass::Bridge
+        } else {
+            EdgeClass::Tree
+        };
+
+        (step.sid, step.tid, class)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn p0() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(classify_edges(&graph), [ ])
+    }
+
+    #[test]
+    fn p3_p2() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 0, 2 ],
+            vec![ 1 ],
+            vec![ 4 ],
+            vec![ 3 ]
+        ]).unwrap();
+
+        assert_eq!(classify_edges(&graph), [
+            (0, 1, EdgeClass::Bridge),
+            (1, 2, EdgeClass::Bridge),
+            (3, 4, EdgeClass::Bridge)
+        ])
+    }
+
+    #[test]
+    fn c3_with_pendant() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2)
+        ]).unwrap();
+
+        assert_eq!(classify_edges(&graph), [
+            (0, 1, EdgeClass::Tree),
+            (1, 2, EdgeClass::Tree),
+            (2, 0, EdgeClass::Back),
+            (0, 3, EdgeClass::Bridge)
+        ])
+    }
+
+    #[test]
+    fn one_entry_per_edge() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4)
+        ]).unwrap();
+        let mut edges = classify_edges(&graph).into_iter()
+            .map(|(sid, tid, _)| (sid.min(tid), sid.max(tid)))
+            .collect::<Vec<_>>();
+
+        edges.sort_unstable();
+
+        assert_eq!(edges, [
+            (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4)
+        ])
+    }
+
+    #[test]
+    fn bicyclic_with_bridge() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1, 2 ],
+            vec![ 0, 2 ],
+            vec![ 0, 1, 3 ],
+            vec![ 2, 4, 5 ],
+            vec![ 3, 5 ],
+            vec![ 3, 4 ]
+        ]).unwrap();
+        let classes = classify_edges(&graph);
+
+        assert_eq!(classes, [
+            (0, 1, EdgeClass::Tree),
+            (1, 2, EdgeClass::Tree),
+            (2, 0, EdgeClass::Back),
+            (2, 3, EdgeClass::Bridge),
+            (3, 4, EdgeClass::Tree),
+            (4, 5, EdgeClass::Tree),
+            (5, 3, EdgeClass::Back)
+        ]);
+        assert!(!classes[3].2.is_cycle_member())
+    }
+}
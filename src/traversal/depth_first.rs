@@ -1,10 +1,13 @@
 use std::collections::HashSet;
 
 use crate::graph::{ Graph, Error };
-use super::Step;
+use super::{ Step, Preorder, Postorder };
 
 /// Implements a depth-first traversal as a Step Iterator.
-/// 
+///
+/// Iterates edges of graph in depth-first order. To visit node ids instead,
+/// use the `preorder` or `postorder` functions.
+///
 /// ```rust
 /// use std::convert::TryFrom;
 /// 
@@ -30,14 +33,10 @@ use super::Step;
 ///     Ok(())
 /// }
 /// ```
-
-
-/// Iterates edges of graph in depth-first order. To perform a depth-first
-/// search, use the `depth_first` function instead.
 #[derive(Debug,PartialEq)]
 pub struct DepthFirst<'a, G> {
     nodes: HashSet<usize>,
-    stack: Vec<(usize, usize)>,
+    stack: Vec<(usize, usize, bool)>,
     graph: &'a G
 }
 
@@ -47,7 +46,7 @@ impl<'a, G: Graph> DepthFirst<'a, G> {
         let mut stack = Vec::new();
     
         for neighbor in graph.neighbors(root)? {
-            stack.push((root, neighbor));
+            stack.push((root, neighbor, false));
         }
     
         nodes.insert(root);
@@ -56,6 +55,22 @@ impl<'a, G: Graph> DepthFirst<'a, G> {
         Ok(Self { nodes, stack, graph })
     }
 
+    /// Returns an Iterator over the ids reachable from root in depth-first
+    /// preorder, or Error if root is not found.
+    pub fn preorder(
+        graph: &'a G, root: usize
+    ) -> Result<Preorder<'a, G>, Error> {
+        Preorder::new(graph, root)
+    }
+
+    /// Returns an Iterator over the ids reachable from root in depth-first
+    /// postorder, or Error if root is not found.
+    pub fn postorder(
+        graph: &'a G, root: usize
+    ) -> Result<Postorder<'a, G>, Error> {
+        Postorder::new(graph, root)
+    }
+
     pub fn into_table(self) -> (Vec<usize>, Vec<(usize, usize)>) {
         let mut nodes = Vec::new();
         let mut edges = Vec::new();
@@ -81,35 +96,35 @@ impl<'a, G> Iterator for DepthFirst<'a, G>
     type Item = Step;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.stack.pop() {
-            None => None,
-            Some((parent, node)) => {
-                if self.nodes.contains(&node) {
-                    Some(Step::new(parent, node, true))
-                } else {
-                    let neighbors = self.graph.neighbors(node).unwrap()
-                        .collect::<Vec<_>>();
-
-                    for neighbor in neighbors.into_iter().rev() {
-                        if neighbor == parent {
-                            continue;
-                        }
-
-                        if self.nodes.contains(&neighbor) {
-                            self.stack.retain(
-                                |edge| edge.0 != neighbor && edge.1 != node
-                            );
-                        }
-
-                        self.stack.push((node, neighbor));
-                    }
-    
-                    self.nodes.insert(node);
-    
-                    Some(Step::new(parent, node, false))
+        // Each edge is pushed by whichever end is expanded first. If the
+        // other end is reached some other way, it pushes the edge back as a
+        // cut and the first entry is skipped when popped.
+        while let Some((parent, node, cut)) = self.stack.pop() {
+            if cut {
+                return Some(Step::new(parent, node, true));
+            } else if self.nodes.contains(&node) {
+                continue;
+            }
+
+            let start = self.stack.len();
+
+            for neighbor in self.graph.neighbors(node).unwrap() {
+                if neighbor != parent {
+                    let cut = self.nodes.contains(&neighbor);
+
+                    self.stack.push((node, neighbor, cut));
                 }
             }
+
+            // neighbors were pushed in order, so reverse them in place to
+            // pop the first one first
+            self.stack[start..].reverse();
+            self.nodes.insert(node);
+
+            return Some(Step::new(parent, node, false));
         }
+
+        None
     }
 }
 
@@ -285,6 +300,38 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn c3_with_pendant() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2)
+        ]).unwrap();
+        let traversal = DepthFirst::new(&graph, 0).unwrap();
+
+        assert_eq!(traversal.collect::<Vec<_>>(), vec![
+            Step::new(0, 1, false),
+            Step::new(1, 2, false),
+            Step::new(2, 0, true),
+            Step::new(0, 3, false)
+        ]);
+    }
+
+    #[test]
+    fn s3_parent_between_neighbors() {
+        let graph = DefaultGraph::try_from(vec![
+            vec![ 1 ],
+            vec![ 2, 0, 3 ],
+            vec![ 1 ],
+            vec![ 1 ]
+        ]).unwrap();
+        let traversal = DepthFirst::new(&graph, 0).unwrap();
+
+        assert_eq!(traversal.collect::<Vec<_>>(), vec![
+            Step::new(0, 1, false),
+            Step::new(1, 2, false),
+            Step::new(1, 3, false)
+        ]);
+    }
+
     #[test]
     fn s3_inside() {
         let graph = DefaultGraph::try_from(vec![
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Graph, Error };
+
+/// Iterates the ids of a graph in lexicographic breadth-first (Lex-BFS)
+/// order. Like breadth-first search, nodes are visited in layers, but
+/// among the candidates the node whose visited neighbors came earliest is
+/// preferred. Ties are broken by the order of `Graph::ids`. Every node is
+/// visited, including those unreachable from the first.
+///
+/// Reversed, the ordering is a perfect elimination ordering exactly when
+/// the graph is chordal.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::traversal::LexBfs;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (0, 2), (1, 3), (2, 3), (2, 4)
+///     ])?;
+///     let ordering = LexBfs::from_root(&graph, 2)?;
+///
+///     assert_eq!(ordering.collect::<Vec<_>>(), vec![ 2, 0, 3, 4, 1 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct LexBfs<'a, G> {
+    partition: Vec<Vec<usize>>,
+    graph: &'a G
+}
+
+impl<'a, G: Graph> LexBfs<'a, G> {
+    /// Returns a LexBfs starting from the first id of graph.
+    pub fn new(graph: &'a G) -> Self {
+        let mut partition = vec![ graph.ids().collect::<Vec<_>>() ];
+
+        partition.retain(|set| !set.is_empty());
+
+        Self { partition, graph }
+    }
+
+    /// Returns a LexBfs starting from root, or Error if root is not found.
+    pub fn from_root(graph: &'a G, root: usize) -> Result<Self, Error> {
+        if !graph.has_id(root) {
+            return Err(Error::UnknownId(root));
+        }
+
+        let rest = graph.ids().filter(|&id| id != root).collect::<Vec<_>>();
+        let mut partition = vec![ vec![ root ], rest ];
+
+        partition.retain(|set| !set.is_empty());
+
+        Ok(Self { partition, graph })
+    }
+}
+
+impl<'a, G: Graph> Iterator for LexBfs<'a, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.partition.first_mut()?;
+        let id = first.remove(0);
+        let neighbors = self.graph.neighbors(id).expect("neighbors")
+            .collect::<HashSet<_>>();
+        let mut refined = Vec::with_capacity(self.partition.len() + 1);
+
+        for set in self.partition.drain(..) {
+            let (inside, outside): (Vec<_>, Vec<_>) = set.into_iter()
+                .partition(|other| neighbors.contains(other));
+
+            if !inside.is_empty() {
+                refined.push(inside);
+            }
+
+            if !outside.is_empty() {
+                refined.push(outside);
+            }
+        }
+
+        self.partition = refined;
+
+        Some(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn empty() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(LexBfs::new(&graph).count(), 0)
+    }
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(
+            LexBfs::from_root(&graph, 0).err(),
+            Some(Error::UnknownId(0))
+        )
+    }
+
+    #[test]
+    fn p4() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3)
+        ]).unwrap();
+
+        assert_eq!(LexBfs::new(&graph).collect::<Vec<_>>(), [ 0, 1, 2, 3 ])
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+        let ordering = LexBfs::from_root(&graph, 3).unwrap();
+
+        assert_eq!(ordering.collect::<Vec<_>>(), [ 3, 2, 0, 1 ])
+    }
+
+    #[test]
+    fn prefers_earlier_neighbors() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (1, 3), (2, 4), (1, 4)
+        ]).unwrap();
+        let ordering = LexBfs::new(&graph).collect::<Vec<_>>();
+
+        assert_eq!(ordering, [ 0, 1, 2, 4, 3 ])
+    }
+}
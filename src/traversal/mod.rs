@@ -1,7 +1,24 @@
 mod depth_first;
 mod breadth_first;
+mod preorder;
+mod postorder;
+mod lex_bfs;
 mod step;
+mod weighted_step;
+mod weighted_depth_first;
+mod weighted_breadth_first;
+mod classify_edges;
+#[allow(clippy::module_inception)]
+mod to_adjacency;
 
 pub use depth_first::DepthFirst;
 pub use breadth_first::BreadthFirst;
-pub use step::Step;
\ No newline at end of file
+pub use preorder::Preorder;
+pub use postorder::Postorder;
+pub use lex_bfs::LexBfs;
+pub use step::Step;
+pub use weighted_step::WeightedStep;
+pub use weighted_depth_first::WeightedDepthFirst;
+pub use weighted_breadth_first::WeightedBreadthFirst;
+pub use classify_edges::{ classify_edges, EdgeClass };
+pub use to_adjacency::to_adjacency;
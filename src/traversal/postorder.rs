@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Graph, Error };
+
+/// Iterates the ids reachable from a root in depth-first postorder: each
+/// node is yielded once all of its descendants have been, so the root
+/// comes last. Neighbors are explored in the order the graph reports them.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::traversal::DepthFirst;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (0, 3)
+///     ])?;
+///     let postorder = DepthFirst::postorder(&graph, 0)?;
+///
+///     assert_eq!(postorder.collect::<Vec<_>>(), vec![ 2, 1, 3, 0 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Postorder<'a, G> {
+    visited: HashSet<usize>,
+    stack: Vec<(usize, Box<dyn Iterator<Item=usize> + 'a>)>,
+    graph: &'a G
+}
+
+impl<'a, G: Graph> Postorder<'a, G> {
+    pub fn new(graph: &'a G, root: usize) -> Result<Self, Error> {
+        let neighbors = graph.neighbors(root)?;
+        let mut visited = HashSet::new();
+
+        visited.insert(root);
+
+        Ok(Self { visited, stack: vec![ (root, neighbors) ], graph })
+    }
+}
+
+impl<'a, G: Graph> Iterator for Postorder<'a, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (id, neighbors) = self.stack.last_mut()?;
+
+            match neighbors.next() {
+                Some(neighbor) => {
+                    if self.visited.insert(neighbor) {
+                        let next = self.graph.neighbors(neighbor)
+                            .expect("neighbors");
+
+                        self.stack.push((neighbor, next));
+                    }
+                },
+                None => {
+                    let id = *id;
+
+                    self.stack.pop();
+
+                    break Some(id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(Postorder::new(&graph, 0).err(), Some(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+        let postorder = Postorder::new(&graph, 0).unwrap();
+
+        assert_eq!(postorder.collect::<Vec<_>>(), [ 0 ])
+    }
+
+    #[test]
+    fn c3_pendant() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2)
+        ]).unwrap();
+        let postorder = Postorder::new(&graph, 0).unwrap();
+
+        assert_eq!(postorder.collect::<Vec<_>>(), [ 2, 1, 3, 0 ])
+    }
+
+    #[test]
+    fn p3_internal() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let postorder = Postorder::new(&graph, 1).unwrap();
+
+        assert_eq!(postorder.collect::<Vec<_>>(), [ 0, 2, 1 ])
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use crate::graph::{ Graph, Error };
+
+/// Iterates the ids reachable from a root in depth-first preorder: each
+/// node is yielded when first discovered, with neighbors explored in the
+/// order the graph reports them.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::traversal::DepthFirst;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (0, 3)
+///     ])?;
+///     let preorder = DepthFirst::preorder(&graph, 0)?;
+///
+///     assert_eq!(preorder.collect::<Vec<_>>(), vec![ 0, 1, 2, 3 ]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug,PartialEq)]
+pub struct Preorder<'a, G> {
+    visited: HashSet<usize>,
+    stack: Vec<usize>,
+    graph: &'a G
+}
+
+impl<'a, G: Graph> Preorder<'a, G> {
+    pub fn new(graph: &'a G, root: usize) -> Result<Self, Error> {
+        if !graph.has_id(root) {
+            return Err(Error::UnknownId(root));
+        }
+
+        Ok(Self { visited: HashSet::new(), stack: vec![ root ], graph })
+    }
+}
+
+impl<'a, G: Graph> Iterator for Preorder<'a, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(id) = self.stack.pop() {
+            if !self.visited.insert(id) {
+                continue;
+            }
+
+            let neighbors = self.graph.neighbors(id).expect("neighbors")
+                .filter(|neighbor| !self.visited.contains(neighbor))
+                .collect::<Vec<_>>();
+
+            self.stack.extend(neighbors.into_iter().rev());
+
+            return Some(id);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultGraph::new();
+
+        assert_eq!(Preorder::new(&graph, 0), Err(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn p1() {
+        let graph = DefaultGraph::try_from(vec![ vec![ ] ]).unwrap();
+        let preorder = Preorder::new(&graph, 0).unwrap();
+
+        assert_eq!(preorder.collect::<Vec<_>>(), [ 0 ])
+    }
+
+    #[test]
+    fn c3_pendant() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (0, 2), (0, 3), (1, 2)
+        ]).unwrap();
+        let preorder = Preorder::new(&graph, 0).unwrap();
+
+        assert_eq!(preorder.collect::<Vec<_>>(), [ 0, 1, 2, 3 ])
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (2, 3) ]).unwrap();
+        let preorder = Preorder::new(&graph, 3).unwrap();
+
+        assert_eq!(preorder.collect::<Vec<_>>(), [ 3, 2 ])
+    }
+}
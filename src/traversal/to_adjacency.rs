@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use super::Step;
+
+/// Returns an adjacency map built from the Steps of a traversal. Each
+/// Step adds its target to the neighbors of its source and vice versa,
+/// so neighbors appear in the order their edges were traversed. A
+/// traversal yielding no Steps produces an empty map.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultGraph };
+/// use gamma::traversal::{ to_adjacency, DepthFirst };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 1), (1, 2), (2, 0)
+///     ])?;
+///     let adjacency = to_adjacency(DepthFirst::new(&graph, 0)?);
+///
+///     assert_eq!(adjacency[&0], vec![ 1, 2 ]);
+///     assert_eq!(adjacency[&1], vec![ 0, 2 ]);
+///     assert_eq!(adjacency[&2], vec![ 1, 0 ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn to_adjacency<I: IntoIterator<Item=Step>>(
+    steps: I
+) -> HashMap<usize, Vec<usize>> {
+    let mut result = HashMap::new();
+
+    for step in steps {
+        result.entry(step.sid).or_insert_with(Vec::new).push(step.tid);
+        result.entry(step.tid).or_insert_with(Vec::new).push(step.sid);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod to_adjacency {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+    use crate::traversal::BreadthFirst;
+
+    #[test]
+    fn empty() {
+        assert_eq!(to_adjacency(vec![ ]), HashMap::new())
+    }
+
+    #[test]
+    fn breadth_first_p3() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2) ]).unwrap();
+        let adjacency = to_adjacency(BreadthFirst::new(&graph, 1).unwrap());
+        let mut expected = HashMap::new();
+
+        expected.insert(0, vec![ 1 ]);
+        expected.insert(1, vec![ 0, 2 ]);
+        expected.insert(2, vec![ 1 ]);
+
+        assert_eq!(adjacency, expected)
+    }
+}
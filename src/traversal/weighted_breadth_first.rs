@@ -0,0 +1,78 @@
+use crate::graph::{ WeightedGraph, Error };
+use super::{ BreadthFirst, WeightedStep };
+
+/// Implements a breadth-first traversal over a WeightedGraph as a WeightedStep
+/// Iterator. Steps are emitted in the same order as BreadthFirst.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Error, DefaultWeightedGraph };
+/// use gamma::traversal::{ WeightedBreadthFirst, WeightedStep };
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 1),
+///         (1, 2, 2),
+///         (2, 0, 3)
+///     ])?;
+///     let traversal = WeightedBreadthFirst::new(&graph, 0)?;
+///
+///     assert_eq!(traversal.collect::<Vec<_>>(), vec![
+///         WeightedStep::new(0, 1, &1, false),
+///         WeightedStep::new(0, 2, &3, false),
+///         WeightedStep::new(1, 2, &2, true)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct WeightedBreadthFirst<'a, G> {
+    traversal: BreadthFirst<'a, G>,
+    graph: &'a G
+}
+
+impl<'a, G: WeightedGraph> WeightedBreadthFirst<'a, G> {
+    pub fn new(graph: &'a G, root: usize) -> Result<Self, Error> {
+        Ok(Self { traversal: BreadthFirst::new(graph, root)?, graph })
+    }
+}
+
+impl<'a, G> Iterator for WeightedBreadthFirst<'a, G>
+    where G: WeightedGraph {
+    type Item = WeightedStep<'a, G::Weight>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.traversal.next()?;
+        let weight = self.graph.weight(step.sid, step.tid)
+            .expect("weight").expect("edge");
+
+        Some(WeightedStep::new(step.sid, step.tid, weight, step.cut))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultWeightedGraph;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultWeightedGraph::<u8>::new();
+
+        assert_eq!(WeightedBreadthFirst::new(&graph, 0).err(), Some(Error::UnknownId(0)))
+    }
+
+    #[test]
+    fn p3() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 'a'),
+            (1, 2, 'b')
+        ]).unwrap();
+        let traversal = WeightedBreadthFirst::new(&graph, 1).unwrap();
+
+        assert_eq!(traversal.map(|step| *step.weight).collect::<Vec<_>>(), [
+            'a', 'b'
+        ])
+    }
+}
@@ -0,0 +1,16 @@
+/// A single weighted traversal step comprised of source and target nodes,
+/// a reference to the weight of the edge joining them, and a boolean flag
+/// indicating whether a cycle cut is present.
+#[derive(Eq,PartialEq,Hash,Debug)]
+pub struct WeightedStep<'a, W> {
+    pub sid: usize,
+    pub tid: usize,
+    pub weight: &'a W,
+    pub cut: bool
+}
+
+impl<'a, W> WeightedStep<'a, W> {
+    pub fn new(sid: usize, tid: usize, weight: &'a W, cut: bool) -> Self {
+        WeightedStep { sid, tid, weight, cut }
+    }
+}
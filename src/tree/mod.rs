@@ -0,0 +1,6 @@
+mod steiner;
+#[allow(clippy::module_inception)]
+mod prim;
+
+pub use steiner::{ steiner_approx, steiner_approx_with_weights };
+pub use prim::{ prim, prim_with_weights };
@@ -0,0 +1,187 @@
+use std::collections::BinaryHeap;
+use std::cmp::Reverse;
+
+use crate::graph::{ Graph, WeightedGraph, Error, DefaultWeightedGraph };
+
+/// Returns a minimum spanning tree of the component containing root, built
+/// with Prim's algorithm. Nodes are added in the order they join the tree.
+/// Returns Error if root is not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultWeightedGraph };
+/// use gamma::tree::prim;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 1, 1),
+///         (1, 2, 2),
+///         (0, 2, 3)
+///     ])?;
+///     let tree = prim(&graph, 0)?;
+///
+///     assert_eq!(tree.edges().collect::<Vec<_>>(), vec![ (0, 1), (1, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn prim<G>(
+    graph: &G, root: usize
+) -> Result<DefaultWeightedGraph<G::Weight>, Error>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Ord
+{
+    prim_with_weights(graph, root, |sid, tid| {
+        *graph.weight(sid, tid).expect("weight").expect("edge")
+    })
+}
+
+/// Returns a minimum spanning tree of the component containing root, as
+/// `prim` does, taking the weight of each edge (sid, tid) from weight
+/// rather than from a WeightedGraph. Returns Error if root is not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::tree::prim_with_weights;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![ (0, 1), (1, 2), (0, 2) ])?;
+///     let tree = prim_with_weights(&graph, 0, |sid, tid| sid + tid)?;
+///
+///     assert_eq!(tree.edges().collect::<Vec<_>>(), vec![ (0, 1), (0, 2) ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn prim_with_weights<G, W, F>(
+    graph: &G, root: usize, weight: F
+) -> Result<DefaultWeightedGraph<W>, Error>
+where
+    G: Graph,
+    W: Copy + Ord,
+    F: Fn(usize, usize) -> W
+{
+    let mut result = DefaultWeightedGraph::new();
+    let mut heap = BinaryHeap::new();
+
+    if !graph.has_id(root) {
+        return Err(Error::UnknownId(root));
+    }
+
+    result.add_node(root)?;
+
+    for neighbor in graph.neighbors(root)? {
+        heap.push(Reverse((weight(root, neighbor), root, neighbor)));
+    }
+
+    while let Some(Reverse((edge_weight, sid, tid))) = heap.pop() {
+        if result.has_id(tid) {
+            continue;
+        }
+
+        result.add_node(tid)?;
+        result.add_edge(sid, tid, edge_weight)?;
+
+        for neighbor in graph.neighbors(tid)? {
+            if !result.has_id(neighbor) {
+                heap.push(Reverse((weight(tid, neighbor), tid, neighbor)));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod prim {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(prim(&graph, 2), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn p1() {
+        let mut graph = DefaultWeightedGraph::<usize>::new();
+
+        graph.add_node(0).unwrap();
+
+        let tree = prim(&graph, 0).unwrap();
+
+        assert_eq!(tree.ids().collect::<Vec<_>>(), [ 0 ]);
+        assert_eq!(tree.size(), 0)
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (2, 3, 1)
+        ]).unwrap();
+        let tree = prim(&graph, 2).unwrap();
+
+        assert_eq!(tree.ids().collect::<Vec<_>>(), [ 2, 3 ])
+    }
+
+    #[test]
+    fn c4_with_chord() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 4),
+            (1, 2, 1),
+            (2, 3, 5),
+            (3, 0, 2),
+            (0, 2, 3)
+        ]).unwrap();
+        let tree = prim(&graph, 0).unwrap();
+
+        assert_eq!(tree.edges().collect::<Vec<_>>(), [
+            (0, 3), (0, 2), (2, 1)
+        ]);
+        assert_eq!(tree.weight(2, 1), Ok(Some(&1)))
+    }
+}
+
+#[cfg(test)]
+mod prim_with_weights {
+    use std::convert::TryFrom;
+    use super::*;
+    use crate::graph::DefaultGraph;
+
+    #[test]
+    fn unknown_root() {
+        let graph = DefaultGraph::try_from(vec![ (0, 1) ]).unwrap();
+
+        assert_eq!(
+            prim_with_weights(&graph, 2, |_, _| 1),
+            Err(Error::UnknownId(2))
+        )
+    }
+
+    #[test]
+    fn external_weights() {
+        let graph = DefaultGraph::try_from(vec![
+            (0, 1), (1, 2), (2, 3), (3, 0)
+        ]).unwrap();
+        let weights = [
+            [ 0, 1, 0, 9 ],
+            [ 1, 0, 8, 0 ],
+            [ 0, 8, 0, 1 ],
+            [ 9, 0, 1, 0 ]
+        ];
+        let tree = prim_with_weights(&graph, 0, |sid, tid| {
+            weights[sid][tid]
+        }).unwrap();
+
+        assert_eq!(tree.edges().collect::<Vec<_>>(), [
+            (0, 1), (1, 2), (2, 3)
+        ])
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::{ HashMap, HashSet };
+use std::ops::Add;
+
+use crate::graph::{ Graph, WeightedGraph, Error, DefaultWeightedGraph };
+use crate::shortest_path::dijkstra_with_weights;
+
+/// Returns a subgraph connecting every terminal, with total weight at most
+/// twice the minimum, or None if the terminals are not all connected.
+/// Returns Error if a terminal is not found.
+///
+/// Uses the metric closure approximation: a minimum spanning tree over
+/// shortest paths between terminals is expanded into graph edges, then
+/// reduced to a spanning tree with non-terminal leaves pruned.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultWeightedGraph };
+/// use gamma::tree::steiner_approx;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultWeightedGraph::try_from(vec![
+///         (0, 3, 1),
+///         (1, 3, 1),
+///         (2, 3, 1),
+///         (0, 1, 3),
+///         (1, 2, 3)
+///     ])?;
+///     let tree = steiner_approx(&graph, &[ 0, 1, 2 ])?.unwrap();
+///
+///     assert_eq!(tree.edges().collect::<Vec<_>>(), vec![
+///         (0, 3), (1, 3), (2, 3)
+///     ]);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn steiner_approx<G>(
+    graph: &G, terminals: &[usize]
+) -> Result<Option<DefaultWeightedGraph<G::Weight>>, Error>
+where
+    G: WeightedGraph,
+    G::Weight: Copy + Ord + Add<Output=G::Weight> + Default
+{
+    steiner_approx_with_weights(graph, terminals, |sid, tid| {
+        *graph.weight(sid, tid).expect("weight").expect("edge")
+    })
+}
+
+/// Returns a subgraph connecting every terminal, as `steiner_approx` does,
+/// taking the weight of each edge (sid, tid) from weight rather than from
+/// a WeightedGraph. Returns Error if a terminal is not found.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gamma::graph::{ Graph, Error, DefaultGraph };
+/// use gamma::tree::steiner_approx_with_weights;
+///
+/// fn main() -> Result<(), Error> {
+///     let graph = DefaultGraph::try_from(vec![
+///         (0, 3), (1, 3), (2, 3), (0, 1), (1, 2)
+///     ])?;
+///     let weight = |sid: usize, tid: usize| {
+///         if sid == 3 || tid == 3 { 1 } else { 3 }
+///     };
+///     let tree = steiner_approx_with_weights(&graph, &[ 0, 1, 2 ], weight)?;
+///
+///     assert_eq!(tree.unwrap().size(), 3);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn steiner_approx_with_weights<G, W, F>(
+    graph: &G, terminals: &[usize], weight: F
+) -> Result<Option<DefaultWeightedGraph<W>>, Error>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output=W> + Default,
+    F: Fn(usize, usize) -> W
+{
+    let mut unique = Vec::new();
+
+    for &id in terminals {
+        if !graph.has_id(id) {
+            return Err(Error::UnknownId(id));
+        }
+
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+
+    let mut closure = Vec::new();
+
+    for (index, &sid) in unique.iter().enumerate() {
+        for &tid in unique[index + 1..].iter() {
+            match dijkstra_with_weights(graph, sid, tid, &weight)? {
+                Some((cost, path)) => closure.push((cost, path)),
+                None => return Ok(None)
+            }
+        }
+    }
+
+    let mut union = HashSet::new();
+
+    for (_, path) in kruskal(closure, |(_, path)| {
+        (path[0], path[path.len() - 1])
+    }) {
+        for pair in path.windows(2) {
+            union.insert((pair[0].min(pair[1]), pair[0].max(pair[1])));
+        }
+    }
+
+    let edges = union.into_iter().map(|(sid, tid)| {
+        (weight(sid, tid), sid, tid)
+    }).collect::<Vec<_>>();
+    let mut tree = kruskal(edges, |&(_, sid, tid)| (sid, tid));
+
+    prune(&mut tree, &unique);
+
+    let mut result = DefaultWeightedGraph::new();
+
+    if let Some(&id) = unique.first() {
+        result.add_node(id)?;
+    }
+
+    for (weight, sid, tid) in tree {
+        for id in [ sid, tid ] {
+            if !result.has_id(id) {
+                result.add_node(id)?;
+            }
+        }
+
+        result.add_edge(sid, tid, weight)?;
+    }
+
+    Ok(Some(result))
+}
+
+/// Returns a minimum spanning forest of items, each of which is an edge
+/// ordered by cost with endpoints given by ends.
+fn kruskal<T: Ord, F>(mut items: Vec<T>, ends: F) -> Vec<T>
+where F: Fn(&T) -> (usize, usize) {
+    let mut parents = HashMap::new();
+    let mut result = Vec::new();
+
+    items.sort();
+
+    for item in items {
+        let (sid, tid) = ends(&item);
+        let (source, target) = (root(&mut parents, sid), root(&mut parents, tid));
+
+        if source != target {
+            parents.insert(source, target);
+            result.push(item);
+        }
+    }
+
+    result
+}
+
+fn root(parents: &mut HashMap<usize, usize>, id: usize) -> usize {
+    let mut result = id;
+
+    while let Some(&parent) = parents.get(&result) {
+        result = parent;
+    }
+
+    if result != id {
+        parents.insert(id, result);
+    }
+
+    result
+}
+
+fn prune<W>(tree: &mut Vec<(W, usize, usize)>, terminals: &[usize]) {
+    loop {
+        let mut degrees = HashMap::new();
+
+        for &(_, sid, tid) in tree.iter() {
+            *degrees.entry(sid).or_insert(0) += 1;
+            *degrees.entry(tid).or_insert(0) += 1;
+        }
+
+        let leaf = |id: &usize| degrees[id] == 1 && !terminals.contains(id);
+        let before = tree.len();
+
+        tree.retain(|(_, sid, tid)| !leaf(sid) && !leaf(tid));
+
+        if tree.len() == before {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use super::*;
+
+    #[test]
+    fn unknown_terminal() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+
+        assert_eq!(steiner_approx(&graph, &[ 0, 2 ]), Err(Error::UnknownId(2)))
+    }
+
+    #[test]
+    fn disconnected() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (2, 3, 1)
+        ]).unwrap();
+
+        assert_eq!(steiner_approx(&graph, &[ 0, 3 ]), Ok(None))
+    }
+
+    #[test]
+    fn single_terminal() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1)
+        ]).unwrap();
+        let tree = steiner_approx(&graph, &[ 1, 1 ]).unwrap().unwrap();
+
+        assert_eq!(tree.ids().collect::<Vec<_>>(), [ 1 ]);
+        assert_eq!(tree.size(), 0)
+    }
+
+    #[test]
+    fn path_through_non_terminals() {
+        let graph = DefaultWeightedGraph::try_from(vec![
+            (0, 1, 1),
+            (1, 2, 1),
+            (2, 3, 1),
+            (3, 4, 1),
+            (1, 5, 1)
+        ]).unwrap();
+        let tree = steiner_approx(&graph, &[ 0, 3 ]).unwrap().unwrap();
+
+        assert_eq!(tree.ids().collect::<Vec<_>>(), [ 0, 1, 2, 3 ]);
+        assert_eq!(tree.edges().collect::<Vec<_>>(), [
+            (0, 1), (1, 2), (2, 3)
+        ])
+    }
+}
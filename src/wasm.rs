@@ -0,0 +1,193 @@
+//! A wasm-bindgen facade over DefaultGraph. Ids cross the boundary as u32,
+//! and edges as flat arrays of the form `[ sid, tid, sid, tid, ... ]`.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::*;
+
+use crate::graph::{ Graph, DefaultGraph };
+use crate::traversal::{ DepthFirst, BreadthFirst, Step };
+use crate::selection::ComponentIndex;
+use crate::matching::{ maximum_matching, Pairing };
+
+/// A DefaultGraph exposed to JavaScript.
+#[wasm_bindgen]
+pub struct WasmGraph {
+    graph: DefaultGraph
+}
+
+#[wasm_bindgen]
+impl WasmGraph {
+    /// Builds a graph from a flat edge array, creating nodes as they're
+    /// first seen.
+    #[wasm_bindgen(constructor)]
+    pub fn new(edges: Vec<u32>) -> Result<WasmGraph, JsValue> {
+        if edges.len() % 2 == 1 {
+            return Err(JsValue::from_str("odd edge array length"));
+        }
+
+        let edges = edges.chunks(2)
+            .map(|pair| (pair[0] as usize, pair[1] as usize))
+            .collect::<Vec<_>>();
+        let graph = DefaultGraph::try_from(edges).map_err(to_js)?;
+
+        Ok(WasmGraph { graph })
+    }
+
+    #[wasm_bindgen(js_name = addNode)]
+    pub fn add_node(&mut self, id: u32) -> Result<(), JsValue> {
+        self.graph.add_node(id as usize).map_err(to_js)
+    }
+
+    #[wasm_bindgen(js_name = addEdge)]
+    pub fn add_edge(&mut self, sid: u32, tid: u32) -> Result<(), JsValue> {
+        self.graph.add_edge(sid as usize, tid as usize).map_err(to_js)
+    }
+
+    pub fn order(&self) -> u32 {
+        to_u32(self.graph.order())
+    }
+
+    pub fn size(&self) -> u32 {
+        to_u32(self.graph.size())
+    }
+
+    pub fn ids(&self) -> Vec<u32> {
+        self.graph.ids().map(to_u32).collect()
+    }
+
+    pub fn edges(&self) -> Vec<u32> {
+        flatten(self.graph.edges())
+    }
+
+    /// Returns depth-first Steps from root as `[ sid, tid, cut, ... ]`
+    /// triples, where cut is 0 or 1.
+    #[wasm_bindgen(js_name = depthFirst)]
+    pub fn depth_first(&self, root: u32) -> Result<Vec<u32>, JsValue> {
+        let traversal = DepthFirst::new(&self.graph, root as usize)
+            .map_err(to_js)?;
+
+        Ok(steps(traversal))
+    }
+
+    /// Returns breadth-first Steps from root as `[ sid, tid, cut, ... ]`
+    /// triples, where cut is 0 or 1.
+    #[wasm_bindgen(js_name = breadthFirst)]
+    pub fn breadth_first(&self, root: u32) -> Result<Vec<u32>, JsValue> {
+        let traversal = BreadthFirst::new(&self.graph, root as usize)
+            .map_err(to_js)?;
+
+        Ok(steps(traversal))
+    }
+
+    /// Returns a component label for each node, in the order of `ids`.
+    pub fn components(&self) -> Vec<u32> {
+        let index = ComponentIndex::new(&self.graph);
+        let mut labels = HashMap::new();
+        let mut count = 0;
+
+        self.graph.ids().map(|id| {
+            if !labels.contains_key(&id) {
+                for &member in index.component(id).expect("component") {
+                    labels.insert(member, to_u32(count));
+                }
+
+                count += 1;
+            }
+
+            labels[&id]
+        }).collect()
+    }
+
+    /// Returns the edges of a maximum matching as a flat edge array.
+    #[wasm_bindgen(js_name = maximumMatching)]
+    pub fn maximum_matching(&self) -> Vec<u32> {
+        let mut pairing = Pairing::new();
+
+        maximum_matching(&self.graph, &mut pairing);
+
+        flatten(pairing.edges())
+    }
+}
+
+fn steps<I: Iterator<Item=Step>>(steps: I) -> Vec<u32> {
+    let mut result = Vec::new();
+
+    for step in steps {
+        result.push(to_u32(step.sid));
+        result.push(to_u32(step.tid));
+        result.push(step.cut as u32);
+    }
+
+    result
+}
+
+fn flatten<I: Iterator<Item=(usize, usize)>>(edges: I) -> Vec<u32> {
+    let mut result = Vec::new();
+
+    for (sid, tid) in edges {
+        result.push(to_u32(sid));
+        result.push(to_u32(tid));
+    }
+
+    result
+}
+
+fn to_u32(id: usize) -> u32 {
+    u32::try_from(id).expect("id exceeds u32")
+}
+
+fn to_js<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p3() {
+        let graph = WasmGraph::new(vec![ 0, 1, 1, 2 ]).ok().unwrap();
+
+        assert_eq!(graph.order(), 3);
+        assert_eq!(graph.size(), 2);
+        assert_eq!(graph.ids(), vec![ 0, 1, 2 ]);
+        assert_eq!(graph.edges(), vec![ 0, 1, 1, 2 ])
+    }
+
+    #[test]
+    fn depth_first_c3() {
+        let graph = WasmGraph::new(vec![ 0, 1, 1, 2, 2, 0 ]).ok().unwrap();
+
+        assert_eq!(graph.depth_first(0).ok().unwrap(), vec![
+            0, 1, 0,
+            1, 2, 0,
+            2, 0, 1
+        ])
+    }
+
+    #[test]
+    fn components_p2_p1() {
+        let mut graph = WasmGraph::new(vec![ 0, 1 ]).ok().unwrap();
+
+        graph.add_node(2).ok().unwrap();
+
+        assert_eq!(graph.components(), vec![ 0, 0, 1 ])
+    }
+
+    #[test]
+    fn components_interleaved() {
+        let graph = WasmGraph::new(vec![ 0, 2, 1, 3, 2, 4 ]).ok().unwrap();
+
+        assert_eq!(graph.components(), vec![ 0, 0, 1, 1, 0 ])
+    }
+
+    #[test]
+    fn maximum_matching_p2() {
+        let graph = WasmGraph::new(vec![ 0, 1 ]).ok().unwrap();
+
+        assert_eq!(graph.maximum_matching(), vec![ 0, 1 ])
+    }
+}
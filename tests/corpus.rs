@@ -0,0 +1,119 @@
+// Runs maximum matching over the molecular graphs in tests/corpus. Each file
+// is an edge list whose header gives the expected matching size:
+//
+//     # name
+//     # maximum matching: 3
+//     0 1
+//     ...
+//
+// New cases only need a file; sizes should come from an independent
+// implementation, not from this crate.
+
+use std::fs::{ self, File };
+use std::io::{ BufRead, BufReader };
+use std::path::{ Path, PathBuf };
+
+use gamma::graph::{ Graph, DefaultGraph };
+use gamma::io::stream_edges;
+use gamma::matching::{ greedy, maximum_matching, Pairing };
+
+const EXPECTED: &str = "# maximum matching:";
+
+fn corpus() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut result = fs::read_dir(dir).expect("corpus dir")
+        .map(|entry| entry.expect("entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "edges"))
+        .collect::<Vec<_>>();
+
+    result.sort();
+
+    result
+}
+
+fn expected(path: &Path) -> usize {
+    let reader = BufReader::new(File::open(path).expect("open"));
+
+    for line in reader.lines() {
+        let line = line.expect("line");
+
+        if let Some(size) = line.strip_prefix(EXPECTED) {
+            return size.trim().parse().expect("expected size");
+        }
+    }
+
+    panic!("no expected size in {}", path.display())
+}
+
+fn read(path: &Path) -> DefaultGraph {
+    let reader = BufReader::new(File::open(path).expect("open"));
+    let mut result = DefaultGraph::new();
+
+    for edge in stream_edges(reader) {
+        let (sid, tid) = edge.expect("edge");
+
+        for &id in &[ sid, tid ] {
+            if !result.has_id(id) {
+                result.add_node(id).expect("add node");
+            }
+        }
+
+        result.add_edge(sid, tid).expect("add edge");
+    }
+
+    result
+}
+
+fn check(graph: &DefaultGraph, pairing: &Pairing) -> Result<usize, String> {
+    let mut seen = Vec::new();
+
+    for (sid, tid) in pairing.edges() {
+        if graph.has_edge(sid, tid) != Ok(true) {
+            return Err(format!("({}, {}) is not an edge", sid, tid));
+        }
+
+        if seen.contains(&sid) || seen.contains(&tid) {
+            return Err(format!("({}, {}) shares a node", sid, tid));
+        }
+
+        seen.push(sid);
+        seen.push(tid);
+    }
+
+    Ok(seen.len() / 2)
+}
+
+#[test]
+fn maximum_matching_sizes() {
+    let paths = corpus();
+    let mut failures = Vec::new();
+
+    assert!(!paths.is_empty(), "empty corpus");
+
+    for path in &paths {
+        let graph = read(path);
+        let expected = expected(path);
+        let name = path.file_stem().expect("stem").to_string_lossy();
+        let starts = vec![
+            ("empty", Pairing::new()),
+            ("greedy", greedy(&graph))
+        ];
+
+        for (start, mut pairing) in starts {
+            maximum_matching(&graph, &mut pairing);
+
+            match check(&graph, &pairing) {
+                Ok(size) if size == expected => (),
+                Ok(size) => failures.push(format!(
+                    "{} from {}: expected {}, got {}",
+                    name, start, expected, size
+                )),
+                Err(error) => failures.push(format!(
+                    "{} from {}: {}", name, start, error
+                ))
+            }
+        }
+    }
+
+    assert_eq!(failures, Vec::<String>::new())
+}